@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{hash::Hash, net::SocketAddr, time::Duration};
 
 use bevy_ecs::{
     entity::Entity,
@@ -6,7 +6,8 @@ use bevy_ecs::{
 };
 
 use naia_bevy_shared::{
-    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message, Tick,
+    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message,
+    MessageHandle, Request, ResponseError, ResponseHandle, ResponseSendKey, Tick,
 };
 use naia_client::{shared::SocketConfig, transport::Socket, Client as NaiaClient, NaiaClientError};
 
@@ -25,6 +26,10 @@ impl<'w> Client<'w> {
         self.client.auth(auth);
     }
 
+    pub fn connect_token(&mut self, connect_token: Vec<u8>) {
+        self.client.connect_token(connect_token);
+    }
+
     pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
         self.client.connect(socket);
     }
@@ -59,14 +64,57 @@ impl<'w> Client<'w> {
     }
 
     //// Messages ////
-    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) {
-        self.client.send_message::<C, M>(message);
+    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) -> Option<MessageHandle> {
+        self.client.send_message::<C, M>(message)
+    }
+
+    pub fn send_message_with_ttl<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+        ttl: Duration,
+    ) -> Option<MessageHandle> {
+        self.client.send_message_with_ttl::<C, M>(message, ttl)
+    }
+
+    pub fn send_message_keyed<C: Channel, M: Message>(
+        &mut self,
+        key: &impl Hash,
+        message: &M,
+    ) -> Option<MessageHandle> {
+        self.client.send_message_keyed::<C, M>(key, message)
     }
 
     pub fn send_tick_buffer_message<C: Channel, M: Message>(&mut self, tick: &Tick, message: &M) {
         self.client.send_tick_buffer_message::<C, M>(tick, message);
     }
 
+    //// Requests ////
+    pub fn send_request<C: Channel, Q: Request>(
+        &mut self,
+        request: &Q,
+    ) -> ResponseHandle<Q::Response> {
+        self.client.send_request::<C, Q>(request)
+    }
+
+    pub fn receive_response<R: Message>(
+        &mut self,
+        response_handle: &ResponseHandle<R>,
+    ) -> Option<Result<R, ResponseError>> {
+        self.client.receive_response(response_handle)
+    }
+
+    pub fn cancel_request<R: Message>(&mut self, response_handle: ResponseHandle<R>) {
+        self.client.cancel_request(response_handle);
+    }
+
+    pub fn send_response<R: Message>(
+        &mut self,
+        response_key: &ResponseSendKey<R>,
+        response: &R,
+    ) -> bool {
+        self.client.send_response(response_key, response)
+    }
+
     //// Ticks ////
 
     pub fn client_tick(&self) -> Option<Tick> {
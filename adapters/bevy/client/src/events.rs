@@ -2,20 +2,27 @@ use std::{any::Any, collections::HashMap};
 
 use bevy_ecs::entity::Entity;
 
-use naia_client::{Events, NaiaClientError};
+use naia_client::{
+    shared::{DisconnectReason, RejectReason},
+    Events, NaiaClientError,
+};
 
 use naia_bevy_shared::{
-    Channel, ChannelKind, ComponentKind, Message, MessageContainer, MessageKind, Replicate, Tick,
+    Channel, ChannelKind, ComponentKind, Message, MessageContainer, MessageHandle, MessageKind,
+    Replicate, Request, ResponseSendKey, Tick,
 };
 
 // ConnectEvent
 pub struct ConnectEvent;
 
 // DisconnectEvent
-pub struct DisconnectEvent;
+pub struct DisconnectEvent(pub DisconnectReason);
 
 // RejectEvent
-pub struct RejectEvent;
+pub struct RejectEvent(pub RejectReason);
+
+// QueuePositionEvent
+pub struct QueuePositionEvent(pub u32);
 
 // ErrorEvent
 pub struct ErrorEvent(pub NaiaClientError);
@@ -56,6 +63,48 @@ impl MessageEvents {
     }
 }
 
+// MessageDeliveredEvent
+pub struct MessageDeliveredEvent(pub MessageHandle);
+
+// MessageExpiredEvent
+pub struct MessageExpiredEvent(pub MessageHandle);
+
+// RequestEvents
+pub struct RequestEvents {
+    inner: HashMap<ChannelKind, HashMap<MessageKind, Vec<(u64, MessageContainer)>>>,
+}
+
+impl From<&mut Events<Entity>> for RequestEvents {
+    fn from(events: &mut Events<Entity>) -> Self {
+        Self {
+            inner: events.take_requests(),
+        }
+    }
+}
+
+impl RequestEvents {
+    pub fn read<C: Channel, Q: Request>(&self) -> Vec<(ResponseSendKey<Q::Response>, Q)> {
+        let mut output = Vec::new();
+
+        let channel_kind = ChannelKind::of::<C>();
+        if let Some(request_map) = self.inner.get(&channel_kind) {
+            let message_kind = MessageKind::of::<Q>();
+            if let Some(requests) = request_map.get(&message_kind) {
+                for (response_key, boxed_request) in requests {
+                    let boxed_any = boxed_request.clone().to_boxed_any();
+                    let request: Q = Box::<dyn Any + 'static>::downcast::<Q>(boxed_any)
+                        .ok()
+                        .map(|boxed_q| *boxed_q)
+                        .unwrap();
+                    output.push((ResponseSendKey::new(*response_key), request));
+                }
+            }
+        }
+
+        output
+    }
+}
+
 // ClientTickEvent
 pub struct ClientTickEvent(pub Tick);
 
@@ -9,7 +9,8 @@ use naia_client::{Client, ClientConfig};
 use super::{
     events::{
         ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
+        InsertComponentEvents, MessageDeliveredEvent, MessageEvents, MessageExpiredEvent,
+        QueuePositionEvent, RejectEvent, RemoveComponentEvents, RequestEvents, ServerTickEvent,
         SpawnEntityEvent, UpdateComponentEvents,
     },
     systems::before_receive_events,
@@ -61,10 +62,14 @@ impl PluginType for Plugin {
             .add_event::<ConnectEvent>()
             .add_event::<DisconnectEvent>()
             .add_event::<RejectEvent>()
+            .add_event::<QueuePositionEvent>()
             .add_event::<ErrorEvent>()
             .add_event::<ClientTickEvent>()
             .add_event::<ServerTickEvent>()
             .add_event::<MessageEvents>()
+            .add_event::<MessageDeliveredEvent>()
+            .add_event::<MessageExpiredEvent>()
+            .add_event::<RequestEvents>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
             .add_event::<InsertComponentEvents>()
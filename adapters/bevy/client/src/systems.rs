@@ -14,14 +14,16 @@ use crate::ServerOwned;
 mod naia_events {
     pub use naia_client::{
         ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        RejectEvent, ServerTickEvent, SpawnEntityEvent,
+        MessageDeliveredEvent, MessageExpiredEvent, QueuePositionEvent, RejectEvent,
+        ServerTickEvent, SpawnEntityEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
         ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
+        InsertComponentEvents, MessageDeliveredEvent, MessageEvents, MessageExpiredEvent,
+        QueuePositionEvent, RejectEvent, RemoveComponentEvents, RequestEvents, ServerTickEvent,
         SpawnEntityEvent, UpdateComponentEvents,
     };
 }
@@ -71,8 +73,8 @@ pub fn before_receive_events(world: &mut World) {
                 let mut disconnect_event_writer = world
                     .get_resource_mut::<Events<bevy_events::DisconnectEvent>>()
                     .unwrap();
-                for _ in events.read::<naia_events::DisconnectEvent>() {
-                    disconnect_event_writer.send(bevy_events::DisconnectEvent);
+                for (_, reason) in events.read::<naia_events::DisconnectEvent>() {
+                    disconnect_event_writer.send(bevy_events::DisconnectEvent(reason));
                 }
             }
 
@@ -81,8 +83,18 @@ pub fn before_receive_events(world: &mut World) {
                 let mut reject_event_writer = world
                     .get_resource_mut::<Events<bevy_events::RejectEvent>>()
                     .unwrap();
-                for _ in events.read::<naia_events::RejectEvent>() {
-                    reject_event_writer.send(bevy_events::RejectEvent);
+                for (_, reason) in events.read::<naia_events::RejectEvent>() {
+                    reject_event_writer.send(bevy_events::RejectEvent(reason));
+                }
+            }
+
+            // Queue Position Event
+            if events.has::<naia_events::QueuePositionEvent>() {
+                let mut queue_position_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::QueuePositionEvent>>()
+                    .unwrap();
+                for position in events.read::<naia_events::QueuePositionEvent>() {
+                    queue_position_event_writer.send(bevy_events::QueuePositionEvent(position));
                 }
             }
 
@@ -124,6 +136,36 @@ pub fn before_receive_events(world: &mut World) {
                 message_event_writer.send(bevy_events::MessageEvents::from(&mut events));
             }
 
+            // Message Delivered Event
+            if events.has::<naia_events::MessageDeliveredEvent>() {
+                let mut message_delivered_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::MessageDeliveredEvent>>()
+                    .unwrap();
+                for message_handle in events.read::<naia_events::MessageDeliveredEvent>() {
+                    message_delivered_event_writer
+                        .send(bevy_events::MessageDeliveredEvent(message_handle));
+                }
+            }
+
+            // Message Expired Event
+            if events.has::<naia_events::MessageExpiredEvent>() {
+                let mut message_expired_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::MessageExpiredEvent>>()
+                    .unwrap();
+                for message_handle in events.read::<naia_events::MessageExpiredEvent>() {
+                    message_expired_event_writer
+                        .send(bevy_events::MessageExpiredEvent(message_handle));
+                }
+            }
+
+            // Request Event
+            if events.has_requests() {
+                let mut request_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::RequestEvents>>()
+                    .unwrap();
+                request_event_writer.send(bevy_events::RequestEvents::from(&mut events));
+            }
+
             // Spawn Entity Event
             if events.has::<naia_events::SpawnEntityEvent>() {
                 let mut spawn_entity_event_writer = world
@@ -3,15 +3,16 @@ use std::{any::Any, collections::HashMap};
 use bevy_ecs::entity::Entity;
 
 use naia_bevy_shared::{
-    Channel, ChannelKind, ComponentKind, Message, MessageContainer, MessageKind, Replicate, Tick,
+    Channel, ChannelKind, ComponentKind, ConnectToken, Message, MessageContainer, MessageHandle,
+    MessageKind, Replicate, Request, ResponseSendKey, Tick,
 };
-use naia_server::{Events, NaiaServerError, User, UserKey};
+use naia_server::{shared::DisconnectReason, Events, NaiaServerError, User, UserKey};
 
 // ConnectEvent
 pub struct ConnectEvent(pub UserKey);
 
 // DisconnectEvent
-pub struct DisconnectEvent(pub UserKey, pub User);
+pub struct DisconnectEvent(pub UserKey, pub User, pub DisconnectReason);
 
 // ErrorEvent
 pub struct ErrorEvent(pub NaiaServerError);
@@ -44,6 +45,9 @@ impl AuthEvents {
     }
 }
 
+// ConnectTokenEvent
+pub struct ConnectTokenEvent(pub UserKey, pub ConnectToken);
+
 // MessageEvents
 pub struct MessageEvents {
     inner: HashMap<ChannelKind, HashMap<MessageKind, Vec<(UserKey, MessageContainer)>>>,
@@ -71,6 +75,48 @@ impl MessageEvents {
     }
 }
 
+// MessageDeliveredEvent
+pub struct MessageDeliveredEvent(pub UserKey, pub MessageHandle);
+
+// MessageExpiredEvent
+pub struct MessageExpiredEvent(pub UserKey, pub MessageHandle);
+
+// RequestEvents
+pub struct RequestEvents {
+    inner: HashMap<ChannelKind, HashMap<MessageKind, Vec<(UserKey, u64, MessageContainer)>>>,
+}
+
+impl<E: Copy> From<&mut Events<E>> for RequestEvents {
+    fn from(events: &mut Events<E>) -> Self {
+        Self {
+            inner: events.take_requests(),
+        }
+    }
+}
+
+impl RequestEvents {
+    pub fn read<C: Channel, Q: Request>(&self) -> Vec<(UserKey, ResponseSendKey<Q::Response>, Q)> {
+        let mut output = Vec::new();
+
+        let channel_kind = ChannelKind::of::<C>();
+        if let Some(request_map) = self.inner.get(&channel_kind) {
+            let message_kind = MessageKind::of::<Q>();
+            if let Some(requests) = request_map.get(&message_kind) {
+                for (user_key, response_key, request) in requests {
+                    let request: Q =
+                        Box::<dyn Any + 'static>::downcast::<Q>(request.clone().to_boxed_any())
+                            .ok()
+                            .map(|boxed_q| *boxed_q)
+                            .unwrap();
+                    output.push((*user_key, ResponseSendKey::new(*response_key), request));
+                }
+            }
+        }
+
+        output
+    }
+}
+
 fn convert_messages<M: Message>(
     boxed_list: &Vec<(UserKey, MessageContainer)>,
 ) -> Vec<(UserKey, M)> {
@@ -8,8 +8,9 @@ use naia_server::{Server, ServerConfig};
 
 use super::{
     events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
+        AuthEvents, ConnectEvent, ConnectTokenEvent, DespawnEntityEvent, DisconnectEvent,
+        ErrorEvent, InsertComponentEvents, MessageDeliveredEvent, MessageEvents,
+        MessageExpiredEvent, RemoveComponentEvents, RequestEvents, SpawnEntityEvent, TickEvent,
         UpdateComponentEvents,
     },
     systems::before_receive_events,
@@ -63,7 +64,11 @@ impl PluginType for Plugin {
             .add_event::<ErrorEvent>()
             .add_event::<TickEvent>()
             .add_event::<MessageEvents>()
+            .add_event::<MessageDeliveredEvent>()
+            .add_event::<MessageExpiredEvent>()
+            .add_event::<RequestEvents>()
             .add_event::<AuthEvents>()
+            .add_event::<ConnectTokenEvent>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
             .add_event::<InsertComponentEvents>()
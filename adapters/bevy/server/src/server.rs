@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{hash::Hash, time::Duration};
 
 use bevy_ecs::{
     entity::Entity,
@@ -11,7 +11,8 @@ use naia_server::{
 };
 
 use naia_bevy_shared::{
-    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message, Tick,
+    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message,
+    MessageHandle, Request, ResponseError, ResponseHandle, ResponseSendKey, Tick,
 };
 
 // Server
@@ -48,10 +49,34 @@ impl<'w> Server<'w> {
     }
 
     //// Messages ////
-    pub fn send_message<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+    ) -> Option<MessageHandle> {
         self.server.send_message::<C, M>(user_key, message)
     }
 
+    pub fn send_message_with_ttl<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+        ttl: Duration,
+    ) -> Option<MessageHandle> {
+        self.server
+            .send_message_with_ttl::<C, M>(user_key, message, ttl)
+    }
+
+    pub fn send_message_keyed<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        key: &impl Hash,
+        message: &M,
+    ) -> Option<MessageHandle> {
+        self.server
+            .send_message_keyed::<C, M>(user_key, key, message)
+    }
+
     /// Sends a message to all connected users using a given channel
     pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
         self.server.broadcast_message::<C, M>(message);
@@ -61,6 +86,34 @@ impl<'w> Server<'w> {
         self.server.receive_tick_buffer_messages(tick)
     }
 
+    //// Requests ////
+    pub fn send_request<C: Channel, Q: Request>(
+        &mut self,
+        user_key: &UserKey,
+        request: &Q,
+    ) -> ResponseHandle<Q::Response> {
+        self.server.send_request::<C, Q>(user_key, request)
+    }
+
+    pub fn receive_response<R: Message>(
+        &mut self,
+        response_handle: &ResponseHandle<R>,
+    ) -> Option<Result<R, ResponseError>> {
+        self.server.receive_response(response_handle)
+    }
+
+    pub fn cancel_request<R: Message>(&mut self, response_handle: ResponseHandle<R>) {
+        self.server.cancel_request(response_handle);
+    }
+
+    pub fn send_response<R: Message>(
+        &mut self,
+        response_key: &ResponseSendKey<R>,
+        response: &R,
+    ) -> bool {
+        self.server.send_response(response_key, response)
+    }
+
     //// Updates ////
 
     pub fn scope_checks(&self) -> Vec<(RoomKey, UserKey, Entity)> {
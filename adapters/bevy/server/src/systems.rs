@@ -13,15 +13,17 @@ use crate::ClientOwned;
 
 mod naia_events {
     pub use naia_server::{
-        ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, InsertComponentEvent,
-        RemoveComponentEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
+        ConnectEvent, ConnectTokenEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
+        InsertComponentEvent, MessageDeliveredEvent, MessageExpiredEvent, RemoveComponentEvent,
+        SpawnEntityEvent, TickEvent, UpdateComponentEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
+        AuthEvents, ConnectEvent, ConnectTokenEvent, DespawnEntityEvent, DisconnectEvent,
+        ErrorEvent, InsertComponentEvents, MessageDeliveredEvent, MessageEvents,
+        MessageExpiredEvent, RemoveComponentEvents, RequestEvents, SpawnEntityEvent, TickEvent,
         UpdateComponentEvents,
     };
 }
@@ -75,8 +77,9 @@ pub fn before_receive_events(world: &mut World) {
                 let mut disconnect_event_writer = world
                     .get_resource_mut::<Events<bevy_events::DisconnectEvent>>()
                     .unwrap();
-                for (user_key, user) in events.read::<naia_events::DisconnectEvent>() {
-                    disconnect_event_writer.send(bevy_events::DisconnectEvent(user_key, user));
+                for (user_key, user, reason) in events.read::<naia_events::DisconnectEvent>() {
+                    disconnect_event_writer
+                        .send(bevy_events::DisconnectEvent(user_key, user, reason));
                 }
             }
 
@@ -109,6 +112,36 @@ pub fn before_receive_events(world: &mut World) {
                 message_event_writer.send(bevy_events::MessageEvents::from(&mut events));
             }
 
+            // Message Delivered Event
+            if events.has::<naia_events::MessageDeliveredEvent>() {
+                let mut message_delivered_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::MessageDeliveredEvent>>()
+                    .unwrap();
+                for (user_key, message_handle) in events.read::<naia_events::MessageDeliveredEvent>() {
+                    message_delivered_event_writer
+                        .send(bevy_events::MessageDeliveredEvent(user_key, message_handle));
+                }
+            }
+
+            // Message Expired Event
+            if events.has::<naia_events::MessageExpiredEvent>() {
+                let mut message_expired_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::MessageExpiredEvent>>()
+                    .unwrap();
+                for (user_key, message_handle) in events.read::<naia_events::MessageExpiredEvent>() {
+                    message_expired_event_writer
+                        .send(bevy_events::MessageExpiredEvent(user_key, message_handle));
+                }
+            }
+
+            // Request Event
+            if events.has_requests() {
+                let mut request_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::RequestEvents>>()
+                    .unwrap();
+                request_event_writer.send(bevy_events::RequestEvents::from(&mut events));
+            }
+
             // Auth Event
             if events.has_auths() {
                 let mut auth_event_writer = world
@@ -117,6 +150,17 @@ pub fn before_receive_events(world: &mut World) {
                 auth_event_writer.send(bevy_events::AuthEvents::from(&mut events));
             }
 
+            // Connect Token Event
+            if events.has::<naia_events::ConnectTokenEvent>() {
+                let mut connect_token_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::ConnectTokenEvent>>()
+                    .unwrap();
+                for (user_key, connect_token) in events.read::<naia_events::ConnectTokenEvent>() {
+                    connect_token_event_writer
+                        .send(bevy_events::ConnectTokenEvent(user_key, connect_token));
+                }
+            }
+
             // Spawn Entity Event
             if events.has::<naia_events::SpawnEntityEvent>() {
                 let mut spawn_entity_event_writer = world
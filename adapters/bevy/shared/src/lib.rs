@@ -1,14 +1,17 @@
 pub use naia_shared::{
     sequence_greater_than, BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelKind,
-    ChannelMode, ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate,
-    ConstBitLength, DiffMask, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    EntityProperty, GlobalEntity, LinkConditionerConfig, LocalEntity,
-    LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
-    MessageBevy as Message, MessageBuilder, MessageContainer, MessageKind, MessageKinds, Named,
-    OwnedBitReader, Property, PropertyMutate, PropertyMutator, Random, ReliableSettings,
-    ReplicaDynMut, ReplicaDynRef, ReplicateBevy as Replicate, ReplicateBuilder, SerdeBevy as Serde,
-    SerdeErr, Tick, TickBufferSettings, UnsignedInteger, WorldMutType, WorldRefType,
-    MTU_SIZE_BYTES,
+    ChannelMode, ChannelSchema, ComponentFieldUpdate, ComponentKind, ComponentKinds,
+    ComponentUpdate, ConnectToken, ConstBitLength, DeltaBaselines, DeltaProperty, DiffMask,
+    EntityAndGlobalEntityConverter, EntityDoesNotExistError, EntityProperty, FieldLayout,
+    GlobalEntity, LinkConditionerConfig, LocalEntity, LocalEntityAndGlobalEntityConverter,
+    LocalEntityAndGlobalEntityConverterMut, MessageBevy as Message, MessageBuilder,
+    MessageContainer, MessageHandle, MessageKind, MessageKinds, Named, NormalizedVec2,
+    NormalizedVec3, OwnedBitReader, Property, PropertyMutate, PropertyMutator, ProtocolSchema,
+    QuantizedF32, Random, ReliableSettings, ReplicaDynMut, ReplicaDynRef,
+    ReplicateBevy as Replicate, ReplicateBuilder, Request, ResponseError, ResponseHandle,
+    ResponseSendKey, SerdeBevy as Serde, SerdeErr, SignedInteger, SignedVariableInteger,
+    SmallestThreeQuat, Tick, TickBufferSettings, TypeLayout, TypeSchema, UnsignedInteger,
+    UnsignedVariableInteger, WorldMutType, WorldRefType, MTU_SIZE_BYTES,
 };
 
 mod change_detection;
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, Replicate,
+    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolSchema, Replicate,
 };
 
 use crate::{ProtocolPlugin, WorldData};
@@ -46,6 +46,11 @@ impl Protocol {
         self
     }
 
+    pub fn enable_name_based_net_ids(&mut self) -> &mut Self {
+        self.inner.enable_name_based_net_ids();
+        self
+    }
+
     pub fn rtc_endpoint(&mut self, path: String) -> &mut Self {
         self.inner.rtc_endpoint(path);
         self
@@ -93,6 +98,10 @@ impl Protocol {
         self.inner.lock();
     }
 
+    pub fn schema(&self) -> ProtocolSchema {
+        self.inner.schema()
+    }
+
     pub fn into(self) -> InnerProtocol {
         self.inner
     }
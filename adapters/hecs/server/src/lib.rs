@@ -1,5 +1,5 @@
 pub use naia_hecs_shared::{Protocol, Random, WorldProxy, WorldProxyMut, WorldWrapper};
 pub use naia_server::{
-    transport, AuthEvent, ConnectEvent, DisconnectEvent, ErrorEvent, RoomKey, Server, ServerConfig,
-    TickEvent,
+    transport, AuthEvent, ConnectEvent, ConnectTokenConfig, ConnectTokenEvent, DisconnectEvent,
+    ErrorEvent, RoomKey, Server, ServerConfig, TickEvent,
 };
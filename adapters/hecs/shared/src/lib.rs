@@ -1,12 +1,15 @@
 pub use naia_shared::{
-    BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelMode, ComponentFieldUpdate,
-    ComponentKind, ComponentKinds, ComponentUpdate, ConstBitLength, DiffMask, EntityProperty,
+    BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelMode, ChannelSchema,
+    ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate, ConnectToken,
+    ConstBitLength, DeltaBaselines, DeltaProperty, DiffMask, EntityProperty, FieldLayout,
     GlobalEntity, LinkConditionerConfig, LocalEntity, LocalEntityAndGlobalEntityConverter,
     LocalEntityAndGlobalEntityConverterMut, MessageBuilder, MessageContainer,
-    MessageHecs as Message, MessageKind, MessageKinds, Named, OwnedBitReader, Property,
-    PropertyMutate, PropertyMutator, Random, ReliableSettings, ReplicaDynMut, ReplicaDynRef,
-    ReplicateBuilder, ReplicateHecs as Replicate, SerdeErr, SerdeHecs as Serde, TickBufferSettings,
-    UnsignedInteger,
+    MessageHecs as Message, MessageKind, MessageKinds, Named, NormalizedVec2, NormalizedVec3,
+    OwnedBitReader, Property, PropertyMutate, PropertyMutator, ProtocolSchema, QuantizedF32,
+    Random, ReliableSettings, ReplicaDynMut, ReplicaDynRef, ReplicateBuilder,
+    ReplicateHecs as Replicate, SerdeErr, SerdeHecs as Serde, SignedInteger, SignedVariableInteger,
+    SmallestThreeQuat, TickBufferSettings, TypeLayout, TypeSchema, UnsignedInteger,
+    UnsignedVariableInteger,
 };
 
 mod component_access;
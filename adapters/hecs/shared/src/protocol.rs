@@ -4,8 +4,8 @@ use hecs::World;
 
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolPlugin, Replicate,
-    SocketConfig,
+    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolPlugin, ProtocolSchema,
+    Replicate, SocketConfig,
 };
 
 use crate::{WorldData, WorldWrapper};
@@ -68,6 +68,11 @@ impl Protocol {
         self
     }
 
+    pub fn enable_name_based_net_ids(&mut self) -> &mut Self {
+        self.inner.enable_name_based_net_ids();
+        self
+    }
+
     pub fn add_default_channels(&mut self) -> &mut Self {
         self.inner.add_default_channels();
         self
@@ -100,6 +105,10 @@ impl Protocol {
         self.inner.lock();
     }
 
+    pub fn schema(&self) -> ProtocolSchema {
+        self.inner.schema()
+    }
+
     pub fn build(&mut self) -> Self {
         std::mem::take(self)
     }
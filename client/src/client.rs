@@ -1,4 +1,4 @@
-use std::{hash::Hash, net::SocketAddr};
+use std::{collections::HashMap, hash::Hash, net::SocketAddr, time::Duration};
 
 use log::warn;
 
@@ -7,10 +7,13 @@ use bevy_ecs::prelude::Resource;
 
 pub use naia_shared::{
     BitReader, BitWriter, Channel, ChannelKind, ChannelKinds, ComponentKind, ConnectionConfig,
-    EntityAndGlobalEntityConverter, EntityConverter, EntityConverterMut, EntityDoesNotExistError,
-    EntityRef, FakeEntityConverter, GameInstant, GlobalEntity, Instant, Message, MessageContainer,
-    PacketType, PingIndex, Protocol, Replicate, Serde, SocketConfig, StandardHeader, Tick, Timer,
-    Timestamp, WorldMutType, WorldRefType,
+    DisconnectReason, EntityAndGlobalEntityConverter, EntityAuthAction, EntityAuthMessage,
+    EntityAuthStatus, EntityConverter, EntityConverterMut, EntityDoesNotExistError, EntityRef,
+    FakeEntityConverter, GameInstant, GlobalEntity, Instant, LocalEntity, LocalEntityConverter,
+    Message, MessageContainer, MessageHandle, OrderingKey, OutgoingRequest, PacketType, PingIndex,
+    Protocol, Replicate, Request, RequestId, ResponseError, ResponseHandle, ResponseSendKey, Serde,
+    SocketConfig, StandardHeader, SystemChannel, Tick, Timer, Timestamp, WorldMutType,
+    WorldRefType,
 };
 
 use crate::{
@@ -39,7 +42,13 @@ pub struct Client<E: Copy + Eq + Hash + Send + Sync> {
     io: Io,
     server_connection: Option<Connection<E>>,
     handshake_manager: HandshakeManager,
-    manual_disconnect: bool,
+    disconnect_reason: Option<DisconnectReason>,
+    // Requests
+    next_request_id: RequestId,
+    // Requests received from the Server, waiting to be responded to, with
+    // when they were received
+    incoming_requests: HashMap<u64, (ChannelKind, RequestId, Instant)>,
+    next_response_key: u64,
     // World
     global_world_manager: GlobalWorldManager<E>,
     // Events
@@ -56,6 +65,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             client_config.send_handshake_interval,
             client_config.ping_interval,
             client_config.handshake_pings,
+            protocol.fingerprint(),
         );
 
         let compression_config = protocol.compression.clone();
@@ -71,7 +81,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             ),
             server_connection: None,
             handshake_manager,
-            manual_disconnect: false,
+            disconnect_reason: None,
+            // Requests
+            next_request_id: RequestId::zero(),
+            incoming_requests: HashMap::new(),
+            next_response_key: 0,
             // World
             global_world_manager: GlobalWorldManager::new(),
             // Events
@@ -88,12 +102,29 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             ));
     }
 
+    /// Set the [`ConnectToken`](naia_shared::ConnectToken) to use when
+    /// setting up a connection with the Server. This is the signed token
+    /// issued by the backend, passed on as is.
+    pub fn connect_token(&mut self, connect_token: Vec<u8>) {
+        self.handshake_manager.set_connect_token(connect_token);
+    }
+
     /// Connect to the given server address
     pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
         if !self.is_disconnected() {
             panic!("Client has already initiated a connection, cannot initiate a new one. TIP: Check client.is_disconnected() before calling client.connect()");
         }
         let boxed_socket: Box<dyn Socket> = socket.into();
+        if boxed_socket.requires_encryption() {
+            #[cfg(feature = "encryption")]
+            {
+                self.io.enable_encryption();
+                self.handshake_manager
+                    .enable_encryption(self.client_config.server_public_key);
+            }
+            #[cfg(not(feature = "encryption"))]
+            panic!("This Socket requires naia to encrypt packets, enable the `encryption` feature of naia-client to use it");
+        }
         let (packet_sender, packet_receiver) = boxed_socket.connect();
         self.io.load(packet_sender, packet_receiver);
     }
@@ -119,15 +150,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             panic!("Trying to disconnect Client which is not connected yet!")
         }
 
-        for _ in 0..10 {
-            let writer = self.handshake_manager.write_disconnect();
-            if self.io.send_packet(writer.to_packet()).is_err() {
-                // TODO: pass this on and handle above
-                warn!("Client Error: Cannot send disconnect packet to Server");
-            }
-        }
-
-        self.manual_disconnect = true;
+        self.send_disconnect(&DisconnectReason::ClientQuit);
+        self.disconnect_reason = Some(DisconnectReason::ClientQuit);
     }
 
     /// Returns socket config
@@ -147,8 +171,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
         // all other operations
         if let Some(connection) = self.server_connection.as_mut() {
-            if connection.base.should_drop() || self.manual_disconnect {
-                self.disconnect_with_events(&mut world);
+            if connection.base.should_drop() && self.disconnect_reason.is_none() {
+                self.disconnect_reason = Some(DisconnectReason::Timeout);
+            }
+            if let Some(reason) = self.disconnect_reason.take() {
+                self.disconnect_with_events(&mut world, reason);
                 return std::mem::take(&mut self.incoming_events);
             }
 
@@ -161,18 +188,52 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                     .read_buffered_packets(&self.protocol, &mut self.global_world_manager)
                     .is_err()
                 {
-                    // TODO: Except for cosmic radiation .. Server should never send a malformed packet .. handle this
+                    // Except for cosmic radiation .. Server should never send a malformed
+                    // packet, so it isn't speaking the same protocol
                     warn!("Error reading from buffered packet!");
+                    self.send_disconnect(&DisconnectReason::ProtocolError);
+                    self.disconnect_with_events(&mut world, DisconnectReason::ProtocolError);
+                    return std::mem::take(&mut self.incoming_events);
                 }
 
                 // receive packets, process into events
                 connection.process_packets(
+                    &self.protocol,
                     &mut self.global_world_manager,
-                    &self.protocol.component_kinds,
                     &mut world,
                     &mut self.incoming_events,
                 );
 
+                // receive requests, which are answered through a response key.
+                // Those the Server has given up on are dropped
+                let connection_config = &self.client_config.connection;
+                self.incoming_requests
+                    .retain(|_, (_, _, received_instant)| {
+                        received_instant.elapsed() < connection_config.request_timeout
+                    });
+                for (channel_kind, request_id, request) in
+                    connection.base.message_manager.take_incoming_requests()
+                {
+                    if self.incoming_requests.len() >= connection_config.max_incoming_requests {
+                        warn!("Dropping Request from the Server, as too many are unanswered");
+                        continue;
+                    }
+                    let response_key = self.next_response_key;
+                    self.next_response_key = self.next_response_key.wrapping_add(1);
+                    self.incoming_requests
+                        .insert(response_key, (channel_kind, request_id, Instant::now()));
+                    self.incoming_events
+                        .push_request(&channel_kind, response_key, request);
+                }
+
+                // receive delivery notifications for sent messages
+                for message_handle in connection.base.message_manager.take_delivered_messages() {
+                    self.incoming_events.push_message_delivery(message_handle);
+                }
+                for message_handle in connection.base.message_manager.take_expired_messages() {
+                    self.incoming_events.push_message_expiry(message_handle);
+                }
+
                 let mut index_tick = prev_receiving_tick.wrapping_add(1);
                 loop {
                     self.incoming_events.push_server_tick(index_tick);
@@ -217,13 +278,58 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     // Messages
 
-    /// Queues up an Message to be sent to the Server
-    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) {
+    /// Queues up an Message to be sent to the Server. Returns a handle which
+    /// a [`MessageDeliveredEvent`](crate::MessageDeliveredEvent) gives back
+    /// once the Server has received a Message sent through a reliable
+    /// Channel, or None if there is no connection to send it through.
+    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) -> Option<MessageHandle> {
+        let cloned_message = M::clone_box(message);
+        self.send_message_inner(&ChannelKind::of::<C>(), cloned_message, None, None)
+    }
+
+    /// Queues up an Message to be sent to the Server like `send_message()`,
+    /// but with a key. On an OrderedReliable Channel it is only kept in order
+    /// with other Messages sent with the same key, so that a lost Message only
+    /// holds back those after it which share its key. On an
+    /// UnreliableLatestByKey Channel it replaces any unsent Message with the
+    /// same key, and is dropped if a newer one with that key was received.
+    /// Keys must come from a bounded set, as an OrderedReliable Channel
+    /// panics once Messages are sent with more than
+    /// [`MAX_ORDERING_KEYS`](naia_shared::MAX_ORDERING_KEYS) of them.
+    pub fn send_message_keyed<C: Channel, M: Message>(
+        &mut self,
+        key: &impl Hash,
+        message: &M,
+    ) -> Option<MessageHandle> {
+        let cloned_message = M::clone_box(message);
+        self.send_message_inner(
+            &ChannelKind::of::<C>(),
+            cloned_message,
+            None,
+            Some(OrderingKey::new(key)),
+        )
+    }
+
+    /// Queues up an Message to be sent to the Server like `send_message()`,
+    /// but which stops being resent once it has gone undelivered for `ttl`. A
+    /// [`MessageExpiredEvent`](crate::MessageExpiredEvent) then gives back its
+    /// handle. The Channel must be UnorderedReliable or SequencedReliable.
+    pub fn send_message_with_ttl<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+        ttl: Duration,
+    ) -> Option<MessageHandle> {
         let cloned_message = M::clone_box(message);
-        self.send_message_inner(&ChannelKind::of::<C>(), cloned_message);
+        self.send_message_inner(&ChannelKind::of::<C>(), cloned_message, Some(ttl), None)
     }
 
-    fn send_message_inner(&mut self, channel_kind: &ChannelKind, message_box: Box<dyn Message>) {
+    fn send_message_inner(
+        &mut self,
+        channel_kind: &ChannelKind,
+        message_box: Box<dyn Message>,
+        ttl: Option<Duration>,
+        key: Option<OrderingKey>,
+    ) -> Option<MessageHandle> {
         let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
         if !channel_settings.can_send_to_server() {
             panic!("Cannot send message to Server on this Channel");
@@ -233,19 +339,44 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             panic!("Cannot call `Client.send_message()` on a Tick Buffered Channel, use `Client.send_tick_buffered_message()` instead");
         }
 
-        if let Some(connection) = &mut self.server_connection {
-            let mut converter = EntityConverterMut::new(
-                &self.global_world_manager,
-                &mut connection.base.local_world_manager,
-            );
-            let message = MessageContainer::from_write(message_box, &mut converter);
-            connection.base.message_manager.send_message(
+        if ttl.is_some() && !channel_settings.can_expire_messages() {
+            panic!("Messages can only be given a time-to-live on an UnorderedReliable or SequencedReliable Channel");
+        }
+
+        if key.is_some() && !channel_settings.keyed() {
+            panic!("Messages can only be given a key on an OrderedReliable or UnreliableLatestByKey Channel");
+        }
+
+        let connection = self.server_connection.as_mut()?;
+        let mut converter = EntityConverterMut::new(
+            &self.global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        let message = MessageContainer::from_write(message_box, &mut converter);
+        let message_manager = &mut connection.base.message_manager;
+        let message_handle = match (ttl, key) {
+            (Some(ttl), _) => message_manager.send_message_with_ttl(
                 &self.protocol.message_kinds,
                 &mut converter,
                 channel_kind,
                 message,
-            );
-        }
+                ttl,
+            ),
+            (None, Some(key)) => message_manager.send_message_keyed(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                key,
+                message,
+            ),
+            (None, None) => message_manager.send_message(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                message,
+            ),
+        };
+        Some(message_handle)
     }
 
     pub fn send_tick_buffer_message<C: Channel, M: Message>(&mut self, tick: &Tick, message: &M) {
@@ -281,6 +412,102 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         }
     }
 
+    // Requests
+
+    /// Queues up a Request to be sent to the Server, returning a handle to
+    /// receive its Response with. The Channel must be reliable & bidirectional,
+    /// as the Response is sent back through it.
+    pub fn send_request<C: Channel, Q: Request>(
+        &mut self,
+        request: &Q,
+    ) -> ResponseHandle<Q::Response> {
+        let channel_kind = ChannelKind::of::<C>();
+        let channel_settings = self.protocol.channel_kinds.channel(&channel_kind);
+        if !channel_settings.can_send_to_server() {
+            panic!("Cannot send request to Server on this Channel");
+        }
+        if !channel_settings.can_send_requests() {
+            panic!("Can only use `Client.send_request()` on a reliable, bidirectional Channel");
+        }
+
+        let request_id = self.next_request_id;
+        self.next_request_id.increment();
+
+        if let Some(connection) = &mut self.server_connection {
+            let mut converter = EntityConverterMut::new(
+                &self.global_world_manager,
+                &mut connection.base.local_world_manager,
+            );
+            let request = MessageContainer::from_write(Q::clone_box(request), &mut converter);
+            connection.base.message_manager.send_request(
+                &self.protocol.message_kinds,
+                &mut converter,
+                &channel_kind,
+                request,
+                OutgoingRequest::of::<Q>(request_id, self.client_config.connection.request_timeout),
+            );
+        }
+
+        ResponseHandle::new(request_id)
+    }
+
+    /// Takes the Response to a Request sent to the Server, if it has arrived.
+    /// Returns an error if the Request timed out, or was sent over a
+    /// connection which has since closed.
+    pub fn receive_response<R: Message>(
+        &mut self,
+        response_handle: &ResponseHandle<R>,
+    ) -> Option<Result<R, ResponseError>> {
+        let Some(connection) = &mut self.server_connection else {
+            return Some(Err(ResponseError::Disconnected));
+        };
+        let outcome = connection
+            .base
+            .message_manager
+            .receive_response(&response_handle.request_id())?;
+        Some(outcome.map(|response| *response.to_boxed_any().downcast::<R>().unwrap()))
+    }
+
+    /// Stops waiting for the Response to a Request sent to the Server
+    pub fn cancel_request<R: Message>(&mut self, response_handle: ResponseHandle<R>) {
+        if let Some(connection) = &mut self.server_connection {
+            connection
+                .base
+                .message_manager
+                .cancel_request(&response_handle.request_id());
+        }
+    }
+
+    /// Queues up the Response to a Request received from the Server. Returns
+    /// false if the Request has already been responded to, or was received
+    /// over a connection which has since closed.
+    pub fn send_response<R: Message>(
+        &mut self,
+        response_key: &ResponseSendKey<R>,
+        response: &R,
+    ) -> bool {
+        let Some((channel_kind, request_id, _)) = self.incoming_requests.remove(&response_key.id())
+        else {
+            return false;
+        };
+        let Some(connection) = &mut self.server_connection else {
+            return false;
+        };
+        let mut converter = EntityConverterMut::new(
+            &self.global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        let response = MessageContainer::from_write(R::clone_box(response), &mut converter);
+        connection.base.message_manager.send_response(
+            &self.protocol.message_kinds,
+            &mut converter,
+            &channel_kind,
+            request_id,
+            response,
+        );
+        true
+    }
+
     // Entities
 
     pub fn enable_replication(&mut self, entity: &E) {
@@ -327,7 +554,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
     /// Panics if the Entity does not exist.
     pub fn entity<W: WorldRefType<E>>(&self, world: W, entity: &E) -> EntityRef<E, W> {
         if world.has_entity(entity) {
-            return EntityRef::new(world, entity);
+            let auth_status = self.global_world_manager.entity_auth_status(entity);
+            return EntityRef::with_auth_status(world, entity, auth_status);
         }
         panic!("No Entity exists for given Key!");
     }
@@ -355,6 +583,67 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         return EntityOwner::Local;
     }
 
+    // Authority
+
+    /// Gets the status of this Client's authority over a Server Entity.
+    /// Returns None if the Entity is not owned by the Server.
+    pub fn entity_auth_status(&self, entity: &E) -> Option<EntityAuthStatus> {
+        self.global_world_manager.entity_auth_status(entity)
+    }
+
+    /// Requests authority over a Server Entity. The Server responds with an
+    /// `EntityAuthGrantedEvent` or an `EntityAuthDeniedEvent`.
+    pub fn request_authority(&mut self, entity: &E) {
+        match self.global_world_manager.entity_auth_status(entity) {
+            Some(EntityAuthStatus::Available) | Some(EntityAuthStatus::Denied) => {}
+            Some(EntityAuthStatus::Requested) | Some(EntityAuthStatus::Granted) => {
+                return;
+            }
+            None => {
+                warn!("Can only request authority over Entities owned by the Server");
+                return;
+            }
+        }
+        self.global_world_manager
+            .set_entity_auth_status(entity, EntityAuthStatus::Requested);
+        self.send_entity_auth_message(entity, EntityAuthAction::Request);
+    }
+
+    /// Gives up authority over a Server Entity, or cancels a pending request
+    /// for it
+    pub fn release_authority(&mut self, entity: &E) {
+        match self.global_world_manager.entity_auth_status(entity) {
+            Some(EntityAuthStatus::Requested) | Some(EntityAuthStatus::Granted) => {}
+            Some(EntityAuthStatus::Available) | Some(EntityAuthStatus::Denied) | None => {
+                return;
+            }
+        }
+        self.global_world_manager
+            .set_entity_auth_status(entity, EntityAuthStatus::Available);
+        self.send_entity_auth_message(entity, EntityAuthAction::Release);
+    }
+
+    fn send_entity_auth_message(&mut self, entity: &E, action: EntityAuthAction) {
+        let message = EntityAuthMessage::new(&self.global_world_manager, entity, action);
+        self.send_message_inner(
+            &ChannelKind::of::<SystemChannel>(),
+            Box::new(message),
+            None,
+            None,
+        );
+    }
+
+    /// Gets the Entity identified by the given LocalEntity on the connection to
+    /// the Server, if it exists
+    pub fn local_entity_to_entity(&self, local_entity: &LocalEntity) -> Option<E> {
+        let connection = self.server_connection.as_ref()?;
+        connection
+            .base
+            .local_world_manager
+            .local_entity_to_entity(local_entity)
+            .ok()
+    }
+
     // Connection
 
     /// Get the address currently associated with the Server
@@ -378,6 +667,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             .time_manager.jitter()
     }
 
+    // Congestion
+
+    /// Gets the rate, in bytes per second, at which data is currently allowed
+    /// to be sent to the Server. Returns None if congestion control is
+    /// disabled.
+    pub fn send_rate(&self) -> Option<f32> {
+        self.server_connection
+            .as_ref()
+            .expect("it is expected that you should verify whether the client is connected before calling this method")
+            .base.send_rate()
+    }
+
     // Ticks
 
     /// Gets the current tick of the Client
@@ -557,26 +858,52 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         loop {
             match self.io.recv_reader() {
                 Ok(Some(mut reader)) => {
-                    match self.handshake_manager.recv(&mut reader) {
-                        Some(HandshakeResult::Connected(time_manager)) => {
+                    let handshake_result = self
+                        .handshake_manager
+                        .recv(&self.protocol.message_kinds, &mut reader);
+
+                    // start encrypting packets once a PacketCipher is agreed on
+                    #[cfg(feature = "encryption")]
+                    if let Some(packet_cipher) = self.handshake_manager.take_packet_cipher() {
+                        self.io.set_packet_cipher(packet_cipher);
+                    }
+
+                    match handshake_result {
+                        Some(HandshakeResult::Connected(
+                            time_manager,
+                            connection_id,
+                            accept_message,
+                        )) => {
                             // new connect!
                             self.server_connection = Some(Connection::new(
                                 &self.client_config.connection,
                                 &self.protocol.channel_kinds,
                                 time_manager,
+                                connection_id,
                                 &self.global_world_manager,
                             ));
+                            #[cfg(feature = "encryption")]
+                            self.io.set_connection_id(connection_id);
 
                             let server_addr = self.server_address_unwrapped();
                             self.incoming_events.push_connection(&server_addr);
+                            if let Some(accept_message) = accept_message {
+                                self.incoming_events.push_accept_message(accept_message);
+                            }
                         }
-                        Some(HandshakeResult::Rejected) => {
+                        Some(HandshakeResult::Rejected(reason, reject_message)) => {
                             let server_addr = self.server_address_unwrapped();
                             self.incoming_events.clear();
-                            self.incoming_events.push_rejection(&server_addr);
+                            self.incoming_events.push_rejection(&server_addr, reason);
+                            if let Some(reject_message) = reject_message {
+                                self.incoming_events.push_reject_message(reject_message);
+                            }
                             self.disconnect_reset_connection();
                             return;
                         }
+                        Some(HandshakeResult::Queued(position)) => {
+                            self.incoming_events.push_queue_position(position);
+                        }
                         None => {}
                     }
                 }
@@ -607,8 +934,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                 Ok(Some(mut reader)) => {
                     connection.base.mark_heard();
 
-                    let header = StandardHeader::de(&mut reader)
-                        .expect("unable to parse header from incoming packet");
+                    let Ok(header) = StandardHeader::de(&mut reader) else {
+                        warn!("unable to parse header from incoming packet");
+                        continue;
+                    };
+
+                    if header.packet_type == PacketType::Disconnect {
+                        // the Server closed the connection
+                        if let Some(reason) = connection.read_disconnect(&mut reader) {
+                            self.disconnect_reason = Some(reason);
+                        }
+                        continue;
+                    }
 
                     match header.packet_type {
                         PacketType::Data
@@ -660,7 +997,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                         }
                         PacketType::Ping => {
                             let Ok(ping_index) = BaseTimeManager::read_ping(&mut reader) else {
-                                panic!("unable to read ping index");
+                                warn!("unable to read ping index");
+                                continue;
                             };
                             BaseTimeManager::send_pong(connection, &mut self.io, ping_index);
                         }
@@ -693,9 +1031,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             let mut writer = BitWriter::new();
 
             // write header
-            connection
-                .base
-                .write_outgoing_header(PacketType::Heartbeat, &mut writer);
+            connection.write_outgoing_header(PacketType::Heartbeat, &mut writer);
 
             // send packet
             if io.send_packet(writer.to_packet()).is_err() {
@@ -707,13 +1043,28 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
     }
 
     fn handle_pings(connection: &mut Connection<E>, io: &mut Io) {
-        // send pings
-        if connection.time_manager.send_ping(io) {
-            connection.base.mark_sent();
+        // send pings. These are answered by the Server without looking up the
+        // Connection, so they don't count as sent: heartbeats still let the
+        // Server find the Connection if the Client's address changes
+        connection.time_manager.send_ping(io);
+    }
+
+    // Send 10 disconnect packets, as some may be lost
+    fn send_disconnect(&mut self, reason: &DisconnectReason) {
+        for _ in 0..10 {
+            let writer = self.handshake_manager.write_disconnect(reason);
+            if self.io.send_packet(writer.to_packet()).is_err() {
+                // TODO: pass this on and handle above
+                warn!("Client Error: Cannot send disconnect packet to Server");
+            }
         }
     }
 
-    fn disconnect_with_events<W: WorldMutType<E>>(&mut self, world: &mut W) {
+    fn disconnect_with_events<W: WorldMutType<E>>(
+        &mut self,
+        world: &mut W,
+        reason: DisconnectReason,
+    ) {
         let server_addr = self.server_address_unwrapped();
 
         self.incoming_events.clear();
@@ -721,7 +1072,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         self.despawn_all_remote_entities(world);
         self.disconnect_reset_connection();
 
-        self.incoming_events.push_disconnection(&server_addr);
+        self.incoming_events
+            .push_disconnection(&server_addr, reason);
     }
 
     fn despawn_all_remote_entities<W: WorldMutType<E>>(&mut self, world: &mut W) {
@@ -741,6 +1093,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     fn disconnect_reset_connection(&mut self) {
         self.server_connection = None;
+        self.incoming_requests.clear();
 
         self.io = Io::new(
             &self.client_config.connection.bandwidth_measure_duration,
@@ -751,6 +1104,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             self.client_config.send_handshake_interval,
             self.client_config.ping_interval,
             self.client_config.handshake_pings,
+            self.protocol.fingerprint(),
         );
     }
 
@@ -1,6 +1,8 @@
 use std::{default::Default, time::Duration};
 
 use naia_shared::ConnectionConfig;
+#[cfg(feature = "encryption")]
+use naia_shared::EncryptionPublicKey;
 
 /// Contains Config properties which will be used by a Server or Client
 #[derive(Clone)]
@@ -17,6 +19,12 @@ pub struct ClientConfig {
     /// taking longer. Keep in mind that the network measurements affect how likely commands
     /// are able to arrive at the server before processing.
     pub handshake_pings: u8,
+    /// The public key the Server must present during the handshake, on
+    /// transports which require naia to encrypt packets. A Server presenting
+    /// any other key, as a man-in-the-middle would, is rejected with
+    /// `RejectReason::ServerKeyMismatch`. If None, any key is trusted
+    #[cfg(feature = "encryption")]
+    pub server_public_key: Option<EncryptionPublicKey>,
 }
 
 impl Default for ClientConfig {
@@ -26,6 +34,8 @@ impl Default for ClientConfig {
             send_handshake_interval: Duration::from_millis(250),
             ping_interval: Duration::from_secs(1),
             handshake_pings: 10,
+            #[cfg(feature = "encryption")]
+            server_public_key: None,
         }
     }
 }
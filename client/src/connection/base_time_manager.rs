@@ -64,9 +64,7 @@ impl BaseTimeManager {
         let mut writer = BitWriter::new();
 
         // write header
-        connection
-            .base
-            .write_outgoing_header(PacketType::Pong, &mut writer);
+        connection.write_outgoing_header(PacketType::Pong, &mut writer);
 
         // write index
         ping_index.ser(&mut writer);
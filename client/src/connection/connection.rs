@@ -3,9 +3,11 @@ use std::hash::Hash;
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BitReader, BitWriter, ChannelKinds, ComponentKinds, ConnectionConfig,
-    EntityConverter, EntityConverterMut, HostType, HostWorldEvents, Instant, OwnedBitReader,
-    PacketType, Protocol, Serde, SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    BaseConnection, BitReader, BitWriter, ChannelKind, ChannelKinds, ConnectionConfig,
+    DisconnectReason, EntityAuthAction, EntityAuthMessage, EntityAuthStatus, EntityConverter,
+    EntityConverterMut, HostType, HostWorldEvents, Instant, MessageContainer, OwnedBitReader,
+    PacketType, Protocol, Serde, SerdeErr, StandardHeader, SystemChannel, Tick, WorldMutType,
+    WorldRefType,
 };
 
 use crate::{
@@ -21,6 +23,9 @@ pub struct Connection<E: Copy + Eq + Hash + Send + Sync> {
     pub base: BaseConnection<E>,
     pub time_manager: TimeManager,
     pub tick_buffer: TickBufferSender,
+    /// Assigned by the Server, which uses it to find this connection if the
+    /// Client's address changes
+    connection_id: u64,
     /// Small buffer when receiving updates (entity actions, entity updates) from the server
     /// to make sure we receive them in order
     jitter_buffer: TickQueue<OwnedBitReader>,
@@ -31,13 +36,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         connection_config: &ConnectionConfig,
         channel_kinds: &ChannelKinds,
         time_manager: TimeManager,
+        connection_id: u64,
         global_world_manager: &GlobalWorldManager<E>,
     ) -> Self {
         let tick_buffer = TickBufferSender::new(channel_kinds);
 
         let mut connection = Connection {
             base: BaseConnection::new(
-                &None,
                 HostType::Client,
                 0,
                 connection_config,
@@ -46,6 +51,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             ),
             time_manager,
             tick_buffer,
+            connection_id,
             jitter_buffer: TickQueue::new(),
         };
 
@@ -108,6 +114,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 global_world_manager,
                 &mut self.base.local_world_manager,
                 protocol,
+                true,
                 server_tick,
                 &mut reader,
             )?;
@@ -116,21 +123,38 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         Ok(())
     }
 
+    /// Reads a Disconnect packet from the Server, returning why the connection
+    /// was closed if the packet is for this connection
+    pub fn read_disconnect(&self, reader: &mut BitReader) -> Option<DisconnectReason> {
+        let connection_id = u64::de(reader).ok()?;
+        if connection_id != self.connection_id {
+            return None;
+        }
+        DisconnectReason::de(reader).ok()
+    }
+
     /// Receive & process messages / entity actions / entity updates and emit events for them
     pub fn process_packets<W: WorldMutType<E>>(
         &mut self,
+        protocol: &Protocol,
         global_world_manager: &mut GlobalWorldManager<E>,
-        component_kinds: &ComponentKinds,
         world: &mut W,
         incoming_events: &mut Events<E>,
     ) {
         // Receive Message Events
         let messages = self.base.message_manager.receive_messages(
+            &protocol.message_kinds,
             global_world_manager,
             &self.base.local_world_manager,
             &mut self.base.remote_world_manager.entity_waitlist,
         );
         for (channel_kind, messages) in messages {
+            if channel_kind == ChannelKind::of::<SystemChannel>() {
+                for message in messages {
+                    Self::receive_system_message(global_world_manager, incoming_events, message);
+                }
+                continue;
+            }
             for message in messages {
                 incoming_events.push_message(&channel_kind, message);
             }
@@ -141,15 +165,116 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         let world_events = self.base.remote_world_manager.process_world_events(
             global_world_manager,
             &mut self.base.local_world_manager,
-            component_kinds,
+            &protocol.component_kinds,
             world,
             remote_events,
         );
         incoming_events.receive_world_events(world_events);
+
+        self.sync_published_components(global_world_manager, world);
+    }
+
+    /// Publishes the Components of the Server Entities this Client holds
+    /// authority over, so that changes to them are sent to the Server as
+    /// updates, and unpublishes them once authority is revoked or released
+    fn sync_published_components<W: WorldMutType<E>>(
+        &mut self,
+        global_world_manager: &mut GlobalWorldManager<E>,
+        world: &mut W,
+    ) {
+        let delegated_entities = global_world_manager.delegated_entities();
+
+        // stop sending updates for Entities which have since despawned
+        for entity in self.base.host_world_manager.delegated_entities() {
+            if !delegated_entities.contains(&entity) {
+                self.base.host_world_manager.undelegate_entity(&entity);
+            }
+        }
+
+        for entity in delegated_entities {
+            let published_kinds = global_world_manager.published_component_kinds(&entity);
+
+            if global_world_manager.entity_auth_status(&entity) == Some(EntityAuthStatus::Granted) {
+                for component_kind in world.component_kinds(&entity) {
+                    if published_kinds.contains(&component_kind) {
+                        continue;
+                    }
+                    if let Some(mut component) =
+                        world.component_mut_of_kind(&entity, &component_kind)
+                    {
+                        global_world_manager.publish_component(&entity, &mut *component);
+                        self.base
+                            .host_world_manager
+                            .delegate_component(&entity, &component_kind);
+                    }
+                }
+                // Components removed by the Server are no longer tracked
+                for component_kind in published_kinds {
+                    if !world.has_component_of_kind(&entity, &component_kind) {
+                        self.base
+                            .host_world_manager
+                            .undelegate_component(&entity, &component_kind);
+                        global_world_manager.unpublish_component(&entity, &component_kind);
+                    }
+                }
+            } else {
+                for component_kind in published_kinds {
+                    if let Some(mut component) =
+                        world.component_mut_of_kind(&entity, &component_kind)
+                    {
+                        component.unpublish();
+                    }
+                    global_world_manager.unpublish_component(&entity, &component_kind);
+                }
+                self.base.host_world_manager.undelegate_entity(&entity);
+                global_world_manager.remove_delegated_entity(&entity);
+            }
+        }
+    }
+
+    fn receive_system_message(
+        global_world_manager: &mut GlobalWorldManager<E>,
+        incoming_events: &mut Events<E>,
+        message: MessageContainer,
+    ) {
+        let Ok(message) = message.to_boxed_any().downcast::<EntityAuthMessage>() else {
+            warn!("Received unknown message on the System Channel");
+            return;
+        };
+        let Some(entity) = message.entity.get(global_world_manager) else {
+            return;
+        };
+        if global_world_manager.entity_auth_status(&entity).is_none() {
+            return;
+        }
+
+        match message.action {
+            EntityAuthAction::Grant => {
+                global_world_manager.set_entity_auth_status(&entity, EntityAuthStatus::Granted);
+                incoming_events.push_auth_grant(entity);
+            }
+            EntityAuthAction::Deny => {
+                global_world_manager.set_entity_auth_status(&entity, EntityAuthStatus::Denied);
+                incoming_events.push_auth_denial(entity);
+            }
+            EntityAuthAction::Revoke => {
+                global_world_manager.set_entity_auth_status(&entity, EntityAuthStatus::Available);
+                incoming_events.push_auth_revoke(entity);
+            }
+            EntityAuthAction::Request | EntityAuthAction::Release => {
+                warn!("Received Entity authority action meant for the Server");
+            }
+        }
     }
 
     // Outgoing data
 
+    /// Writes the header of an outgoing packet, followed by the connection id
+    pub fn write_outgoing_header(&mut self, packet_type: PacketType, writer: &mut BitWriter) {
+        self.base.write_outgoing_header(packet_type, writer);
+        self.connection_id.ser(writer);
+    }
+
     /// Collect and send any outgoing packets from client to server
     pub fn send_outgoing_packets<W: WorldRefType<E>>(
         &mut self,
@@ -166,10 +291,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             &self.time_manager.client_sending_tick,
             &self.time_manager.server_receivable_tick,
         );
-        let mut host_world_events = self
-            .base
-            .host_world_manager
-            .take_outgoing_events(now, &rtt_millis);
+        let mut host_world_events = self.base.host_world_manager.take_outgoing_events(
+            now,
+            &rtt_millis,
+            &protocol.component_kinds,
+            global_world_manager,
+            self.base.local_world_manager.get_user_key(),
+        );
 
         let mut any_sent = false;
         loop {
@@ -205,6 +333,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             || self.base.message_manager.has_outgoing_messages()
             || self.tick_buffer.has_outgoing_messages()
         {
+            // hold back what the send rate can't afford until a later tick
+            if !self.base.can_send_packet() {
+                return false;
+            }
+
             let next_packet_index = self.base.next_packet_index();
 
             let mut writer = BitWriter::new();
@@ -217,8 +350,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             writer.reserve_bits(4);
 
             // write header
-            self.base
-                .write_outgoing_header(PacketType::Data, &mut writer);
+            self.write_outgoing_header(PacketType::Data, &mut writer);
 
             // write client tick
             let client_tick: Tick = self.time_manager.client_sending_tick;
@@ -254,12 +386,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 world,
                 global_world_manager,
                 &mut has_written,
-                protocol.client_authoritative_entities,
+                true,
                 host_world_events,
             );
 
             // send packet
-            if io.send_packet(writer.to_packet()).is_err() {
+            let packet = writer.to_packet();
+            self.base.record_sent_packet(packet.slice().len());
+            if io.send_packet(packet).is_err() {
                 // TODO: pass this on and handle above
                 warn!("Client Error: Cannot send data packet to Server");
             }
@@ -3,9 +3,11 @@ use std::time::Duration;
 use log::warn;
 
 use naia_shared::{
-    BitReader, BitWriter, FakeEntityConverter, MessageContainer, MessageKinds, PacketType, Serde,
-    StandardHeader, Timer, Timestamp as stamp_time,
+    BitReader, BitWriter, DisconnectReason, FakeEntityConverter, MessageContainer, MessageKinds,
+    PacketType, RejectReason, Serde, SerdeErr, StandardHeader, Timer, Timestamp as stamp_time,
 };
+#[cfg(feature = "encryption")]
+use naia_shared::{EncryptionPublicKey, KeyExchange, PacketCipher};
 
 use super::io::Io;
 use crate::connection::{handshake_time_manager::HandshakeTimeManager, time_manager::TimeManager};
@@ -41,22 +43,40 @@ impl PartialEq for HandshakeState {
 }
 
 pub enum HandshakeResult {
-    Connected(TimeManager),
-    Rejected,
+    Connected(TimeManager, u64, Option<MessageContainer>),
+    Rejected(RejectReason, Option<MessageContainer>),
+    Queued(u32),
 }
 
 pub struct HandshakeManager {
     ping_interval: Duration,
     handshake_pings: u8,
+    protocol_fingerprint: u64,
     pub connection_state: HandshakeState,
     handshake_timer: Timer,
     pre_connection_timestamp: Timestamp,
     pre_connection_digest: Option<Vec<u8>>,
     auth_message: Option<MessageContainer>,
+    connect_token: Option<Vec<u8>>,
+    connection_id: Option<u64>,
+    queue_position: Option<u32>,
+    #[cfg(feature = "encryption")]
+    key_exchange: Option<KeyExchange>,
+    #[cfg(feature = "encryption")]
+    trusted_server_public_key: Option<EncryptionPublicKey>,
+    #[cfg(feature = "encryption")]
+    packet_cipher: Option<PacketCipher>,
+    #[cfg(feature = "encryption")]
+    sealed_validate_payload: Option<Vec<u8>>,
 }
 
 impl HandshakeManager {
-    pub fn new(send_interval: Duration, ping_interval: Duration, handshake_pings: u8) -> Self {
+    pub fn new(
+        send_interval: Duration,
+        ping_interval: Duration,
+        handshake_pings: u8,
+        protocol_fingerprint: u64,
+    ) -> Self {
         let mut handshake_timer = Timer::new(send_interval);
         handshake_timer.ring_manual();
 
@@ -68,15 +88,57 @@ impl HandshakeManager {
             pre_connection_digest: None,
             connection_state: HandshakeState::AwaitingChallengeResponse,
             auth_message: None,
+            connect_token: None,
+            connection_id: None,
+            queue_position: None,
             ping_interval,
             handshake_pings,
+            protocol_fingerprint,
+            #[cfg(feature = "encryption")]
+            key_exchange: None,
+            #[cfg(feature = "encryption")]
+            trusted_server_public_key: None,
+            #[cfg(feature = "encryption")]
+            packet_cipher: None,
+            #[cfg(feature = "encryption")]
+            sealed_validate_payload: None,
         }
     }
 
+    /// Agrees on a PacketCipher with the Server during the handshake, for
+    /// transports which do not encrypt packets themselves. If a public key is
+    /// given, a Server presenting any other key is rejected
+    #[cfg(feature = "encryption")]
+    pub fn enable_encryption(&mut self, trusted_server_public_key: Option<EncryptionPublicKey>) {
+        self.key_exchange = Some(KeyExchange::new());
+        self.trusted_server_public_key = trusted_server_public_key;
+    }
+
+    #[cfg(feature = "encryption")]
+    fn encryption_enabled(&self) -> bool {
+        self.key_exchange.is_some()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn encryption_enabled(&self) -> bool {
+        false
+    }
+
+    /// Takes the PacketCipher agreed on with the Server, once the Server's
+    /// challenge response has been received
+    #[cfg(feature = "encryption")]
+    pub fn take_packet_cipher(&mut self) -> Option<PacketCipher> {
+        self.packet_cipher.take()
+    }
+
     pub fn set_auth_message(&mut self, auth: MessageContainer) {
         self.auth_message = Some(auth);
     }
 
+    pub fn set_connect_token(&mut self, connect_token: Vec<u8>) {
+        self.connect_token = Some(connect_token);
+    }
+
     pub fn is_connected(&self) -> bool {
         self.connection_state == HandshakeState::Connected
     }
@@ -125,7 +187,11 @@ impl HandshakeManager {
     }
 
     // Call this regularly so handshake manager can process incoming requests
-    pub fn recv(&mut self, reader: &mut BitReader) -> Option<HandshakeResult> {
+    pub fn recv(
+        &mut self,
+        message_kinds: &MessageKinds,
+        reader: &mut BitReader,
+    ) -> Option<HandshakeResult> {
         let header_result = StandardHeader::de(reader);
         if header_result.is_err() {
             return None;
@@ -133,8 +199,7 @@ impl HandshakeManager {
         let header = header_result.unwrap();
         match header.packet_type {
             PacketType::ServerChallengeResponse => {
-                self.recv_challenge_response(reader);
-                return None;
+                return self.recv_challenge_response(message_kinds, reader);
             }
             PacketType::ServerValidateResponse => {
                 if self.connection_state == HandshakeState::AwaitingValidateResponse {
@@ -143,10 +208,19 @@ impl HandshakeManager {
                 return None;
             }
             PacketType::ServerConnectResponse => {
-                return self.recv_connect_response();
+                return self.recv_connect_response(message_kinds, reader);
+            }
+            PacketType::ServerQueueResponse => {
+                return self.recv_queue_response(reader);
             }
             PacketType::ServerRejectResponse => {
-                return Some(HandshakeResult::Rejected);
+                let Ok(reason) = RejectReason::de(reader) else {
+                    return None;
+                };
+                let Ok(reject_message) = Self::read_response_message(message_kinds, reader) else {
+                    return None;
+                };
+                return Some(HandshakeResult::Rejected(reason, reject_message));
             }
             PacketType::Pong => {
                 // Time Manager should record incoming Pongs in order to sync time
@@ -186,30 +260,62 @@ impl HandshakeManager {
         StandardHeader::new(PacketType::ClientChallengeRequest, 0, 0, 0).ser(&mut writer);
 
         self.pre_connection_timestamp.ser(&mut writer);
+        self.protocol_fingerprint.ser(&mut writer);
 
         writer
     }
 
     // Step 2 of Handshake
-    pub fn recv_challenge_response(&mut self, reader: &mut BitReader) {
+    pub fn recv_challenge_response(
+        &mut self,
+        message_kinds: &MessageKinds,
+        reader: &mut BitReader,
+    ) -> Option<HandshakeResult> {
         if self.connection_state == HandshakeState::AwaitingChallengeResponse {
             let timestamp_result = Timestamp::de(reader);
             if timestamp_result.is_err() {
-                return;
+                return None;
             }
             let timestamp = timestamp_result.unwrap();
 
             if self.pre_connection_timestamp == timestamp {
                 let digest_bytes_result = Vec::<u8>::de(reader);
                 if digest_bytes_result.is_err() {
-                    return;
+                    return None;
                 }
                 let digest_bytes = digest_bytes_result.unwrap();
+
+                // the Server sends its public key if packets must be encrypted
+                let Ok(server_public_key) = Option::<[u8; 32]>::de(reader) else {
+                    return None;
+                };
+                if server_public_key.is_some() != self.encryption_enabled() {
+                    return None;
+                }
+                #[cfg(feature = "encryption")]
+                if let Some(server_public_key) = server_public_key {
+                    if self
+                        .trusted_server_public_key
+                        .is_some_and(|trusted_key| trusted_key != server_public_key)
+                    {
+                        return Some(HandshakeResult::Rejected(
+                            RejectReason::ServerKeyMismatch,
+                            None,
+                        ));
+                    }
+                    if !self.agree_packet_cipher(message_kinds, &server_public_key) {
+                        return None;
+                    }
+                }
+                #[cfg(not(feature = "encryption"))]
+                let _ = message_kinds;
+
                 self.pre_connection_digest = Some(digest_bytes);
 
                 self.connection_state = HandshakeState::AwaitingValidateResponse;
             }
         }
+        None
     }
 
     // Step 3 of Handshake
@@ -221,17 +327,19 @@ impl HandshakeManager {
         // write timestamp & digest into payload
         self.write_signed_timestamp(&mut writer);
 
-        // write auth message if there is one
-        if let Some(auth_message) = &self.auth_message {
-            // write that we have auth
-            true.ser(&mut writer);
-            // write payload
-            auth_message.write(message_kinds, &mut writer, &mut FakeEntityConverter);
-        } else {
-            // write that we do not have auth
-            false.ser(&mut writer);
+        // write public key & sealed auth message, if packets must be encrypted
+        #[cfg(feature = "encryption")]
+        if let (Some(key_exchange), Some(sealed_payload)) =
+            (&self.key_exchange, &self.sealed_validate_payload)
+        {
+            Some(key_exchange.public_key()).ser(&mut writer);
+            sealed_payload.ser(&mut writer);
+            return writer;
         }
 
+        None::<[u8; 32]>.ser(&mut writer);
+        self.write_auth_message(message_kinds, &mut writer);
+
         writer
     }
 
@@ -252,24 +360,114 @@ impl HandshakeManager {
     }
 
     // Step 6 of Handshake
-    fn recv_connect_response(&mut self) -> Option<HandshakeResult> {
+    fn recv_connect_response(
+        &mut self,
+        message_kinds: &MessageKinds,
+        reader: &mut BitReader,
+    ) -> Option<HandshakeResult> {
+        // the Server assigns an id to the connection, sent with each packet
+        let Ok(connection_id) = u64::de(reader) else {
+            return None;
+        };
+        let Ok(accept_message) = Self::read_response_message(message_kinds, reader) else {
+            return None;
+        };
+
         let HandshakeState::AwaitingConnectResponse(time_manager) = std::mem::replace(&mut self.connection_state, HandshakeState::Connected) else {
             return None;
         };
+        self.connection_id = Some(connection_id);
 
-        return Some(HandshakeResult::Connected(time_manager));
+        return Some(HandshakeResult::Connected(
+            time_manager,
+            connection_id,
+            accept_message,
+        ));
+    }
+
+    // Sent by the Server instead of the connect response while it is full.
+    // Returns the position in the join queue, if it has changed
+    fn recv_queue_response(&mut self, reader: &mut BitReader) -> Option<HandshakeResult> {
+        if !matches!(
+            self.connection_state,
+            HandshakeState::AwaitingConnectResponse(_)
+        ) {
+            return None;
+        }
+        let position = u32::de(reader).ok()?;
+        if self.queue_position == Some(position) {
+            return None;
+        }
+        self.queue_position = Some(position);
+        Some(HandshakeResult::Queued(position))
+    }
+
+    // Reads the Message the Server attached to its accept / reject response,
+    // if there is one
+    fn read_response_message(
+        message_kinds: &MessageKinds,
+        reader: &mut BitReader,
+    ) -> Result<Option<MessageContainer>, SerdeErr> {
+        if !bool::de(reader)? {
+            return Ok(None);
+        }
+        Ok(Some(message_kinds.read(reader, &FakeEntityConverter)?))
     }
 
     // Send 10 disconnect packets
-    pub fn write_disconnect(&self) -> BitWriter {
+    pub fn write_disconnect(&self, reason: &DisconnectReason) -> BitWriter {
         let mut writer = BitWriter::new();
         StandardHeader::new(PacketType::Disconnect, 0, 0, 0).ser(&mut writer);
+        self.connection_id
+            .expect("cannot disconnect before connecting")
+            .ser(&mut writer);
         self.write_signed_timestamp(&mut writer);
+        reason.ser(&mut writer);
         writer
     }
 
     // Private methods
 
+    fn write_auth_message(&self, message_kinds: &MessageKinds, writer: &mut BitWriter) {
+        // write auth message if there is one
+        if let Some(auth_message) = &self.auth_message {
+            // write that we have auth
+            true.ser(writer);
+            // write payload
+            auth_message.write(message_kinds, writer, &mut FakeEntityConverter);
+        } else {
+            // write that we do not have auth
+            false.ser(writer);
+        }
+
+        // write connect token if there is one
+        self.connect_token.ser(writer);
+    }
+
+    // Agrees on a PacketCipher with the Server, and seals the auth message &
+    // connect token with it. The sealed payload is kept to be resent as is, as
+    // sealing a different payload again would reuse its nonce.
+    #[cfg(feature = "encryption")]
+    fn agree_packet_cipher(
+        &mut self,
+        message_kinds: &MessageKinds,
+        server_public_key: &[u8; 32],
+    ) -> bool {
+        let Some(key_exchange) = &self.key_exchange else {
+            return false;
+        };
+        let Some(packet_cipher) = key_exchange.client_cipher(server_public_key) else {
+            return false;
+        };
+
+        let mut auth_writer = BitWriter::new();
+        self.write_auth_message(message_kinds, &mut auth_writer);
+        self.sealed_validate_payload = Some(packet_cipher.seal_handshake(&auth_writer.to_bytes()));
+        self.packet_cipher = Some(packet_cipher);
+
+        true
+    }
+
     fn write_signed_timestamp(&self, writer: &mut BitWriter) {
         self.pre_connection_timestamp.ser(writer);
         let digest: &Vec<u8> = self.pre_connection_digest.as_ref().unwrap();
@@ -103,14 +103,19 @@ impl HandshakeTimeManager {
 
         // Prune out any pong values outside the standard deviation (mitigation)
         let mut pruned_pongs = Vec::new();
-        for (time_offset_millis, rtt_millis) in pongs {
-            let offset_diff = (time_offset_millis - offset_mean).abs();
-            let rtt_diff = (rtt_millis - rtt_mean).abs();
-            if offset_diff < offset_stdv && rtt_diff < rtt_stdv {
-                pruned_pongs.push((time_offset_millis, rtt_millis));
+        for (time_offset_millis, rtt_millis) in &pongs {
+            let offset_diff = (*time_offset_millis - offset_mean).abs();
+            let rtt_diff = (*rtt_millis - rtt_mean).abs();
+            if offset_diff <= offset_stdv && rtt_diff <= rtt_stdv {
+                pruned_pongs.push((*time_offset_millis, *rtt_millis));
             }
         }
 
+        // No single pong may be within the standard deviation of both values
+        if pruned_pongs.is_empty() {
+            pruned_pongs = pongs;
+        }
+
         // Find the mean of the pruned pongs
         let pruned_sample_count = pruned_pongs.len() as f32;
         let mut pruned_offset_mean = 0.0;
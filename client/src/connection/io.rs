@@ -2,7 +2,10 @@ use std::{net::SocketAddr, time::Duration};
 
 use naia_shared::{
     BandwidthMonitor, BitReader, CompressionConfig, Decoder, Encoder, OutgoingPacket,
+    MTU_SIZE_BYTES,
 };
+#[cfg(feature = "encryption")]
+use naia_shared::{PacketCipher, PacketEncryption};
 
 use crate::{
     error::NaiaClientError,
@@ -16,6 +19,8 @@ pub struct Io {
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    #[cfg(feature = "encryption")]
+    encryption: Option<PacketEncryption>,
 }
 
 impl Io {
@@ -46,6 +51,8 @@ impl Io {
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            #[cfg(feature = "encryption")]
+            encryption: None,
         }
     }
 
@@ -66,13 +73,57 @@ impl Io {
         self.packet_sender.is_some()
     }
 
+    /// Encrypts all packets other than those of the handshake, for
+    /// transports which do not encrypt packets themselves
+    #[cfg(feature = "encryption")]
+    pub fn enable_encryption(&mut self) {
+        self.encryption = Some(PacketEncryption::new());
+    }
+
+    /// Sets the PacketCipher agreed on with the Server during the handshake
+    #[cfg(feature = "encryption")]
+    pub fn set_packet_cipher(&mut self, cipher: PacketCipher) {
+        if let Some(encryption) = &mut self.encryption {
+            encryption.set_cipher(cipher);
+        }
+    }
+
+    /// Sets the connection id assigned by the Server, sent with each sealed
+    /// packet
+    #[cfg(feature = "encryption")]
+    pub fn set_connection_id(&mut self, connection_id: u64) {
+        if let Some(encryption) = &mut self.encryption {
+            encryption.set_connection_id(connection_id);
+        }
+    }
+
     pub fn send_packet(&mut self, packet: OutgoingPacket) -> Result<(), NaiaClientError> {
         // get payload
         let mut payload = packet.slice();
 
+        // Handshake packets are sent in the clear, and left uncompressed so
+        // their type can be checked when received
+        #[cfg(feature = "encryption")]
+        let seal = self.encryption.is_some() && PacketEncryption::seals(payload);
+        #[cfg(feature = "encryption")]
+        let compress = seal || self.encryption.is_none();
+        #[cfg(not(feature = "encryption"))]
+        let compress = true;
+
         // Compression
-        if let Some(encoder) = &mut self.outgoing_encoder {
-            payload = encoder.encode(payload);
+        if compress {
+            if let Some(encoder) = &mut self.outgoing_encoder {
+                payload = encoder.encode(payload);
+            }
+        }
+
+        // Encryption
+        #[cfg(feature = "encryption")]
+        if let Some(encryption) = &mut self.encryption {
+            let Some(encrypted_payload) = encryption.encrypt(payload, seal) else {
+                return Err(NaiaClientError::SendError);
+            };
+            payload = encrypted_payload;
         }
 
         // Bandwidth monitoring
@@ -88,6 +139,11 @@ impl Io {
     }
 
     pub fn recv_reader(&mut self) -> Result<Option<BitReader>, NaiaClientError> {
+        #[cfg(feature = "encryption")]
+        if self.encryption.is_some() {
+            return self.recv_encrypted_reader();
+        }
+
         let receive_result = self
             .packet_receiver
             .as_mut()
@@ -100,9 +156,18 @@ impl Io {
                 monitor.record_packet(payload.len());
             }
 
-            // Decompression
+            // Decompression, dropping packets which can't be decompressed
+            // (the rest are received next frame)
             if let Some(decoder) = &mut self.incoming_decoder {
-                payload = decoder.decode(payload);
+                let Ok(decoded) = decoder.decode(payload) else {
+                    return Ok(None);
+                };
+                payload = decoded;
+            }
+
+            // no packet naia writes is larger than this
+            if payload.len() > MTU_SIZE_BYTES {
+                return Ok(None);
             }
 
             Ok(Some(BitReader::new(payload)))
@@ -113,6 +178,52 @@ impl Io {
         }
     }
 
+    #[cfg(feature = "encryption")]
+    fn recv_encrypted_reader(&mut self) -> Result<Option<BitReader>, NaiaClientError> {
+        let packet_receiver = self
+            .packet_receiver
+            .as_mut()
+            .expect("Cannot call Client.receive_packet() until you call Client.connect()!");
+        let encryption = self.encryption.as_mut().unwrap();
+
+        loop {
+            match packet_receiver.receive() {
+                Ok(Some(packet)) => {
+                    // Bandwidth monitoring
+                    if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+                        monitor.record_packet(packet.len());
+                    }
+
+                    // Decryption, dropping packets which can't be authenticated
+                    if encryption.decrypt(packet) {
+                        break;
+                    }
+                }
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(NaiaClientError::RecvError),
+            }
+        }
+
+        let mut payload = encryption.payload();
+
+        // Decompression, of all but the handshake packets sent in the clear
+        if encryption.was_sealed() {
+            if let Some(decoder) = &mut self.incoming_decoder {
+                let Ok(decoded) = decoder.decode(payload) else {
+                    return Ok(None);
+                };
+                payload = decoded;
+            }
+        }
+
+        // no packet naia writes is larger than this
+        if payload.len() > MTU_SIZE_BYTES {
+            return Ok(None);
+        }
+
+        Ok(Some(BitReader::new(payload)))
+    }
+
     pub fn server_addr(&self) -> Result<SocketAddr, NaiaClientError> {
         if let Some(packet_sender) = self.packet_sender.as_ref() {
             if let ServerAddr::Found(server_addr) = packet_sender.server_addr() {
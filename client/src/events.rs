@@ -1,25 +1,34 @@
 use std::{collections::HashMap, marker::PhantomData, mem, net::SocketAddr, vec::IntoIter};
 
 use naia_shared::{
-    Channel, ChannelKind, ComponentKind, EntityEvent, Message, MessageContainer, MessageKind,
-    Replicate, Tick,
+    Channel, ChannelKind, ComponentKind, DisconnectReason, EntityEvent, Message, MessageContainer,
+    MessageHandle, MessageKind, RejectReason, Replicate, Request, ResponseSendKey, Tick,
 };
 
 use crate::NaiaClientError;
 
 pub struct Events<E: Copy> {
     connections: Vec<SocketAddr>,
-    rejections: Vec<SocketAddr>,
-    disconnections: Vec<SocketAddr>,
+    rejections: Vec<(SocketAddr, RejectReason)>,
+    queue_positions: Vec<u32>,
+    accept_messages: Vec<MessageContainer>,
+    reject_messages: Vec<MessageContainer>,
+    disconnections: Vec<(SocketAddr, DisconnectReason)>,
     client_ticks: Vec<Tick>,
     server_ticks: Vec<Tick>,
     errors: Vec<NaiaClientError>,
     messages: HashMap<ChannelKind, HashMap<MessageKind, Vec<MessageContainer>>>,
+    requests: HashMap<ChannelKind, HashMap<MessageKind, Vec<(u64, MessageContainer)>>>,
+    message_deliveries: Vec<MessageHandle>,
+    message_expiries: Vec<MessageHandle>,
     spawns: Vec<E>,
     despawns: Vec<E>,
     inserts: HashMap<ComponentKind, Vec<E>>,
     removes: HashMap<ComponentKind, Vec<(E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    auth_grants: Vec<E>,
+    auth_denials: Vec<E>,
+    auth_revokes: Vec<E>,
     empty: bool,
 }
 
@@ -34,16 +43,25 @@ impl<E: Copy> Events<E> {
         Self {
             connections: Vec::new(),
             rejections: Vec::new(),
+            queue_positions: Vec::new(),
+            accept_messages: Vec::new(),
+            reject_messages: Vec::new(),
             disconnections: Vec::new(),
             client_ticks: Vec::new(),
             server_ticks: Vec::new(),
             errors: Vec::new(),
             messages: HashMap::new(),
+            requests: HashMap::new(),
+            message_deliveries: Vec::new(),
+            message_expiries: Vec::new(),
             spawns: Vec::new(),
             despawns: Vec::new(),
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            auth_grants: Vec::new(),
+            auth_denials: Vec::new(),
+            auth_revokes: Vec::new(),
             empty: true,
         }
     }
@@ -70,6 +88,16 @@ impl<E: Copy> Events<E> {
         mem::take(&mut self.messages)
     }
 
+    // This method is exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
+    pub fn has_requests(&self) -> bool {
+        !self.requests.is_empty()
+    }
+    pub fn take_requests(
+        &mut self,
+    ) -> HashMap<ChannelKind, HashMap<MessageKind, Vec<(u64, MessageContainer)>>> {
+        mem::take(&mut self.requests)
+    }
+
     // These methods are exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
     pub fn has_inserts(&self) -> bool {
         !self.inserts.is_empty()
@@ -113,13 +141,32 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
-    pub(crate) fn push_rejection(&mut self, socket_addr: &SocketAddr) {
-        self.rejections.push(*socket_addr);
+    pub(crate) fn push_rejection(&mut self, socket_addr: &SocketAddr, reason: RejectReason) {
+        self.rejections.push((*socket_addr, reason));
         self.empty = false;
     }
 
-    pub(crate) fn push_disconnection(&mut self, socket_addr: &SocketAddr) {
-        self.disconnections.push(*socket_addr);
+    pub(crate) fn push_queue_position(&mut self, position: u32) {
+        self.queue_positions.push(position);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_accept_message(&mut self, message: MessageContainer) {
+        self.accept_messages.push(message);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_reject_message(&mut self, message: MessageContainer) {
+        self.reject_messages.push(message);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_disconnection(
+        &mut self,
+        socket_addr: &SocketAddr,
+        reason: DisconnectReason,
+    ) {
+        self.disconnections.push((*socket_addr, reason));
         self.empty = false;
     }
 
@@ -138,6 +185,31 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_request(
+        &mut self,
+        channel_kind: &ChannelKind,
+        response_key: u64,
+        request: MessageContainer,
+    ) {
+        self.requests
+            .entry(*channel_kind)
+            .or_default()
+            .entry(request.kind())
+            .or_default()
+            .push((response_key, request));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_message_delivery(&mut self, message_handle: MessageHandle) {
+        self.message_deliveries.push(message_handle);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_message_expiry(&mut self, message_handle: MessageHandle) {
+        self.message_expiries.push(message_handle);
+        self.empty = false;
+    }
+
     pub(crate) fn push_client_tick(&mut self, tick: Tick) {
         self.client_ticks.push(tick);
         self.empty = false;
@@ -191,6 +263,21 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_auth_grant(&mut self, entity: E) {
+        self.auth_grants.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_denial(&mut self, entity: E) {
+        self.auth_denials.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_revoke(&mut self, entity: E) {
+        self.auth_revokes.push(entity);
+        self.empty = false;
+    }
+
     pub(crate) fn receive_world_events(&mut self, entity_events: Vec<EntityEvent<E>>) {
         for event in entity_events {
             match event {
@@ -216,16 +303,25 @@ impl<E: Copy> Events<E> {
     pub(crate) fn clear(&mut self) {
         self.connections.clear();
         self.rejections.clear();
+        self.queue_positions.clear();
+        self.accept_messages.clear();
+        self.reject_messages.clear();
         self.disconnections.clear();
         self.client_ticks.clear();
         self.server_ticks.clear();
         self.errors.clear();
         self.messages.clear();
+        self.requests.clear();
+        self.message_deliveries.clear();
+        self.message_expiries.clear();
         self.spawns.clear();
         self.despawns.clear();
         self.inserts.clear();
         self.removes.clear();
         self.updates.clear();
+        self.auth_grants.clear();
+        self.auth_denials.clear();
+        self.auth_revokes.clear();
         self.empty = true;
     }
 }
@@ -257,7 +353,7 @@ impl<E: Copy> Event<E> for ConnectEvent {
 // RejectEvent
 pub struct RejectEvent;
 impl<E: Copy> Event<E> for RejectEvent {
-    type Iter = IntoIter<SocketAddr>;
+    type Iter = IntoIter<(SocketAddr, RejectReason)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let list = std::mem::take(&mut events.rejections);
@@ -269,10 +365,80 @@ impl<E: Copy> Event<E> for RejectEvent {
     }
 }
 
+// QueuePositionEvent
+pub struct QueuePositionEvent;
+impl<E: Copy> Event<E> for QueuePositionEvent {
+    type Iter = IntoIter<u32>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.queue_positions);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.queue_positions.is_empty()
+    }
+}
+
+// AcceptMessageEvent
+pub struct AcceptMessageEvent<M: Message> {
+    phantom_m: PhantomData<M>,
+}
+impl<E: Copy, M: Message> Event<E> for AcceptMessageEvent<M> {
+    type Iter = IntoIter<M>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        take_response_messages(&mut events.accept_messages)
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        has_response_message::<M>(&events.accept_messages)
+    }
+}
+
+// RejectMessageEvent
+pub struct RejectMessageEvent<M: Message> {
+    phantom_m: PhantomData<M>,
+}
+impl<E: Copy, M: Message> Event<E> for RejectMessageEvent<M> {
+    type Iter = IntoIter<M>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        take_response_messages(&mut events.reject_messages)
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        has_response_message::<M>(&events.reject_messages)
+    }
+}
+
+// Takes the Messages of type M the Server attached to its accept / reject
+// responses, leaving the others
+fn take_response_messages<M: Message>(messages: &mut Vec<MessageContainer>) -> IntoIter<M> {
+    let message_kind: MessageKind = MessageKind::of::<M>();
+    let mut output_list: Vec<M> = Vec::new();
+    for message in std::mem::take(messages) {
+        if message.kind() == message_kind {
+            let message = message.to_boxed_any().downcast::<M>().unwrap();
+            output_list.push(*message);
+        } else {
+            messages.push(message);
+        }
+    }
+    return IntoIterator::into_iter(output_list);
+}
+
+fn has_response_message<M: Message>(messages: &[MessageContainer]) -> bool {
+    let message_kind: MessageKind = MessageKind::of::<M>();
+    messages
+        .iter()
+        .any(|message| message.kind() == message_kind)
+}
+
 // DisconnectEvent
 pub struct DisconnectEvent;
 impl<E: Copy> Event<E> for DisconnectEvent {
-    type Iter = IntoIter<SocketAddr>;
+    type Iter = IntoIter<(SocketAddr, DisconnectReason)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let list = std::mem::take(&mut events.disconnections);
@@ -366,6 +532,73 @@ impl<E: Copy, C: Channel, M: Message> Event<E> for MessageEvent<C, M> {
     }
 }
 
+// Message Delivered Event
+pub struct MessageDeliveredEvent;
+impl<E: Copy> Event<E> for MessageDeliveredEvent {
+    type Iter = IntoIter<MessageHandle>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.message_deliveries);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.message_deliveries.is_empty()
+    }
+}
+
+// Message Expired Event
+pub struct MessageExpiredEvent;
+impl<E: Copy> Event<E> for MessageExpiredEvent {
+    type Iter = IntoIter<MessageHandle>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.message_expiries);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.message_expiries.is_empty()
+    }
+}
+
+// Request Event
+pub struct RequestEvent<C: Channel, Q: Request> {
+    phantom_c: PhantomData<C>,
+    phantom_q: PhantomData<Q>,
+}
+impl<E: Copy, C: Channel, Q: Request> Event<E> for RequestEvent<C, Q> {
+    type Iter = IntoIter<(ResponseSendKey<Q::Response>, Q)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let channel_kind: ChannelKind = ChannelKind::of::<C>();
+        if let Some(channel_map) = events.requests.get_mut(&channel_kind) {
+            let message_kind: MessageKind = MessageKind::of::<Q>();
+            if let Some(boxed_list) = channel_map.remove(&message_kind) {
+                let mut output_list: Vec<(ResponseSendKey<Q::Response>, Q)> = Vec::new();
+
+                for (response_key, boxed_request) in boxed_list {
+                    let boxed_any = boxed_request.to_boxed_any();
+                    let request = boxed_any.downcast::<Q>().unwrap();
+                    output_list.push((ResponseSendKey::new(response_key), *request));
+                }
+
+                return IntoIterator::into_iter(output_list);
+            }
+        }
+        return IntoIterator::into_iter(Vec::new());
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let channel_kind: ChannelKind = ChannelKind::of::<C>();
+        if let Some(channel_map) = events.requests.get(&channel_kind) {
+            let message_kind: MessageKind = MessageKind::of::<Q>();
+            return channel_map.contains_key(&message_kind);
+        }
+        return false;
+    }
+}
+
 // Spawn Event
 pub struct SpawnEntityEvent;
 impl<E: Copy> Event<E> for SpawnEntityEvent {
@@ -396,6 +629,51 @@ impl<E: Copy> Event<E> for DespawnEntityEvent {
     }
 }
 
+// Entity Auth Granted Event
+pub struct EntityAuthGrantedEvent;
+impl<E: Copy> Event<E> for EntityAuthGrantedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_grants);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_grants.is_empty()
+    }
+}
+
+// Entity Auth Denied Event
+pub struct EntityAuthDeniedEvent;
+impl<E: Copy> Event<E> for EntityAuthDeniedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_denials);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_denials.is_empty()
+    }
+}
+
+// Entity Auth Revoked Event
+pub struct EntityAuthRevokedEvent;
+impl<E: Copy> Event<E> for EntityAuthRevokedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_revokes);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_revokes.is_empty()
+    }
+}
+
 // Insert Event
 pub struct InsertComponentEvent<C: Replicate> {
     phantom_c: PhantomData<C>,
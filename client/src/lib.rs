@@ -16,7 +16,8 @@ extern crate cfg_if;
 pub mod transport;
 pub mod shared {
     pub use naia_shared::{
-        default_channels, sequence_greater_than, EntityRef, Random, SocketConfig, Tick,
+        default_channels, sequence_greater_than, DisconnectReason, EntityAuthStatus, EntityRef,
+        Random, RejectReason, SocketConfig, Tick,
     };
 }
 pub mod internal {
@@ -36,8 +37,10 @@ pub use client_config::ClientConfig;
 pub use command_history::CommandHistory;
 pub use error::NaiaClientError;
 pub use events::{
-    ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RejectEvent, RemoveComponentEvent, ServerTickEvent,
-    SpawnEntityEvent, UpdateComponentEvent,
+    AcceptMessageEvent, ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent,
+    EntityAuthDeniedEvent, EntityAuthGrantedEvent, EntityAuthRevokedEvent, ErrorEvent, Events,
+    InsertComponentEvent, MessageDeliveredEvent, MessageEvent, MessageExpiredEvent,
+    QueuePositionEvent, RejectEvent, RejectMessageEvent, RemoveComponentEvent, RequestEvent,
+    ServerTickEvent, SpawnEntityEvent, UpdateComponentEvent,
 };
 pub use world::entity_mut::EntityMut;
@@ -0,0 +1,115 @@
+use std::net::SocketAddr;
+
+use naia_shared::{LinkConditionerConfig, LocalTransportHub};
+
+use super::{
+    conditioner::ConditionedPacketReceiver, PacketReceiver as TransportReceiver,
+    PacketSender as TransportSender, RecvError, SendError, ServerAddr as TransportAddr,
+    Socket as TransportSocket,
+};
+
+// Socket
+pub struct Socket {
+    hub: LocalTransportHub,
+    client_addr: SocketAddr,
+    config: Option<LinkConditionerConfig>,
+}
+
+impl Socket {
+    pub fn new(hub: &LocalTransportHub, config: Option<LinkConditionerConfig>) -> Self {
+        let client_addr = hub.register_client();
+
+        return Self {
+            hub: hub.clone(),
+            client_addr,
+            config,
+        };
+    }
+
+    /// Get the (virtual) address this Socket sends from
+    pub fn client_addr(&self) -> SocketAddr {
+        self.client_addr
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let sender = Box::new(PacketSender::new(self.hub.clone(), self.client_addr));
+
+        let receiver: Box<dyn TransportReceiver> = {
+            let inner_receiver = Box::new(PacketReceiver::new(self.hub.clone(), self.client_addr));
+            if let Some(config) = &self.config {
+                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+            } else {
+                inner_receiver
+            }
+        };
+
+        return (sender, receiver);
+    }
+}
+
+// Packet Sender
+struct PacketSender {
+    hub: LocalTransportHub,
+    client_addr: SocketAddr,
+}
+
+impl PacketSender {
+    pub fn new(hub: LocalTransportHub, client_addr: SocketAddr) -> Self {
+        return Self { hub, client_addr };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Client Socket
+    fn send(&self, payload: &[u8]) -> Result<(), SendError> {
+        self.hub.send_to_server(&self.client_addr, payload);
+        return Ok(());
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        TransportAddr::Found(self.hub.server_addr())
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    hub: LocalTransportHub,
+    client_addr: SocketAddr,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(hub: LocalTransportHub, client_addr: SocketAddr) -> Self {
+        return Self {
+            hub,
+            client_addr,
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Client Socket
+    fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        match self.hub.recv_on_client(&self.client_addr) {
+            Some(payload) => {
+                self.last_payload = Some(payload);
+                Ok(Some(self.last_payload.as_ref().unwrap()))
+            }
+            None => Ok(None),
+        }
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        TransportAddr::Found(self.hub.server_addr())
+    }
+}
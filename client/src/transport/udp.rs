@@ -62,6 +62,10 @@ impl TransportSocket for Socket {
 
         return (sender, receiver);
     }
+
+    fn requires_encryption(&self) -> bool {
+        true
+    }
 }
 
 // Packet Sender
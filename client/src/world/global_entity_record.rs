@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use naia_shared::{ComponentKind, GlobalEntity};
+use naia_shared::{ComponentKind, EntityAuthStatus, GlobalEntity};
 
 use crate::world::entity_owner::EntityOwner;
 
@@ -8,6 +8,10 @@ pub struct GlobalEntityRecord {
     pub global_entity: GlobalEntity,
     pub component_kinds: HashSet<ComponentKind>,
     pub owner: EntityOwner,
+    pub auth_status: EntityAuthStatus,
+    /// Components of a Server Entity whose changes are sent to the Server,
+    /// while this Client holds authority over it
+    pub published_kinds: HashSet<ComponentKind>,
 }
 
 impl GlobalEntityRecord {
@@ -19,6 +23,8 @@ impl GlobalEntityRecord {
             global_entity,
             component_kinds: HashSet::new(),
             owner,
+            auth_status: EntityAuthStatus::Available,
+            published_kinds: HashSet::new(),
         }
     }
 }
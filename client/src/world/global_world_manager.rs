@@ -1,13 +1,14 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     hash::Hash,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use naia_shared::{
-    BigMap, ComponentKind, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType, MutChannelType, PropertyMutator,
-    Replicate,
+    BigMap, ComponentKind, EntityAndGlobalEntityConverter, EntityAuthStatus,
+    EntityDoesNotExistError, GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType,
+    MutChannelType, PropertyMutator, Replicate,
 };
 
 use super::global_entity_record::GlobalEntityRecord;
@@ -19,6 +20,9 @@ pub struct GlobalWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     entity_records: HashMap<E, GlobalEntityRecord>,
     /// Map from the internal [`GlobalEntity`] to the external (e.g. Bevy's) entity id
     global_entity_map: BigMap<GlobalEntity, E>,
+    /// Server Entities which this Client has been granted authority over, and
+    /// whose Components may still be published
+    delegated_entities: HashSet<E>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
@@ -27,6 +31,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
             diff_handler: Arc::new(RwLock::new(GlobalDiffHandler::new())),
             entity_records: HashMap::default(),
             global_entity_map: BigMap::new(),
+            delegated_entities: HashSet::new(),
         }
     }
 
@@ -48,6 +53,84 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
         return None;
     }
 
+    /// Gets the status of this Client's authority over a Server Entity.
+    /// Returns None for Entities which are not owned by the Server.
+    pub fn entity_auth_status(&self, entity: &E) -> Option<EntityAuthStatus> {
+        let record = self.entity_records.get(entity)?;
+        if record.owner != EntityOwner::Server {
+            return None;
+        }
+        Some(record.auth_status)
+    }
+
+    pub fn set_entity_auth_status(&mut self, entity: &E, auth_status: EntityAuthStatus) {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.auth_status = auth_status;
+            if auth_status == EntityAuthStatus::Granted {
+                self.delegated_entities.insert(*entity);
+            }
+        }
+    }
+
+    pub fn delegated_entities(&self) -> Vec<E> {
+        self.delegated_entities.iter().copied().collect()
+    }
+
+    /// Stops tracking the publishing of an Entity's Components, once they
+    /// have all been unpublished
+    pub fn remove_delegated_entity(&mut self, entity: &E) {
+        self.delegated_entities.remove(entity);
+    }
+
+    pub fn published_component_kinds(&self, entity: &E) -> Vec<ComponentKind> {
+        let Some(record) = self.entity_records.get(entity) else {
+            return Vec::new();
+        };
+        record.published_kinds.iter().copied().collect()
+    }
+
+    /// Publishes a Component of a Server Entity this Client holds authority
+    /// over, so that its changes are tracked & sent to the Server
+    pub fn publish_component(&mut self, entity: &E, component: &mut dyn Replicate) {
+        let component_kind = component.kind();
+        let diff_mask_length: u8 = component.diff_mask_size();
+
+        let Some(record) = self.entity_records.get_mut(entity) else {
+            panic!("entity does not exist!");
+        };
+        if !record.published_kinds.insert(component_kind) {
+            panic!("component has already been published!");
+        }
+
+        let mut_sender = self
+            .diff_handler
+            .as_ref()
+            .write()
+            .expect("DiffHandler should be initialized")
+            .register_component(self, entity, &component_kind, diff_mask_length);
+
+        let prop_mutator = PropertyMutator::new(mut_sender);
+
+        component.publish(&prop_mutator);
+    }
+
+    /// Stops tracking the changes of a published Component. The Component
+    /// itself is unpublished by the caller, if it still exists.
+    pub fn unpublish_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        let Some(record) = self.entity_records.get_mut(entity) else {
+            return;
+        };
+        if !record.published_kinds.remove(component_kind) {
+            return;
+        }
+
+        self.diff_handler
+            .as_ref()
+            .write()
+            .expect("Haven't initialized DiffHandler")
+            .deregister_component(entity, component_kind);
+    }
+
     // Spawn
     pub fn host_spawn_entity(&mut self, entity: &E) {
         if self.entity_records.contains_key(entity) {
@@ -145,6 +228,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
         return false;
     }
 
+    fn entity_is_delegated_to_user(&self, _entity: &E, _user_key: &u64) -> bool {
+        // only the Server delegates authority over its Entities
+        false
+    }
+
+    fn entity_priority(&self, _entity: &E) -> f32 {
+        // Client-authoritative Entities all share the same priority
+        1.0
+    }
+
+    fn entity_update_interval(&self, _entity: &E) -> Option<Duration> {
+        // Client-authoritative Entities use the update interval of each Component
+        None
+    }
+
     fn new_mut_channel(&self, diff_mask_length: u8) -> Arc<RwLock<dyn MutChannelType>> {
         let mut_channel = MutChannelData::new(diff_mask_length);
         return Arc::new(RwLock::new(mut_channel));
@@ -172,6 +270,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
             .expect("Cannot despawn non-existant entity!");
         let global_entity = record.global_entity;
         self.global_entity_map.remove(&global_entity);
+
+        self.delegated_entities.remove(entity);
+        let mut diff_handler = self
+            .diff_handler
+            .as_ref()
+            .write()
+            .expect("Haven't initialized DiffHandler");
+        for component_kind in &record.published_kinds {
+            diff_handler.deregister_component(entity, component_kind);
+        }
     }
 }
 
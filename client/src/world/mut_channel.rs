@@ -1,5 +1,3 @@
-use std::net::SocketAddr;
-
 use naia_shared::{MutChannelType, MutReceiver};
 
 pub struct MutChannelData {
@@ -15,15 +13,19 @@ impl MutChannelData {
 }
 
 impl MutChannelType for MutChannelData {
-    fn new_receiver(&mut self, address_opt: &Option<SocketAddr>) -> Option<MutReceiver> {
-        if address_opt.is_some() {
+    fn new_receiver(&mut self, user_key_opt: &Option<u64>) -> Option<MutReceiver> {
+        if user_key_opt.is_some() {
             panic!(
-                "should not initialize client MutReceiver with an address (there is only 1 server)"
+                "should not initialize client MutReceiver with a user key (there is only 1 server)"
             );
         }
         return Some(self.receiver.clone());
     }
 
+    fn remove_receiver(&mut self, _user_key: &u64) {
+        // the single receiver belongs to the connection to the Server
+    }
+
     fn send(&self, diff: u8) {
         self.receiver.mutate(diff);
     }
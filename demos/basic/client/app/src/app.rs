@@ -60,10 +60,10 @@ impl App {
         for server_address in events.read::<ConnectEvent>() {
             info!("Client connected to: {}", server_address);
         }
-        for server_address in events.read::<RejectEvent>() {
+        for (server_address, reason) in events.read::<RejectEvent>() {
             info!(
-                "Client received unauthorized response from: {}",
-                server_address
+                "Client received unauthorized response from: {}, {:?}",
+                server_address, reason
             );
 
             // Now give the correct username / password
@@ -73,8 +73,8 @@ impl App {
             let socket = webrtc::Socket::new("http://127.0.0.1:14191", &self.socket_config);
             self.client.connect(socket);
         }
-        for server_address in events.read::<DisconnectEvent>() {
-            info!("Client disconnected from: {}", server_address);
+        for (server_address, reason) in events.read::<DisconnectEvent>() {
+            info!("Client disconnected from: {}, {:?}", server_address, reason);
         }
         for message in events.read::<MessageEvent<UnorderedReliableChannel, StringMessage>>() {
             let message_contents = &(*message.contents);
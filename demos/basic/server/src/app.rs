@@ -103,8 +103,11 @@ impl App {
                     .room_mut(&self.main_room_key)
                     .add_user(&user_key);
             }
-            for (_user_key, user) in events.read::<DisconnectEvent>() {
-                info!("Naia Server disconnected from: {:?}", user.address);
+            for (_user_key, user, reason) in events.read::<DisconnectEvent>() {
+                info!(
+                    "Naia Server disconnected from: {:?}, {:?}",
+                    user.address, reason
+                );
             }
             for (user_key, message) in
                 events.read::<MessageEvent<UnorderedReliableChannel, StringMessage>>()
@@ -77,14 +77,14 @@ pub fn connect_events(
 }
 
 pub fn reject_events(mut event_reader: EventReader<RejectEvent>) {
-    for _ in event_reader.iter() {
-        info!("Client rejected from connecting to Server");
+    for RejectEvent(reason) in event_reader.iter() {
+        info!("Client rejected from connecting to Server: {:?}", reason);
     }
 }
 
 pub fn disconnect_events(mut event_reader: EventReader<DisconnectEvent>) {
-    for _ in event_reader.iter() {
-        info!("Client disconnected from Server");
+    for DisconnectEvent(reason) in event_reader.iter() {
+        info!("Client disconnected from Server: {:?}", reason);
     }
 }
 
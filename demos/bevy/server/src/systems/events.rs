@@ -112,8 +112,11 @@ pub fn disconnect_events(
     mut global: ResMut<Global>,
     mut event_reader: EventReader<DisconnectEvent>,
 ) {
-    for DisconnectEvent(user_key, user) in event_reader.iter() {
-        info!("Naia Server disconnected from: {:?}", user.address);
+    for DisconnectEvent(user_key, user, reason) in event_reader.iter() {
+        info!(
+            "Naia Server disconnected from: {:?}, {:?}",
+            user.address, reason
+        );
 
         if let Some(entity) = global.user_to_square_map.remove(user_key) {
             global.square_to_user_map.remove(&entity);
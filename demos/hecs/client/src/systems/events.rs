@@ -21,8 +21,8 @@ pub fn process_events(app: &mut App) {
     }
 
     // Disconnect Events
-    for server_address in events.read::<DisconnectEvent>() {
-        info!("Client disconnected from: {}", server_address);
+    for (server_address, reason) in events.read::<DisconnectEvent>() {
+        info!("Client disconnected from: {}, {:?}", server_address, reason);
     }
 
     // Spawn Entity Events
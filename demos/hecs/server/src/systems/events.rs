@@ -30,8 +30,11 @@ pub fn process_events(app: &mut App) {
             info!("Naia Server connected to: {}", address);
             app.has_user = true;
         }
-        for (_user_key, user) in events.read::<DisconnectEvent>() {
-            info!("Naia Server disconnected from: {:?}", user.address);
+        for (_user_key, user, reason) in events.read::<DisconnectEvent>() {
+            info!(
+                "Naia Server disconnected from: {:?}, {:?}",
+                user.address, reason
+            );
         }
         for _ in events.read::<TickEvent>() {
             app.tick();
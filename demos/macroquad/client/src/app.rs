@@ -152,8 +152,8 @@ impl App {
         }
 
         // Disconnect Events
-        for server_address in events.read::<DisconnectEvent>() {
-            info!("Client disconnected from: {}", server_address);
+        for (server_address, reason) in events.read::<DisconnectEvent>() {
+            info!("Client disconnected from: {}, {:?}", server_address, reason);
 
             self.world = World::default();
             self.owned_entity = None;
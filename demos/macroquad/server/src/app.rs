@@ -141,8 +141,11 @@ impl App {
         }
 
         // Disconnect Events
-        for (user_key, user) in events.read::<DisconnectEvent>() {
-            info!("Naia Server disconnected from: {}", user.address);
+        for (user_key, user, reason) in events.read::<DisconnectEvent>() {
+            info!(
+                "Naia Server disconnected from: {}, {:?}",
+                user.address, reason
+            );
             if let Some(entity) = self.user_to_square_map.remove(&user_key) {
                 self.server
                     .entity_mut(self.world.proxy_mut(), &entity)
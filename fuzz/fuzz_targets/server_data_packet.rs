@@ -0,0 +1,65 @@
+#![no_main]
+
+use std::time::Duration;
+
+use libfuzzer_sys::fuzz_target;
+use naia_shared::{
+    default_channels::UnorderedReliableChannel, BitReader, BitWrite, BitWriter, PacketType,
+    Protocol, Serde, StandardHeader,
+};
+use naia_test::{Auth, Inventory, Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_client_authoritative_entities()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build()
+}
+
+// Packets from a connected Client, after a valid header & connection id
+fuzz_target!(|data: &[u8]| {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+
+    {
+        let (client, world) = scenario.client_and_world_mut(0);
+        client
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(1, 2));
+        client.send_message::<UnorderedReliableChannel, _>(&Auth::new("charlie", "1234567"));
+    }
+    scenario.step();
+
+    // find the connection id in a packet the Client sent
+    let packets = scenario.take_packets_to_server();
+    let Some((_, sent_packet)) = packets.first() else {
+        return;
+    };
+    let mut reader = BitReader::new(sent_packet);
+    let header = StandardHeader::de(&mut reader).unwrap();
+    let connection_id = u64::de(&mut reader).unwrap();
+
+    let mut writer = BitWriter::new();
+    StandardHeader::new(
+        PacketType::Data,
+        header.sender_packet_index.wrapping_add(1),
+        header.sender_ack_index,
+        header.sender_ack_bitfield,
+    )
+    .ser(&mut writer);
+    connection_id.ser(&mut writer);
+    for byte in data.iter().take(writer.bits_free() as usize / 8) {
+        writer.write_byte(*byte);
+    }
+
+    for (_, packet) in packets {
+        scenario.send_raw_to_server(0, &packet);
+    }
+    scenario.send_raw_to_server(0, &writer.to_bytes());
+    scenario.step_ticks(3);
+});
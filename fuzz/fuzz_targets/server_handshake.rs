@@ -0,0 +1,25 @@
+#![no_main]
+
+use std::time::Duration;
+
+use libfuzzer_sys::fuzz_target;
+use naia_shared::Protocol;
+use naia_test::{Auth, Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+// Packets from an address the Server has no Connection for
+fuzz_target!(|data: &[u8]| {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+
+    scenario.send_raw_to_server(0, data);
+    scenario.step();
+});
@@ -0,0 +1,50 @@
+#![no_main]
+
+use std::time::Duration;
+
+use libfuzzer_sys::fuzz_target;
+use naia_shared::{
+    BitReader, FakeEntityConverter, Protocol, Serde, StandardHeader, MTU_SIZE_BYTES,
+};
+use naia_test::{Auth, Inventory, Position};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build()
+}
+
+// Every decoder must return an error, rather than panic, on malformed input
+fuzz_target!(|data: &[u8]| {
+    // larger packets are dropped before they're read
+    if data.len() > MTU_SIZE_BYTES {
+        return;
+    }
+
+    let protocol = protocol();
+
+    let mut reader = BitReader::new(data);
+    let _ = StandardHeader::de(&mut reader);
+
+    let mut reader = BitReader::new(data);
+    let _ = protocol
+        .message_kinds
+        .read(&mut reader, &FakeEntityConverter);
+
+    let mut reader = BitReader::new(data);
+    let _ = protocol
+        .component_kinds
+        .read(&mut reader, &FakeEntityConverter);
+
+    let mut reader = BitReader::new(data);
+    if let Ok(update) = protocol.component_kinds.read_create_update(&mut reader) {
+        let kind = update.kind;
+        let _ = protocol
+            .component_kinds
+            .split_update(&FakeEntityConverter, &kind, update);
+    }
+});
@@ -76,7 +76,7 @@ impl ChannelTickBufferReceiver {
         for _ in 0..message_count {
             // read message id diff, add to last read id
             let id_diff = UnsignedVariableInteger::<2>::de(reader)?.get() as ShortMessageIndex;
-            let message_index: ShortMessageIndex = last_read_message_index + id_diff;
+            let message_index: ShortMessageIndex = last_read_message_index.wrapping_add(id_diff);
             last_read_message_index = message_index;
 
             // read payload
@@ -3,9 +3,10 @@ use std::{hash::Hash, net::SocketAddr};
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKinds, ConnectionConfig,
-    EntityConverter, EntityEvent, HostType, HostWorldEvents, Instant, PacketType, Protocol, Serde,
-    SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKind, ChannelKinds, ConnectionConfig,
+    EntityAuthAction, EntityAuthMessage, EntityConverter, EntityEvent, HostType, HostWorldEvents,
+    Instant, PacketType, Protocol, Serde, SerdeErr, StandardHeader, SystemChannel, Tick,
+    WorldMutType, WorldRefType,
 };
 
 use crate::{
@@ -24,6 +25,7 @@ use super::ping_manager::PingManager;
 pub struct Connection<E: Copy + Eq + Hash + Send + Sync> {
     pub address: SocketAddr,
     pub user_key: UserKey,
+    pub connection_id: u64,
     pub base: BaseConnection<E>,
     pub ping_manager: PingManager,
     tick_buffer: TickBufferReceiver,
@@ -35,14 +37,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         ping_config: &PingConfig,
         user_address: &SocketAddr,
         user_key: &UserKey,
+        connection_id: u64,
         channel_kinds: &ChannelKinds,
         global_world_manager: &GlobalWorldManager<E>,
     ) -> Self {
         Connection {
             address: *user_address,
             user_key: *user_key,
+            connection_id,
             base: BaseConnection::new(
-                &Some(*user_address),
                 HostType::Server,
                 user_key.to_u64(),
                 connection_config,
@@ -95,57 +98,75 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             )?;
         }
 
-        // read world events
-        if protocol.client_authoritative_entities {
-            self.base.remote_world_reader.read_world_events(
-                global_world_manager,
-                &mut self.base.local_world_manager,
-                protocol,
-                client_tick,
-                reader,
-            )?;
-        }
+        // read world events, which only hold the updates to the Entities
+        // delegated to the Client unless it may spawn its own
+        self.base.remote_world_reader.read_world_events(
+            global_world_manager,
+            &mut self.base.local_world_manager,
+            protocol,
+            protocol.client_authoritative_entities,
+            client_tick,
+            reader,
+        )?;
 
         return Ok(());
     }
 
-    /// Receive & process stored packet data
+    /// Receive & process stored packet data. Returns the Entity authority
+    /// actions received from the Client, to be handled by the Server.
     pub fn process_packets<W: WorldMutType<E>>(
         &mut self,
         protocol: &Protocol,
         global_world_manager: &mut GlobalWorldManager<E>,
         world: &mut W,
         incoming_events: &mut Events<E>,
-    ) {
+    ) -> Vec<(E, EntityAuthAction)> {
+        let mut auth_actions = Vec::new();
+
         // Receive Message Events
         let messages = self.base.message_manager.receive_messages(
+            &protocol.message_kinds,
             global_world_manager,
             &self.base.local_world_manager,
             &mut self.base.remote_world_manager.entity_waitlist,
         );
         for (channel_kind, messages) in messages {
+            if channel_kind == ChannelKind::of::<SystemChannel>() {
+                for message in messages {
+                    let Ok(message) = message.to_boxed_any().downcast::<EntityAuthMessage>() else {
+                        warn!("Received unknown message on the System Channel");
+                        continue;
+                    };
+                    if let Some(entity) = message.entity.get(global_world_manager) {
+                        auth_actions.push((entity, message.action));
+                    }
+                }
+                continue;
+            }
             for message in messages {
                 incoming_events.push_message(&self.user_key, &channel_kind, message);
             }
         }
 
-        // read world events
-        if protocol.client_authoritative_entities {
-            let remote_events = self.base.remote_world_reader.take_incoming_events();
-            let world_events = self.base.remote_world_manager.process_world_events(
-                global_world_manager,
-                &mut self.base.local_world_manager,
-                &protocol.component_kinds,
-                world,
-                remote_events,
-            );
-            for event in &world_events {
-                if let EntityEvent::SpawnEntity(entity) = event {
-                    global_world_manager.remote_spawn_entity_record(entity, &self.user_key);
-                }
+        // process world events, including the changes the Client made to the
+        // Server Entities it holds authority over, which are then passed on
+        // to other Users
+        let remote_events = self.base.remote_world_reader.take_incoming_events();
+        let world_events = self.base.remote_world_manager.process_world_events(
+            global_world_manager,
+            &mut self.base.local_world_manager,
+            &protocol.component_kinds,
+            world,
+            remote_events,
+        );
+        for event in &world_events {
+            if let EntityEvent::SpawnEntity(entity) = event {
+                global_world_manager.remote_spawn_entity_record(entity, &self.user_key);
             }
-            incoming_events.receive_entity_events(&self.user_key, world_events);
         }
+        incoming_events.receive_entity_events(&self.user_key, world_events);
+
+        auth_actions
     }
 
     pub fn tick_buffer_messages(&mut self, tick: &Tick, messages: &mut TickBufferMessages) {
@@ -169,10 +190,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
     ) {
         let rtt_millis = self.ping_manager.rtt_average;
         self.base.collect_outgoing_messages(now, &rtt_millis);
-        let mut host_world_events = self
-            .base
-            .host_world_manager
-            .take_outgoing_events(now, &rtt_millis);
+        let mut host_world_events = self.base.host_world_manager.take_outgoing_events(
+            now,
+            &rtt_millis,
+            &protocol.component_kinds,
+            global_world_manager,
+            self.base.local_world_manager.get_user_key(),
+        );
 
         let mut any_sent = false;
         loop {
@@ -208,6 +232,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         host_world_events: &mut HostWorldEvents<E>,
     ) -> bool {
         if host_world_events.has_events() || self.base.message_manager.has_outgoing_messages() {
+            // hold back what the send rate can't afford until a later tick
+            if !self.base.can_send_packet() {
+                return false;
+            }
+
             let next_packet_index = self.base.next_packet_index();
 
             let mut writer = BitWriter::new();
@@ -244,7 +273,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             );
 
             // send packet
-            if io.send_packet(&self.address, writer.to_packet()).is_err() {
+            let packet = writer.to_packet();
+            self.base.record_sent_packet(packet.slice().len());
+            if io.send_packet(&self.address, packet).is_err() {
                 // TODO: pass this on and handle above
                 warn!("Server Error: Cannot send data packet to {}", &self.address);
             }
@@ -0,0 +1,85 @@
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    time::Duration,
+};
+
+use naia_shared::{Instant, Timer};
+
+use crate::server_config::HandshakeConfig;
+
+/// Decides which packets from addresses without an established connection
+/// are handled, dropping those from banned IPs or beyond the rate limit
+pub struct HandshakeGuard {
+    max_packets_per_second: Option<u32>,
+    rate_timer: Timer,
+    packet_counts: HashMap<IpAddr, u32>,
+    allowed_ips: HashSet<IpAddr>,
+    // banned IPs, with when & for how long they were banned if not forever
+    banned_ips: HashMap<IpAddr, Option<(Instant, Duration)>>,
+}
+
+impl HandshakeGuard {
+    pub fn new(config: &HandshakeConfig) -> Self {
+        Self {
+            max_packets_per_second: config.max_packets_per_ip_per_second,
+            rate_timer: Timer::new(Duration::from_secs(1)),
+            packet_counts: HashMap::new(),
+            allowed_ips: config.allowed_ips.iter().copied().collect(),
+            banned_ips: config.denied_ips.iter().map(|ip| (*ip, None)).collect(),
+        }
+    }
+
+    /// Returns whether a packet from the given IP should be handled, counting
+    /// it against the IP's rate limit
+    pub fn allows_packet(&mut self, ip: &IpAddr) -> bool {
+        // counts are only kept for the current second, so IPs which stop
+        // sending don't take up space
+        if self.rate_timer.ringing() {
+            self.rate_timer.reset();
+            self.packet_counts.clear();
+            self.banned_ips.retain(|_, ban| match ban {
+                None => true,
+                Some((banned_at, duration)) => banned_at.elapsed() < *duration,
+            });
+        }
+
+        if self.is_banned(ip) {
+            return false;
+        }
+        if self.allowed_ips.contains(ip) {
+            return true;
+        }
+        let Some(max_packets_per_second) = self.max_packets_per_second else {
+            return true;
+        };
+        let count = self.packet_counts.entry(*ip).or_insert(0);
+        *count = count.saturating_add(1);
+        *count <= max_packets_per_second
+    }
+
+    pub fn ban(&mut self, ip: IpAddr, duration: Option<Duration>) {
+        self.banned_ips
+            .insert(ip, duration.map(|duration| (Instant::now(), duration)));
+    }
+
+    pub fn unban(&mut self, ip: &IpAddr) {
+        self.banned_ips.remove(ip);
+    }
+
+    pub fn is_banned(&self, ip: &IpAddr) -> bool {
+        match self.banned_ips.get(ip) {
+            None => false,
+            Some(None) => true,
+            Some(Some((banned_at, duration))) => banned_at.elapsed() < *duration,
+        }
+    }
+
+    pub fn allow(&mut self, ip: IpAddr) {
+        self.allowed_ips.insert(ip);
+    }
+
+    pub fn disallow(&mut self, ip: &IpAddr) {
+        self.allowed_ips.remove(ip);
+    }
+}
@@ -1,49 +1,116 @@
 use std::{collections::HashMap, hash::Hash, net::SocketAddr};
 
-use ring::{hmac, rand};
+use ring::{
+    hmac,
+    rand::{self, SecureRandom},
+};
 
 pub use naia_shared::{
-    wrapping_diff, BaseConnection, BitReader, BitWriter, ConnectionConfig, FakeEntityConverter,
-    Instant, KeyGenerator, Message, MessageContainer, MessageKinds, PacketType, PropertyMutate,
-    PropertyMutator, Replicate, Serde, SerdeErr, StandardHeader, Timer, WorldMutType, WorldRefType,
+    wrapping_diff, BaseConnection, BitCounter, BitReader, BitWrite, BitWriter, ConnectToken,
+    ConnectionConfig, DisconnectReason, FakeEntityConverter, Instant, KeyGenerator, Message,
+    MessageContainer, MessageKinds, PacketType, PropertyMutate, PropertyMutator, RejectReason,
+    Replicate, Serde, SerdeErr, StandardHeader, Timer, WorldMutType, WorldRefType, MTU_SIZE_BITS,
 };
+#[cfg(feature = "encryption")]
+use naia_shared::{EncryptionSecretKey, KeyExchange, PacketCipher};
 
-use crate::{cache_map::CacheMap, connection::connection::Connection};
+use crate::{
+    cache_map::CacheMap, connection::connection::Connection, server_config::ConnectTokenConfig,
+};
 
 pub type Timestamp = u64;
 
 pub enum HandshakeResult {
     Invalid,
-    Success(Option<MessageContainer>),
+    Success(Option<MessageContainer>, Option<ConnectToken>),
 }
 
 pub struct HandshakeManager {
     connection_hash_key: hmac::Key,
     require_auth: bool,
+    protocol_fingerprint: u64,
     address_to_timestamp_map: HashMap<SocketAddr, Timestamp>,
     timestamp_digest_map: CacheMap<Timestamp, Vec<u8>>,
+    connect_token_config: Option<ConnectTokenConfig>,
+    connect_token_user_ids: HashMap<u64, SocketAddr>,
+    #[cfg(feature = "encryption")]
+    key_exchange: Option<KeyExchange>,
+    #[cfg(feature = "encryption")]
+    packet_cipher: Option<PacketCipher>,
 }
 
 impl HandshakeManager {
-    pub fn new(require_auth: bool) -> Self {
+    pub fn new(require_auth: bool, protocol_fingerprint: u64) -> Self {
         let connection_hash_key =
             hmac::Key::generate(hmac::HMAC_SHA256, &rand::SystemRandom::new()).unwrap();
 
         Self {
             connection_hash_key,
             require_auth,
+            protocol_fingerprint,
             address_to_timestamp_map: HashMap::new(),
             timestamp_digest_map: CacheMap::with_capacity(64),
+            connect_token_config: None,
+            connect_token_user_ids: HashMap::new(),
+            #[cfg(feature = "encryption")]
+            key_exchange: None,
+            #[cfg(feature = "encryption")]
+            packet_cipher: None,
         }
     }
 
+    /// Requires that Clients connect with a ConnectToken which is valid for
+    /// the given configuration
+    pub fn enable_connect_tokens(&mut self, config: ConnectTokenConfig) {
+        self.connect_token_config = Some(config);
+    }
+
+    /// Agrees on a PacketCipher with each Client during the handshake, for
+    /// transports which do not encrypt packets themselves. A random key pair
+    /// is generated if no secret key is given
+    #[cfg(feature = "encryption")]
+    pub fn enable_encryption(&mut self, secret_key: Option<EncryptionSecretKey>) {
+        self.key_exchange = Some(match secret_key {
+            Some(secret_key) => KeyExchange::from_secret_key(secret_key),
+            None => KeyExchange::new(),
+        });
+    }
+
+    #[cfg(feature = "encryption")]
+    fn encryption_enabled(&self) -> bool {
+        self.key_exchange.is_some()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn encryption_enabled(&self) -> bool {
+        false
+    }
+
+    /// Takes the PacketCipher agreed on with the Client whose validate
+    /// request was last received successfully
+    #[cfg(feature = "encryption")]
+    pub fn take_packet_cipher(&mut self) -> Option<PacketCipher> {
+        self.packet_cipher.take()
+    }
+
     // Step 1 of Handshake
     pub fn recv_challenge_request(
         &mut self,
+        message_kinds: &MessageKinds,
         reader: &mut BitReader,
     ) -> Result<BitWriter, SerdeErr> {
         let timestamp = Timestamp::de(reader)?;
 
+        // a Client with a different Protocol would misread packets
+        let protocol_fingerprint = u64::de(reader)?;
+        if protocol_fingerprint != self.protocol_fingerprint {
+            return Ok(self.write_reject_response(
+                message_kinds,
+                &RejectReason::ProtocolMismatch,
+                None,
+            ));
+        }
+
         Ok(self.write_challenge_response(&timestamp))
     }
 
@@ -64,6 +131,16 @@ impl HandshakeManager {
             .get_unchecked(timestamp)
             .ser(&mut writer);
 
+        // write public key, if packets must be encrypted
+        #[cfg(feature = "encryption")]
+        let public_key = self
+            .key_exchange
+            .as_ref()
+            .map(|key_exchange| key_exchange.public_key());
+        #[cfg(not(feature = "encryption"))]
+        let public_key: Option<[u8; 32]> = None;
+        public_key.ser(&mut writer);
+
         writer
     }
 
@@ -79,6 +156,53 @@ impl HandshakeManager {
         let Some(timestamp) = self.timestamp_validate(reader) else {
             return HandshakeResult::Invalid;
         };
+        // The Client sends its public key if packets must be encrypted
+        let Ok(client_public_key) = Option::<[u8; 32]>::de(reader) else {
+            return HandshakeResult::Invalid;
+        };
+        if client_public_key.is_some() != self.encryption_enabled() {
+            return HandshakeResult::Invalid;
+        }
+
+        // The rest of the request is sealed with the agreed PacketCipher
+        #[cfg(feature = "encryption")]
+        if let Some(client_public_key) = client_public_key {
+            let Some(packet_cipher) = self
+                .key_exchange
+                .as_ref()
+                .and_then(|key_exchange| key_exchange.server_cipher(&client_public_key))
+            else {
+                return HandshakeResult::Invalid;
+            };
+            let Ok(sealed_payload) = Vec::<u8>::de(reader) else {
+                return HandshakeResult::Invalid;
+            };
+            let Some(payload) = packet_cipher.open_handshake(&sealed_payload) else {
+                return HandshakeResult::Invalid;
+            };
+
+            let result = self.recv_auth(
+                message_kinds,
+                address,
+                timestamp,
+                &mut BitReader::new(&payload),
+            );
+            if let HandshakeResult::Success(..) = result {
+                self.packet_cipher = Some(packet_cipher);
+            }
+            return result;
+        }
+
+        self.recv_auth(message_kinds, address, timestamp, reader)
+    }
+
+    fn recv_auth(
+        &mut self,
+        message_kinds: &MessageKinds,
+        address: &SocketAddr,
+        timestamp: Timestamp,
+        reader: &mut BitReader,
+    ) -> HandshakeResult {
         // Timestamp hash is validated, now start configured auth process
         let Ok(has_auth) = bool::de(reader) else {
             return HandshakeResult::Invalid;
@@ -87,17 +211,39 @@ impl HandshakeManager {
             return HandshakeResult::Invalid;
         }
 
-        self.address_to_timestamp_map.insert(*address, timestamp);
-
-        if !has_auth {
-            return HandshakeResult::Success(None);
-        }
+        let auth_message = if has_auth {
+            let Ok(auth_message) = message_kinds.read(reader, &FakeEntityConverter) else {
+                return HandshakeResult::Invalid;
+            };
+            Some(auth_message)
+        } else {
+            None
+        };
 
-        let Ok(auth_message) = message_kinds.read(reader, &FakeEntityConverter) else {
+        // Check the ConnectToken, if one is required
+        let Ok(signed_connect_token) = Option::<Vec<u8>>::de(reader) else {
             return HandshakeResult::Invalid;
         };
+        if signed_connect_token.is_some() != self.connect_token_config.is_some() {
+            return HandshakeResult::Invalid;
+        }
+        let connect_token = match signed_connect_token {
+            Some(signed_connect_token) => {
+                let Some(connect_token) =
+                    self.validate_connect_token(address, &signed_connect_token)
+                else {
+                    return HandshakeResult::Invalid;
+                };
+                self.connect_token_user_ids
+                    .insert(connect_token.user_id, *address);
+                Some(connect_token)
+            }
+            None => None,
+        };
+
+        self.address_to_timestamp_map.insert(*address, timestamp);
 
-        return HandshakeResult::Success(Some(auth_message));
+        HandshakeResult::Success(auth_message, connect_token)
     }
 
     // Step 4 of Handshake
@@ -108,38 +254,183 @@ impl HandshakeManager {
     }
 
     // Step 5 of Handshake
-    pub(crate) fn write_connect_response(&self) -> BitWriter {
+    pub(crate) fn write_connect_response(
+        &self,
+        message_kinds: &MessageKinds,
+        connection_id: u64,
+        accept_message: Option<&MessageContainer>,
+    ) -> BitWriter {
+        let mut writer = BitWriter::new();
+        Self::ser_connect_response(message_kinds, connection_id, accept_message, &mut writer);
+        writer
+    }
+
+    /// Returns whether a connect response carrying the given Message fits in
+    /// a single packet
+    pub(crate) fn connect_response_fits(
+        &self,
+        message_kinds: &MessageKinds,
+        accept_message: &MessageContainer,
+    ) -> bool {
+        let mut counter = BitCounter::new(0, 0, MTU_SIZE_BITS);
+        Self::ser_connect_response(message_kinds, 0, Some(accept_message), &mut counter);
+        !counter.overflowed()
+    }
+
+    fn ser_connect_response(
+        message_kinds: &MessageKinds,
+        connection_id: u64,
+        accept_message: Option<&MessageContainer>,
+        writer: &mut dyn BitWrite,
+    ) {
+        StandardHeader::new(PacketType::ServerConnectResponse, 0, 0, 0).ser(writer);
+        connection_id.ser(writer);
+        Self::write_response_message(message_kinds, accept_message, writer);
+    }
+
+    // Sent instead of the connect response while the Server is full, with the
+    // Client's position in the join queue
+    pub(crate) fn write_queue_response(&self, position: u32) -> BitWriter {
         let mut writer = BitWriter::new();
-        StandardHeader::new(PacketType::ServerConnectResponse, 0, 0, 0).ser(&mut writer);
+        StandardHeader::new(PacketType::ServerQueueResponse, 0, 0, 0).ser(&mut writer);
+        position.ser(&mut writer);
         writer
     }
 
+    /// Generates a random id for a new connection, which the Client sends
+    /// with each packet so its Connection can be found if its address changes
+    pub(crate) fn generate_connection_id(&self) -> u64 {
+        let mut bytes = [0; 8];
+        rand::SystemRandom::new().fill(&mut bytes).unwrap();
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns the reason the Client disconnected, if the request is valid
     pub fn verify_disconnect_request<E: Copy + Eq + Hash + Send + Sync>(
         &mut self,
         connection: &Connection<E>,
         reader: &mut BitReader,
-    ) -> bool {
+    ) -> Option<DisconnectReason> {
         // Verify that timestamp hash has been written by this
         // server instance
-        if let Some(new_timestamp) = self.timestamp_validate(reader) {
-            if let Some(old_timestamp) = self.address_to_timestamp_map.get(&connection.address) {
-                if *old_timestamp == new_timestamp {
-                    return true;
-                }
-            }
+        let new_timestamp = self.timestamp_validate(reader)?;
+        let old_timestamp = self.address_to_timestamp_map.get(&connection.address)?;
+        if *old_timestamp != new_timestamp {
+            return None;
         }
 
-        false
+        // A Client can only have quit, or failed to read the Server's packets
+        match DisconnectReason::de(reader).ok()? {
+            DisconnectReason::ProtocolError => Some(DisconnectReason::ProtocolError),
+            _ => Some(DisconnectReason::ClientQuit),
+        }
+    }
+
+    pub(crate) fn write_disconnect(
+        &self,
+        connection_id: u64,
+        reason: &DisconnectReason,
+    ) -> BitWriter {
+        let mut writer = BitWriter::new();
+        StandardHeader::new(PacketType::Disconnect, 0, 0, 0).ser(&mut writer);
+        connection_id.ser(&mut writer);
+        reason.ser(&mut writer);
+        writer
     }
 
-    pub fn write_reject_response(&self) -> BitWriter {
+    pub fn write_reject_response(
+        &self,
+        message_kinds: &MessageKinds,
+        reason: &RejectReason,
+        reject_message: Option<&MessageContainer>,
+    ) -> BitWriter {
         let mut writer = BitWriter::new();
-        StandardHeader::new(PacketType::ServerRejectResponse, 0, 0, 0).ser(&mut writer);
+        Self::ser_reject_response(message_kinds, reason, reject_message, &mut writer);
         writer
     }
 
+    /// Returns whether a reject response carrying the given Message fits in
+    /// a single packet
+    pub(crate) fn reject_response_fits(
+        &self,
+        message_kinds: &MessageKinds,
+        reason: &RejectReason,
+        reject_message: &MessageContainer,
+    ) -> bool {
+        let mut counter = BitCounter::new(0, 0, MTU_SIZE_BITS);
+        Self::ser_reject_response(message_kinds, reason, Some(reject_message), &mut counter);
+        !counter.overflowed()
+    }
+
+    fn ser_reject_response(
+        message_kinds: &MessageKinds,
+        reason: &RejectReason,
+        reject_message: Option<&MessageContainer>,
+        writer: &mut dyn BitWrite,
+    ) {
+        StandardHeader::new(PacketType::ServerRejectResponse, 0, 0, 0).ser(writer);
+        reason.ser(writer);
+        Self::write_response_message(message_kinds, reject_message, writer);
+    }
+
+    // Writes the Message the Server attached to its accept / reject response,
+    // if there is one
+    fn write_response_message(
+        message_kinds: &MessageKinds,
+        message: Option<&MessageContainer>,
+        writer: &mut dyn BitWrite,
+    ) {
+        message.is_some().ser(writer);
+        if let Some(message) = message {
+            message.write(message_kinds, writer, &mut FakeEntityConverter);
+        }
+    }
+
     pub fn delete_user(&mut self, address: &SocketAddr) {
         self.address_to_timestamp_map.remove(address);
+        self.connect_token_user_ids
+            .retain(|_, user_address| user_address != address);
+    }
+
+    pub fn migrate_user(&mut self, old_address: &SocketAddr, new_address: &SocketAddr) {
+        if let Some(timestamp) = self.address_to_timestamp_map.remove(old_address) {
+            self.address_to_timestamp_map
+                .insert(*new_address, timestamp);
+        }
+        for user_address in self.connect_token_user_ids.values_mut() {
+            if user_address == old_address {
+                *user_address = *new_address;
+            }
+        }
+    }
+
+    // Reads a signed ConnectToken, returning it if it was issued for this
+    // Server, has not expired, and its user is not already connected from
+    // another address
+    fn validate_connect_token(
+        &self,
+        address: &SocketAddr,
+        signed_connect_token: &[u8],
+    ) -> Option<ConnectToken> {
+        let config = self.connect_token_config.as_ref()?;
+        let connect_token = ConnectToken::verify(signed_connect_token, &config.key)?;
+
+        if connect_token.is_expired() {
+            return None;
+        }
+        if !connect_token
+            .server_addresses
+            .contains(&config.server_address)
+        {
+            return None;
+        }
+        if let Some(user_address) = self.connect_token_user_ids.get(&connect_token.user_id) {
+            if user_address != address {
+                return None;
+            }
+        }
+
+        Some(connect_token)
     }
 
     fn timestamp_validate(&self, reader: &mut BitReader) -> Option<Timestamp> {
@@ -1,6 +1,12 @@
+#[cfg(feature = "encryption")]
+use std::collections::HashMap;
 use std::{net::SocketAddr, panic, time::Duration};
 
-use naia_shared::{CompressionConfig, Decoder, Encoder, OutgoingPacket, OwnedBitReader};
+use naia_shared::{
+    CompressionConfig, Decoder, Encoder, OutgoingPacket, OwnedBitReader, MTU_SIZE_BYTES,
+};
+#[cfg(feature = "encryption")]
+use naia_shared::{PacketCipher, PacketEncryption};
 
 use super::bandwidth_monitor::BandwidthMonitor;
 use crate::{
@@ -15,6 +21,8 @@ pub struct Io {
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    #[cfg(feature = "encryption")]
+    encryption: Option<IoEncryption>,
 }
 
 impl Io {
@@ -45,6 +53,8 @@ impl Io {
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            #[cfg(feature = "encryption")]
+            encryption: None,
         }
     }
 
@@ -65,6 +75,60 @@ impl Io {
         self.packet_sender.is_some()
     }
 
+    /// Encrypts all packets other than those of the handshake, for
+    /// transports which do not encrypt packets themselves
+    #[cfg(feature = "encryption")]
+    pub fn enable_encryption(&mut self) {
+        self.encryption = Some(IoEncryption::new());
+    }
+
+    /// Sets the PacketCipher agreed on with the Client at the given address
+    /// during the handshake
+    #[cfg(feature = "encryption")]
+    pub fn set_packet_cipher(&mut self, address: &SocketAddr, cipher: PacketCipher) {
+        if let Some(encryption) = &mut self.encryption {
+            encryption
+                .clients
+                .entry(*address)
+                .or_default()
+                .set_cipher(cipher);
+        }
+    }
+
+    /// Sets the connection id assigned to the Client at the given address,
+    /// which its sealed packets carry
+    #[cfg(feature = "encryption")]
+    pub fn set_connection_id(&mut self, address: &SocketAddr, connection_id: u64) {
+        if let Some(encryption) = &mut self.encryption {
+            encryption.connection_ids.insert(connection_id, *address);
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    pub fn remove_packet_cipher(&mut self, address: &SocketAddr) {
+        if let Some(encryption) = &mut self.encryption {
+            encryption.clients.remove(address);
+            encryption
+                .connection_ids
+                .retain(|_, client_address| client_address != address);
+        }
+    }
+
+    /// Moves the PacketCipher of a Client whose address has changed
+    #[cfg(feature = "encryption")]
+    pub fn migrate_packet_cipher(&mut self, old_address: &SocketAddr, new_address: &SocketAddr) {
+        if let Some(encryption) = &mut self.encryption {
+            if let Some(packet_encryption) = encryption.clients.remove(old_address) {
+                encryption.clients.insert(*new_address, packet_encryption);
+            }
+            for client_address in encryption.connection_ids.values_mut() {
+                if client_address == old_address {
+                    *client_address = *new_address;
+                }
+            }
+        }
+    }
+
     pub fn send_packet(
         &mut self,
         address: &SocketAddr,
@@ -73,9 +137,29 @@ impl Io {
         // get payload
         let mut payload = packet.slice();
 
+        // Handshake packets are sent in the clear, and left uncompressed so
+        // their type can be checked when received
+        #[cfg(feature = "encryption")]
+        let seal = self.encryption.is_some() && PacketEncryption::seals(payload);
+        #[cfg(feature = "encryption")]
+        let compress = seal || self.encryption.is_none();
+        #[cfg(not(feature = "encryption"))]
+        let compress = true;
+
         // Compression
-        if let Some(encoder) = &mut self.outgoing_encoder {
-            payload = encoder.encode(payload);
+        if compress {
+            if let Some(encoder) = &mut self.outgoing_encoder {
+                payload = encoder.encode(payload);
+            }
+        }
+
+        // Encryption
+        #[cfg(feature = "encryption")]
+        if let Some(encryption) = &mut self.encryption {
+            let Some(encrypted_payload) = encryption.get_mut(address).encrypt(payload, seal) else {
+                return Err(NaiaServerError::SendError(*address));
+            };
+            payload = encrypted_payload;
         }
 
         // Bandwidth monitoring
@@ -91,28 +175,89 @@ impl Io {
     }
 
     pub fn recv_reader(&mut self) -> Result<Option<(SocketAddr, OwnedBitReader)>, NaiaServerError> {
-        let receive_result = self
+        #[cfg(feature = "encryption")]
+        if self.encryption.is_some() {
+            return self.recv_encrypted_reader();
+        }
+
+        let packet_receiver = self
             .packet_receiver
             .as_mut()
-            .expect("Cannot call Server.receive_packet() until you call Server.listen()!")
-            .receive();
-
-        match receive_result {
-            Ok(Some((address, mut payload))) => {
-                // Bandwidth monitoring
-                if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
-                    monitor.record_packet(&address, payload.len());
-                }
+            .expect("Cannot call Server.receive_packet() until you call Server.listen()!");
+
+        loop {
+            match packet_receiver.receive() {
+                Ok(Some((address, mut payload))) => {
+                    // Bandwidth monitoring
+                    if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+                        monitor.record_packet(&address, payload.len());
+                    }
+
+                    // Decompression, dropping packets which can't be decompressed
+                    if let Some(decoder) = &mut self.incoming_decoder {
+                        let Ok(decoded) = decoder.decode(payload) else {
+                            continue;
+                        };
+                        payload = decoded;
+                    }
+
+                    // no packet naia writes is larger than this
+                    if payload.len() > MTU_SIZE_BYTES {
+                        continue;
+                    }
 
-                // Decompression
-                if let Some(decoder) = &mut self.incoming_decoder {
-                    payload = decoder.decode(payload);
+                    return Ok(Some((address, OwnedBitReader::new(payload))));
                 }
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(NaiaServerError::RecvError),
+            }
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    fn recv_encrypted_reader(
+        &mut self,
+    ) -> Result<Option<(SocketAddr, OwnedBitReader)>, NaiaServerError> {
+        let packet_receiver = self
+            .packet_receiver
+            .as_mut()
+            .expect("Cannot call Server.receive_packet() until you call Server.listen()!");
+        let encryption = self.encryption.as_mut().unwrap();
 
-                Ok(Some((address, OwnedBitReader::new(payload))))
+        loop {
+            match packet_receiver.receive() {
+                Ok(Some((address, packet))) => {
+                    // Bandwidth monitoring
+                    if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+                        monitor.record_packet(&address, packet.len());
+                    }
+
+                    // Decryption, dropping packets which can't be authenticated
+                    let Some(packet_encryption) = encryption.decrypt(&address, packet) else {
+                        continue;
+                    };
+                    let mut payload = packet_encryption.payload();
+
+                    // Decompression, of all but the handshake packets sent in the clear
+                    if packet_encryption.was_sealed() {
+                        if let Some(decoder) = &mut self.incoming_decoder {
+                            let Ok(decoded) = decoder.decode(payload) else {
+                                continue;
+                            };
+                            payload = decoded;
+                        }
+                    }
+
+                    // no packet naia writes is larger than this
+                    if payload.len() > MTU_SIZE_BYTES {
+                        continue;
+                    }
+
+                    return Ok(Some((address, OwnedBitReader::new(payload))));
+                }
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(NaiaServerError::RecvError),
             }
-            Ok(None) => Ok(None),
-            Err(_) => Err(NaiaServerError::RecvError),
         }
     }
 
@@ -174,3 +319,53 @@ impl Io {
             .client_bandwidth(address);
     }
 }
+
+/// Encrypts the packets of each Client, for transports which do not encrypt
+/// packets themselves
+#[cfg(feature = "encryption")]
+struct IoEncryption {
+    clients: HashMap<SocketAddr, PacketEncryption>,
+    connection_ids: HashMap<u64, SocketAddr>,
+    // used for Clients which have not agreed on a PacketCipher yet
+    handshake: PacketEncryption,
+}
+
+#[cfg(feature = "encryption")]
+impl IoEncryption {
+    fn new() -> Self {
+        Self {
+            clients: HashMap::new(),
+            connection_ids: HashMap::new(),
+            handshake: PacketEncryption::new(),
+        }
+    }
+
+    fn get_mut(&mut self, address: &SocketAddr) -> &mut PacketEncryption {
+        match self.clients.get_mut(address) {
+            Some(packet_encryption) => packet_encryption,
+            None => &mut self.handshake,
+        }
+    }
+
+    // Decrypts a packet, returning the PacketEncryption it was decrypted
+    // with. Packets from an unknown address are decrypted with the
+    // PacketCipher of the connection id they carry, as the Client's address
+    // may have changed.
+    fn decrypt(&mut self, address: &SocketAddr, packet: &[u8]) -> Option<&PacketEncryption> {
+        let mut cipher_address = *address;
+        if !self.clients.contains_key(address) {
+            if let Some(client_address) = PacketEncryption::connection_id(packet)
+                .and_then(|connection_id| self.connection_ids.get(&connection_id))
+            {
+                cipher_address = *client_address;
+            }
+        }
+
+        let packet_encryption = self.get_mut(&cipher_address);
+        if packet_encryption.decrypt(packet) {
+            Some(packet_encryption)
+        } else {
+            None
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod bandwidth_monitor;
 pub mod channel_tick_buffer_receiver;
 pub mod connection;
+pub mod handshake_guard;
 pub mod handshake_manager;
 pub mod io;
 pub mod ping_config;
@@ -48,7 +48,9 @@ impl TickBufferReceiver {
             let channel_kind = ChannelKind::de(&protocol.channel_kinds, reader)?;
 
             // continue read inside channel
-            let channel = self.channel_receivers.get_mut(&channel_kind).unwrap();
+            let Some(channel) = self.channel_receivers.get_mut(&channel_kind) else {
+                return Err(SerdeErr);
+            };
             channel.read_messages(
                 converter,
                 &protocol.message_kinds,
@@ -1,28 +1,40 @@
-use std::{any::Any, collections::HashMap, marker::PhantomData, mem, vec::IntoIter};
+use std::{
+    any::Any, collections::HashMap, marker::PhantomData, mem, net::SocketAddr, vec::IntoIter,
+};
 
 use log::warn;
 
 use naia_shared::{
-    Channel, ChannelKind, ComponentKind, EntityEvent, Message, MessageContainer, MessageKind,
-    Replicate, Tick,
+    Channel, ChannelKind, ComponentKind, ConnectToken, DisconnectReason, EntityEvent, Message,
+    MessageContainer, MessageHandle, MessageKind, Replicate, Request, ResponseSendKey, Tick,
 };
 
 use super::user::{User, UserKey};
 
 use crate::NaiaServerError;
 
+// Requests received from Users, with the key each is responded to with
+type RequestMap = HashMap<ChannelKind, HashMap<MessageKind, Vec<(UserKey, u64, MessageContainer)>>>;
+
 pub struct Events<E: Copy> {
     connections: Vec<UserKey>,
-    disconnections: Vec<(UserKey, User)>,
+    disconnections: Vec<(UserKey, User, DisconnectReason)>,
+    address_changes: Vec<(UserKey, SocketAddr, SocketAddr)>,
     ticks: Vec<Tick>,
     errors: Vec<NaiaServerError>,
     auths: HashMap<MessageKind, Vec<(UserKey, MessageContainer)>>,
+    connect_tokens: Vec<(UserKey, ConnectToken)>,
     messages: HashMap<ChannelKind, HashMap<MessageKind, Vec<(UserKey, MessageContainer)>>>,
+    requests: RequestMap,
+    message_deliveries: Vec<(UserKey, MessageHandle)>,
+    message_expiries: Vec<(UserKey, MessageHandle)>,
     spawns: Vec<(UserKey, E)>,
     despawns: Vec<(UserKey, E)>,
     inserts: HashMap<ComponentKind, Vec<(UserKey, E)>>,
     removes: HashMap<ComponentKind, Vec<(UserKey, E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(UserKey, E)>>,
+    auth_requests: Vec<(UserKey, E)>,
+    auth_releases: Vec<(UserKey, E)>,
     empty: bool,
 }
 
@@ -31,15 +43,22 @@ impl<E: Copy> Events<E> {
         Self {
             connections: Vec::new(),
             disconnections: Vec::new(),
+            address_changes: Vec::new(),
             ticks: Vec::new(),
             errors: Vec::new(),
             auths: HashMap::new(),
+            connect_tokens: Vec::new(),
             messages: HashMap::new(),
+            requests: HashMap::new(),
+            message_deliveries: Vec::new(),
+            message_expiries: Vec::new(),
             spawns: Vec::new(),
             despawns: Vec::new(),
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            auth_requests: Vec::new(),
+            auth_releases: Vec::new(),
             empty: true,
         }
     }
@@ -68,6 +87,14 @@ impl<E: Copy> Events<E> {
         mem::take(&mut self.messages)
     }
 
+    // This method is exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
+    pub fn has_requests(&self) -> bool {
+        !self.requests.is_empty()
+    }
+    pub fn take_requests(&mut self) -> RequestMap {
+        mem::take(&mut self.requests)
+    }
+
     // This method is exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
     pub fn has_auths(&self) -> bool {
         !self.auths.is_empty()
@@ -121,8 +148,29 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
-    pub(crate) fn push_disconnection(&mut self, user_key: &UserKey, user: User) {
-        self.disconnections.push((*user_key, user));
+    pub(crate) fn push_disconnection(
+        &mut self,
+        user_key: &UserKey,
+        user: User,
+        reason: DisconnectReason,
+    ) {
+        self.disconnections.push((*user_key, user, reason));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_address_change(
+        &mut self,
+        user_key: &UserKey,
+        old_address: &SocketAddr,
+        new_address: &SocketAddr,
+    ) {
+        self.address_changes
+            .push((*user_key, *old_address, *new_address));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_connect_token(&mut self, user_key: &UserKey, connect_token: ConnectToken) {
+        self.connect_tokens.push((*user_key, connect_token));
         self.empty = false;
     }
 
@@ -146,6 +194,40 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_request(
+        &mut self,
+        user_key: &UserKey,
+        channel_kind: &ChannelKind,
+        response_key: u64,
+        request: MessageContainer,
+    ) {
+        self.requests
+            .entry(*channel_kind)
+            .or_default()
+            .entry(request.kind())
+            .or_default()
+            .push((*user_key, response_key, request));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_message_delivery(
+        &mut self,
+        user_key: &UserKey,
+        message_handle: MessageHandle,
+    ) {
+        self.message_deliveries.push((*user_key, message_handle));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_message_expiry(
+        &mut self,
+        user_key: &UserKey,
+        message_handle: MessageHandle,
+    ) {
+        self.message_expiries.push((*user_key, message_handle));
+        self.empty = false;
+    }
+
     pub(crate) fn push_tick(&mut self, tick: Tick) {
         self.ticks.push(tick);
         self.empty = false;
@@ -210,6 +292,16 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_auth_request(&mut self, user_key: &UserKey, entity: &E) {
+        self.auth_requests.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_release(&mut self, user_key: &UserKey, entity: &E) {
+        self.auth_releases.push((*user_key, *entity));
+        self.empty = false;
+    }
+
     pub(crate) fn receive_entity_events(
         &mut self,
         user_key: &UserKey,
@@ -278,7 +370,7 @@ impl<E: Copy> Event<E> for ConnectEvent {
 // DisconnectEvent
 pub struct DisconnectEvent;
 impl<E: Copy> Event<E> for DisconnectEvent {
-    type Iter = IntoIter<(UserKey, User)>;
+    type Iter = IntoIter<(UserKey, User, DisconnectReason)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let list = std::mem::take(&mut events.disconnections);
@@ -290,6 +382,42 @@ impl<E: Copy> Event<E> for DisconnectEvent {
     }
 }
 
+// AddressChangeEvent
+/// Emitted when a connected User's address has changed, such as when their
+/// NAT mapping is rebound, with the User's old and new addresses
+pub struct AddressChangeEvent;
+impl<E: Copy> Event<E> for AddressChangeEvent {
+    type Iter = IntoIter<(UserKey, SocketAddr, SocketAddr)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.address_changes);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.address_changes.is_empty()
+    }
+}
+
+// ConnectTokenEvent
+/// Emitted when a User connects with a valid ConnectToken, with the token's
+/// contents. If the Client also sent an auth message, this is emitted
+/// alongside its AuthEvent, and the User still awaits being accepted.
+/// Otherwise, the User has already been accepted.
+pub struct ConnectTokenEvent;
+impl<E: Copy> Event<E> for ConnectTokenEvent {
+    type Iter = IntoIter<(UserKey, ConnectToken)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.connect_tokens);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.connect_tokens.is_empty()
+    }
+}
+
 // Tick Event
 pub struct TickEvent;
 impl<E: Copy> Event<E> for TickEvent {
@@ -365,6 +493,69 @@ impl<E: Copy, C: Channel, M: Message> Event<E> for MessageEvent<C, M> {
     }
 }
 
+// Message Delivered Event
+pub struct MessageDeliveredEvent;
+impl<E: Copy> Event<E> for MessageDeliveredEvent {
+    type Iter = IntoIter<(UserKey, MessageHandle)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.message_deliveries);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.message_deliveries.is_empty()
+    }
+}
+
+// Message Expired Event
+pub struct MessageExpiredEvent;
+impl<E: Copy> Event<E> for MessageExpiredEvent {
+    type Iter = IntoIter<(UserKey, MessageHandle)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.message_expiries);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.message_expiries.is_empty()
+    }
+}
+
+// Request Event
+pub struct RequestEvent<C: Channel, Q: Request> {
+    phantom_c: PhantomData<C>,
+    phantom_q: PhantomData<Q>,
+}
+impl<E: Copy, C: Channel, Q: Request> Event<E> for RequestEvent<C, Q> {
+    type Iter = IntoIter<(UserKey, ResponseSendKey<Q::Response>, Q)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let channel_kind: ChannelKind = ChannelKind::of::<C>();
+        let mut output_list = Vec::new();
+        if let Some(channel_map) = events.requests.get_mut(&channel_kind) {
+            let message_kind: MessageKind = MessageKind::of::<Q>();
+            if let Some(requests) = channel_map.remove(&message_kind) {
+                for (user_key, response_key, request) in requests {
+                    let request: Q = request.to_boxed_any().downcast::<Q>().map(|q| *q).unwrap();
+                    output_list.push((user_key, ResponseSendKey::new(response_key), request));
+                }
+            }
+        }
+        return IntoIterator::into_iter(output_list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let channel_kind: ChannelKind = ChannelKind::of::<C>();
+        if let Some(channel_map) = events.requests.get(&channel_kind) {
+            let message_kind: MessageKind = MessageKind::of::<Q>();
+            return channel_map.contains_key(&message_kind);
+        }
+        return false;
+    }
+}
+
 pub(crate) fn read_channel_messages<C: Channel, M: Message>(
     messages: &mut HashMap<ChannelKind, HashMap<MessageKind, Vec<(UserKey, MessageContainer)>>>,
 ) -> Vec<(UserKey, M)> {
@@ -443,6 +634,36 @@ impl<E: Copy> Event<E> for DespawnEntityEvent {
     }
 }
 
+// Entity Auth Request Event
+pub struct EntityAuthRequestEvent;
+impl<E: Copy> Event<E> for EntityAuthRequestEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_requests);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_requests.is_empty()
+    }
+}
+
+// Entity Auth Release Event
+pub struct EntityAuthReleaseEvent;
+impl<E: Copy> Event<E> for EntityAuthReleaseEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_releases);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_releases.is_empty()
+    }
+}
+
 // Insert Event
 pub struct InsertComponentEvent<C: Replicate> {
     phantom_c: PhantomData<C>,
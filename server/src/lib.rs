@@ -15,7 +15,10 @@ extern crate cfg_if;
 
 pub mod transport;
 pub mod shared {
-    pub use naia_shared::{default_channels, EntityRef, Random, SocketConfig};
+    pub use naia_shared::{
+        default_channels, DisconnectReason, EntityAuthStatus, EntityRef, Random, RejectReason,
+        SocketConfig, KICK_MESSAGE_MAX_BYTES,
+    };
 }
 pub mod internal {
     pub use crate::connection::handshake_manager::{HandshakeManager, HandshakeResult};
@@ -36,13 +39,14 @@ mod world;
 pub use connection::tick_buffer_messages::TickBufferMessages;
 pub use error::NaiaServerError;
 pub use events::{
-    AuthEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RemoveComponentEvent, SpawnEntityEvent, TickEvent,
-    UpdateComponentEvent,
+    AddressChangeEvent, AuthEvent, ConnectEvent, ConnectTokenEvent, DespawnEntityEvent,
+    DisconnectEvent, EntityAuthReleaseEvent, EntityAuthRequestEvent, ErrorEvent, Events,
+    InsertComponentEvent, MessageDeliveredEvent, MessageEvent, MessageExpiredEvent,
+    RemoveComponentEvent, RequestEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
 };
 pub use room::{RoomKey, RoomMut, RoomRef};
 pub use server::Server;
-pub use server_config::ServerConfig;
+pub use server_config::{ConnectTokenConfig, HandshakeConfig, ServerConfig};
 pub use user::{User, UserKey, UserMut, UserRef};
 pub use user_scope::UserScopeMut;
 pub use world::entity_mut::EntityMut;
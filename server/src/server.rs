@@ -1,7 +1,7 @@
 use std::{
-    collections::{hash_set::Iter, HashMap, HashSet},
+    collections::{hash_set::Iter, HashMap, HashSet, VecDeque},
     hash::Hash,
-    net::SocketAddr,
+    net::{IpAddr, SocketAddr},
     panic,
     time::Duration,
 };
@@ -12,15 +12,19 @@ use log::warn;
 use bevy_ecs::prelude::Resource;
 
 use naia_shared::{
-    BigMap, BitReader, BitWriter, Channel, ChannelKind, ComponentKind,
-    EntityAndGlobalEntityConverter, EntityConverterMut, EntityDoesNotExistError, EntityRef,
-    GlobalEntity, Instant, Message, MessageContainer, PacketType, Protocol, Replicate, Serde,
-    SerdeErr, SocketConfig, StandardHeader, Tick, Timer, WorldMutType, WorldRefType,
+    BigMap, BitReader, BitWriter, Channel, ChannelKind, ComponentKind, DisconnectReason,
+    EntityAndGlobalEntityConverter, EntityAuthAction, EntityAuthMessage, EntityAuthStatus,
+    EntityConverterMut, EntityDoesNotExistError, EntityRef, FakeEntityConverter, GlobalEntity,
+    Instant, LocalEntity, LocalEntityConverter, Message, MessageContainer, MessageHandle,
+    OrderingKey, OutgoingRequest, PacketType, Protocol, RejectReason, Replicate, Request,
+    RequestId, ResponseError, ResponseHandle, ResponseSendKey, Serde, SerdeErr, SocketConfig,
+    StandardHeader, SystemChannel, Tick, Timer, WorldMutType, WorldRefType,
 };
 
 use crate::{
     connection::{
         connection::Connection,
+        handshake_guard::HandshakeGuard,
         handshake_manager::{HandshakeManager, HandshakeResult},
         io::Io,
         tick_buffer_messages::TickBufferMessages,
@@ -59,6 +63,21 @@ pub struct Server<E: Copy + Eq + Hash + Send + Sync> {
     users: BigMap<UserKey, User>,
     user_connections: HashMap<SocketAddr, Connection<E>>,
     validated_users: HashMap<SocketAddr, UserKey>,
+    connection_ids: HashMap<u64, UserKey>,
+    // Users which haven't finished connecting, with when they started
+    pending_users: HashMap<SocketAddr, (UserKey, Instant)>,
+    // Users waiting for a free slot while the Server is full, in order, with
+    // when they were last heard from
+    queued_users: VecDeque<(UserKey, Instant)>,
+    handshake_guard: HandshakeGuard,
+    accept_messages: HashMap<UserKey, MessageContainer>,
+    // Requests
+    next_request_id: RequestId,
+    outgoing_requests: HashMap<RequestId, UserKey>,
+    // Requests received from Users, waiting to be responded to, with when
+    // they were received
+    incoming_requests: HashMap<u64, (UserKey, ChannelKind, RequestId, Instant)>,
+    next_response_key: u64,
     // Rooms
     rooms: BigMap<RoomKey, Room<E>>,
     // Entities
@@ -84,6 +103,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             &protocol.compression,
         );
 
+        let mut handshake_manager =
+            HandshakeManager::new(server_config.require_auth, protocol.fingerprint());
+        if let Some(connect_token_config) = &server_config.connect_token {
+            handshake_manager.enable_connect_tokens(connect_token_config.clone());
+        }
+
         Server {
             // Config
             server_config: server_config.clone(),
@@ -93,11 +118,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             heartbeat_timer: Timer::new(server_config.connection.heartbeat_interval),
             timeout_timer: Timer::new(server_config.connection.disconnection_timeout_duration),
             ping_timer: Timer::new(server_config.ping.ping_interval),
-            handshake_manager: HandshakeManager::new(server_config.require_auth),
+            handshake_manager,
             // Users
             users: BigMap::new(),
             user_connections: HashMap::new(),
             validated_users: HashMap::new(),
+            connection_ids: HashMap::new(),
+            pending_users: HashMap::new(),
+            queued_users: VecDeque::new(),
+            handshake_guard: HandshakeGuard::new(&server_config.handshake),
+            accept_messages: HashMap::new(),
+            // Requests
+            next_request_id: RequestId::zero(),
+            outgoing_requests: HashMap::new(),
+            incoming_requests: HashMap::new(),
+            next_response_key: 0,
             // Rooms
             rooms: BigMap::new(),
             // Entities
@@ -114,6 +149,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Listen at the given addresses
     pub fn listen<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
         let boxed_socket: Box<dyn Socket> = socket.into();
+        if boxed_socket.requires_encryption() {
+            #[cfg(feature = "encryption")]
+            {
+                self.io.enable_encryption();
+                self.handshake_manager
+                    .enable_encryption(self.server_config.encryption_secret_key);
+            }
+            #[cfg(not(feature = "encryption"))]
+            panic!("This Socket requires naia to encrypt packets, enable the `encryption` feature of naia-server to use it");
+        }
         let (packet_sender, packet_receiver) = boxed_socket.listen();
         self.io.load(packet_sender, packet_receiver);
     }
@@ -135,6 +180,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         // Need to run this to maintain connection with all clients, and receive packets
         // until none left
         self.maintain_socket(world);
+        self.expire_incoming_requests();
 
         // tick event
         if self.time_manager.recv_server_tick() {
@@ -151,6 +197,34 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Accepts an incoming Client User, allowing them to establish a connection
     /// with the Server
     pub fn accept_connection(&mut self, user_key: &UserKey) {
+        self.accept_connection_inner(user_key, None);
+    }
+
+    /// Accepts an incoming Client User like `accept_connection`, sending them
+    /// the given Message once connected. It is received by the Client as an
+    /// `AcceptMessageEvent`
+    ///
+    /// Returns an error, leaving the User unaccepted, if the Message is too
+    /// large to fit in the connect response packet
+    pub fn accept_connection_with<M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: M,
+    ) -> Result<(), NaiaServerError> {
+        let message = MessageContainer::from_write(Box::new(message), &mut FakeEntityConverter);
+        if !self
+            .handshake_manager
+            .connect_response_fits(&self.protocol.message_kinds, &message)
+        {
+            return Err(NaiaServerError::from_message(
+                "accept Message is too large to fit in the connect response packet",
+            ));
+        }
+        self.accept_connection_inner(user_key, Some(message));
+        Ok(())
+    }
+
+    fn accept_connection_inner(&mut self, user_key: &UserKey, message: Option<MessageContainer>) {
         let Some(user) = self.users.get(user_key) else {
             warn!("unknown user is finalizing connection...");
             return;
@@ -171,6 +245,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
 
         self.validated_users.insert(user.address, *user_key);
+        if let Some(message) = message {
+            self.accept_messages.insert(*user_key, message);
+        }
     }
 
     fn finalize_connection(&mut self, user_key: &UserKey) {
@@ -178,17 +255,28 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             warn!("unknown user is finalizing connection...");
             return;
         };
+        let connection_id = loop {
+            let connection_id = self.handshake_manager.generate_connection_id();
+            if !self.connection_ids.contains_key(&connection_id) {
+                break connection_id;
+            }
+        };
         let new_connection = Connection::new(
             &self.server_config.connection,
             &self.server_config.ping,
             &user.address,
             user_key,
+            connection_id,
             &self.protocol.channel_kinds,
             &self.global_world_manager,
         );
 
         // send connect response
-        let writer = self.handshake_manager.write_connect_response();
+        let writer = self.handshake_manager.write_connect_response(
+            &self.protocol.message_kinds,
+            connection_id,
+            self.accept_messages.get(user_key),
+        );
         if self
             .io
             .send_packet(&user.address, writer.to_packet())
@@ -202,6 +290,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
 
         self.user_connections.insert(user.address, new_connection);
+        self.connection_ids.insert(connection_id, *user_key);
+        self.pending_users.remove(&user.address);
+        #[cfg(feature = "encryption")]
+        self.io.set_connection_id(&user.address, connection_id);
         if self.io.bandwidth_monitor_enabled() {
             self.io.register_client(&user.address);
         }
@@ -211,9 +303,47 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Rejects an incoming Client User, terminating their attempt to establish
     /// a connection with the Server
     pub fn reject_connection(&mut self, user_key: &UserKey) {
+        self.reject_connection_inner(user_key, RejectReason::Rejected, None);
+    }
+
+    /// Rejects an incoming Client User like `reject_connection`, sending them
+    /// the given Message, e.g. the reason they were rejected. It is received
+    /// by the Client as a `RejectMessageEvent`
+    ///
+    /// Returns an error, leaving the User unrejected, if the Message is too
+    /// large to fit in the reject response packet
+    pub fn reject_connection_with<M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: M,
+    ) -> Result<(), NaiaServerError> {
+        let message = MessageContainer::from_write(Box::new(message), &mut FakeEntityConverter);
+        if !self.handshake_manager.reject_response_fits(
+            &self.protocol.message_kinds,
+            &RejectReason::Rejected,
+            &message,
+        ) {
+            return Err(NaiaServerError::from_message(
+                "reject Message is too large to fit in the reject response packet",
+            ));
+        }
+        self.reject_connection_inner(user_key, RejectReason::Rejected, Some(message));
+        Ok(())
+    }
+
+    fn reject_connection_inner(
+        &mut self,
+        user_key: &UserKey,
+        reason: RejectReason,
+        message: Option<MessageContainer>,
+    ) {
         if let Some(user) = self.users.get(user_key) {
             // send connect reject response
-            let writer = self.handshake_manager.write_reject_response();
+            let writer = self.handshake_manager.write_reject_response(
+                &self.protocol.message_kinds,
+                &reason,
+                message.as_ref(),
+            );
             if self
                 .io
                 .send_packet(&user.address, writer.to_packet())
@@ -230,13 +360,112 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.user_delete(user_key);
     }
 
+    /// Returns the number of Users waiting in the join queue for a free slot
+    pub fn queued_users_count(&self) -> usize {
+        self.queued_users.len()
+    }
+
+    /// Bans an IP address from connecting, for the given duration or until
+    /// unbanned. Users already connected from it are not disconnected, use
+    /// `UserMut::kick` for that
+    pub fn ban_ip(&mut self, ip: IpAddr, duration: Option<Duration>) {
+        self.handshake_guard.ban(ip, duration);
+    }
+
+    /// Lifts a ban on an IP address
+    pub fn unban_ip(&mut self, ip: &IpAddr) {
+        self.handshake_guard.unban(ip);
+    }
+
+    /// Returns whether an IP address is currently banned from connecting
+    pub fn is_ip_banned(&self, ip: &IpAddr) -> bool {
+        self.handshake_guard.is_banned(ip)
+    }
+
+    /// Exempts an IP address from the handshake packet rate limit
+    pub fn allow_ip(&mut self, ip: IpAddr) {
+        self.handshake_guard.allow(ip);
+    }
+
+    /// Subjects an IP address to the handshake packet rate limit again
+    pub fn disallow_ip(&mut self, ip: &IpAddr) {
+        self.handshake_guard.disallow(ip);
+    }
+
+    /// Disconnects every User, letting their Clients know the Server is
+    /// shutting down rather than waiting for their connections to time out
+    pub fn shutdown<W: WorldMutType<E>>(&mut self, mut world: W) {
+        for user_key in self.user_keys() {
+            self.user_kick(&user_key, DisconnectReason::ServerShutdown, &mut world);
+        }
+    }
+
     // Messages
 
     /// Queues up an Message to be sent to the Client associated with a given
-    /// UserKey
-    pub fn send_message<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+    /// UserKey. Returns a handle which a
+    /// [`MessageDeliveredEvent`](crate::MessageDeliveredEvent) gives back once
+    /// the Client has received a Message sent through a reliable Channel, or
+    /// None if the Client isn't connected.
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+    ) -> Option<MessageHandle> {
         let cloned_message = M::clone_box(message);
-        self.send_message_inner(user_key, &ChannelKind::of::<C>(), cloned_message);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<C>(),
+            cloned_message,
+            None,
+            None,
+        )
+    }
+
+    /// Queues up an Message to be sent to the Client associated with a given
+    /// UserKey like `send_message()`, but with a key. On an OrderedReliable
+    /// Channel it is only kept in order with other Messages sent with the same
+    /// key, so that a lost Message only holds back those after it which share
+    /// its key. On an UnreliableLatestByKey Channel it replaces any unsent
+    /// Message with the same key, and is dropped if a newer one with that key
+    /// was received. Keys must come from a bounded set, as an OrderedReliable
+    /// Channel panics once Messages are sent with more than
+    /// [`MAX_ORDERING_KEYS`](naia_shared::MAX_ORDERING_KEYS) of them.
+    pub fn send_message_keyed<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        key: &impl Hash,
+        message: &M,
+    ) -> Option<MessageHandle> {
+        let cloned_message = M::clone_box(message);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<C>(),
+            cloned_message,
+            None,
+            Some(OrderingKey::new(key)),
+        )
+    }
+
+    /// Queues up an Message to be sent to the Client associated with a given
+    /// UserKey like `send_message()`, but which stops being resent once it has
+    /// gone undelivered for `ttl`. A
+    /// [`MessageExpiredEvent`](crate::MessageExpiredEvent) then gives back its
+    /// handle. The Channel must be UnorderedReliable or SequencedReliable.
+    pub fn send_message_with_ttl<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+        ttl: Duration,
+    ) -> Option<MessageHandle> {
+        let cloned_message = M::clone_box(message);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<C>(),
+            cloned_message,
+            Some(ttl),
+            None,
+        )
     }
 
     /// Queues up an Message to be sent to the Client associated with a given
@@ -246,28 +475,54 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         user_key: &UserKey,
         channel_kind: &ChannelKind,
         message_box: Box<dyn Message>,
-    ) {
+        ttl: Option<Duration>,
+        key: Option<OrderingKey>,
+    ) -> Option<MessageHandle> {
         let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
 
         if !channel_settings.can_send_to_client() {
             panic!("Cannot send message to Client on this Channel");
         }
 
-        if let Some(user) = self.users.get(user_key) {
-            if let Some(connection) = self.user_connections.get_mut(&user.address) {
-                let mut converter = EntityConverterMut::new(
-                    &self.global_world_manager,
-                    &mut connection.base.local_world_manager,
-                );
-                let message = MessageContainer::from_write(message_box, &mut converter);
-                connection.base.message_manager.send_message(
-                    &self.protocol.message_kinds,
-                    &mut converter,
-                    channel_kind,
-                    message,
-                );
-            }
+        if ttl.is_some() && !channel_settings.can_expire_messages() {
+            panic!("Messages can only be given a time-to-live on an UnorderedReliable or SequencedReliable Channel");
         }
+
+        if key.is_some() && !channel_settings.keyed() {
+            panic!("Messages can only be given a key on an OrderedReliable or UnreliableLatestByKey Channel");
+        }
+
+        let user = self.users.get(user_key)?;
+        let connection = self.user_connections.get_mut(&user.address)?;
+        let mut converter = EntityConverterMut::new(
+            &self.global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        let message = MessageContainer::from_write(message_box, &mut converter);
+        let message_manager = &mut connection.base.message_manager;
+        let message_handle = match (ttl, key) {
+            (Some(ttl), _) => message_manager.send_message_with_ttl(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                message,
+                ttl,
+            ),
+            (None, Some(key)) => message_manager.send_message_keyed(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                key,
+                message,
+            ),
+            (None, None) => message_manager.send_message(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                message,
+            ),
+        };
+        Some(message_handle)
     }
 
     /// Sends a message to all connected users using a given channel
@@ -282,7 +537,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         message_box: Box<dyn Message>,
     ) {
         self.user_keys().iter().for_each(|user_key| {
-            self.send_message_inner(user_key, channel_kind, message_box.clone())
+            self.send_message_inner(user_key, channel_kind, message_box.clone(), None, None);
         })
     }
 
@@ -295,6 +550,136 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         tick_buffer_messages
     }
 
+    // Requests
+
+    /// Queues up a Request to be sent to the Client associated with a given
+    /// UserKey, returning a handle to receive its Response with. The Channel
+    /// must be reliable & bidirectional, as the Response is sent back through
+    /// it.
+    pub fn send_request<C: Channel, Q: Request>(
+        &mut self,
+        user_key: &UserKey,
+        request: &Q,
+    ) -> ResponseHandle<Q::Response> {
+        let channel_kind = ChannelKind::of::<C>();
+        let channel_settings = self.protocol.channel_kinds.channel(&channel_kind);
+        if !channel_settings.can_send_to_client() {
+            panic!("Cannot send request to Client on this Channel");
+        }
+        if !channel_settings.can_send_requests() {
+            panic!("Can only use `Server.send_request()` on a reliable, bidirectional Channel");
+        }
+
+        let request_id = self.next_request_id;
+        self.next_request_id.increment();
+
+        if let Some(user) = self.users.get(user_key) {
+            if let Some(connection) = self.user_connections.get_mut(&user.address) {
+                let mut converter = EntityConverterMut::new(
+                    &self.global_world_manager,
+                    &mut connection.base.local_world_manager,
+                );
+                let request = MessageContainer::from_write(Q::clone_box(request), &mut converter);
+                connection.base.message_manager.send_request(
+                    &self.protocol.message_kinds,
+                    &mut converter,
+                    &channel_kind,
+                    request,
+                    OutgoingRequest::of::<Q>(
+                        request_id,
+                        self.server_config.connection.request_timeout,
+                    ),
+                );
+                self.outgoing_requests.insert(request_id, *user_key);
+            }
+        }
+
+        ResponseHandle::new(request_id)
+    }
+
+    /// Takes the Response to a Request sent to a Client, if it has arrived.
+    /// Returns an error if the Request timed out, or the Client has since
+    /// disconnected.
+    pub fn receive_response<R: Message>(
+        &mut self,
+        response_handle: &ResponseHandle<R>,
+    ) -> Option<Result<R, ResponseError>> {
+        let request_id = response_handle.request_id();
+        let Some(connection) = self
+            .outgoing_requests
+            .get(&request_id)
+            .and_then(|user_key| self.users.get(user_key))
+            .and_then(|user| self.user_connections.get_mut(&user.address))
+        else {
+            self.outgoing_requests.remove(&request_id);
+            return Some(Err(ResponseError::Disconnected));
+        };
+        let outcome = connection
+            .base
+            .message_manager
+            .receive_response(&request_id)?;
+        self.outgoing_requests.remove(&request_id);
+        Some(outcome.map(|response| *response.to_boxed_any().downcast::<R>().unwrap()))
+    }
+
+    /// Stops waiting for the Response to a Request sent to a Client
+    pub fn cancel_request<R: Message>(&mut self, response_handle: ResponseHandle<R>) {
+        let request_id = response_handle.request_id();
+        let Some(user_key) = self.outgoing_requests.remove(&request_id) else {
+            return;
+        };
+        if let Some(connection) = self
+            .users
+            .get(&user_key)
+            .and_then(|user| self.user_connections.get_mut(&user.address))
+        {
+            connection.base.message_manager.cancel_request(&request_id);
+        }
+    }
+
+    /// Queues up the Response to a Request received from a Client. Returns
+    /// false if the Request has already been responded to, or the Client has
+    /// since disconnected.
+    pub fn send_response<R: Message>(
+        &mut self,
+        response_key: &ResponseSendKey<R>,
+        response: &R,
+    ) -> bool {
+        let Some((user_key, channel_kind, request_id, _)) =
+            self.incoming_requests.remove(&response_key.id())
+        else {
+            return false;
+        };
+        let Some(connection) = self
+            .users
+            .get(&user_key)
+            .and_then(|user| self.user_connections.get_mut(&user.address))
+        else {
+            return false;
+        };
+        let mut converter = EntityConverterMut::new(
+            &self.global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        let response = MessageContainer::from_write(R::clone_box(response), &mut converter);
+        connection.base.message_manager.send_response(
+            &self.protocol.message_kinds,
+            &mut converter,
+            &channel_kind,
+            request_id,
+            response,
+        );
+        true
+    }
+
+    // Drops Requests which have waited too long to be responded to, as the
+    // Client has given up on them
+    fn expire_incoming_requests(&mut self) {
+        let request_timeout = self.server_config.connection.request_timeout;
+        self.incoming_requests
+            .retain(|_, (_, _, _, received_instant)| received_instant.elapsed() < request_timeout);
+    }
+
     // Updates
 
     /// Used to evaluate whether, given a User & Entity that are in the
@@ -385,7 +770,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Panics if the Entity does not exist.
     pub fn entity<W: WorldRefType<E>>(&self, world: W, entity: &E) -> EntityRef<E, W> {
         if world.has_entity(entity) {
-            return EntityRef::new(world, entity);
+            let auth_status = self.entity_auth_status(entity);
+            return EntityRef::with_auth_status(world, entity, auth_status);
         }
         panic!("No Entity exists for given Key!");
     }
@@ -412,6 +798,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         return EntityOwner::Local;
     }
 
+    /// Gets the LocalEntity which identifies the given Entity on a User's
+    /// connection, if the Entity is currently in-scope for that User
+    pub fn user_local_entity(&self, user_key: &UserKey, entity: &E) -> Option<LocalEntity> {
+        let user = self.users.get(user_key)?;
+        let connection = self.user_connections.get(&user.address)?;
+        connection
+            .base
+            .local_world_manager
+            .entity_to_local_entity(entity)
+            .ok()
+    }
+
     // Users
 
     /// Returns whether or not a User exists for the given RoomKey
@@ -567,6 +965,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         None
     }
 
+    // Congestion
+    /// Gets the rate, in bytes per second, at which data is currently allowed
+    /// to be sent to the given User's Client. Returns None if the User does
+    /// not exist or congestion control is disabled.
+    pub fn send_rate(&self, user_key: &UserKey) -> Option<f32> {
+        let user = self.users.get(user_key)?;
+        let connection = self.user_connections.get(&user.address)?;
+        connection.base.send_rate()
+    }
+
     // Crate-Public methods
 
     //// Entities
@@ -603,6 +1011,170 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.global_world_manager.host_despawn_entity(entity);
     }
 
+    pub(crate) fn entity_set_priority(&mut self, entity: &E, gain: f32) {
+        self.global_world_manager.set_entity_priority(entity, gain);
+    }
+
+    pub(crate) fn entity_set_update_interval(&mut self, entity: &E, update_interval: Duration) {
+        self.global_world_manager
+            .set_entity_update_interval(entity, update_interval);
+    }
+
+    //// Entity Authority
+
+    /// Gets the User which currently has authority over the given Entity, if
+    /// any
+    pub fn entity_auth_holder(&self, entity: &E) -> Option<UserKey> {
+        self.global_world_manager.entity_auth_holder(entity)
+    }
+
+    /// Gets the status of authority over the given Entity. Returns None if the
+    /// Entity is not owned by the Server.
+    pub fn entity_auth_status(&self, entity: &E) -> Option<EntityAuthStatus> {
+        if self.global_world_manager.entity_owner(entity) != Some(EntityOwner::Server) {
+            return None;
+        }
+        if self
+            .global_world_manager
+            .entity_auth_holder(entity)
+            .is_some()
+        {
+            return Some(EntityAuthStatus::Granted);
+        }
+        if self.global_world_manager.entity_has_auth_requests(entity) {
+            return Some(EntityAuthStatus::Requested);
+        }
+        Some(EntityAuthStatus::Available)
+    }
+
+    pub(crate) fn entity_grant_authority(&mut self, entity: &E, user_key: &UserKey) {
+        if self.global_world_manager.entity_owner(entity) != Some(EntityOwner::Server) {
+            warn!("Can only grant authority over Entities owned by the Server");
+            return;
+        }
+        if !self.user_has_entity_in_scope(user_key, entity) {
+            warn!("Cannot grant authority over an Entity which is not in the User's scope");
+            return;
+        }
+
+        let previous_holder = self.global_world_manager.entity_auth_holder(entity);
+        if previous_holder == Some(*user_key) {
+            return;
+        }
+        if let Some(previous_holder) = previous_holder {
+            self.send_entity_auth_message(&previous_holder, entity, EntityAuthAction::Revoke);
+        }
+
+        // any other pending requests can no longer be granted
+        for requesting_user_key in self.global_world_manager.take_entity_auth_requests(entity) {
+            if requesting_user_key != *user_key {
+                self.send_entity_auth_message(&requesting_user_key, entity, EntityAuthAction::Deny);
+            }
+        }
+
+        self.global_world_manager
+            .set_entity_auth_holder(entity, Some(*user_key));
+        self.send_entity_auth_message(user_key, entity, EntityAuthAction::Grant);
+    }
+
+    pub(crate) fn entity_deny_authority(&mut self, entity: &E, user_key: &UserKey) {
+        if self.global_world_manager.entity_auth_holder(entity) == Some(*user_key) {
+            warn!("Cannot deny authority to the User which holds it, revoke it instead");
+            return;
+        }
+        if self
+            .global_world_manager
+            .remove_entity_auth_request(entity, user_key)
+        {
+            self.send_entity_auth_message(user_key, entity, EntityAuthAction::Deny);
+        }
+    }
+
+    pub(crate) fn entity_revoke_authority(&mut self, entity: &E) {
+        let Some(user_key) = self.global_world_manager.entity_auth_holder(entity) else {
+            return;
+        };
+        self.global_world_manager
+            .set_entity_auth_holder(entity, None);
+        self.send_entity_auth_message(&user_key, entity, EntityAuthAction::Revoke);
+    }
+
+    /// Handles an Entity authority action received from a User
+    fn receive_entity_auth_action(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        action: EntityAuthAction,
+    ) {
+        match action {
+            EntityAuthAction::Request => {
+                if self.global_world_manager.entity_owner(entity) != Some(EntityOwner::Server) {
+                    self.send_entity_auth_message(user_key, entity, EntityAuthAction::Deny);
+                    return;
+                }
+                match self.global_world_manager.entity_auth_holder(entity) {
+                    Some(holder) if holder == *user_key => {
+                        self.send_entity_auth_message(user_key, entity, EntityAuthAction::Grant);
+                    }
+                    Some(_) => {
+                        self.send_entity_auth_message(user_key, entity, EntityAuthAction::Deny);
+                    }
+                    None => {
+                        self.global_world_manager
+                            .insert_entity_auth_request(entity, user_key);
+                        self.incoming_events.push_auth_request(user_key, entity);
+                    }
+                }
+            }
+            EntityAuthAction::Release => {
+                if self.global_world_manager.entity_auth_holder(entity) == Some(*user_key) {
+                    self.release_entity_authority(user_key, entity);
+                } else {
+                    // the User is cancelling a pending request
+                    self.global_world_manager
+                        .remove_entity_auth_request(entity, user_key);
+                }
+            }
+            EntityAuthAction::Grant | EntityAuthAction::Deny | EntityAuthAction::Revoke => {
+                warn!("Received Entity authority action meant for the Client");
+            }
+        }
+    }
+
+    /// Takes authority over the Entity back from the User without notifying
+    /// them, for when they have released it or can no longer see the Entity
+    fn release_entity_authority(&mut self, user_key: &UserKey, entity: &E) {
+        self.global_world_manager
+            .set_entity_auth_holder(entity, None);
+        self.incoming_events.push_auth_release(user_key, entity);
+    }
+
+    fn send_entity_auth_message(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        action: EntityAuthAction,
+    ) {
+        let message = EntityAuthMessage::new(&self.global_world_manager, entity, action);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<SystemChannel>(),
+            Box::new(message),
+            None,
+            None,
+        );
+    }
+
+    fn user_has_entity_in_scope(&self, user_key: &UserKey, entity: &E) -> bool {
+        let Some(user) = self.users.get(user_key) else {
+            return false;
+        };
+        let Some(connection) = self.user_connections.get(&user.address) else {
+            return false;
+        };
+        connection.base.host_world_manager.host_has_entity(entity)
+    }
+
     //// Entity Scopes
 
     /// Remove all entities from a User's scope
@@ -620,6 +1192,24 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             .insert(*user_key, *entity, is_contained);
     }
 
+    pub(crate) fn user_scope_set_entity_priority(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        gain: f32,
+    ) {
+        let Some(user) = self.users.get(user_key) else {
+            return;
+        };
+        if let Some(connection) = self.user_connections.get_mut(&user.address) {
+            connection
+                .base
+                .host_world_manager
+                .entity_priorities
+                .set_gain(entity, gain);
+        }
+    }
+
     //// Components
 
     /// Adds a Component to an Entity
@@ -732,13 +1322,46 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     pub(crate) fn user_disconnect<W: WorldMutType<E>>(
         &mut self,
         user_key: &UserKey,
+        reason: DisconnectReason,
         world: &mut W,
     ) {
         if self.protocol.client_authoritative_entities {
             self.despawn_all_remote_entities(user_key, world);
         }
         let user = self.user_delete(user_key);
-        self.incoming_events.push_disconnection(user_key, user);
+        self.incoming_events
+            .push_disconnection(user_key, user, reason);
+    }
+
+    /// Disconnects a User, letting their Client know why
+    pub(crate) fn user_kick<W: WorldMutType<E>>(
+        &mut self,
+        user_key: &UserKey,
+        reason: DisconnectReason,
+        world: &mut W,
+    ) {
+        if let Some(user) = self.users.get(user_key) {
+            if let Some(connection) = self.user_connections.get(&user.address) {
+                // send disconnect packets, as some may be lost
+                for _ in 0..10 {
+                    let writer = self
+                        .handshake_manager
+                        .write_disconnect(connection.connection_id, &reason);
+                    if self
+                        .io
+                        .send_packet(&user.address, writer.to_packet())
+                        .is_err()
+                    {
+                        // TODO: pass this on and handle above
+                        warn!(
+                            "Server Error: Cannot send disconnect packet to {}",
+                            &user.address
+                        );
+                    }
+                }
+            }
+        }
+        self.user_disconnect(user_key, reason, world);
     }
 
     /// All necessary cleanup, when they're actually gone...
@@ -766,10 +1389,31 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             panic!("Attempting to delete non-existant user!");
         };
 
-        self.user_connections.remove(&user.address);
+        if let Some(connection) = self.user_connections.remove(&user.address) {
+            self.connection_ids.remove(&connection.connection_id);
+        }
         self.validated_users.remove(&user.address);
+        self.pending_users.remove(&user.address);
+        self.queued_users.retain(|(key, _)| key != user_key);
+        self.accept_messages.remove(user_key);
+        self.outgoing_requests.retain(|_, key| key != user_key);
+        self.incoming_requests
+            .retain(|_, (key, _, _, _)| key != user_key);
         self.entity_scope_map.remove_user(user_key);
+        self.global_world_manager.remove_user_receivers(user_key);
+
+        // Release any authority the user held
+        self.global_world_manager
+            .remove_user_auth_requests(user_key);
+        for entity in self
+            .global_world_manager
+            .entities_with_auth_holder(user_key)
+        {
+            self.release_entity_authority(user_key, &entity);
+        }
         self.handshake_manager.delete_user(&user.address);
+        #[cfg(feature = "encryption")]
+        self.io.remove_packet_cipher(&user.address);
 
         // Clean up all user data
         for room_key in user.room_keys() {
@@ -870,7 +1514,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         if let Some(room) = self.rooms.get(room_key) {
             let user_keys: Vec<UserKey> = room.user_keys().cloned().collect();
             for user_key in &user_keys {
-                self.send_message_inner(user_key, channel_kind, message_box.clone())
+                self.send_message_inner(user_key, channel_kind, message_box.clone(), None, None);
             }
         }
     }
@@ -935,6 +1579,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
 
     /// Maintain connection with a client and read all incoming packet data
     fn maintain_socket<W: WorldMutType<E>>(&mut self, mut world: W) {
+        self.handle_pending_users();
+        self.handle_join_queue();
         self.handle_disconnects(&mut world);
         self.handle_heartbeats();
         self.handle_pings();
@@ -944,12 +1590,19 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         loop {
             match self.io.recv_reader() {
                 Ok(Some((address, owned_reader))) => {
+                    // Packets from addresses without a Connection are dropped
+                    // if their IP is banned or sending too many
+                    if !self.user_connections.contains_key(&address)
+                        && !self.handshake_guard.allows_packet(&address.ip())
+                    {
+                        continue;
+                    }
+
                     let mut reader = owned_reader.borrow();
 
                     // Read header
                     let Ok(header) = StandardHeader::de(&mut reader) else {
                         // Received a malformed packet
-                        // TODO: increase suspicion against packet sender
                         continue;
                     };
 
@@ -961,6 +1614,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                         continue;
                     }
 
+                    // Read the connection id, finding the Connection the
+                    // packet belongs to even if the Client's address changed
+                    let Ok(connection_id) = u64::de(&mut reader) else {
+                        warn!("Server Error: cannot read malformed packet");
+                        continue;
+                    };
+                    if !self.check_connection_id(&address, connection_id, &header) {
+                        continue;
+                    }
+
                     addresses.insert(address);
 
                     if self
@@ -968,6 +1631,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                         .is_err()
                     {
                         warn!("Server Error: cannot read malformed packet");
+                        // the Client isn't speaking the same protocol
+                        if let Some(user_key) = self
+                            .user_connections
+                            .get(&address)
+                            .map(|connection| connection.user_key)
+                        {
+                            self.user_kick(&user_key, DisconnectReason::ProtocolError, &mut world);
+                        }
                         continue;
                     }
                 }
@@ -996,7 +1667,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         // Handshake stuff
         match header.packet_type {
             PacketType::ClientChallengeRequest => {
-                if let Ok(writer) = self.handshake_manager.recv_challenge_request(reader) {
+                if let Ok(writer) = self
+                    .handshake_manager
+                    .recv_challenge_request(&self.protocol.message_kinds, reader)
+                {
                     if self.io.send_packet(&address, writer.to_packet()).is_err() {
                         // TODO: pass this on and handle above
                         warn!(
@@ -1008,12 +1682,26 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 return Ok(true);
             }
             PacketType::ClientValidateRequest => {
+                // drop requests which would add a User beyond the limit
+                if let Some(max_pending_users) = self.server_config.handshake.max_pending_users {
+                    if self.pending_users.len() >= max_pending_users
+                        && !self.pending_users.contains_key(address)
+                    {
+                        return Ok(true);
+                    }
+                }
+
                 match self.handshake_manager.recv_validate_request(
                     &self.protocol.message_kinds,
                     address,
                     reader,
                 ) {
-                    HandshakeResult::Success(auth_message_opt) => {
+                    HandshakeResult::Success(auth_message_opt, connect_token_opt) => {
+                        #[cfg(feature = "encryption")]
+                        if let Some(packet_cipher) = self.handshake_manager.take_packet_cipher() {
+                            self.io.set_packet_cipher(address, packet_cipher);
+                        }
+
                         if self.validated_users.contains_key(address) {
                             // send validate response
                             let writer = self.handshake_manager.write_validate_response();
@@ -1021,9 +1709,20 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                                 // TODO: pass this on and handle above
                                 warn!("Server Error: Cannot send validate success response packet to {}", &address);
                             };
+                        } else if self.pending_users.contains_key(address) {
+                            // the User's auth is still being decided
                         } else {
                             let user = User::new(*address);
                             let user_key = self.users.insert(user);
+                            self.pending_users
+                                .insert(*address, (user_key, Instant::now()));
+
+                            // the ConnectToken has already been validated, it is
+                            // passed on so its contents can be read
+                            if let Some(connect_token) = connect_token_opt {
+                                self.incoming_events
+                                    .push_connect_token(&user_key, connect_token);
+                            }
 
                             if let Some(auth_message) = auth_message_opt {
                                 self.incoming_events.push_auth(&user_key, auth_message);
@@ -1039,9 +1738,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 return Ok(true);
             }
             PacketType::ClientConnectRequest => {
-                if self.user_connections.contains_key(address) {
+                if let Some(connection) = self.user_connections.get(address) {
                     // send connect response
-                    let writer = self.handshake_manager.write_connect_response();
+                    let writer = self.handshake_manager.write_connect_response(
+                        &self.protocol.message_kinds,
+                        connection.connection_id,
+                        self.accept_messages.get(&connection.user_key),
+                    );
                     if self.io.send_packet(address, writer.to_packet()).is_err() {
                         // TODO: pass this on and handle above
                         warn!(
@@ -1051,16 +1754,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                     };
                     //
                 } else {
-                    let user_key = *self
-                        .validated_users
-                        .get(address)
-                        .expect("should be a user by now, from validation step");
-                    self.finalize_connection(&user_key);
+                    // the User may have been rejected since sending this
+                    let Some(user_key) = self.validated_users.get(address).copied() else {
+                        return Ok(true);
+                    };
+                    if self.admit_user(&user_key) {
+                        self.finalize_connection(&user_key);
+                    }
                 }
                 return Ok(true);
             }
             PacketType::Ping => {
-                let response = self.time_manager.process_ping(reader).unwrap();
+                let response = self.time_manager.process_ping(reader)?;
                 // send packet
                 if self.io.send_packet(address, response.to_packet()).is_err() {
                     // TODO: pass this on and handle above
@@ -1077,6 +1782,68 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         return Ok(false);
     }
 
+    // Checks that a packet's connection id belongs to the Connection at the
+    // address it was received from. If the Connection is at another address,
+    // the Client's address has changed, and the Connection is moved over
+    // once a packet newer than all those received before arrives. Returns
+    // whether the packet should be read.
+    fn check_connection_id(
+        &mut self,
+        address: &SocketAddr,
+        connection_id: u64,
+        header: &StandardHeader,
+    ) -> bool {
+        let Some(user_key) = self.connection_ids.get(&connection_id).copied() else {
+            return false;
+        };
+        let old_address = self.users.get(&user_key).unwrap().address;
+        if old_address == *address {
+            return true;
+        }
+
+        // Another Client is connected from the new address
+        if self.user_connections.contains_key(address) {
+            return false;
+        }
+        // Older packets may have been delayed on the way from the old
+        // address, or replayed by someone else
+        let connection = self.user_connections.get(&old_address).unwrap();
+        if !connection.base.is_newest_incoming_packet(header) {
+            return false;
+        }
+
+        self.migrate_user(&user_key, &old_address, address);
+        true
+    }
+
+    fn migrate_user(
+        &mut self,
+        user_key: &UserKey,
+        old_address: &SocketAddr,
+        new_address: &SocketAddr,
+    ) {
+        self.users.get_mut(user_key).unwrap().address = *new_address;
+
+        let mut connection = self.user_connections.remove(old_address).unwrap();
+        connection.address = *new_address;
+        self.user_connections.insert(*new_address, connection);
+        if let Some(user_key) = self.validated_users.remove(old_address) {
+            self.validated_users.insert(*new_address, user_key);
+        }
+
+        self.handshake_manager
+            .migrate_user(old_address, new_address);
+        #[cfg(feature = "encryption")]
+        self.io.migrate_packet_cipher(old_address, new_address);
+        if self.io.bandwidth_monitor_enabled() {
+            self.io.deregister_client(old_address);
+            self.io.register_client(new_address);
+        }
+
+        self.incoming_events
+            .push_address_change(user_key, old_address, new_address);
+    }
+
     fn read_packet<W: WorldMutType<E>>(
         &mut self,
         address: &SocketAddr,
@@ -1112,12 +1879,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 )?;
             }
             PacketType::Disconnect => {
-                if self
+                if let Some(reason) = self
                     .handshake_manager
                     .verify_disconnect_request(connection, reader)
                 {
                     let user_key = connection.user_key;
-                    self.user_disconnect(&user_key, world);
+                    self.user_disconnect(&user_key, reason, world);
                 }
             }
             PacketType::Heartbeat => {
@@ -1141,12 +1908,141 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             return;
         };
 
-        connection.process_packets(
+        let user_key = connection.user_key;
+        let auth_actions = connection.process_packets(
             &self.protocol,
             &mut self.global_world_manager,
             world,
             &mut self.incoming_events,
         );
+
+        // receive requests, which are answered through a response key
+        let requests = connection.base.message_manager.take_incoming_requests();
+        if !requests.is_empty() {
+            let mut unanswered_count = self
+                .incoming_requests
+                .values()
+                .filter(|(key, _, _, _)| *key == user_key)
+                .count();
+            for (channel_kind, request_id, request) in requests {
+                if unanswered_count >= self.server_config.connection.max_incoming_requests {
+                    warn!("Dropping Request from a User with too many unanswered Requests");
+                    continue;
+                }
+                unanswered_count += 1;
+                let response_key = self.next_response_key;
+                self.next_response_key = self.next_response_key.wrapping_add(1);
+                self.incoming_requests.insert(
+                    response_key,
+                    (user_key, channel_kind, request_id, Instant::now()),
+                );
+                self.incoming_events
+                    .push_request(&user_key, &channel_kind, response_key, request);
+            }
+        }
+
+        // receive delivery notifications for sent messages
+        for message_handle in connection.base.message_manager.take_delivered_messages() {
+            self.incoming_events
+                .push_message_delivery(&user_key, message_handle);
+        }
+        for message_handle in connection.base.message_manager.take_expired_messages() {
+            self.incoming_events
+                .push_message_expiry(&user_key, message_handle);
+        }
+
+        for (entity, action) in auth_actions {
+            self.receive_entity_auth_action(&user_key, &entity, action);
+        }
+    }
+
+    // Checks whether a User may finish connecting, given `max_users`. If the
+    // Server is full, the User is either rejected, or placed in the join queue
+    // & sent their position in it
+    fn admit_user(&mut self, user_key: &UserKey) -> bool {
+        let Some(max_users) = self.server_config.max_users else {
+            return true;
+        };
+        let free_slots = max_users.saturating_sub(self.user_connections.len());
+
+        if !self.server_config.join_queue {
+            if free_slots == 0 {
+                self.reject_connection_inner(user_key, RejectReason::ServerFull, None);
+                return false;
+            }
+            return true;
+        }
+
+        let index = match self
+            .queued_users
+            .iter()
+            .position(|(key, _)| key == user_key)
+        {
+            Some(index) => {
+                self.queued_users[index].1 = Instant::now();
+                index
+            }
+            None => {
+                if self.queued_users.len() < free_slots {
+                    return true;
+                }
+                // the User has been accepted, so should wait as long as it takes
+                // for a slot to free rather than time out
+                let address = self.users.get(user_key).unwrap().address;
+                self.pending_users.remove(&address);
+                self.queued_users.push_back((*user_key, Instant::now()));
+                self.queued_users.len() - 1
+            }
+        };
+
+        if index < free_slots {
+            self.queued_users.remove(index);
+            return true;
+        }
+
+        // send queue response
+        let address = self.users.get(user_key).unwrap().address;
+        let position = (index - free_slots + 1) as u32;
+        let writer = self.handshake_manager.write_queue_response(position);
+        if self.io.send_packet(&address, writer.to_packet()).is_err() {
+            // TODO: pass this on and handle above
+            warn!(
+                "Server Error: Cannot send queue response packet to {}",
+                &address
+            );
+        }
+        false
+    }
+
+    // Removes Users from the join queue which have stopped asking to connect
+    fn handle_join_queue(&mut self) {
+        let timeout = self.server_config.connection.disconnection_timeout_duration;
+        let expired_user_keys: Vec<UserKey> = self
+            .queued_users
+            .iter()
+            .filter(|(_, last_heard)| last_heard.elapsed() > timeout)
+            .map(|(user_key, _)| *user_key)
+            .collect();
+        for user_key in expired_user_keys {
+            self.user_delete(&user_key);
+        }
+    }
+
+    // Rejects Users which have taken too long to connect, such as those whose
+    // auth was never accepted or rejected
+    fn handle_pending_users(&mut self) {
+        let Some(auth_timeout) = self.server_config.handshake.auth_timeout else {
+            return;
+        };
+        let expired_user_keys: Vec<UserKey> = self
+            .pending_users
+            .values()
+            .filter(|(_, started)| started.elapsed() > auth_timeout)
+            .map(|(user_key, _)| *user_key)
+            .collect();
+        for user_key in expired_user_keys {
+            self.reject_connection(&user_key);
+        }
     }
 
     fn handle_disconnects<W: WorldMutType<E>>(&mut self, world: &mut W) {
@@ -1165,7 +2061,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             }
 
             for user_key in user_disconnects {
-                self.user_disconnect(&user_key, world);
+                self.user_disconnect(&user_key, DisconnectReason::Timeout, world);
             }
         }
     }
@@ -1255,6 +2151,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     // Entity Scopes
 
     fn update_entity_scopes<W: WorldRefType<E>>(&mut self, world: &W) {
+        let mut removed_from_scope: Vec<(UserKey, E)> = Vec::new();
+
         for (_, room) in self.rooms.iter_mut() {
             while let Some((removed_user, removed_entity)) = room.pop_entity_removal_queue() {
                 if let Some(user) = self.users.get(&removed_user) {
@@ -1267,6 +2165,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                             .base
                             .host_world_manager
                             .despawn_entity(&removed_entity);
+                        removed_from_scope.push((removed_user, removed_entity));
                     }
                 }
             }
@@ -1305,6 +2204,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                                 } else if currently_in_scope {
                                     // remove entity from the connections local scope
                                     connection.base.host_world_manager.despawn_entity(entity);
+                                    removed_from_scope.push((*user_key, *entity));
                                 }
                             }
                         }
@@ -1312,6 +2212,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 }
             }
         }
+
+        // Users can't hold authority over Entities they can't see
+        for (user_key, entity) in removed_from_scope {
+            self.global_world_manager
+                .remove_entity_auth_request(&entity, &user_key);
+            if self.global_world_manager.entity_auth_holder(&entity) == Some(user_key) {
+                self.release_entity_authority(&user_key, &entity);
+            }
+        }
     }
 }
 
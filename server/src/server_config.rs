@@ -1,6 +1,12 @@
-use std::default::Default;
+use std::{
+    default::Default,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use naia_shared::ConnectionConfig;
+#[cfg(feature = "encryption")]
+use naia_shared::EncryptionSecretKey;
 
 use crate::connection::ping_config::PingConfig;
 
@@ -14,6 +20,25 @@ pub struct ServerConfig {
     pub require_auth: bool,
     /// Configuration used to monitor the ping & jitter on the network
     pub ping: PingConfig,
+    /// If set, requires that the Client connect with a
+    /// [`ConnectToken`](naia_shared::ConnectToken) issued by a backend
+    pub connect_token: Option<ConnectTokenConfig>,
+    /// Limits on Clients which have not finished connecting, to protect the
+    /// Server from floods of handshake packets
+    pub handshake: HandshakeConfig,
+    /// The maximum number of Users which may be connected at once. None
+    /// disables the limit
+    pub max_users: Option<usize>,
+    /// Determines whether Users connecting while the Server has `max_users`
+    /// wait in a FIFO queue for a free slot, receiving their position in it,
+    /// rather than being rejected with `RejectReason::ServerFull`
+    pub join_queue: bool,
+    /// The X25519 secret key the Server agrees on encryption keys with, on
+    /// transports which require naia to encrypt packets. If None, a random
+    /// one is generated. Set it so that Clients can pin the matching public
+    /// key, with `ClientConfig::server_public_key`
+    #[cfg(feature = "encryption")]
+    pub encryption_secret_key: Option<EncryptionSecretKey>,
 }
 
 impl Default for ServerConfig {
@@ -22,6 +47,58 @@ impl Default for ServerConfig {
             connection: ConnectionConfig::default(),
             require_auth: true,
             ping: PingConfig::default(),
+            connect_token: None,
+            handshake: HandshakeConfig::default(),
+            max_users: None,
+            join_queue: false,
+            #[cfg(feature = "encryption")]
+            encryption_secret_key: None,
+        }
+    }
+}
+
+/// Contains Config properties used to check the
+/// [`ConnectToken`](naia_shared::ConnectToken)s Clients connect with
+#[derive(Clone)]
+pub struct ConnectTokenConfig {
+    /// The key shared with the backend, which it signs ConnectTokens with
+    pub key: Vec<u8>,
+    /// The public address of the Server, which a ConnectToken must list in
+    /// order to be accepted
+    pub server_address: SocketAddr,
+}
+
+/// Contains Config properties used to limit the handshake packets & state the
+/// Server accepts from Clients which have not finished connecting
+#[derive(Clone)]
+pub struct HandshakeConfig {
+    /// The maximum number of packets accepted each second from a single IP
+    /// address which has no established connection. Further packets are
+    /// dropped. None disables the limit
+    pub max_packets_per_ip_per_second: Option<u32>,
+    /// The maximum number of Users which may be connecting at once. Requests
+    /// which would add a User beyond this are dropped. None disables the limit
+    pub max_pending_users: Option<usize>,
+    /// How long a User may take to finish connecting, including waiting for
+    /// the Server to accept or reject their auth, before being rejected. None
+    /// disables the timeout
+    pub auth_timeout: Option<Duration>,
+    /// IP addresses exempt from the packet rate limit. More can be added
+    /// with `Server::allow_ip`
+    pub allowed_ips: Vec<IpAddr>,
+    /// IP addresses which can never connect. More can be added, for a time,
+    /// with `Server::ban_ip`
+    pub denied_ips: Vec<IpAddr>,
+}
+
+impl Default for HandshakeConfig {
+    fn default() -> Self {
+        Self {
+            max_packets_per_ip_per_second: None,
+            max_pending_users: Some(1024),
+            auth_timeout: Some(Duration::from_secs(30)),
+            allowed_ips: Vec::new(),
+            denied_ips: Vec::new(),
         }
     }
 }
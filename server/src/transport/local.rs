@@ -0,0 +1,94 @@
+use std::net::SocketAddr;
+
+use naia_shared::{LinkConditionerConfig, LocalTransportHub};
+
+use super::{
+    conditioner::ConditionedPacketReceiver, PacketReceiver as TransportReceiver,
+    PacketSender as TransportSender, RecvError, SendError, Socket as TransportSocket,
+};
+
+// Socket
+pub struct Socket {
+    hub: LocalTransportHub,
+    config: Option<LinkConditionerConfig>,
+}
+
+impl Socket {
+    pub fn new(hub: &LocalTransportHub, config: Option<LinkConditionerConfig>) -> Self {
+        return Self {
+            hub: hub.clone(),
+            config,
+        };
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let sender = Box::new(PacketSender::new(self.hub.clone()));
+
+        let receiver: Box<dyn TransportReceiver> = {
+            let inner_receiver = Box::new(PacketReceiver::new(self.hub.clone()));
+            if let Some(config) = &self.config {
+                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+            } else {
+                inner_receiver
+            }
+        };
+
+        return (sender, receiver);
+    }
+}
+
+// Packet Sender
+struct PacketSender {
+    hub: LocalTransportHub,
+}
+
+impl PacketSender {
+    pub fn new(hub: LocalTransportHub) -> Self {
+        return Self { hub };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Server Socket
+    fn send(&self, socket_addr: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+        self.hub.send_to_client(socket_addr, payload);
+        return Ok(());
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    hub: LocalTransportHub,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(hub: LocalTransportHub) -> Self {
+        return Self {
+            hub,
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Server Socket
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        match self.hub.recv_on_server() {
+            Some((address, payload)) => {
+                self.last_payload = Some(payload);
+                Ok(Some((address, self.last_payload.as_ref().unwrap())))
+            }
+            None => Ok(None),
+        }
+    }
+}
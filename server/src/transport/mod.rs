@@ -6,6 +6,15 @@ cfg_if! {
 cfg_if! {
     if #[cfg(feature = "transport_udp")] {
         pub mod udp;
+    } else {}
+}
+cfg_if! {
+    if #[cfg(feature = "transport_local")] {
+        pub mod local;
+    } else {}
+}
+cfg_if! {
+    if #[cfg(any(feature = "transport_udp", feature = "transport_local"))] {
         mod conditioner;
     } else {}
 }
@@ -22,6 +31,11 @@ mod inner {
 
     pub trait Socket {
         fn listen(self: Box<Self>) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>);
+        /// Whether naia must encrypt the packets sent through this Socket,
+        /// because its transport sends them in the clear
+        fn requires_encryption(&self) -> bool {
+            false
+        }
     }
 
     pub trait PacketSender: Send + Sync {
@@ -52,6 +52,10 @@ impl TransportSocket for Socket {
 
         return (sender, receiver);
     }
+
+    fn requires_encryption(&self) -> bool {
+        true
+    }
 }
 
 // Packet Sender
@@ -4,7 +4,7 @@ use std::{
     net::SocketAddr,
 };
 
-use naia_shared::{BigMapKey, WorldMutType};
+use naia_shared::{BigMapKey, DisconnectReason, WorldMutType, KICK_MESSAGE_MAX_BYTES};
 
 use crate::{RoomKey, Server};
 
@@ -104,8 +104,24 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> UserMut<'s, E> {
         self.server.user_address(&self.key).unwrap()
     }
 
-    pub fn disconnect<W: WorldMutType<E>>(&mut self, mut world: W) {
-        self.server.user_disconnect(&self.key, &mut world);
+    /// Disconnects the User, which their Client sees as being kicked
+    pub fn disconnect<W: WorldMutType<E>>(&mut self, world: W) {
+        self.kick(world, None);
+    }
+
+    /// Disconnects the User, sending their Client an optional message, e.g.
+    /// why they were kicked. The message is sent in a single packet, so it is
+    /// truncated to `KICK_MESSAGE_MAX_BYTES`
+    pub fn kick<W: WorldMutType<E>>(&mut self, mut world: W, mut message: Option<String>) {
+        if let Some(message) = &mut message {
+            let mut length = message.len().min(KICK_MESSAGE_MAX_BYTES);
+            while !message.is_char_boundary(length) {
+                length -= 1;
+            }
+            message.truncate(length);
+        }
+        self.server
+            .user_kick(&self.key, DisconnectReason::Kicked(message), &mut world);
     }
 
     // Rooms
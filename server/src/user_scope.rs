@@ -26,6 +26,16 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> UserScopeMut<'s, E> {
         self
     }
 
+    /// Sets the Entity's update priority gain for this User only, which
+    /// multiplies the Entity's global priority gain. Defaults to 1.0, and is
+    /// reset when the Entity leaves the User's scope
+    pub fn set_priority(&mut self, entity: &E, gain: f32) -> &mut Self {
+        self.server
+            .user_scope_set_entity_priority(&self.key, entity, gain);
+
+        self
+    }
+
     /// Removes all Entities from the User's scope
     pub fn clear(&mut self) -> &mut Self {
         self.server.user_scope_remove_user(&self.key);
@@ -1,8 +1,8 @@
-use std::hash::Hash;
+use std::{hash::Hash, time::Duration};
 
 use naia_shared::{ReplicaMutWrapper, Replicate, WorldMutType};
 
-use crate::{room::RoomKey, server::Server};
+use crate::{room::RoomKey, server::Server, UserKey};
 
 // EntityMut
 pub struct EntityMut<'s, E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>> {
@@ -58,6 +58,54 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>> EntityMut<'s, E,
             .remove_component::<R, W>(&mut self.world, &self.entity)
     }
 
+    // Priority
+
+    /// Sets the Entity's update priority gain for all Users. Each tick the
+    /// Entity has updates waiting to be sent, its priority grows by this
+    /// amount, and Entities with higher priority are sent first.
+    /// Defaults to 1.0
+    pub fn set_priority(&mut self, gain: f32) -> &mut Self {
+        self.server.entity_set_priority(&self.entity, gain);
+
+        self
+    }
+
+    // Update Interval
+
+    /// Sets the minimum duration between updates of any of the Entity's
+    /// Components being sent, overriding the update interval each Component
+    /// kind was registered with in the Protocol
+    pub fn set_update_interval(&mut self, update_interval: Duration) -> &mut Self {
+        self.server
+            .entity_set_update_interval(&self.entity, update_interval);
+
+        self
+    }
+
+    // Authority
+
+    /// Grants authority over the Entity to the given User, revoking it from
+    /// any User which held it before. Other pending requests are denied.
+    pub fn grant_authority(&mut self, user_key: &UserKey) -> &mut Self {
+        self.server.entity_grant_authority(&self.entity, user_key);
+
+        self
+    }
+
+    /// Denies the given User's pending request for authority over the Entity
+    pub fn deny_authority(&mut self, user_key: &UserKey) -> &mut Self {
+        self.server.entity_deny_authority(&self.entity, user_key);
+
+        self
+    }
+
+    /// Takes authority over the Entity back from the User which holds it
+    pub fn revoke_authority(&mut self) -> &mut Self {
+        self.server.entity_revoke_authority(&self.entity);
+
+        self
+    }
+
     // Rooms
 
     pub fn enter_room(&mut self, room_key: &RoomKey) -> &mut Self {
@@ -1,13 +1,17 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
 use naia_shared::{ComponentKind, GlobalEntity};
 
-use crate::EntityOwner;
+use crate::{EntityOwner, UserKey};
 
 pub struct GlobalEntityRecord {
     pub global_entity: GlobalEntity,
     pub component_kinds: HashSet<ComponentKind>,
     pub owner: EntityOwner,
+    pub priority: f32,
+    pub update_interval: Option<Duration>,
+    pub auth_holder: Option<UserKey>,
+    pub auth_requests: HashSet<UserKey>,
 }
 
 impl GlobalEntityRecord {
@@ -19,6 +23,10 @@ impl GlobalEntityRecord {
             global_entity,
             component_kinds: HashSet::new(),
             owner,
+            priority: 1.0,
+            update_interval: None,
+            auth_holder: None,
+            auth_requests: HashSet::new(),
         }
     }
 }
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     hash::Hash,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use naia_shared::{
@@ -52,6 +53,81 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
         return None;
     }
 
+    pub fn set_entity_priority(&mut self, entity: &E, priority: f32) {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.priority = priority;
+        }
+    }
+
+    pub fn entity_auth_holder(&self, entity: &E) -> Option<UserKey> {
+        self.entity_records.get(entity)?.auth_holder
+    }
+
+    pub fn set_entity_auth_holder(&mut self, entity: &E, auth_holder: Option<UserKey>) {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.auth_holder = auth_holder;
+        }
+    }
+
+    pub fn entity_has_auth_requests(&self, entity: &E) -> bool {
+        if let Some(record) = self.entity_records.get(entity) {
+            !record.auth_requests.is_empty()
+        } else {
+            false
+        }
+    }
+
+    pub fn insert_entity_auth_request(&mut self, entity: &E, user_key: &UserKey) {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.auth_requests.insert(*user_key);
+        }
+    }
+
+    /// Removes the User's pending request for authority over the Entity.
+    /// Returns whether there was such a request.
+    pub fn remove_entity_auth_request(&mut self, entity: &E, user_key: &UserKey) -> bool {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.auth_requests.remove(user_key)
+        } else {
+            false
+        }
+    }
+
+    /// Removes & returns all pending requests for authority over the Entity
+    pub fn take_entity_auth_requests(&mut self, entity: &E) -> Vec<UserKey> {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.auth_requests.drain().collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Removes all of the User's pending requests for authority
+    pub fn remove_user_auth_requests(&mut self, user_key: &UserKey) {
+        for (_, record) in self.entity_records.iter_mut() {
+            record.auth_requests.remove(user_key);
+        }
+    }
+
+    /// Gets all Entities which the given User has authority over
+    pub fn entities_with_auth_holder(&self, user_key: &UserKey) -> Vec<E> {
+        let mut output = Vec::new();
+
+        for (entity, record) in &self.entity_records {
+            if record.auth_holder == Some(*user_key) {
+                output.push(*entity);
+            }
+        }
+
+        output
+    }
+
+    pub fn set_entity_update_interval(&mut self, entity: &E, update_interval: Duration) {
+        if let Some(record) = self.entity_records.get_mut(entity) {
+            record.update_interval = Some(update_interval);
+        }
+    }
+
     // Spawn
     pub fn host_spawn_entity(&mut self, entity: &E) {
         if self.entity_records.contains_key(entity) {
@@ -129,13 +205,23 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
             .deregister_component(entity, component_kind);
     }
 
+    /// Stops tracking changes to Components for a User which has been deleted
+    pub fn remove_user_receivers(&mut self, user_key: &UserKey) {
+        self.diff_handler
+            .as_ref()
+            .write()
+            .expect("Haven't initialized DiffHandler")
+            .remove_user(&user_key.to_u64());
+    }
+
     pub fn remote_spawn_entity_record(&mut self, entity: &E, user_key: &UserKey) {
+        // the Entity may have been despawned (and its World Entity reused)
+        // by later actions in the same packet
         let Some(record) = self.entity_records.get_mut(entity) else {
-            panic!("entity record does not exist!");
+            return;
         };
-
         if record.owner != EntityOwner::ClientWaiting(*user_key) {
-            panic!("client entity record is not waiting to be updated!");
+            return;
         }
 
         record.owner = EntityOwner::Client(*user_key);
@@ -165,6 +251,27 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
         return false;
     }
 
+    fn entity_is_delegated_to_user(&self, entity: &E, user_key: &u64) -> bool {
+        let Some(auth_holder) = self.entity_auth_holder(entity) else {
+            return false;
+        };
+        auth_holder.to_u64() == *user_key
+    }
+
+    fn entity_priority(&self, entity: &E) -> f32 {
+        if let Some(record) = self.entity_records.get(entity) {
+            return record.priority;
+        }
+        return 1.0;
+    }
+
+    fn entity_update_interval(&self, entity: &E) -> Option<Duration> {
+        if let Some(record) = self.entity_records.get(entity) {
+            return record.update_interval;
+        }
+        return None;
+    }
+
     fn new_mut_channel(&self, diff_mask_length: u8) -> Arc<RwLock<dyn MutChannelType>> {
         let mut_channel = MutChannelData::new(diff_mask_length);
         return Arc::new(RwLock::new(mut_channel));
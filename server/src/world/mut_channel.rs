@@ -1,9 +1,10 @@
-use std::{collections::HashMap, net::SocketAddr};
+use std::collections::HashMap;
 
 use naia_shared::{MutChannelType, MutReceiver};
 
 pub struct MutChannelData {
-    receiver_map: HashMap<SocketAddr, MutReceiver>,
+    /// A receiver for each User the Component is replicated to
+    receiver_map: HashMap<u64, MutReceiver>,
     diff_mask_length: u8,
 }
 
@@ -17,18 +18,22 @@ impl MutChannelData {
 }
 
 impl MutChannelType for MutChannelData {
-    fn new_receiver(&mut self, address_opt: &Option<SocketAddr>) -> Option<MutReceiver> {
-        let address = address_opt.expect("cannot initialize receiver without address");
-        if let Some(receiver) = self.receiver_map.get(&address) {
+    fn new_receiver(&mut self, user_key_opt: &Option<u64>) -> Option<MutReceiver> {
+        let user_key = user_key_opt.expect("cannot initialize receiver without user key");
+        if let Some(receiver) = self.receiver_map.get(&user_key) {
             Some(receiver.clone())
         } else {
             let receiver = MutReceiver::new(self.diff_mask_length);
-            self.receiver_map.insert(address, receiver.clone());
+            self.receiver_map.insert(user_key, receiver.clone());
 
             Some(receiver)
         }
     }
 
+    fn remove_receiver(&mut self, user_key: &u64) {
+        self.receiver_map.remove(user_key);
+    }
+
     fn send(&self, diff: u8) {
         for (_, receiver) in self.receiver_map.iter() {
             receiver.mutate(diff);
@@ -1,7 +1,7 @@
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, LitStr};
 
-use super::shared::{get_struct_type, StructType};
+use super::shared::{get_explicit_net_id_method, get_struct_type, StructType};
 
 pub fn channel_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -15,13 +15,20 @@ pub fn channel_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         _ => {}
     }
 
+    // Methods
+    let explicit_net_id_method = get_explicit_net_id_method(&input);
+
     // Names
     let struct_name = input.ident;
+    let struct_name_str = LitStr::new(&struct_name.to_string(), struct_name.span());
 
     let gen = quote! {
 
         impl Channel for #struct_name {
-
+            fn name() -> &'static str where Self:Sized {
+                #struct_name_str
+            }
+            #explicit_net_id_method
         }
     };
 
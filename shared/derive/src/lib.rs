@@ -17,21 +17,21 @@ use replicate::replicate_impl;
 // Replicate
 
 /// Derives the Replicate trait for a given struct
-#[proc_macro_derive(Replicate)]
+#[proc_macro_derive(Replicate, attributes(net_id))]
 pub fn replicate_derive_shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_shared };
     replicate_impl(input, shared_crate_name)
 }
 
 /// Derives the Replicate trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(ReplicateBevy)]
+#[proc_macro_derive(ReplicateBevy, attributes(net_id))]
 pub fn replicate_derive_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_bevy_shared };
     replicate_impl(input, shared_crate_name)
 }
 
 /// Derives the Replicate trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(ReplicateHecs)]
+#[proc_macro_derive(ReplicateHecs, attributes(net_id))]
 pub fn replicate_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_hecs_shared };
     replicate_impl(input, shared_crate_name)
@@ -40,7 +40,7 @@ pub fn replicate_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::Toke
 // Channel
 
 /// Derives the Channel trait for a given struct
-#[proc_macro_derive(Channel)]
+#[proc_macro_derive(Channel, attributes(net_id))]
 pub fn channel_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     channel_impl(input)
 }
@@ -48,35 +48,35 @@ pub fn channel_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 // Message
 
 /// Derives the Message trait for a given struct, for internal
-#[proc_macro_derive(MessageInternal)]
+#[proc_macro_derive(MessageInternal, attributes(net_id))]
 pub fn message_derive_internal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { crate };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for FragmentedMessage
-#[proc_macro_derive(MessageFragment)]
+#[proc_macro_derive(MessageFragment, attributes(net_id))]
 pub fn message_derive_fragment(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { crate };
     message_impl(input, shared_crate_name, true)
 }
 
 /// Derives the Message trait for a given struct
-#[proc_macro_derive(Message)]
+#[proc_macro_derive(Message, attributes(net_id))]
 pub fn message_derive_shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_shared };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(MessageBevy)]
+#[proc_macro_derive(MessageBevy, attributes(net_id))]
 pub fn message_derive_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_bevy_shared };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for the Hecs adapter
-#[proc_macro_derive(MessageHecs)]
+#[proc_macro_derive(MessageHecs, attributes(net_id))]
 pub fn message_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_hecs_shared };
     message_impl(input, shared_crate_name, false)
@@ -2,7 +2,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Index, LitStr, Member, Type};
 
-use super::shared::{get_struct_type, StructType};
+use super::shared::{get_explicit_net_id_method, get_struct_type, StructType};
 
 pub fn message_impl(
     input: proc_macro::TokenStream,
@@ -14,6 +14,7 @@ pub fn message_impl(
     // Helper Properties
     let struct_type = get_struct_type(&input);
     let fields = get_fields(&input);
+    let explicit_net_id_method = get_explicit_net_id_method(&input);
 
     // Names
     let struct_name = input.ident;
@@ -34,6 +35,7 @@ pub fn message_impl(
     let bit_length_method = get_bit_length_method(&fields, &struct_type);
     let write_method = get_write_method(&fields, &struct_type);
     let create_builder_method = get_create_builder_method(&builder_name);
+    let type_layout_method = get_type_layout_method(&struct_name_str, &fields);
     let read_method = get_read_method(&struct_name, &fields, &struct_type);
     let is_fragment_method = get_is_fragment_method(is_fragment);
 
@@ -44,7 +46,8 @@ pub fn message_impl(
             pub use std::collections::HashSet;
             pub use #shared_crate_name::{
                 Named, GlobalEntity, Message, BitWrite, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, LocalEntity,
-                EntityProperty, MessageKind, MessageKinds, Serde, MessageBuilder, BitReader, SerdeErr, ConstBitLength, MessageContainer
+                EntityProperty, MessageKind, MessageKinds, Serde, MessageBuilder, BitReader, SerdeErr, ConstBitLength, MessageContainer,
+                TypeLayout, FieldLayout
             };
             use super::*;
 
@@ -63,6 +66,8 @@ pub fn message_impl(
                 #is_fragment_method
                 #bit_length_method
                 #create_builder_method
+                #type_layout_method
+                #explicit_net_id_method
                 #relations_waiting_method
                 #relations_complete_method
                 #write_method
@@ -376,6 +381,31 @@ pub fn get_create_builder_method(builder_name: &Ident) -> TokenStream {
     }
 }
 
+fn get_type_layout_method(struct_name_str: &LitStr, fields: &[Field]) -> TokenStream {
+    let mut field_layouts = quote! {};
+
+    for field in fields.iter() {
+        let field_name = field.variable_name().to_string();
+        let type_name = match field {
+            Field::Normal(field) => {
+                let field_type = &field.field_type;
+                quote! { <#field_type as Serde>::layout() }
+            }
+            Field::EntityProperty(_) => quote! { String::from("EntityProperty") },
+        };
+        field_layouts = quote! {
+            #field_layouts
+            FieldLayout::new(#field_name, &#type_name),
+        };
+    }
+
+    quote! {
+        fn type_layout() -> TypeLayout where Self:Sized {
+            TypeLayout::new(#struct_name_str, vec![#field_layouts])
+        }
+    }
+}
+
 fn get_fields(input: &DeriveInput) -> Vec<Field> {
     let mut fields = Vec::new();
 
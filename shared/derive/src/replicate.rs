@@ -5,7 +5,7 @@ use syn::{
     PathArguments, Type,
 };
 
-use crate::shared::{get_struct_type, StructType};
+use crate::shared::{get_explicit_net_id_method, get_struct_type, StructType};
 
 const UNNAMED_FIELD_PREFIX: &'static str = "unnamed_field_";
 
@@ -30,6 +30,7 @@ pub struct NonReplicatedProperty {
 #[allow(clippy::large_enum_variant)]
 pub enum Property {
     Normal(NormalProperty),
+    Delta(NormalProperty),
     Entity(EntityProperty),
     NonReplicated(NonReplicatedProperty),
 }
@@ -70,6 +71,8 @@ pub fn replicate_impl(
     let new_complete_method =
         get_new_complete_method(&replica_name, &enum_name, &properties, &struct_type);
     let create_builder_method = get_create_builder_method(&builder_name);
+    let type_layout_method = get_type_layout_method(&replica_name_str, &properties);
+    let explicit_net_id_method = get_explicit_net_id_method(&input);
     let read_method = get_read_method(&replica_name, &properties, &struct_type);
     let read_create_update_method = get_read_create_update_method(&replica_name, &properties);
 
@@ -78,6 +81,8 @@ pub fn replicate_impl(
     let clone_method = get_clone_method(&replica_name, &properties, &struct_type);
     let mirror_method = get_mirror_method(&replica_name, &properties, &struct_type);
     let set_mutator_method = get_set_mutator_method(&properties, &struct_type);
+    let publish_method = get_publish_method(&enum_name, &properties, &struct_type);
+    let unpublish_method = get_unpublish_method(&properties, &struct_type);
     let read_apply_update_method = get_read_apply_update_method(&properties, &struct_type);
     let read_apply_field_update_method =
         get_read_apply_field_update_method(&properties, &struct_type);
@@ -98,6 +103,7 @@ pub fn replicate_impl(
                 ReplicaDynRef, ReplicaDynMut, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, ComponentKind, Named,
                 BitReader, BitWrite, BitWriter, OwnedBitReader, SerdeErr, Serde, LocalEntity,
                 EntityProperty, GlobalEntity, Replicate, Property, ComponentKinds, ReplicateBuilder, ComponentFieldUpdate,
+                DeltaProperty, DeltaBaselines, TypeLayout, FieldLayout,
             };
             use super::*;
 
@@ -141,10 +147,14 @@ pub fn replicate_impl(
                 }
                 fn diff_mask_size(&self) -> u8 { #diff_mask_size }
                 #create_builder_method
+                #type_layout_method
+                #explicit_net_id_method
                 #dyn_ref_method
                 #dyn_mut_method
                 #mirror_method
                 #set_mutator_method
+                #publish_method
+                #unpublish_method
                 #write_method
                 #write_update_method
                 #read_apply_update_method
@@ -196,6 +206,18 @@ impl Property {
         })
     }
 
+    pub fn delta(index: usize, variable_name: Ident, inner_type: Type) -> Self {
+        Self::Delta(NormalProperty {
+            index,
+            variable_name: variable_name.clone(),
+            inner_type,
+            uppercase_variable_name: Ident::new(
+                variable_name.to_string().to_uppercase().as_str(),
+                Span::call_site(),
+            ),
+        })
+    }
+
     pub fn entity(index: usize, variable_name: Ident) -> Self {
         Self::Entity(EntityProperty {
             index,
@@ -216,14 +238,14 @@ impl Property {
 
     pub fn is_replicated(&self) -> bool {
         match self {
-            Self::Normal(_) | Self::Entity(_) => true,
+            Self::Normal(_) | Self::Delta(_) | Self::Entity(_) => true,
             Self::NonReplicated(_) => false,
         }
     }
 
     pub fn variable_name(&self) -> &Ident {
         match self {
-            Self::Normal(property) => &property.variable_name,
+            Self::Normal(property) | Self::Delta(property) => &property.variable_name,
             Self::Entity(property) => &property.variable_name,
             Self::NonReplicated(property) => &property.variable_name,
         }
@@ -231,7 +253,7 @@ impl Property {
 
     pub fn uppercase_variable_name(&self) -> &Ident {
         match self {
-            Self::Normal(property) => &property.uppercase_variable_name,
+            Self::Normal(property) | Self::Delta(property) => &property.uppercase_variable_name,
             Self::Entity(property) => &property.uppercase_variable_name,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
@@ -239,7 +261,7 @@ impl Property {
 
     pub fn index(&self) -> usize {
         match self {
-            Self::Normal(property) => property.index,
+            Self::Normal(property) | Self::Delta(property) => property.index,
             Self::Entity(property) => property.index,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
@@ -280,6 +302,22 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                                             continue;
                                         }
                                     }
+                                // DeltaProperty
+                                } else if property_type == "DeltaProperty" {
+                                    if let PathArguments::AngleBracketed(angle_args) =
+                                        &property_seg.arguments
+                                    {
+                                        if let Some(GenericArgument::Type(inner_type)) =
+                                            angle_args.args.first()
+                                        {
+                                            fields.push(Property::delta(
+                                                fields.len(),
+                                                variable_name.clone(),
+                                                inner_type.clone(),
+                                            ));
+                                            continue;
+                                        }
+                                    }
                                 // Non-replicated Property
                                 } else {
                                     fields.push(Property::nonreplicated(
@@ -308,11 +346,19 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                                 if let Some(GenericArgument::Type(inner_type)) =
                                     angle_args.args.first()
                                 {
-                                    fields.push(Property::normal(
-                                        fields.len(),
-                                        variable_name,
-                                        inner_type.clone(),
-                                    ));
+                                    if property_type == "DeltaProperty" {
+                                        fields.push(Property::delta(
+                                            fields.len(),
+                                            variable_name,
+                                            inner_type.clone(),
+                                        ));
+                                    } else {
+                                        fields.push(Property::normal(
+                                            fields.len(),
+                                            variable_name,
+                                            inner_type.clone(),
+                                        ));
+                                    }
                                     continue;
                                 }
                             }
@@ -389,7 +435,7 @@ fn get_clone_method(
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         match property {
-            Property::Normal(_) => {
+            Property::Normal(_) | Property::Delta(_) => {
                 let new_output_right = quote! {
                     (*self.#field_name).clone(),
                 };
@@ -483,6 +529,69 @@ fn get_set_mutator_method(properties: &[Property], struct_type: &StructType) ->
     }
 }
 
+fn get_publish_method(
+    enum_name: &Ident,
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let mut output = quote! {};
+
+    for property in properties.iter() {
+        let field_name = get_field_name(property, struct_type);
+        let new_output_right = match property {
+            Property::Normal(property) | Property::Delta(property) => {
+                let uppercase_variant_name = &property.uppercase_variable_name;
+                quote! {
+                    self.#field_name.remote_publish(#enum_name::#uppercase_variant_name as u8, mutator);
+                }
+            }
+            Property::Entity(_) | Property::NonReplicated(_) => {
+                continue;
+            }
+        };
+        let new_output_result = quote! {
+            #output
+            #new_output_right
+        };
+        output = new_output_result;
+    }
+
+    quote! {
+        fn publish(&mut self, mutator: &PropertyMutator) {
+            #output
+        }
+    }
+}
+
+fn get_unpublish_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let mut output = quote! {};
+
+    for property in properties.iter() {
+        let field_name = get_field_name(property, struct_type);
+        let new_output_right = match property {
+            Property::Normal(_) | Property::Delta(_) => {
+                quote! {
+                    self.#field_name.remote_unpublish();
+                }
+            }
+            Property::Entity(_) | Property::NonReplicated(_) => {
+                continue;
+            }
+        };
+        let new_output_result = quote! {
+            #output
+            #new_output_right
+        };
+        output = new_output_result;
+    }
+
+    quote! {
+        fn unpublish(&mut self) {
+            #output
+        }
+    }
+}
+
 pub fn get_new_complete_method(
     replica_name: &Ident,
     enum_name: &Ident,
@@ -492,7 +601,7 @@ pub fn get_new_complete_method(
     let mut args = quote! {};
     for property in properties.iter() {
         match property {
-            Property::Normal(property) => {
+            Property::Normal(property) | Property::Delta(property) => {
                 let field_name = &property.variable_name;
                 let field_type = &property.inner_type;
 
@@ -548,6 +657,27 @@ pub fn get_new_complete_method(
                     }
                 }
             }
+            Property::Delta(property) => {
+                let field_name = &property.variable_name;
+                let field_type = &property.inner_type;
+                let uppercase_variant_name = &property.uppercase_variable_name;
+
+                match *struct_type {
+                    StructType::Struct => {
+                        quote! {
+                            #field_name: DeltaProperty::<#field_type>::host_owned(#field_name, #enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    StructType::TupleStruct => {
+                        quote! {
+                            DeltaProperty::<#field_type>::host_owned(#field_name, #enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    _ => {
+                        quote! {}
+                    }
+                }
+            }
             Property::Entity(property) => {
                 let field_name = &property.variable_name;
                 let uppercase_variant_name = &property.uppercase_variable_name;
@@ -632,6 +762,39 @@ pub fn get_create_builder_method(builder_name: &Ident) -> TokenStream {
     }
 }
 
+fn get_type_layout_method(replica_name_str: &LitStr, properties: &[Property]) -> TokenStream {
+    let mut field_layouts = quote! {};
+
+    for property in properties.iter() {
+        let type_name = match property {
+            Property::Normal(property) => {
+                let inner_type = &property.inner_type;
+                quote! { format!("Property<{}>", <#inner_type as Serde>::layout()) }
+            }
+            Property::Delta(property) => {
+                let inner_type = &property.inner_type;
+                quote! { format!("DeltaProperty<{}>", <#inner_type as Serde>::layout()) }
+            }
+            Property::Entity(_) => quote! { String::from("EntityProperty") },
+            Property::NonReplicated(_) => {
+                // not written to the network
+                continue;
+            }
+        };
+        let field_name = property.variable_name().to_string();
+        field_layouts = quote! {
+            #field_layouts
+            FieldLayout::new(#field_name, &#type_name),
+        };
+    }
+
+    quote! {
+        fn type_layout() -> TypeLayout where Self:Sized {
+            TypeLayout::new(#replica_name_str, vec![#field_layouts])
+        }
+    }
+}
+
 pub fn get_read_method(
     replica_name: &Ident,
     properties: &[Property],
@@ -660,6 +823,12 @@ pub fn get_read_method(
                     let #field_name = Property::<#field_type>::new_read(reader)?;
                 }
             }
+            Property::Delta(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    let #field_name = DeltaProperty::<#field_type>::new_read(reader)?;
+                }
+            }
             Property::Entity(_) => {
                 quote! {
                     let #field_name = EntityProperty::new_read(reader, converter)?;
@@ -728,6 +897,18 @@ pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Propert
                     }
                 }
             }
+            Property::Delta(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    {
+                        let should_read = bool::de(reader)?;
+                        should_read.ser(&mut update_writer);
+                        if should_read {
+                            DeltaProperty::<#field_type>::read_write(reader, &mut update_writer)?;
+                        }
+                    }
+                }
+            }
             Property::Entity(_) => {
                 quote! {
                     {
@@ -781,6 +962,17 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
                     }
                 }
             }
+            Property::Delta(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    let should_read = bool::de(reader)?;
+                    should_read.ser(&mut ready_writer);
+                    if should_read {
+                        DeltaProperty::<#field_type>::read_write(reader, &mut ready_writer)?;
+                        ready_did_write = true;
+                    }
+                }
+            }
             Property::Entity(inner_property) => {
                 let index = inner_property.index as u8;
                 quote! {
@@ -876,6 +1068,13 @@ fn get_read_apply_update_method(properties: &[Property], struct_type: &StructTyp
                     }
                 }
             }
+            Property::Delta(_) => {
+                quote! {
+                    if bool::de(reader)? {
+                        DeltaProperty::read(&mut self.#field_name, reader)?;
+                    }
+                }
+            }
             Property::Entity(_) => {
                 quote! {
                     if bool::de(reader)? {
@@ -913,7 +1112,7 @@ fn get_read_apply_field_update_method(
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         let new_output_right = match property {
-            Property::Normal(_) | Property::NonReplicated(_) => {
+            Property::Normal(_) | Property::Delta(_) | Property::NonReplicated(_) => {
                 continue;
             }
             Property::Entity(inner_property) => {
@@ -956,6 +1155,11 @@ fn get_write_method(properties: &[Property], struct_type: &StructType) -> TokenS
                     Property::write(&self.#field_name, writer);
                 }
             }
+            Property::Delta(_) => {
+                quote! {
+                    DeltaProperty::write(&self.#field_name, writer);
+                }
+            }
             Property::Entity(_) => {
                 quote! {
                     EntityProperty::write(&self.#field_name, writer, converter);
@@ -1002,6 +1206,17 @@ fn get_write_update_method(
                     }
                 }
             }
+            Property::Delta(property) => {
+                let uppercase_variant_name = &property.uppercase_variable_name;
+                quote! {
+                    if let Some(true) = diff_mask.bit(#enum_name::#uppercase_variant_name as u8) {
+                        true.ser(writer);
+                        DeltaProperty::write_update(&self.#field_name, delta_baselines, writer);
+                    } else {
+                        false.ser(writer);
+                    }
+                }
+            }
             Property::Entity(property) => {
                 let uppercase_variant_name = &property.uppercase_variable_name;
                 quote! {
@@ -1026,7 +1241,7 @@ fn get_write_update_method(
     }
 
     quote! {
-        fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) {
+        fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut, delta_baselines: &mut DeltaBaselines) {
             #output
         }
     }
@@ -1,4 +1,6 @@
-use syn::{Data, DeriveInput, Fields};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Lit, Meta};
 
 pub enum StructType {
     Struct,
@@ -17,3 +19,31 @@ pub(crate) fn get_struct_type(input: &DeriveInput) -> StructType {
     }
     panic!("Can only derive on a struct")
 }
+
+/// Get the `explicit_net_id()` method, which returns the NetId given by a
+/// `#[net_id = N]` attribute, if any
+pub(crate) fn get_explicit_net_id_method(input: &DeriveInput) -> TokenStream {
+    let mut net_id = quote! { None };
+
+    for attr in &input.attrs {
+        if !attr.path.is_ident("net_id") {
+            continue;
+        }
+        let Ok(Meta::NameValue(name_value)) = attr.parse_meta() else {
+            panic!("Expected a NetId of the form `#[net_id = 7]`");
+        };
+        let Lit::Int(lit) = name_value.lit else {
+            panic!("Expected a NetId of the form `#[net_id = 7]`");
+        };
+        let value: u16 = lit
+            .base10_parse()
+            .expect("A NetId given with `#[net_id = N]` must fit in a u16");
+        net_id = quote! { Some(#value) };
+    }
+
+    quote! {
+        fn explicit_net_id() -> Option<u16> where Self:Sized {
+            #net_id
+        }
+    }
+}
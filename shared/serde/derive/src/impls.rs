@@ -1,7 +1,9 @@
 mod enumeration;
+mod field;
 mod structure;
 mod tuple_structure;
 
 pub use enumeration::*;
+pub use field::*;
 pub use structure::*;
 pub use tuple_structure::*;
@@ -2,7 +2,9 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{DataEnum, Fields};
 
-fn bits_needed_for(max_value: usize) -> u8 {
+use super::FieldSerde;
+
+pub(crate) fn bits_needed_for(max_value: usize) -> u8 {
     let mut bits = 1;
     while 2_usize.pow(bits) <= max_value {
         bits += 1;
@@ -13,6 +15,22 @@ fn bits_needed_for(max_value: usize) -> u8 {
     bits as u8
 }
 
+fn named_binding(field_name: &Ident, used: bool) -> TokenStream {
+    if used {
+        quote! { #field_name }
+    } else {
+        quote! { #field_name: _ }
+    }
+}
+
+fn unnamed_binding(field_name: &Ident, used: bool) -> TokenStream {
+    if used {
+        quote! { #field_name }
+    } else {
+        quote! { _ }
+    }
+}
+
 #[allow(clippy::format_push_string)]
 pub fn derive_serde_enum(
     enum_: &DataEnum,
@@ -22,9 +40,10 @@ pub fn derive_serde_enum(
     let variant_number = enum_.variants.len();
     let bits_needed = bits_needed_for(variant_number);
 
-    let ser_method = get_ser_method(enum_, bits_needed);
-    let de_method = get_de_method(enum_, bits_needed);
-    let bit_length_method = get_bit_length_method(enum_, bits_needed);
+    let ser_method = get_ser_method(enum_, bits_needed, &serde_crate_name);
+    let de_method = get_de_method(enum_, bits_needed, &serde_crate_name);
+    let bit_length_method = get_bit_length_method(enum_, bits_needed, &serde_crate_name);
+    let layout_method = get_layout_method(enum_, enum_name, &serde_crate_name);
 
     let lowercase_enum_name = Ident::new(
         enum_name.to_string().to_lowercase().as_str(),
@@ -45,12 +64,17 @@ pub fn derive_serde_enum(
                 #ser_method
                 #de_method
                 #bit_length_method
+                #layout_method
             }
         }
     }
 }
 
-fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_ser_method(
+    enum_: &DataEnum,
+    bits_needed: u8,
+    serde_crate_name: &TokenStream,
+) -> TokenStream {
     let mut ser = quote! {};
     for (index, variant) in enum_.variants.iter().enumerate() {
         let variant_index = index as u16;
@@ -65,17 +89,7 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                 }
             }
             Fields::Named(fields) => {
-                let names: Vec<&Ident> = fields
-                    .named
-                    .iter()
-                    .map(|field| {
-                        field
-                            .ident
-                            .as_ref()
-                            .expect("expected field to have a name.")
-                    })
-                    .collect();
-                let left = quote! { Self::#variant_name{ #(#names),* } };
+                let mut bindings = Vec::new();
                 let mut right = quote! {
                     let index = UnsignedInteger::<#bits_needed>::new(#variant_index);
                     index.ser(writer);
@@ -85,34 +99,38 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_serde = FieldSerde::from_attributes(&field.attrs);
+                    bindings.push(named_binding(field_name, field_serde.ser_uses_value()));
+                    let field_ser =
+                        field_serde.ser(serde_crate_name, &quote! { (*#field_name) }, &field.ty);
                     right = quote! {
                         #right
-                        #field_name.ser(writer);
+                        #field_ser
                     }
                 }
+                let left = quote! { Self::#variant_name{ #(#bindings),* } };
                 quote! {
                     #left => { #right }
                 }
             }
             Fields::Unnamed(fields) => {
-                let names: Vec<Ident> = fields
-                    .unnamed
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format_ident!("f{}", i))
-                    .collect();
-                let left = quote! { Self::#variant_name( #(#names),* ) };
-
+                let mut bindings = Vec::new();
                 let mut right = quote! {
                     let index = UnsignedInteger::<#bits_needed>::new(#variant_index);
                     index.ser(writer);
                 };
-                for field_name in names {
+                for (i, field) in fields.unnamed.iter().enumerate() {
+                    let field_name = format_ident!("f{}", i);
+                    let field_serde = FieldSerde::from_attributes(&field.attrs);
+                    bindings.push(unnamed_binding(&field_name, field_serde.ser_uses_value()));
+                    let field_ser =
+                        field_serde.ser(serde_crate_name, &quote! { (*#field_name) }, &field.ty);
                     right = quote! {
                         #right
-                        #field_name.ser(writer);
+                        #field_ser
                     }
                 }
+                let left = quote! { Self::#variant_name( #(#bindings),* ) };
                 quote! {
                     #left => { #right }
                 }
@@ -132,7 +150,7 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
     }
 }
 
-fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_de_method(enum_: &DataEnum, bits_needed: u8, serde_crate_name: &TokenStream) -> TokenStream {
     let mut de = quote! {};
 
     for (index, variant) in enum_.variants.iter().enumerate() {
@@ -152,9 +170,11 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_de =
+                        FieldSerde::from_attributes(&field.attrs).de(serde_crate_name, &field.ty);
                     base = quote! {
                         #base
-                        #field_name: Serde::de(reader)?,
+                        #field_name: #field_de,
                     }
                 }
                 de = quote! {
@@ -166,10 +186,12 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             }
             Fields::Unnamed(fields) => {
                 let mut base = quote! {};
-                for _ in fields.unnamed.iter() {
+                for field in fields.unnamed.iter() {
+                    let field_de =
+                        FieldSerde::from_attributes(&field.attrs).de(serde_crate_name, &field.ty);
                     base = quote! {
                         #base
-                        Serde::de(reader)?,
+                        #field_de,
                     }
                 }
                 de = quote! {
@@ -193,7 +215,11 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
     }
 }
 
-fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_bit_length_method(
+    enum_: &DataEnum,
+    bits_needed: u8,
+    serde_crate_name: &TokenStream,
+) -> TokenStream {
     let mut bit_length = quote! {};
     for (_, variant) in enum_.variants.iter().enumerate() {
         let variant_name = &variant.ident;
@@ -206,17 +232,7 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                 }
             }
             Fields::Named(fields) => {
-                let names: Vec<&Ident> = fields
-                    .named
-                    .iter()
-                    .map(|field| {
-                        field
-                            .ident
-                            .as_ref()
-                            .expect("expected field to have a name.")
-                    })
-                    .collect();
-                let left = quote! { Self::#variant_name{ #(#names),* } };
+                let mut bindings = Vec::new();
                 let mut right = quote! {
                     output += <UnsignedInteger::<#bits_needed> as ConstBitLength>::const_bit_length();
                 };
@@ -225,33 +241,49 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_serde = FieldSerde::from_attributes(&field.attrs);
+                    bindings.push(named_binding(
+                        field_name,
+                        field_serde.bit_length_uses_value(),
+                    ));
+                    let field_bit_length = field_serde.bit_length(
+                        serde_crate_name,
+                        &quote! { (*#field_name) },
+                        &field.ty,
+                    );
                     right = quote! {
                         #right
-                        output += #field_name.bit_length();
+                        #field_bit_length
                     }
                 }
+                let left = quote! { Self::#variant_name{ #(#bindings),* } };
                 quote! {
                     #left => { #right }
                 }
             }
             Fields::Unnamed(fields) => {
-                let names: Vec<Ident> = fields
-                    .unnamed
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format_ident!("f{}", i))
-                    .collect();
-                let left = quote! { Self::#variant_name( #(#names),* ) };
-
+                let mut bindings = Vec::new();
                 let mut right = quote! {
                     output += <UnsignedInteger::<#bits_needed> as ConstBitLength>::const_bit_length();
                 };
-                for field_name in names {
+                for (i, field) in fields.unnamed.iter().enumerate() {
+                    let field_name = format_ident!("f{}", i);
+                    let field_serde = FieldSerde::from_attributes(&field.attrs);
+                    bindings.push(unnamed_binding(
+                        &field_name,
+                        field_serde.bit_length_uses_value(),
+                    ));
+                    let field_bit_length = field_serde.bit_length(
+                        serde_crate_name,
+                        &quote! { (*#field_name) },
+                        &field.ty,
+                    );
                     right = quote! {
                         #right
-                        output += #field_name.bit_length();
+                        #field_bit_length
                     }
                 }
+                let left = quote! { Self::#variant_name( #(#bindings),* ) };
                 quote! {
                     #left => { #right }
                 }
@@ -272,3 +304,74 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
          }
     }
 }
+
+fn get_layout_method(
+    enum_: &DataEnum,
+    enum_name: &Ident,
+    serde_crate_name: &TokenStream,
+) -> TokenStream {
+    let mut variants = quote! {};
+    for variant in enum_.variants.iter() {
+        let variant_name = variant.ident.to_string();
+        let variant_layout = match &variant.fields {
+            Fields::Unit => {
+                quote! {
+                    String::from(#variant_name)
+                }
+            }
+            Fields::Named(fields) => {
+                let mut field_layouts = quote! {};
+                for field in fields.named.iter() {
+                    let field_name = field
+                        .ident
+                        .as_ref()
+                        .expect("expected field to have a name.")
+                        .to_string();
+                    let field_layout = FieldSerde::from_attributes(&field.attrs)
+                        .layout(serde_crate_name, &field.ty);
+                    field_layouts = quote! {
+                        #field_layouts
+                        format!("{}: {}", #field_name, #field_layout),
+                    }
+                }
+                quote! {
+                    {
+                        let fields: Vec<String> = vec![#field_layouts];
+                        format!("{} {{ {} }}", #variant_name, fields.join(", "))
+                    }
+                }
+            }
+            Fields::Unnamed(fields) => {
+                let mut field_layouts = quote! {};
+                for field in fields.unnamed.iter() {
+                    let field_layout = FieldSerde::from_attributes(&field.attrs)
+                        .layout(serde_crate_name, &field.ty);
+                    field_layouts = quote! {
+                        #field_layouts
+                        #field_layout,
+                    }
+                }
+                quote! {
+                    {
+                        let fields: Vec<String> = vec![#field_layouts];
+                        format!("{}({})", #variant_name, fields.join(", "))
+                    }
+                }
+            }
+        };
+        variants = quote! {
+            #variants
+            #variant_layout,
+        }
+    }
+    let enum_name_str = enum_name.to_string();
+    quote! {
+        fn layout() -> String {
+            // field types are resolved from the module the type was defined in
+            #[allow(unused_imports)]
+            use super::*;
+            let variants: Vec<String> = vec![#variants];
+            format!("{} {{ {} }}", #enum_name_str, variants.join(", "))
+        }
+    }
+}
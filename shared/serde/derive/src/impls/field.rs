@@ -0,0 +1,339 @@
+use proc_macro2::{Literal, TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Ident, LitInt, LitStr, Path, Token, Type,
+};
+
+use super::enumeration::bits_needed_for;
+
+/// How a single field is serialized, as set by its `#[serde(..)]` attributes
+pub enum FieldSerde {
+    /// Serialized with the field type's own Serde implementation
+    Normal,
+    /// An integer serialized with a fixed number of bits
+    Bits(u8),
+    /// An integer serialized with a variable number of bits, written in
+    /// groups of the given size
+    VarInt(u8),
+    /// An integer within [min, max], serialized relative to min with only
+    /// as many bits as the range needs. Serializing a value outside of the
+    /// range panics, as it can't be represented
+    Bounded(i128, i128),
+    /// Not serialized. Deserialized with the given function if there is one,
+    /// and with `Default::default()` otherwise
+    Skip(Option<Path>),
+}
+
+/// The number of bits in each group of a `#[serde(varint)]` field, if not
+/// given
+const DEFAULT_VARINT_BITS: u8 = 7;
+
+enum FieldAttribute {
+    Bits(u8),
+    VarInt(Option<u8>),
+    Bounded(i128, i128),
+    Skip,
+    Default(Path),
+    /// A key meant for another derive sharing the `serde` attribute, such as
+    /// serde's own `rename`
+    Unknown,
+}
+
+impl Parse for FieldAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        match name.to_string().as_str() {
+            "bits" => {
+                input.parse::<Token![=]>()?;
+                Ok(Self::Bits(parse_bits(input)?))
+            }
+            "varint" => {
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    Ok(Self::VarInt(Some(parse_bits(input)?)))
+                } else {
+                    Ok(Self::VarInt(None))
+                }
+            }
+            "bounded" => {
+                let content;
+                parenthesized!(content in input);
+                let min = parse_integer(&content)?;
+                content.parse::<Token![,]>()?;
+                let max = parse_integer(&content)?;
+                if min >= max {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "bounded(min, max) requires min to be less than max",
+                    ));
+                }
+                Ok(Self::Bounded(min, max))
+            }
+            "skip" => Ok(Self::Skip),
+            "default" => {
+                input.parse::<Token![=]>()?;
+                let path: LitStr = input.parse()?;
+                Ok(Self::Default(path.parse()?))
+            }
+            _ => {
+                // skip the key's arguments, up to the next comma
+                while !input.is_empty() && !input.peek(Token![,]) {
+                    input.parse::<TokenTree>()?;
+                }
+                Ok(Self::Unknown)
+            }
+        }
+    }
+}
+
+fn parse_bits(input: ParseStream) -> syn::Result<u8> {
+    let bits: LitInt = input.parse()?;
+    let value: u8 = bits.base10_parse()?;
+    if value == 0 || value > 127 {
+        return Err(syn::Error::new(
+            bits.span(),
+            "number of bits must be between 1 and 127",
+        ));
+    }
+    Ok(value)
+}
+
+fn parse_integer(input: ParseStream) -> syn::Result<i128> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let value: i128 = input.parse::<LitInt>()?.base10_parse()?;
+    if negative {
+        Ok(-value)
+    } else {
+        Ok(value)
+    }
+}
+
+fn is_signed(field_type: &Type) -> bool {
+    if let Type::Path(type_path) = field_type {
+        if let Some(segment) = type_path.path.segments.last() {
+            return matches!(
+                segment.ident.to_string().as_str(),
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize"
+            );
+        }
+    }
+    false
+}
+
+impl FieldSerde {
+    pub fn from_attributes(attributes: &[Attribute]) -> Self {
+        let mut output = Self::Normal;
+        let mut default = None;
+
+        for attribute in attributes {
+            if !attribute.path.is_ident("serde") {
+                continue;
+            }
+            let field_attributes = attribute
+                .parse_args_with(Punctuated::<FieldAttribute, Token![,]>::parse_terminated)
+                .unwrap_or_else(|error| panic!("invalid serde attribute: {}", error));
+
+            for field_attribute in field_attributes {
+                let next_output = match field_attribute {
+                    FieldAttribute::Bits(bits) => Self::Bits(bits),
+                    FieldAttribute::VarInt(bits) => {
+                        Self::VarInt(bits.unwrap_or(DEFAULT_VARINT_BITS))
+                    }
+                    FieldAttribute::Bounded(min, max) => Self::Bounded(min, max),
+                    FieldAttribute::Skip => Self::Skip(None),
+                    FieldAttribute::Default(path) => {
+                        default = Some(path);
+                        continue;
+                    }
+                    FieldAttribute::Unknown => continue,
+                };
+                if !matches!(output, Self::Normal) {
+                    panic!("a field can only have one of the serde attributes: bits, varint, bounded, skip");
+                }
+                output = next_output;
+            }
+        }
+
+        if let Some(path) = default {
+            match &mut output {
+                Self::Skip(default) => *default = Some(path),
+                _ => panic!("the serde attribute `default` can only be used together with `skip`"),
+            }
+        }
+
+        output
+    }
+
+    /// Whether the field's value is read when serializing it
+    pub fn ser_uses_value(&self) -> bool {
+        !matches!(self, Self::Skip(_))
+    }
+
+    /// Whether the field's value is read when getting its bit length
+    pub fn bit_length_uses_value(&self) -> bool {
+        matches!(self, Self::Normal | Self::VarInt(_))
+    }
+
+    /// Returns a statement writing the field's value into `writer`
+    pub fn ser(
+        &self,
+        serde_crate_name: &TokenStream,
+        value: &TokenStream,
+        field_type: &Type,
+    ) -> TokenStream {
+        match self {
+            Self::Normal => quote! {
+                #value.ser(writer);
+            },
+            Self::Bits(_) | Self::VarInt(_) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                quote! {
+                    #integer_type::new(#value as i128).ser(writer);
+                }
+            }
+            Self::Bounded(min, max) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                let min = Literal::i128_unsuffixed(*min);
+                let max = Literal::i128_unsuffixed(*max);
+                quote! {
+                    {
+                        let value = #value as i128;
+                        if !(#min..=#max).contains(&value) {
+                            panic!("can't serialize {}, which is outside of its bounds [{}, {}]", value, #min, #max);
+                        }
+                        #integer_type::new(value - #min).ser(writer);
+                    }
+                }
+            }
+            Self::Skip(_) => quote! {},
+        }
+    }
+
+    /// Returns an expression reading the field's value from `reader`
+    pub fn de(&self, serde_crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Normal => quote! {
+                Serde::de(reader)?
+            },
+            Self::Bits(_) | Self::VarInt(_) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                quote! {
+                    (#integer_type::de(reader)?.get() as #field_type)
+                }
+            }
+            Self::Bounded(min, max) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                let min = Literal::i128_unsuffixed(*min);
+                let max = Literal::i128_unsuffixed(*max);
+                quote! {
+                    {
+                        let value = #integer_type::de(reader)?.get() + #min;
+                        if value > #max {
+                            return Err(SerdeErr);
+                        }
+                        value as #field_type
+                    }
+                }
+            }
+            // The generated impl lives in its own module, so the default function is
+            // resolved from the module the type was defined in
+            Self::Skip(Some(path)) => quote! {
+                {
+                    #[allow(unused_imports)]
+                    use super::*;
+                    #path()
+                }
+            },
+            Self::Skip(None) => quote! {
+                Default::default()
+            },
+        }
+    }
+
+    /// Returns a statement adding the field's bit length to `output`
+    pub fn bit_length(
+        &self,
+        serde_crate_name: &TokenStream,
+        value: &TokenStream,
+        field_type: &Type,
+    ) -> TokenStream {
+        match self {
+            Self::Normal => quote! {
+                output += #value.bit_length();
+            },
+            Self::Bits(_) | Self::Bounded(_, _) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                quote! {
+                    output += <#integer_type as ConstBitLength>::const_bit_length();
+                }
+            }
+            Self::VarInt(_) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                quote! {
+                    output += #integer_type::new(#value as i128).bit_length();
+                }
+            }
+            Self::Skip(_) => quote! {},
+        }
+    }
+
+    /// Returns an expression describing how the field is serialized, for
+    /// `Serde::layout()`
+    pub fn layout(&self, serde_crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Normal => quote! {
+                <#field_type as Serde>::layout()
+            },
+            Self::Bits(_) | Self::VarInt(_) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                quote! {
+                    <#integer_type as Serde>::layout()
+                }
+            }
+            Self::Bounded(min, max) => {
+                let integer_type = self.integer_type(serde_crate_name, field_type);
+                let min = Literal::i128_unsuffixed(*min);
+                let max = Literal::i128_unsuffixed(*max);
+                quote! {
+                    format!("{}[{}, {}]", <#integer_type as Serde>::layout(), #min, #max)
+                }
+            }
+            Self::Skip(_) => quote! {
+                String::from("skip")
+            },
+        }
+    }
+
+    /// Gets the integer type used to serialize the field, which is signed if
+    /// the field's type is
+    fn integer_type(&self, serde_crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        let signed = is_signed(field_type);
+        match self {
+            Self::Bits(bits) => {
+                if signed {
+                    quote! { #serde_crate_name::SignedInteger::<#bits> }
+                } else {
+                    quote! { #serde_crate_name::UnsignedInteger::<#bits> }
+                }
+            }
+            Self::VarInt(bits) => {
+                if signed {
+                    quote! { #serde_crate_name::SignedVariableInteger::<#bits> }
+                } else {
+                    quote! { #serde_crate_name::UnsignedVariableInteger::<#bits> }
+                }
+            }
+            Self::Bounded(min, max) => {
+                let range = (*max - *min) as usize;
+                let bits = bits_needed_for(range);
+                quote! { #serde_crate_name::UnsignedInteger::<#bits> }
+            }
+            Self::Normal | Self::Skip(_) => {
+                panic!("field is not serialized as an integer")
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::DataStruct;
 
+use super::FieldSerde;
+
 #[allow(clippy::format_push_string)]
 pub fn derive_serde_struct(
     struct_: &DataStruct,
@@ -11,22 +13,36 @@ pub fn derive_serde_struct(
     let mut ser_body = quote! {};
     let mut de_body = quote! {};
     let mut bit_length_body = quote! {};
+    let mut layout_body = quote! {};
 
     for field in &struct_.fields {
         let field_name = field.ident.as_ref().expect("expected field to have a name");
+        let field_serde = FieldSerde::from_attributes(&field.attrs);
+        let field_value = quote! { self.#field_name };
+
+        let field_ser = field_serde.ser(&serde_crate_name, &field_value, &field.ty);
         ser_body = quote! {
             #ser_body
-            self.#field_name.ser(writer);
+            #field_ser
         };
+        let field_de = field_serde.de(&serde_crate_name, &field.ty);
         de_body = quote! {
             #de_body
-            #field_name: Serde::de(reader)?,
+            #field_name: #field_de,
         };
+        let field_bit_length = field_serde.bit_length(&serde_crate_name, &field_value, &field.ty);
         bit_length_body = quote! {
             #bit_length_body
-            output += self.#field_name.bit_length();
+            #field_bit_length
+        };
+        let field_name_str = field_name.to_string();
+        let field_layout = field_serde.layout(&serde_crate_name, &field.ty);
+        layout_body = quote! {
+            #layout_body
+            format!("{}: {}", #field_name_str, #field_layout),
         };
     }
+    let struct_name_str = struct_name.to_string();
 
     let lowercase_struct_name = Ident::new(
         struct_name.to_string().to_lowercase().as_str(),
@@ -55,6 +71,13 @@ pub fn derive_serde_struct(
                     #bit_length_body
                     output
                 }
+                fn layout() -> String {
+                    // field types are resolved from the module the type was defined in
+                    #[allow(unused_imports)]
+                    use super::*;
+                    let fields: Vec<String> = vec![#layout_body];
+                    format!("{} {{ {} }}", #struct_name_str, fields.join(", "))
+                }
             }
         }
     }
@@ -1,6 +1,8 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::DataStruct;
+use syn::{DataStruct, Index};
+
+use super::FieldSerde;
 
 #[allow(clippy::format_push_string)]
 pub fn derive_serde_tuple_struct(
@@ -11,22 +13,35 @@ pub fn derive_serde_tuple_struct(
     let mut ser_body = quote! {};
     let mut de_body = quote! {};
     let mut bit_length_body = quote! {};
+    let mut layout_body = quote! {};
+
+    for (i, field) in struct_.fields.iter().enumerate() {
+        let field_index = Index::from(i);
+        let field_serde = FieldSerde::from_attributes(&field.attrs);
+        let field_value = quote! { self.#field_index };
 
-    for (i, _) in struct_.fields.iter().enumerate() {
-        let field_index = i;
+        let field_ser = field_serde.ser(&serde_crate_name, &field_value, &field.ty);
         ser_body = quote! {
             #ser_body
-            self.#field_index.ser(writer);
+            #field_ser
         };
+        let field_de = field_serde.de(&serde_crate_name, &field.ty);
         de_body = quote! {
             #de_body
-            #field_index: Serde::de(reader)?,
+            #field_index: #field_de,
         };
+        let field_bit_length = field_serde.bit_length(&serde_crate_name, &field_value, &field.ty);
         bit_length_body = quote! {
             #bit_length_body
-            output += self.#field_index.bit_length();
+            #field_bit_length
+        };
+        let field_layout = field_serde.layout(&serde_crate_name, &field.ty);
+        layout_body = quote! {
+            #layout_body
+            #field_layout,
         };
     }
+    let struct_name_str = struct_name.to_string();
 
     let lowercase_struct_name = Ident::new(
         struct_name.to_string().to_lowercase().as_str(),
@@ -55,6 +70,16 @@ pub fn derive_serde_tuple_struct(
                     #bit_length_body
                     output
                 }
+                fn layout() -> String {
+                    // field types are resolved from the module the type was defined in
+                    #[allow(unused_imports)]
+                    use super::*;
+                    let fields: Vec<String> = vec![#layout_body];
+                    if fields.is_empty() {
+                        return #struct_name_str.to_string();
+                    }
+                    format!("{}({})", #struct_name_str, fields.join(", "))
+                }
             }
         }
     }
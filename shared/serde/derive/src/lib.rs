@@ -4,25 +4,36 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 mod impls;
 use impls::*;
 
-#[proc_macro_derive(Serde)]
+/// Derives `Serde` for a struct or enum. How each field is serialized can be
+/// changed with `#[serde(..)]` attributes:
+/// - `bits = N`: an integer written with a fixed N bits
+/// - `varint` or `varint = N`: an integer written in groups of N bits
+/// - `bounded(min, max)`: an integer within [min, max], written with only as
+///   many bits as the range needs. Serializing a value outside of the range
+///   panics, and deserializing one is a `SerdeErr`
+/// - `skip`, optionally with `default = "path"`: not written, and read as
+///   `path()` or `Default::default()`
+///
+/// Other keys are ignored, so the attribute can be shared with other derives
+#[proc_macro_derive(Serde, attributes(serde))]
 pub fn derive_serde(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_shared };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeInternal)]
+#[proc_macro_derive(SerdeInternal, attributes(serde))]
 pub fn derive_serde_internal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_serde };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeBevy)]
+#[proc_macro_derive(SerdeBevy, attributes(serde))]
 pub fn derive_serde_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_bevy_shared };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeHecs)]
+#[proc_macro_derive(SerdeHecs, attributes(serde))]
 pub fn derive_serde_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_hecs_shared };
     derive_serde_common(input, serde_crate_name)
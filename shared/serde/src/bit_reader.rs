@@ -26,6 +26,11 @@ impl<'b> BitReader<'b> {
         }
     }
 
+    /// Gets the number of bits left to read
+    pub fn bits_remaining(&self) -> usize {
+        (self.buffer.len() - self.state.buffer_index) * 8 + self.state.scratch_index as usize
+    }
+
     pub(crate) fn read_bit(&mut self) -> Result<bool, SerdeErr> {
         if self.state.scratch_index == 0 {
             if self.state.buffer_index == self.buffer.len() {
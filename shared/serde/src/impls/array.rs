@@ -2,7 +2,7 @@ use crate::{
     bit_reader::BitReader,
     bit_writer::BitWrite,
     error::SerdeErr,
-    serde::{ConstBitLength, Serde},
+    serde::{ConstBitLength, Serde, SerdeDelta},
 };
 
 impl<T: Serde> Serde for &[T] {
@@ -23,6 +23,10 @@ impl<T: Serde> Serde for &[T] {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("&[{}]", T::layout())
+    }
 }
 
 impl<T: Serde, const N: usize> Serde for [T; N] {
@@ -50,6 +54,36 @@ impl<T: Serde, const N: usize> Serde for [T; N] {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("[{}; {}]", T::layout(), N)
+    }
+}
+
+// Each element is either unchanged from the baseline element at the same
+// index, or is sent in full
+impl<T: Serde, const N: usize> SerdeDelta for [T; N] {
+    type Delta = [Option<T>; N];
+
+    fn delta(&self, baseline: &Self) -> Self::Delta {
+        std::array::from_fn(|index| {
+            if self[index] == baseline[index] {
+                None
+            } else {
+                Some(self[index].clone())
+            }
+        })
+    }
+
+    fn apply_delta(baseline: &Self, delta: Self::Delta) -> Result<Self, SerdeErr> {
+        let mut output = baseline.clone();
+        for (index, change) in delta.into_iter().enumerate() {
+            if let Some(item) = change {
+                output[index] = item;
+            }
+        }
+        Ok(output)
+    }
 }
 
 impl<T: ConstBitLength, const N: usize> ConstBitLength for [T; N] {
@@ -62,7 +96,11 @@ impl<T: ConstBitLength, const N: usize> ConstBitLength for [T; N] {
 
 #[cfg(test)]
 mod tests {
-    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::BitWriter,
+        serde::{Serde, SerdeDelta},
+    };
 
     #[test]
     fn read_write() {
@@ -86,4 +124,27 @@ mod tests {
         assert_eq!(in_1, out_1);
         assert_eq!(in_2, out_2);
     }
+
+    #[test]
+    fn read_write_array_delta() {
+        let baseline: [i32; 4] = [5, 11, 52, 8];
+        let in_1: [i32; 4] = [5, 12, 52, 8];
+
+        // Write
+        let mut writer = BitWriter::new();
+
+        let delta_1 = in_1.delta(&baseline);
+        assert!(delta_1.bit_length() < in_1.bit_length());
+
+        delta_1.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = <[i32; 4]>::apply_delta(&baseline, Serde::de(&mut reader).unwrap()).unwrap();
+
+        assert_eq!(in_1, out_1);
+    }
 }
@@ -18,6 +18,11 @@ impl<T: Serde> Serde for Box<T> {
     fn bit_length(&self) -> u32 {
         (**self).bit_length()
     }
+
+    fn layout() -> String {
+        // a Box is written as the value it holds
+        T::layout()
+    }
 }
 
 impl<T: ConstBitLength> ConstBitLength for Box<T> {
@@ -39,6 +44,9 @@ impl Serde for Box<[u8]> {
     fn de(reader: &mut BitReader) -> Result<Box<[u8]>, SerdeErr> {
         let length_int = UnsignedVariableInteger::<9>::de(reader)?;
         let length_usize = length_int.get() as usize;
+        if length_usize > reader.bits_remaining() / 8 {
+            return Err(SerdeErr);
+        }
         let mut bytes: Vec<u8> = Vec::with_capacity(length_usize);
         for _ in 0..length_usize {
             bytes.push(reader.read_byte()?);
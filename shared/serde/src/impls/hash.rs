@@ -36,6 +36,10 @@ impl<K: Serde + Eq + Hash> Serde for HashSet<K> {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("HashSet<{}>", K::layout())
+    }
 }
 
 impl<K: Serde + Eq + Hash, V: Serde> Serde for HashMap<K, V> {
@@ -70,6 +74,10 @@ impl<K: Serde + Eq + Hash, V: Serde> Serde for HashMap<K, V> {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("HashMap<{}, {}>", K::layout(), V::layout())
+    }
 }
 
 // Tests
@@ -30,6 +30,10 @@ impl<T: Serde> Serde for Option<T> {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("Option<{}>", T::layout())
+    }
 }
 
 impl<T: ConstBitLength> ConstBitLength for Option<T> {
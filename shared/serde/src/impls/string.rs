@@ -16,13 +16,15 @@ impl Serde for String {
     fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let length_int = UnsignedVariableInteger::<9>::de(reader)?;
         let length_usize = length_int.get() as usize;
+        if length_usize > reader.bits_remaining() / 8 {
+            return Err(SerdeErr);
+        }
         let mut bytes: Vec<u8> = Vec::with_capacity(length_usize);
         for _ in 0..length_usize {
             bytes.push(reader.read_byte()?);
         }
 
-        let result = String::from_utf8_lossy(&bytes).into_owned();
-        Ok(result)
+        String::from_utf8(bytes).map_err(|_| SerdeErr)
     }
 
     fn bit_length(&self) -> u32 {
@@ -38,7 +40,12 @@ impl Serde for String {
 
 #[cfg(test)]
 mod tests {
-    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::{BitWrite, BitWriter},
+        serde::Serde,
+        UnsignedVariableInteger,
+    };
 
     #[test]
     fn read_write() {
@@ -62,4 +69,20 @@ mod tests {
         assert_eq!(in_1, out_1);
         assert_eq!(in_2, out_2);
     }
+
+    #[test]
+    fn read_length_beyond_buffer() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        UnsignedVariableInteger::<9>::new(1000).ser(&mut writer);
+        writer.write_byte(b'a');
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        assert!(String::de(&mut reader).is_err());
+    }
 }
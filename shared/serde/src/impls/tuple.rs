@@ -13,6 +13,10 @@ macro_rules! impl_reflect_tuple {
                 $(output += self.$index.bit_length();)*
                 output
             }
+            fn layout() -> String {
+                let layouts: Vec<String> = vec![$($name::layout(),)*];
+                format!("({})", layouts.join(", "))
+            }
         }
     }
 }
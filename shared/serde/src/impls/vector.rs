@@ -1,7 +1,10 @@
 use std::collections::VecDeque;
 
 use crate::{
-    bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde,
+    bit_reader::BitReader,
+    bit_writer::BitWrite,
+    error::SerdeErr,
+    serde::{Serde, SerdeDelta},
     UnsignedVariableInteger,
 };
 
@@ -17,7 +20,8 @@ impl<T: Serde> Serde for Vec<T> {
     fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let length_int = UnsignedVariableInteger::<5>::de(reader)?;
         let length_usize = length_int.get() as usize;
-        let mut output: Vec<T> = Vec::with_capacity(length_usize);
+        // don't allocate more than could possibly be read
+        let mut output: Vec<T> = Vec::with_capacity(length_usize.min(reader.bits_remaining()));
         for _ in 0..length_usize {
             output.push(T::de(reader)?)
         }
@@ -33,6 +37,40 @@ impl<T: Serde> Serde for Vec<T> {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("Vec<{}>", T::layout())
+    }
+}
+
+// Each element is either unchanged from the baseline element at the same
+// index, or is sent in full
+impl<T: Serde> SerdeDelta for Vec<T> {
+    type Delta = Vec<Option<T>>;
+
+    fn delta(&self, baseline: &Self) -> Self::Delta {
+        self.iter()
+            .enumerate()
+            .map(|(index, item)| {
+                if baseline.get(index) == Some(item) {
+                    None
+                } else {
+                    Some(item.clone())
+                }
+            })
+            .collect()
+    }
+
+    fn apply_delta(baseline: &Self, delta: Self::Delta) -> Result<Self, SerdeErr> {
+        let mut output = Vec::with_capacity(delta.len());
+        for (index, change) in delta.into_iter().enumerate() {
+            match change {
+                Some(item) => output.push(item),
+                None => output.push(baseline.get(index).ok_or(SerdeErr)?.clone()),
+            }
+        }
+        Ok(output)
+    }
 }
 
 impl<T: Serde> Serde for VecDeque<T> {
@@ -47,7 +85,9 @@ impl<T: Serde> Serde for VecDeque<T> {
     fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let length_int = UnsignedVariableInteger::<5>::de(reader)?;
         let length_usize = length_int.get() as usize;
-        let mut output: VecDeque<T> = VecDeque::with_capacity(length_usize);
+        // don't allocate more than could possibly be read
+        let mut output: VecDeque<T> =
+            VecDeque::with_capacity(length_usize.min(reader.bits_remaining()));
         for _ in 0..length_usize {
             output.push_back(T::de(reader)?)
         }
@@ -63,13 +103,21 @@ impl<T: Serde> Serde for VecDeque<T> {
         }
         output
     }
+
+    fn layout() -> String {
+        format!("VecDeque<{}>", T::layout())
+    }
 }
 
 // Tests
 
 #[cfg(test)]
 mod tests {
-    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::BitWriter,
+        serde::{Serde, SerdeDelta},
+    };
     use std::collections::VecDeque;
 
     #[test]
@@ -130,4 +178,40 @@ mod tests {
         assert_eq!(in_1, out_1);
         assert_eq!(in_2, out_2);
     }
+
+    #[test]
+    fn read_write_vec_delta() {
+        let baseline = vec![5, 3, 2, 7];
+        let in_1 = vec![5, 4, 2, 7, 9];
+        let in_2 = vec![5, 3];
+
+        // Write
+        let mut writer = BitWriter::new();
+
+        let delta_1 = in_1.delta(&baseline);
+        let delta_2 = in_2.delta(&baseline);
+        assert!(delta_1.bit_length() < in_1.bit_length());
+
+        delta_1.ser(&mut writer);
+        delta_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Vec::<i32>::apply_delta(&baseline, Serde::de(&mut reader).unwrap()).unwrap();
+        let out_2 = Vec::<i32>::apply_delta(&baseline, Serde::de(&mut reader).unwrap()).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+
+    #[test]
+    fn vec_delta_requires_baseline_elements() {
+        let baseline = vec![1];
+        let delta = vec![None, None];
+
+        assert!(Vec::<i32>::apply_delta(&baseline, delta).is_err());
+    }
 }
@@ -120,6 +120,10 @@ impl<const SIGNED: bool, const VARIABLE: bool, const BITS: u8> Serde
 
                 for _ in 0..BITS {
                     total_bits += 1;
+                    if total_bits > 127 {
+                        // too large to be stored
+                        return Err(SerdeErr);
+                    }
 
                     output <<= 1;
 
@@ -217,7 +221,7 @@ impl<const SIGNED: bool, const BITS: u8> ConstBitLength for SerdeInteger<SIGNED,
 mod tests {
     use crate::{
         bit_reader::BitReader,
-        bit_writer::BitWriter,
+        bit_writer::{BitWrite, BitWriter},
         integer::{SignedInteger, SignedVariableInteger, UnsignedInteger, UnsignedVariableInteger},
         serde::Serde,
     };
@@ -338,4 +342,21 @@ mod tests {
         assert_eq!(in_2, out_2);
         assert_eq!(in_3, out_3);
     }
+
+    #[test]
+    fn read_variable_too_large() {
+        // Write, a variable integer which never ends
+        let mut writer = BitWriter::new();
+
+        for _ in 0..256 {
+            writer.write_bit(true);
+        }
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        assert!(UnsignedVariableInteger::<3>::de(&mut reader).is_err());
+    }
 }
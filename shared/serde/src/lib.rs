@@ -8,6 +8,7 @@ mod error;
 mod impls;
 mod integer;
 mod outgoing_packet;
+mod quantized;
 mod serde;
 
 pub use bit_counter::BitCounter;
@@ -17,6 +18,8 @@ pub use constants::{MTU_SIZE_BITS, MTU_SIZE_BYTES};
 pub use error::SerdeErr;
 pub use integer::{SignedInteger, SignedVariableInteger, UnsignedInteger, UnsignedVariableInteger};
 pub use outgoing_packet::OutgoingPacket;
+pub use quantized::{NormalizedVec2, NormalizedVec3, QuantizedF32, SmallestThreeQuat};
 pub use serde::{
     ConstBitLength, Serde, Serde as SerdeInternal, Serde as SerdeBevy, Serde as SerdeHecs,
+    SerdeDelta,
 };
@@ -0,0 +1,485 @@
+use std::f32::consts::{FRAC_1_SQRT_2, TAU};
+
+use crate::{
+    bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde, ConstBitLength,
+    UnsignedInteger,
+};
+
+// Quantization
+
+fn check_bits(bits: u8) {
+    if bits == 0 {
+        panic!("can't quantize a value with 0 bits...");
+    }
+    if bits > 32 {
+        panic!("can't quantize a value with more than 32 bits...");
+    }
+}
+
+fn max_step(bits: u8) -> u32 {
+    ((1_u64 << bits) - 1) as u32
+}
+
+/// Maps a value within [min, max] onto one of 2^bits evenly spaced steps,
+/// clamping values outside of that range
+fn quantize(value: f32, min: f32, max: f32, bits: u8) -> u32 {
+    let max_step = max_step(bits);
+    if value.is_nan() {
+        return 0;
+    }
+    let fraction = ((value - min) / (max - min)).clamp(0.0, 1.0) as f64;
+    (fraction * max_step as f64).round() as u32
+}
+
+fn dequantize(step: u32, min: f32, max: f32, bits: u8) -> f32 {
+    let fraction = step as f64 / max_step(bits) as f64;
+    (min as f64 + fraction * (max as f64 - min as f64)) as f32
+}
+
+fn ser_step<const BITS: u8>(step: u32, writer: &mut dyn BitWrite) {
+    UnsignedInteger::<BITS>::new(step).ser(writer);
+}
+
+fn de_step<const BITS: u8>(reader: &mut BitReader) -> Result<u32, SerdeErr> {
+    Ok(UnsignedInteger::<BITS>::de(reader)?.get() as u32)
+}
+
+// QuantizedF32
+
+/// An f32 within the range [MIN, MAX], sent using BITS bits. Values outside
+/// of the range are clamped to it. The precision of the value is
+/// (MAX - MIN) / (2^BITS - 1).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QuantizedF32<const MIN: i32, const MAX: i32, const BITS: u8> {
+    step: u32,
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> QuantizedF32<MIN, MAX, BITS> {
+    pub fn new(value: f32) -> Self {
+        check_bits(BITS);
+        if MIN >= MAX {
+            panic!("can't quantize a value with a range where MIN >= MAX...");
+        }
+
+        Self {
+            step: quantize(value, MIN as f32, MAX as f32, BITS),
+        }
+    }
+
+    pub fn get(&self) -> f32 {
+        dequantize(self.step, MIN as f32, MAX as f32, BITS)
+    }
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> Serde for QuantizedF32<MIN, MAX, BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        ser_step::<BITS>(self.step, writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        Ok(Self {
+            step: de_step::<BITS>(reader)?,
+        })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> ConstBitLength
+    for QuantizedF32<MIN, MAX, BITS>
+{
+    fn const_bit_length() -> u32 {
+        BITS as u32
+    }
+}
+
+// NormalizedVec2
+
+/// A 2D unit vector, sent as its angle using BITS bits
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NormalizedVec2<const BITS: u8> {
+    angle_step: u32,
+}
+
+impl<const BITS: u8> NormalizedVec2<BITS> {
+    /// Creates a new NormalizedVec2 pointing in the direction of the given
+    /// vector. A zero vector points along the x axis.
+    pub fn new(x: f32, y: f32) -> Self {
+        check_bits(BITS);
+
+        let mut angle = y.atan2(x);
+        if angle < 0.0 {
+            angle += TAU;
+        }
+        // the angle is divided into 2^BITS steps, where the last wraps
+        // around to the first
+        let steps = 1_u64 << BITS;
+        let angle_step = ((angle / TAU) as f64 * steps as f64).round() as u64 % steps;
+
+        Self {
+            angle_step: angle_step as u32,
+        }
+    }
+
+    /// Gets the (x, y) components of the vector
+    pub fn get(&self) -> (f32, f32) {
+        let steps = 1_u64 << BITS;
+        let angle = (self.angle_step as f64 / steps as f64) as f32 * TAU;
+        (angle.cos(), angle.sin())
+    }
+}
+
+impl<const BITS: u8> Serde for NormalizedVec2<BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        ser_step::<BITS>(self.angle_step, writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        Ok(Self {
+            angle_step: de_step::<BITS>(reader)?,
+        })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const BITS: u8> ConstBitLength for NormalizedVec2<BITS> {
+    fn const_bit_length() -> u32 {
+        BITS as u32
+    }
+}
+
+// Smallest components
+
+/// Finds the index of the component with the largest magnitude
+fn largest_index(components: &[f32]) -> usize {
+    let mut largest = 0;
+    for (index, component) in components.iter().enumerate() {
+        if component.abs() > components[largest].abs() {
+            largest = index;
+        }
+    }
+    largest
+}
+
+/// Rebuilds the largest component of a unit vector or quaternion from the
+/// others, which are stored in place around it
+fn restore_largest(mut components: [f32; 4], count: usize, largest: usize) -> [f32; 4] {
+    let mut sum_squares = 0.0;
+    for (index, component) in components.iter().take(count).enumerate() {
+        if index != largest {
+            sum_squares += component * component;
+        }
+    }
+    components[largest] = (1.0 - sum_squares).max(0.0).sqrt();
+    components
+}
+
+// NormalizedVec3
+
+/// A 3D unit vector. The component with the largest magnitude is left out,
+/// and rebuilt from the other two, which are each sent using BITS bits.
+/// Uses 3 + 2 * BITS bits in total.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NormalizedVec3<const BITS: u8> {
+    largest: u8,
+    largest_negative: bool,
+    steps: [u32; 2],
+}
+
+impl<const BITS: u8> NormalizedVec3<BITS> {
+    /// Creates a new NormalizedVec3 pointing in the direction of the given
+    /// vector. A zero vector points along the x axis.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        check_bits(BITS);
+
+        let length = (x * x + y * y + z * z).sqrt();
+        let components = if length > 0.0 {
+            [x / length, y / length, z / length]
+        } else {
+            [1.0, 0.0, 0.0]
+        };
+
+        let largest = largest_index(&components);
+        let mut steps = [0; 2];
+        let mut step_index = 0;
+        for (index, component) in components.iter().enumerate() {
+            if index != largest {
+                steps[step_index] = quantize(*component, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS);
+                step_index += 1;
+            }
+        }
+
+        Self {
+            largest: largest as u8,
+            largest_negative: components[largest] < 0.0,
+            steps,
+        }
+    }
+
+    /// Gets the (x, y, z) components of the vector
+    pub fn get(&self) -> (f32, f32, f32) {
+        let largest = self.largest as usize;
+        let mut components = [0.0; 4];
+        let mut step_index = 0;
+        for (index, component) in components.iter_mut().take(3).enumerate() {
+            if index != largest {
+                *component =
+                    dequantize(self.steps[step_index], -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS);
+                step_index += 1;
+            }
+        }
+
+        let mut components = restore_largest(components, 3, largest);
+        if self.largest_negative {
+            components[largest] = -components[largest];
+        }
+        (components[0], components[1], components[2])
+    }
+}
+
+impl<const BITS: u8> Serde for NormalizedVec3<BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        UnsignedInteger::<2>::new(self.largest).ser(writer);
+        self.largest_negative.ser(writer);
+        for step in self.steps {
+            ser_step::<BITS>(step, writer);
+        }
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let largest = UnsignedInteger::<2>::de(reader)?.get() as u8;
+        if largest > 2 {
+            return Err(SerdeErr);
+        }
+        let largest_negative = bool::de(reader)?;
+        let steps = [de_step::<BITS>(reader)?, de_step::<BITS>(reader)?];
+        Ok(Self {
+            largest,
+            largest_negative,
+            steps,
+        })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const BITS: u8> ConstBitLength for NormalizedVec3<BITS> {
+    fn const_bit_length() -> u32 {
+        3 + 2 * BITS as u32
+    }
+}
+
+// SmallestThreeQuat
+
+/// A unit quaternion, such as a rotation, encoded with the "smallest three"
+/// method: the component with the largest magnitude is left out, and
+/// rebuilt from the other three, which are each sent using BITS bits.
+/// Uses 2 + 3 * BITS bits in total.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SmallestThreeQuat<const BITS: u8> {
+    largest: u8,
+    steps: [u32; 3],
+}
+
+impl<const BITS: u8> SmallestThreeQuat<BITS> {
+    /// Creates a new SmallestThreeQuat from the normalized form of the given
+    /// quaternion. A zero quaternion becomes the identity.
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        check_bits(BITS);
+
+        let length = (x * x + y * y + z * z + w * w).sqrt();
+        let mut components = if length > 0.0 {
+            [x / length, y / length, z / length, w / length]
+        } else {
+            [0.0, 0.0, 0.0, 1.0]
+        };
+
+        // q and -q represent the same rotation, so make the largest
+        // component positive & there is no need to send its sign
+        let largest = largest_index(&components);
+        if components[largest] < 0.0 {
+            for component in &mut components {
+                *component = -*component;
+            }
+        }
+
+        let mut steps = [0; 3];
+        let mut step_index = 0;
+        for (index, component) in components.iter().enumerate() {
+            if index != largest {
+                steps[step_index] = quantize(*component, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS);
+                step_index += 1;
+            }
+        }
+
+        Self {
+            largest: largest as u8,
+            steps,
+        }
+    }
+
+    /// Gets the (x, y, z, w) components of the quaternion
+    pub fn get(&self) -> (f32, f32, f32, f32) {
+        let largest = self.largest as usize;
+        let mut components = [0.0; 4];
+        let mut step_index = 0;
+        for (index, component) in components.iter_mut().enumerate() {
+            if index != largest {
+                *component =
+                    dequantize(self.steps[step_index], -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS);
+                step_index += 1;
+            }
+        }
+
+        let components = restore_largest(components, 4, largest);
+        (components[0], components[1], components[2], components[3])
+    }
+}
+
+impl<const BITS: u8> Serde for SmallestThreeQuat<BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        UnsignedInteger::<2>::new(self.largest).ser(writer);
+        for step in self.steps {
+            ser_step::<BITS>(step, writer);
+        }
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let largest = UnsignedInteger::<2>::de(reader)?.get() as u8;
+        let steps = [
+            de_step::<BITS>(reader)?,
+            de_step::<BITS>(reader)?,
+            de_step::<BITS>(reader)?,
+        ];
+        Ok(Self { largest, steps })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const BITS: u8> ConstBitLength for SmallestThreeQuat<BITS> {
+    fn const_bit_length() -> u32 {
+        2 + 3 * BITS as u32
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::BitWriter,
+        serde::{ConstBitLength, Serde},
+        NormalizedVec2, NormalizedVec3, QuantizedF32, SmallestThreeQuat,
+    };
+
+    fn round_trip<T: Serde>(value: &T) -> T {
+        let mut writer = BitWriter::new();
+        value.ser(&mut writer);
+        let buffer = writer.to_bytes();
+        let mut reader = BitReader::new(&buffer);
+        T::de(&mut reader).unwrap()
+    }
+
+    fn assert_near(a: f32, b: f32, tolerance: f32) {
+        assert!(
+            (a - b).abs() <= tolerance,
+            "{a} is not within {tolerance} of {b}"
+        );
+    }
+
+    #[test]
+    fn quantized_f32_read_write() {
+        type Coordinate = QuantizedF32<-100, 100, 16>;
+
+        let in_1 = Coordinate::new(12.345);
+        let in_2 = Coordinate::new(-100.0);
+        let in_3 = Coordinate::new(100.0);
+
+        let out_1 = round_trip(&in_1);
+        let out_2 = round_trip(&in_2);
+        let out_3 = round_trip(&in_3);
+
+        assert_eq!(in_1, out_1);
+        assert_near(out_1.get(), 12.345, 200.0 / 65535.0);
+        assert_eq!(out_2.get(), -100.0);
+        assert_eq!(out_3.get(), 100.0);
+        assert_eq!(in_1.bit_length(), 16);
+        assert_eq!(Coordinate::const_bit_length(), 16);
+    }
+
+    #[test]
+    fn quantized_f32_clamps_to_range() {
+        type Percent = QuantizedF32<0, 1, 8>;
+
+        assert_eq!(Percent::new(-3.0).get(), 0.0);
+        assert_eq!(Percent::new(3.0).get(), 1.0);
+    }
+
+    #[test]
+    fn normalized_vec2_read_write() {
+        let in_1 = NormalizedVec2::<12>::new(3.0, -4.0);
+
+        let out_1 = round_trip(&in_1);
+        let (x, y) = out_1.get();
+
+        assert_eq!(in_1, out_1);
+        assert_near(x, 0.6, 0.002);
+        assert_near(y, -0.8, 0.002);
+        assert_eq!(in_1.bit_length(), 12);
+    }
+
+    #[test]
+    fn normalized_vec3_read_write() {
+        let in_1 = NormalizedVec3::<10>::new(-2.0, 1.0, 2.0);
+
+        let out_1 = round_trip(&in_1);
+        let (x, y, z) = out_1.get();
+
+        assert_eq!(in_1, out_1);
+        assert_near(x, -2.0 / 3.0, 0.002);
+        assert_near(y, 1.0 / 3.0, 0.002);
+        assert_near(z, 2.0 / 3.0, 0.002);
+        assert_eq!(in_1.bit_length(), 23);
+    }
+
+    #[test]
+    fn smallest_three_quat_read_write() {
+        // 90 degrees around the y axis
+        let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+        let in_1 = SmallestThreeQuat::<12>::new(0.0, half_sqrt_2, 0.0, half_sqrt_2);
+
+        let out_1 = round_trip(&in_1);
+        let (x, y, z, w) = out_1.get();
+
+        assert_eq!(in_1, out_1);
+        assert_near(x, 0.0, 0.001);
+        assert_near(y, half_sqrt_2, 0.001);
+        assert_near(z, 0.0, 0.001);
+        assert_near(w, half_sqrt_2, 0.001);
+        assert_eq!(in_1.bit_length(), 38);
+    }
+
+    #[test]
+    fn smallest_three_quat_makes_largest_positive() {
+        let in_1 = SmallestThreeQuat::<12>::new(0.1, -0.2, 0.1, -0.9);
+
+        let (x, y, z, w) = round_trip(&in_1).get();
+        let length = (0.01_f32 + 0.04 + 0.01 + 0.81).sqrt();
+
+        assert_near(x, -0.1 / length, 0.001);
+        assert_near(y, 0.2 / length, 0.001);
+        assert_near(z, -0.1 / length, 0.001);
+        assert_near(w, 0.9 / length, 0.001);
+    }
+}
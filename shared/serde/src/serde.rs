@@ -10,8 +10,52 @@ pub trait Serde: Sized + Clone + PartialEq {
 
     /// Return length of value in bits
     fn bit_length(&self) -> u32;
+
+    /// Describes how Self is written to the bitstream, so that Protocols can
+    /// be compared. Derived implementations describe each of their fields, so
+    /// a change to a nested type changes the layout of every type holding it.
+    /// Defaults to the type's name, without module paths
+    fn layout() -> String {
+        unqualified_type_name::<Self>()
+    }
+}
+
+// Strips the module paths from a type's name, e.g. `alloc::string::String`
+// becomes `String`, so a type's layout doesn't depend on how it's imported
+fn unqualified_type_name<T>() -> String {
+    let mut output = String::new();
+    let mut segment_start = 0;
+    let mut chars = std::any::type_name::<T>().chars().peekable();
+    while let Some(next) = chars.next() {
+        if next == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            output.truncate(segment_start);
+            continue;
+        }
+        output.push(next);
+        if !(next.is_alphanumeric() || next == '_') {
+            segment_start = output.len();
+        }
+    }
+    output
 }
 
 pub trait ConstBitLength {
     fn const_bit_length() -> u32;
 }
+
+/// A trait for values which can be serialized as a difference from an
+/// earlier value of the same type, known to the receiver as a baseline.
+/// Used by `DeltaProperty` to send only the parts of a large value which
+/// have changed.
+pub trait SerdeDelta: Serde {
+    /// The serialized difference between a value and its baseline
+    type Delta: Serde;
+
+    /// Returns the difference between Self and the given baseline
+    fn delta(&self, baseline: &Self) -> Self::Delta;
+
+    /// Recreates a value from the baseline it was encoded against, and the
+    /// difference returned by `delta()`
+    fn apply_delta(baseline: &Self, delta: Self::Delta) -> Result<Self, SerdeErr>;
+}
@@ -1,6 +1,7 @@
-use js_sys::Date;
 use std::time::Duration;
 
+use naia_socket_shared::Clock;
+
 /// A Timer with a given duration after which it will enter into a "Ringing"
 /// state. The Timer can be reset at an given time, or manually set to start
 /// "Ringing" again.
@@ -14,7 +15,7 @@ impl Timer {
     /// Creates a new Timer with a given Duration
     pub fn new(duration: Duration) -> Self {
         Timer {
-            last: Date::now(),
+            last: Clock::now(),
             duration: duration.as_millis() as f64,
         }
     }
@@ -22,13 +23,13 @@ impl Timer {
     /// Reset the Timer to stop ringing and wait till 'Duration' has elapsed
     /// again
     pub fn reset(&mut self) {
-        self.last = Date::now();
+        self.last = Clock::now();
     }
 
     /// Gets whether or not the Timer is "Ringing" (i.e. the given Duration has
     /// elapsed since the last "reset")
     pub fn ringing(&self) -> bool {
-        (Date::now() - self.last) > self.duration
+        (Clock::now() - self.last) > self.duration
     }
 
     /// Manually causes the Timer to enter into a "Ringing" state
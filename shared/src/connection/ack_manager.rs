@@ -1,8 +1,10 @@
 use std::{collections::HashMap, hash::Hash};
 
 use crate::{
-    messages::message_manager::MessageManager, types::PacketIndex,
-    wrapping_number::sequence_greater_than, HostWorldManager, LocalWorldManager,
+    messages::message_manager::MessageManager,
+    types::PacketIndex,
+    wrapping_number::{sequence_greater_than, sequence_less_than},
+    HostWorldManager, LocalWorldManager,
 };
 
 use super::{
@@ -26,6 +28,9 @@ pub struct AckManager {
     // However, we can only reasonably ack up to `REDUNDANT_PACKET_ACKS_SIZE + 1` packets on each
     // message we send so this should be that large.
     received_packets: SequenceBuffer<ReceivedPacket>,
+    // Number of sent packets found to be delivered / dropped, since last taken
+    delivered_packets_count: u32,
+    dropped_packets_count: u32,
 }
 
 impl AckManager {
@@ -35,14 +40,31 @@ impl AckManager {
             last_recv_packet_index: u16::MAX,
             sent_packets: HashMap::with_capacity(DEFAULT_SEND_PACKETS_SIZE),
             received_packets: SequenceBuffer::with_capacity(REDUNDANT_PACKET_ACKS_SIZE + 1),
+            delivered_packets_count: 0,
+            dropped_packets_count: 0,
         }
     }
 
+    /// Returns the number of sent packets which have been found to be
+    /// delivered & dropped, respectively, since this was last called
+    pub fn take_packet_counts(&mut self) -> (u32, u32) {
+        let counts = (self.delivered_packets_count, self.dropped_packets_count);
+        self.delivered_packets_count = 0;
+        self.dropped_packets_count = 0;
+        counts
+    }
+
     /// Get the index of the next outgoing packet
     pub fn next_sender_packet_index(&self) -> PacketIndex {
         self.next_packet_index
     }
 
+    /// Whether the given incoming packet is newer than all those received
+    /// so far
+    pub fn is_newest_incoming_packet(&self, sender_packet_index: PacketIndex) -> bool {
+        !sequence_less_than(sender_packet_index, self.received_packets.sequence_num())
+    }
+
     /// Process an incoming packet, handle notifications of delivered / dropped
     /// packets
     pub fn process_incoming_header<E: Copy + Eq + Hash + Send + Sync>(
@@ -79,6 +101,7 @@ impl AckManager {
             }
 
             self.sent_packets.remove(&sender_ack_index);
+            self.delivered_packets_count = self.delivered_packets_count.saturating_add(1);
         }
 
         // The `sender_ack_bitfield` is going to include whether or not the past 32
@@ -99,8 +122,10 @@ impl AckManager {
                     }
 
                     self.sent_packets.remove(&sent_packet_index);
+                    self.delivered_packets_count = self.delivered_packets_count.saturating_add(1);
                 } else {
                     self.sent_packets.remove(&sent_packet_index);
+                    self.dropped_packets_count = self.dropped_packets_count.saturating_add(1);
                 }
             }
 
@@ -1,4 +1,4 @@
-use std::{hash::Hash, net::SocketAddr};
+use std::hash::Hash;
 
 use naia_serde::{BitWriter, Serde};
 use naia_socket_shared::Instant;
@@ -17,8 +17,9 @@ use crate::{
 };
 
 use super::{
-    ack_manager::AckManager, connection_config::ConnectionConfig,
-    packet_notifiable::PacketNotifiable, packet_type::PacketType, standard_header::StandardHeader,
+    ack_manager::AckManager, congestion_control::CongestionController,
+    connection_config::ConnectionConfig, packet_notifiable::PacketNotifiable,
+    packet_type::PacketType, standard_header::StandardHeader,
 };
 
 /// Represents a connection to a remote host, and provides functionality to
@@ -32,24 +33,38 @@ pub struct BaseConnection<E: Copy + Eq + Hash + Send + Sync> {
     heartbeat_timer: Timer,
     timeout_timer: Timer,
     ack_manager: AckManager,
+    congestion_controller: Option<CongestionController>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
     /// Create a new BaseConnection, given the appropriate underlying managers
     pub fn new(
-        address: &Option<SocketAddr>,
         host_type: HostType,
         user_key: u64,
         connection_config: &ConnectionConfig,
         channel_kinds: &ChannelKinds,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
+        // the Server tracks the changes to its Components separately for each
+        // User, while a Client only sends to the Server
+        let receiver_user_key = match host_type {
+            HostType::Server => Some(user_key),
+            HostType::Client => None,
+        };
         BaseConnection {
             heartbeat_timer: Timer::new(connection_config.heartbeat_interval),
             timeout_timer: Timer::new(connection_config.disconnection_timeout_duration),
             ack_manager: AckManager::new(),
+            congestion_controller: connection_config
+                .congestion
+                .as_ref()
+                .map(CongestionController::new),
             message_manager: MessageManager::new(host_type, channel_kinds),
-            host_world_manager: HostWorldManager::new(address, global_world_manager),
+            host_world_manager: HostWorldManager::new(
+                &receiver_user_key,
+                connection_config.update_bytes_per_tick,
+                global_world_manager,
+            ),
             remote_world_manager: RemoteWorldManager::new(),
             remote_world_reader: RemoteWorldReader::new(),
             local_world_manager: LocalWorldManager::new(user_key),
@@ -83,6 +98,32 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         self.timeout_timer.ringing()
     }
 
+    // Congestion
+
+    /// Returns whether another data packet may be sent to the remote host
+    /// without exceeding the current send rate
+    pub fn can_send_packet(&mut self) -> bool {
+        match &mut self.congestion_controller {
+            Some(controller) => controller.can_send(),
+            None => true,
+        }
+    }
+
+    /// Record that a data packet of the given size has been sent
+    pub fn record_sent_packet(&mut self, bytes: usize) {
+        if let Some(controller) = &mut self.congestion_controller {
+            controller.record_sent(bytes);
+        }
+    }
+
+    /// Gets the current send rate to the remote host, in bytes per second,
+    /// or None if congestion control is disabled
+    pub fn send_rate(&self) -> Option<f32> {
+        self.congestion_controller
+            .as_ref()
+            .map(|controller| controller.bytes_per_second())
+    }
+
     // Acks & Headers
 
     /// Process an incoming packet, pulling out the packet index number to keep
@@ -100,6 +141,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
             &mut self.local_world_manager,
             packet_notifiables,
         );
+
+        let (delivered, dropped) = self.ack_manager.take_packet_counts();
+        if let Some(controller) = &mut self.congestion_controller {
+            controller.record_packets(delivered, dropped);
+        }
+    }
+
+    /// Whether the packet with the given header is newer than all those
+    /// received from the remote host so far
+    pub fn is_newest_incoming_packet(&self, header: &StandardHeader) -> bool {
+        self.ack_manager
+            .is_newest_incoming_packet(header.sender_packet_index)
     }
 
     /// Given a packet payload, start tracking the packet via it's index, attach
@@ -118,6 +171,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
     }
 
     pub fn collect_outgoing_messages(&mut self, now: &Instant, rtt_millis: &f32) {
+        if let Some(controller) = &mut self.congestion_controller {
+            controller.update(*rtt_millis);
+        }
         self.host_world_manager
             .collect_outgoing_messages(rtt_millis);
         self.message_manager
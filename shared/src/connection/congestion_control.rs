@@ -0,0 +1,260 @@
+use std::{default::Default, time::Duration};
+
+use naia_serde::MTU_SIZE_BYTES;
+use naia_socket_shared::Instant;
+
+/// The shortest time between two adjustments of the send rate
+const MIN_ADJUSTMENT_INTERVAL_MILLIS: f32 = 100.0;
+/// The time's worth of sending which may be saved up while idle
+const BURST_DURATION_SECS: f32 = 0.1;
+/// How quickly the baseline round trip time follows the current one upwards,
+/// per adjustment, so that a lasting change in route isn't seen as congestion
+const BASE_RTT_DRIFT: f32 = 0.05;
+
+/// Contains Config properties which will be used to adapt the rate at which
+/// data is sent over a Connection to the network conditions it experiences
+#[derive(Clone, Debug)]
+pub struct CongestionConfig {
+    /// The send rate, in bytes per second, at the start of a Connection
+    pub initial_bytes_per_second: u32,
+    /// The lowest the send rate will go, in bytes per second
+    pub min_bytes_per_second: u32,
+    /// The highest the send rate will go, in bytes per second
+    pub max_bytes_per_second: u32,
+    /// How much the send rate grows, in bytes per second, each round trip
+    /// without congestion in which the full rate was used
+    pub additive_increase: u32,
+    /// The factor the send rate is multiplied by after congestion is detected
+    pub multiplicative_decrease: f32,
+    /// The fraction of packets which may be lost in a round trip before this
+    /// is considered congestion
+    pub loss_threshold: f32,
+    /// How far the round trip time may rise above its recent baseline before
+    /// this is considered congestion
+    pub rtt_tolerance: Duration,
+}
+
+impl Default for CongestionConfig {
+    fn default() -> Self {
+        Self {
+            initial_bytes_per_second: 64_000,
+            min_bytes_per_second: 4_000,
+            max_bytes_per_second: 512_000,
+            additive_increase: 4_000,
+            multiplicative_decrease: 0.5,
+            loss_threshold: 0.05,
+            rtt_tolerance: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Limits the bytes sent over a Connection with a send rate which follows
+/// additive-increase / multiplicative-decrease: the rate grows steadily while
+/// the link keeps up, and is cut whenever packet loss or round trip time
+/// indicate congestion
+pub struct CongestionController {
+    config: CongestionConfig,
+    bytes_per_second: f32,
+    allowance: f32,
+    last_refill: Instant,
+    last_adjustment: Instant,
+    base_rtt_millis: Option<f32>,
+    delivered_packets: u32,
+    dropped_packets: u32,
+    was_limited: bool,
+}
+
+impl CongestionController {
+    pub fn new(config: &CongestionConfig) -> Self {
+        let bytes_per_second = config.initial_bytes_per_second as f32;
+        let now = Instant::now();
+        let mut controller = Self {
+            config: config.clone(),
+            bytes_per_second,
+            allowance: 0.0,
+            last_refill: now.clone(),
+            last_adjustment: now,
+            base_rtt_millis: None,
+            delivered_packets: 0,
+            dropped_packets: 0,
+            was_limited: false,
+        };
+        controller.allowance = controller.max_allowance();
+        controller
+    }
+
+    /// Gets the current send rate, in bytes per second
+    pub fn bytes_per_second(&self) -> f32 {
+        self.bytes_per_second
+    }
+
+    /// Records the fate of packets sent to the remote host
+    pub fn record_packets(&mut self, delivered: u32, dropped: u32) {
+        self.delivered_packets = self.delivered_packets.saturating_add(delivered);
+        self.dropped_packets = self.dropped_packets.saturating_add(dropped);
+    }
+
+    /// Returns whether another packet may be sent at the current send rate
+    pub fn can_send(&mut self) -> bool {
+        self.refill();
+        if self.allowance > 0.0 {
+            return true;
+        }
+        self.was_limited = true;
+        false
+    }
+
+    /// Records that a packet of the given size has been sent
+    pub fn record_sent(&mut self, bytes: usize) {
+        self.allowance -= bytes as f32;
+    }
+
+    /// Adjusts the send rate once per round trip, using the loss observed
+    /// since the last adjustment & the current round trip time
+    pub fn update(&mut self, rtt_millis: f32) {
+        let base_rtt_millis = match self.base_rtt_millis {
+            Some(base_rtt_millis) if base_rtt_millis <= rtt_millis => base_rtt_millis,
+            _ => rtt_millis,
+        };
+        self.base_rtt_millis = Some(base_rtt_millis);
+
+        let adjustment_interval = rtt_millis.max(MIN_ADJUSTMENT_INTERVAL_MILLIS);
+        if self.last_adjustment.elapsed().as_secs_f32() * 1000.0 < adjustment_interval {
+            return;
+        }
+
+        let total_packets = self.delivered_packets + self.dropped_packets;
+        if total_packets == 0 {
+            // nothing has been heard about sent packets, hold the rate
+            return;
+        }
+
+        let loss = self.dropped_packets as f32 / total_packets as f32;
+        let rtt_tolerance_millis = self.config.rtt_tolerance.as_secs_f32() * 1000.0;
+        let congested = loss > self.config.loss_threshold
+            || rtt_millis > base_rtt_millis + rtt_tolerance_millis;
+
+        if congested {
+            self.bytes_per_second *= self.config.multiplicative_decrease;
+        } else if self.was_limited {
+            self.bytes_per_second += self.config.additive_increase as f32;
+        }
+        self.bytes_per_second = self.bytes_per_second.clamp(
+            self.config.min_bytes_per_second as f32,
+            self.config.max_bytes_per_second as f32,
+        );
+        self.allowance = self.allowance.min(self.max_allowance());
+
+        self.base_rtt_millis =
+            Some(base_rtt_millis + (rtt_millis - base_rtt_millis) * BASE_RTT_DRIFT);
+        self.last_adjustment = Instant::now();
+        self.delivered_packets = 0;
+        self.dropped_packets = 0;
+        self.was_limited = false;
+    }
+
+    fn refill(&mut self) {
+        let elapsed = self.last_refill.elapsed().as_secs_f32();
+        self.last_refill = Instant::now();
+        self.allowance =
+            (self.allowance + elapsed * self.bytes_per_second).min(self.max_allowance());
+    }
+
+    fn max_allowance(&self) -> f32 {
+        (self.bytes_per_second * BURST_DURATION_SECS).max(MTU_SIZE_BYTES as f32)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod congestion_controller_tests {
+    use std::time::Duration;
+
+    use naia_socket_shared::Clock;
+
+    use super::{CongestionConfig, CongestionController};
+
+    fn controller() -> CongestionController {
+        Clock::set_manual();
+        CongestionController::new(&CongestionConfig::default())
+    }
+
+    fn saturate(controller: &mut CongestionController) {
+        while controller.can_send() {
+            controller.record_sent(1000);
+        }
+    }
+
+    #[test]
+    fn allowance_limits_bytes_sent() {
+        let mut controller = controller();
+
+        saturate(&mut controller);
+        assert!(!controller.can_send());
+
+        // 64,000 bytes per second refills in well under 100ms
+        Clock::advance(Duration::from_millis(100));
+        assert!(controller.can_send());
+    }
+
+    #[test]
+    fn loss_decreases_rate() {
+        let mut controller = controller();
+
+        controller.record_packets(10, 10);
+        Clock::advance(Duration::from_millis(200));
+        controller.update(50.0);
+
+        assert_eq!(controller.bytes_per_second(), 32_000.0);
+    }
+
+    #[test]
+    fn rtt_increase_decreases_rate() {
+        let mut controller = controller();
+        controller.update(50.0);
+
+        controller.record_packets(10, 0);
+        Clock::advance(Duration::from_millis(200));
+        controller.update(500.0);
+        Clock::advance(Duration::from_millis(500));
+        controller.update(500.0);
+
+        assert_eq!(controller.bytes_per_second(), 32_000.0);
+    }
+
+    #[test]
+    fn limited_sender_increases_rate() {
+        let mut controller = controller();
+
+        controller.record_packets(10, 0);
+        saturate(&mut controller);
+        Clock::advance(Duration::from_millis(200));
+        controller.update(50.0);
+
+        assert_eq!(controller.bytes_per_second(), 68_000.0);
+    }
+
+    #[test]
+    fn unlimited_sender_holds_rate() {
+        let mut controller = controller();
+
+        controller.record_packets(10, 0);
+        Clock::advance(Duration::from_millis(200));
+        controller.update(50.0);
+
+        assert_eq!(controller.bytes_per_second(), 64_000.0);
+    }
+
+    #[test]
+    fn rate_stays_within_bounds() {
+        let mut controller = controller();
+
+        for _ in 0..20 {
+            controller.record_packets(0, 10);
+            Clock::advance(Duration::from_millis(200));
+            controller.update(50.0);
+        }
+
+        assert_eq!(controller.bytes_per_second(), 4_000.0);
+    }
+}
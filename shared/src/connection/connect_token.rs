@@ -0,0 +1,150 @@
+use std::net::SocketAddr;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use naia_serde::{BitReader, BitWriter, Serde, SerdeErr};
+
+use crate::backends::Timestamp;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SIGNATURE_BYTES: usize = 32;
+
+/// Permission for a Client to connect to a Server, issued by a backend (such
+/// as a matchmaker) rather than the Server itself.
+///
+/// The backend signs the token with a key it shares with the Server, and
+/// hands the signed bytes to the Client, which passes them on to
+/// `Client::connect_token()`. The Server checks the signature, expiry and
+/// addresses itself, without contacting the backend, and then exposes the
+/// token through a `ConnectTokenEvent`.
+///
+/// The token is signed, not encrypted: the Client can read its contents.
+pub struct ConnectToken {
+    /// Identifies the user the token was issued to. Only one Client may be
+    /// connected with a given user id at a time.
+    pub user_id: u64,
+    /// Unix timestamp, in seconds, after which the token is rejected
+    pub expire_timestamp: u64,
+    /// Public addresses of the Servers the token may be used to connect to
+    pub server_addresses: Vec<SocketAddr>,
+    /// Custom data passed on to the Server as is
+    pub user_data: Vec<u8>,
+}
+
+impl ConnectToken {
+    pub fn new(
+        user_id: u64,
+        expire_timestamp: u64,
+        server_addresses: Vec<SocketAddr>,
+        user_data: Vec<u8>,
+    ) -> Self {
+        Self {
+            user_id,
+            expire_timestamp,
+            server_addresses,
+            user_data,
+        }
+    }
+
+    /// Signs the token with the key shared with the Server, returning the
+    /// bytes to give to the Client
+    pub fn sign(&self, key: &[u8]) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        self.user_id.ser(&mut writer);
+        self.expire_timestamp.ser(&mut writer);
+        self.server_addresses.ser(&mut writer);
+        self.user_data.ser(&mut writer);
+
+        let mut output = writer.to_bytes().to_vec();
+        let signature = signature(key, &output).finalize().into_bytes();
+        output.extend_from_slice(&signature);
+        output
+    }
+
+    /// Reads a token signed with `sign()`. Returns None if it was not signed
+    /// with the given key, or can't be read.
+    pub fn verify(signed_token: &[u8], key: &[u8]) -> Option<Self> {
+        let contents_length = signed_token.len().checked_sub(SIGNATURE_BYTES)?;
+        let (contents, signature_bytes) = signed_token.split_at(contents_length);
+        signature(key, contents)
+            .verify_slice(signature_bytes)
+            .ok()?;
+
+        Self::read(&mut BitReader::new(contents)).ok()
+    }
+
+    /// Whether the token's expiry time has passed
+    pub fn is_expired(&self) -> bool {
+        Timestamp::now() >= self.expire_timestamp
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        Ok(Self {
+            user_id: Serde::de(reader)?,
+            expire_timestamp: Serde::de(reader)?,
+            server_addresses: Serde::de(reader)?,
+            user_data: Serde::de(reader)?,
+        })
+    }
+}
+
+fn signature(key: &[u8], contents: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(contents);
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConnectToken;
+
+    const KEY: &[u8] = b"a key shared with the backend";
+
+    fn token() -> ConnectToken {
+        ConnectToken::new(
+            42,
+            u64::MAX,
+            vec!["127.0.0.1:14191".parse().unwrap()],
+            b"team blue".to_vec(),
+        )
+    }
+
+    #[test]
+    fn signed_token_round_trips() {
+        let signed = token().sign(KEY);
+        let verified = ConnectToken::verify(&signed, KEY).unwrap();
+
+        assert_eq!(verified.user_id, 42);
+        assert_eq!(verified.expire_timestamp, u64::MAX);
+        assert_eq!(
+            verified.server_addresses,
+            vec!["127.0.0.1:14191".parse().unwrap()]
+        );
+        assert_eq!(verified.user_data, b"team blue".to_vec());
+        assert!(!verified.is_expired());
+    }
+
+    #[test]
+    fn token_signed_with_other_key_is_rejected() {
+        let signed = token().sign(b"some other key");
+        assert!(ConnectToken::verify(&signed, KEY).is_none());
+    }
+
+    #[test]
+    fn tampered_token_is_rejected() {
+        let mut signed = token().sign(KEY);
+        signed[0] ^= 1;
+        assert!(ConnectToken::verify(&signed, KEY).is_none());
+
+        assert!(ConnectToken::verify(&signed[..10], KEY).is_none());
+    }
+
+    #[test]
+    fn token_expires() {
+        let mut token = token();
+        token.expire_timestamp = 0;
+        assert!(token.is_expired());
+    }
+}
@@ -1,5 +1,7 @@
 use std::{default::Default, time::Duration};
 
+use super::congestion_control::CongestionConfig;
+
 /// Contains Config properties which will be used by a Server or Client
 #[derive(Clone, Debug)]
 pub struct ConnectionConfig {
@@ -12,6 +14,22 @@ pub struct ConnectionConfig {
     /// The duration over which to measure bandwidth. Set to None to avoid
     /// measure bandwidth at all.
     pub bandwidth_measure_duration: Option<Duration>,
+    /// The maximum number of bytes of Entity updates to send to a remote host
+    /// each tick. Updates which don't fit are sent in later ticks, in order of
+    /// Entity priority. Set to None to send as many updates as possible.
+    pub update_bytes_per_tick: Option<u32>,
+    /// Configuration used to adapt the rate at which packets are sent to a
+    /// remote host to the observed packet loss & round trip time. Set to
+    /// None to send packets as fast as they are produced.
+    pub congestion: Option<CongestionConfig>,
+    /// The duration to wait for the Response to a Request sent to a remote
+    /// host, before giving up on it. Requests received from the remote host
+    /// which haven't been responded to within this duration are dropped
+    pub request_timeout: Duration,
+    /// The maximum number of Requests received from a remote host which can
+    /// wait to be responded to at once. Further Requests are dropped, and
+    /// time out on the remote host
+    pub max_incoming_requests: usize,
 }
 
 impl ConnectionConfig {
@@ -25,6 +43,10 @@ impl ConnectionConfig {
             disconnection_timeout_duration,
             heartbeat_interval,
             bandwidth_measure_duration,
+            update_bytes_per_tick: None,
+            congestion: None,
+            request_timeout: Duration::from_secs(10),
+            max_incoming_requests: 64,
         }
     }
 }
@@ -35,6 +57,10 @@ impl Default for ConnectionConfig {
             disconnection_timeout_duration: Duration::from_secs(30),
             heartbeat_interval: Duration::from_secs(4),
             bandwidth_measure_duration: None,
+            update_bytes_per_tick: None,
+            congestion: None,
+            request_timeout: Duration::from_secs(10),
+            max_incoming_requests: 64,
         }
     }
 }
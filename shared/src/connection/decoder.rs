@@ -1,6 +1,7 @@
 cfg_if! {
     if #[cfg(feature = "zstd_support")]
     {
+        use naia_serde::{SerdeErr, MTU_SIZE_BYTES};
         use zstd::bulk::Decompressor;
 
         use super::compression_config::CompressionMode;
@@ -28,25 +29,28 @@ cfg_if! {
                 }
             }
 
-            pub fn decode(&mut self, payload: &[u8]) -> &[u8] {
+            pub fn decode(&mut self, payload: &[u8]) -> Result<&[u8], SerdeErr> {
                 if let Some(decoder) = &mut self.decoder {
+                    // packets are never larger than the MTU before being
+                    // compressed, so don't trust a frame claiming otherwise
+                    let capacity = Decompressor::<'static>::upper_bound(payload)
+                        .ok_or(SerdeErr)?
+                        .min(MTU_SIZE_BYTES);
                     self.result = decoder
-                        .decompress(
-                            payload,
-                            Decompressor::<'static>::upper_bound(payload)
-                                .expect("upper bound decode error"),
-                        )
-                        .expect("decode error");
-                    return &self.result;
+                        .decompress(payload, capacity)
+                        .map_err(|_| SerdeErr)?;
+                    return Ok(&self.result);
                 } else {
                     self.result = payload.to_vec();
-                    return &self.result;
+                    return Ok(&self.result);
                 }
             }
         }
     }
     else
     {
+        use naia_serde::SerdeErr;
+
         use super::compression_config::CompressionMode;
 
         pub struct Decoder {
@@ -60,9 +64,9 @@ cfg_if! {
                 }
             }
 
-            pub fn decode(&mut self, payload: &[u8]) -> &[u8] {
+            pub fn decode(&mut self, payload: &[u8]) -> Result<&[u8], SerdeErr> {
                 self.result = payload.to_vec();
-                &self.result
+                Ok(&self.result)
             }
         }
     }
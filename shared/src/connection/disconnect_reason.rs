@@ -0,0 +1,21 @@
+use naia_serde::SerdeInternal;
+
+/// The longest message, in bytes, a User can be kicked with. The message is
+/// sent in a single disconnect packet, so longer messages are truncated
+pub const KICK_MESSAGE_MAX_BYTES: usize = 256;
+
+/// Why a connection between a Client & the Server was closed. Sent with
+/// `PacketType::Disconnect` so both sides know how the connection ended
+#[derive(Clone, Debug, PartialEq, Eq, SerdeInternal)]
+pub enum DisconnectReason {
+    /// Nothing was heard from the remote host for too long
+    Timeout,
+    /// The Server kicked the User, optionally with a custom message
+    Kicked(Option<String>),
+    /// The Client chose to disconnect
+    ClientQuit,
+    /// A malformed packet was received from the remote host
+    ProtocolError,
+    /// The Server shut down
+    ServerShutdown,
+}
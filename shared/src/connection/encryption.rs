@@ -0,0 +1,605 @@
+use chacha20poly1305::{aead::AeadInPlace, ChaCha20Poly1305, Key, KeyInit, Nonce, Tag};
+use hkdf::Hkdf;
+use rand_core::OsRng;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use naia_serde::{BitReader, Serde};
+
+use crate::connection::packet_type::PacketType;
+
+/// The bytes of an X25519 public key, as sent during the handshake
+pub type EncryptionPublicKey = [u8; 32];
+
+/// The bytes of an X25519 secret key
+pub type EncryptionSecretKey = [u8; 32];
+
+// Every packet sent over an encrypted connection starts with one of these
+const PLAINTEXT_PACKET: u8 = 0;
+const SEALED_PACKET: u8 = 1;
+// A sealed packet which also carries the sender's connection id, so the
+// receiver can find its PacketCipher after the sender's address has changed
+const SEALED_CONNECTION_PACKET: u8 = 2;
+
+const CONNECTION_ID_BYTES: usize = 8;
+const SEQUENCE_BYTES: usize = 8;
+const TAG_BYTES: usize = 16;
+
+// The sequence number reserved for the sealed part of the handshake's
+// validate request. Packets are sealed with sequence numbers starting after it.
+const HANDSHAKE_SEQUENCE: u64 = 0;
+
+/// How far behind the most recent sequence number a sealed packet may be
+/// before it is rejected as a possible replay
+const REPLAY_WINDOW: u64 = 64;
+
+const CLIENT_TO_SERVER_INFO: &[u8] = b"naia client to server";
+const SERVER_TO_CLIENT_INFO: &[u8] = b"naia server to client";
+
+/// Whether packets of the given type are sent in the clear on an encrypted
+/// connection. These are the handshake packets sent before both ends have
+/// agreed on a PacketCipher, which protect anything sensitive they carry
+/// themselves. All other packets are sealed.
+pub fn is_plaintext_packet(packet_type: PacketType) -> bool {
+    matches!(
+        packet_type,
+        PacketType::ClientChallengeRequest
+            | PacketType::ServerChallengeResponse
+            | PacketType::ClientValidateRequest
+    )
+}
+
+/// An X25519 key pair, used during the handshake to agree on the keys of a
+/// PacketCipher with the remote host
+pub struct KeyExchange {
+    secret: StaticSecret,
+    public_key: PublicKey,
+}
+
+impl KeyExchange {
+    /// Generates a new, random key pair
+    pub fn new() -> Self {
+        let secret = StaticSecret::random_from_rng(OsRng);
+        let public_key = PublicKey::from(&secret);
+        Self { secret, public_key }
+    }
+
+    /// Creates the key pair with the given secret key, so that its public
+    /// key is the same every time
+    pub fn from_secret_key(secret_key: EncryptionSecretKey) -> Self {
+        let secret = StaticSecret::from(secret_key);
+        let public_key = PublicKey::from(&secret);
+        Self { secret, public_key }
+    }
+
+    pub fn secret_key(&self) -> EncryptionSecretKey {
+        self.secret.to_bytes()
+    }
+
+    pub fn public_key(&self) -> EncryptionPublicKey {
+        self.public_key.to_bytes()
+    }
+
+    /// Agrees on a PacketCipher with the Server owning the given public key.
+    /// Returns None if the key is not a valid X25519 public key.
+    pub fn client_cipher(&self, server_public_key: &EncryptionPublicKey) -> Option<PacketCipher> {
+        let (client_to_server, server_to_client) =
+            self.derive_keys(server_public_key, &self.public_key(), server_public_key)?;
+        Some(PacketCipher::new(
+            *server_public_key,
+            &client_to_server,
+            &server_to_client,
+        ))
+    }
+
+    /// Agrees on a PacketCipher with the Client owning the given public key.
+    /// Returns None if the key is not a valid X25519 public key.
+    pub fn server_cipher(&self, client_public_key: &EncryptionPublicKey) -> Option<PacketCipher> {
+        let (client_to_server, server_to_client) =
+            self.derive_keys(client_public_key, client_public_key, &self.public_key())?;
+        Some(PacketCipher::new(
+            *client_public_key,
+            &server_to_client,
+            &client_to_server,
+        ))
+    }
+
+    fn derive_keys(
+        &self,
+        remote_public_key: &EncryptionPublicKey,
+        client_public_key: &EncryptionPublicKey,
+        server_public_key: &EncryptionPublicKey,
+    ) -> Option<([u8; 32], [u8; 32])> {
+        let shared_secret = self
+            .secret
+            .diffie_hellman(&PublicKey::from(*remote_public_key));
+        if !shared_secret.was_contributory() {
+            return None;
+        }
+
+        let mut salt = [0; 64];
+        salt[..32].copy_from_slice(client_public_key);
+        salt[32..].copy_from_slice(server_public_key);
+        let hkdf = Hkdf::<Sha256>::new(Some(&salt), shared_secret.as_bytes());
+
+        let mut client_to_server = [0; 32];
+        let mut server_to_client = [0; 32];
+        hkdf.expand(CLIENT_TO_SERVER_INFO, &mut client_to_server)
+            .ok()?;
+        hkdf.expand(SERVER_TO_CLIENT_INFO, &mut server_to_client)
+            .ok()?;
+
+        Some((client_to_server, server_to_client))
+    }
+}
+
+impl Default for KeyExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encrypts & authenticates the packets of a single connection with
+/// ChaCha20-Poly1305, using the keys agreed on during the handshake. Each
+/// sealed packet carries a sequence number which is used as its nonce, and
+/// which the receiver uses to reject replayed packets.
+pub struct PacketCipher {
+    remote_public_key: EncryptionPublicKey,
+    send_cipher: ChaCha20Poly1305,
+    receive_cipher: ChaCha20Poly1305,
+    next_send_sequence: u64,
+    replay_protection: ReplayProtection,
+}
+
+impl PacketCipher {
+    fn new(
+        remote_public_key: EncryptionPublicKey,
+        send_key: &[u8; 32],
+        receive_key: &[u8; 32],
+    ) -> Self {
+        Self {
+            remote_public_key,
+            send_cipher: ChaCha20Poly1305::new(Key::from_slice(send_key)),
+            receive_cipher: ChaCha20Poly1305::new(Key::from_slice(receive_key)),
+            next_send_sequence: HANDSHAKE_SEQUENCE + 1,
+            replay_protection: ReplayProtection::new(),
+        }
+    }
+
+    /// Gets the public key of the remote host this cipher was agreed with
+    pub fn remote_public_key(&self) -> &EncryptionPublicKey {
+        &self.remote_public_key
+    }
+
+    /// Seals the part of the handshake which must not be sent in the clear.
+    /// Sealing the same payload again gives the same output, so it is safe
+    /// to resend.
+    pub fn seal_handshake(&self, payload: &[u8]) -> Vec<u8> {
+        let mut output = payload.to_vec();
+        let tag = self
+            .send_cipher
+            .encrypt_in_place_detached(&nonce(HANDSHAKE_SEQUENCE), &[], &mut output)
+            .expect("payload too large to encrypt");
+        output.extend_from_slice(&tag);
+        output
+    }
+
+    /// Opens the part of the handshake sealed by the remote host's
+    /// `seal_handshake()`. Returns None if it can't be authenticated.
+    pub fn open_handshake(&self, sealed: &[u8]) -> Option<Vec<u8>> {
+        let payload_length = sealed.len().checked_sub(TAG_BYTES)?;
+        let mut output = sealed[..payload_length].to_vec();
+        let tag = Tag::from_slice(&sealed[payload_length..]);
+        self.receive_cipher
+            .decrypt_in_place_detached(&nonce(HANDSHAKE_SEQUENCE), &[], &mut output, tag)
+            .ok()?;
+        Some(output)
+    }
+
+    fn seal(&mut self, connection_id: Option<u64>, payload: &[u8], output: &mut Vec<u8>) {
+        let sequence = self.next_send_sequence;
+        self.next_send_sequence += 1;
+
+        output.clear();
+        match connection_id {
+            Some(connection_id) => {
+                output.push(SEALED_CONNECTION_PACKET);
+                output.extend_from_slice(&connection_id.to_le_bytes());
+            }
+            None => output.push(SEALED_PACKET),
+        }
+        output.extend_from_slice(&sequence.to_le_bytes());
+        let header_length = output.len();
+        output.extend_from_slice(payload);
+
+        let (header, body) = output.split_at_mut(header_length);
+        let tag = self
+            .send_cipher
+            .encrypt_in_place_detached(&nonce(sequence), header, body)
+            .expect("payload too large to encrypt");
+        output.extend_from_slice(&tag);
+    }
+
+    fn open(&mut self, packet: &[u8], output: &mut Vec<u8>) -> bool {
+        let header_length = match packet.first() {
+            Some(&SEALED_CONNECTION_PACKET) => 1 + CONNECTION_ID_BYTES + SEQUENCE_BYTES,
+            _ => 1 + SEQUENCE_BYTES,
+        };
+        if packet.len() < header_length + TAG_BYTES {
+            return false;
+        }
+        let (header, rest) = packet.split_at(header_length);
+        let (body, tag) = rest.split_at(rest.len() - TAG_BYTES);
+
+        let mut sequence_bytes = [0; SEQUENCE_BYTES];
+        sequence_bytes.copy_from_slice(&header[header_length - SEQUENCE_BYTES..]);
+        let sequence = u64::from_le_bytes(sequence_bytes);
+
+        if !self.replay_protection.is_new(sequence) {
+            return false;
+        }
+
+        output.clear();
+        output.extend_from_slice(body);
+        if self
+            .receive_cipher
+            .decrypt_in_place_detached(&nonce(sequence), header, output, Tag::from_slice(tag))
+            .is_err()
+        {
+            return false;
+        }
+
+        // only record the sequence once the packet has been authenticated,
+        // so forged packets can't block genuine ones
+        self.replay_protection.record(sequence);
+        true
+    }
+}
+
+fn nonce(sequence: u64) -> Nonce {
+    let mut nonce = [0; 12];
+    nonce[4..].copy_from_slice(&sequence.to_le_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+/// Tracks which of the most recent sequence numbers have been received
+struct ReplayProtection {
+    most_recent: u64,
+    // bit N is set if `most_recent - N - 1` has been received
+    received_bitfield: u64,
+}
+
+impl ReplayProtection {
+    fn new() -> Self {
+        // the handshake sequence number is never valid for a sealed packet
+        Self {
+            most_recent: HANDSHAKE_SEQUENCE,
+            received_bitfield: 0,
+        }
+    }
+
+    fn is_new(&self, sequence: u64) -> bool {
+        if sequence > self.most_recent {
+            return true;
+        }
+        let age = self.most_recent - sequence;
+        if age == 0 || age > REPLAY_WINDOW {
+            return false;
+        }
+        self.received_bitfield & (1 << (age - 1)) == 0
+    }
+
+    fn record(&mut self, sequence: u64) {
+        if sequence > self.most_recent {
+            let shift = sequence - self.most_recent;
+            self.received_bitfield = if shift > REPLAY_WINDOW {
+                0
+            } else {
+                // the previous most recent sequence becomes bit `shift - 1`
+                ((self.received_bitfield << 1) | 1)
+                    .checked_shl((shift - 1) as u32)
+                    .unwrap_or(0)
+            };
+            self.most_recent = sequence;
+        } else {
+            let age = self.most_recent - sequence;
+            self.received_bitfield |= 1 << (age - 1);
+        }
+    }
+}
+
+/// Encrypts outgoing and decrypts incoming packets of a connection over a
+/// transport which does not encrypt them itself. Handshake packets are sent
+/// in the clear, all others are sealed with the connection's PacketCipher
+/// once one has been agreed on.
+pub struct PacketEncryption {
+    cipher: Option<PacketCipher>,
+    connection_id: Option<u64>,
+    buffer: Vec<u8>,
+    sealed: bool,
+}
+
+impl PacketEncryption {
+    pub fn new() -> Self {
+        Self {
+            cipher: None,
+            connection_id: None,
+            buffer: Vec::new(),
+            sealed: false,
+        }
+    }
+
+    /// Sets the connection id the Server assigned to this connection. It is
+    /// sent with each sealed packet, so the Server can still authenticate
+    /// them after the sender's address has changed.
+    pub fn set_connection_id(&mut self, connection_id: u64) {
+        self.connection_id = Some(connection_id);
+    }
+
+    /// Gets the connection id carried by a received packet, if any. It is
+    /// not authenticated until the packet has been passed to `decrypt()`.
+    pub fn connection_id(packet: &[u8]) -> Option<u64> {
+        if packet.first() != Some(&SEALED_CONNECTION_PACKET) {
+            return None;
+        }
+        let connection_id_bytes = packet.get(1..1 + CONNECTION_ID_BYTES)?;
+        Some(u64::from_le_bytes(connection_id_bytes.try_into().ok()?))
+    }
+
+    pub fn has_cipher(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// Sets the PacketCipher agreed on with the remote host. A cipher agreed
+    /// with the same remote key is kept instead, as restarting its sequence
+    /// numbers would reuse nonces.
+    pub fn set_cipher(&mut self, cipher: PacketCipher) {
+        if let Some(current_cipher) = &self.cipher {
+            if current_cipher.remote_public_key == cipher.remote_public_key {
+                return;
+            }
+        }
+        self.cipher = Some(cipher);
+    }
+
+    /// Whether the given packet is sealed when sent. Packets which aren't
+    /// must be sent uncompressed, so the receiver can check their type.
+    pub fn seals(packet: &[u8]) -> bool {
+        !packet_type(packet).is_some_and(is_plaintext_packet)
+    }
+
+    /// Prepares a packet to be sent, sealing it if `seal` is set. Returns
+    /// None if the packet must be sealed, but no PacketCipher has been
+    /// agreed on yet.
+    pub fn encrypt(&mut self, payload: &[u8], seal: bool) -> Option<&[u8]> {
+        if seal {
+            self.cipher
+                .as_mut()?
+                .seal(self.connection_id, payload, &mut self.buffer);
+        } else {
+            self.buffer.clear();
+            self.buffer.push(PLAINTEXT_PACKET);
+            self.buffer.extend_from_slice(payload);
+        }
+        Some(&self.buffer)
+    }
+
+    /// Reads a received packet, which can then be retrieved with
+    /// `payload()`. Returns false if the packet should be dropped, because it
+    /// is malformed, can't be authenticated, is a replay, or is not a
+    /// handshake packet but was sent in the clear.
+    pub fn decrypt(&mut self, packet: &[u8]) -> bool {
+        match packet.first() {
+            Some(&PLAINTEXT_PACKET) => {
+                let payload = &packet[1..];
+                if Self::seals(payload) {
+                    return false;
+                }
+                self.buffer.clear();
+                self.buffer.extend_from_slice(payload);
+                self.sealed = false;
+                true
+            }
+            Some(&SEALED_PACKET) | Some(&SEALED_CONNECTION_PACKET) => {
+                let Some(cipher) = self.cipher.as_mut() else {
+                    return false;
+                };
+                self.sealed = true;
+                cipher.open(packet, &mut self.buffer)
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the payload of the last packet passed to `decrypt()`
+    pub fn payload(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Whether the last packet passed to `decrypt()` was sealed
+    pub fn was_sealed(&self) -> bool {
+        self.sealed
+    }
+}
+
+impl Default for PacketEncryption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn packet_type(payload: &[u8]) -> Option<PacketType> {
+    PacketType::de(&mut BitReader::new(payload)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_serde::{BitWriter, Serde};
+
+    use crate::{connection::standard_header::StandardHeader, PacketType};
+
+    use super::{KeyExchange, PacketEncryption};
+
+    fn packet(packet_type: PacketType, contents: &[u8]) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        StandardHeader::new(packet_type, 7, 0, 0).ser(&mut writer);
+        let mut bytes = writer.to_bytes().to_vec();
+        bytes.extend_from_slice(contents);
+        bytes
+    }
+
+    fn encrypt(encryption: &mut PacketEncryption, packet: &[u8]) -> Option<Vec<u8>> {
+        encryption
+            .encrypt(packet, PacketEncryption::seals(packet))
+            .map(|payload| payload.to_vec())
+    }
+
+    fn connected_pair() -> (PacketEncryption, PacketEncryption) {
+        let client_keys = KeyExchange::new();
+        let server_keys = KeyExchange::new();
+
+        let mut client = PacketEncryption::new();
+        client.set_cipher(
+            client_keys
+                .client_cipher(&server_keys.public_key())
+                .unwrap(),
+        );
+        let mut server = PacketEncryption::new();
+        server.set_cipher(
+            server_keys
+                .server_cipher(&client_keys.public_key())
+                .unwrap(),
+        );
+
+        (client, server)
+    }
+
+    #[test]
+    fn sealed_packets_round_trip() {
+        let (mut client, mut server) = connected_pair();
+
+        let data = packet(PacketType::Data, b"secret auth token");
+        let sealed = encrypt(&mut client, &data).unwrap();
+        assert!(!sealed
+            .windows(b"secret".len())
+            .any(|window| window == b"secret"));
+
+        assert!(server.decrypt(&sealed));
+        assert_eq!(server.payload(), data.as_slice());
+
+        let response = packet(PacketType::Heartbeat, b"");
+        let sealed = encrypt(&mut server, &response).unwrap();
+        assert!(client.decrypt(&sealed));
+        assert_eq!(client.payload(), response.as_slice());
+    }
+
+    #[test]
+    fn handshake_packets_are_sent_in_the_clear() {
+        let mut client = PacketEncryption::new();
+        let mut server = PacketEncryption::new();
+
+        let request = packet(PacketType::ClientChallengeRequest, b"hello");
+        let sent = encrypt(&mut client, &request).unwrap();
+        assert!(server.decrypt(&sent));
+        assert_eq!(server.payload(), request.as_slice());
+
+        // other packets can't be sent until a cipher is agreed
+        assert!(encrypt(&mut client, &packet(PacketType::Data, b"")).is_none());
+    }
+
+    #[test]
+    fn plaintext_data_packets_are_dropped() {
+        let (_, mut server) = connected_pair();
+
+        let mut forged = vec![0];
+        forged.extend_from_slice(&packet(PacketType::Data, b"forged"));
+        assert!(!server.decrypt(&forged));
+    }
+
+    #[test]
+    fn tampered_packets_are_dropped() {
+        let (mut client, mut server) = connected_pair();
+
+        let mut sealed = encrypt(&mut client, &packet(PacketType::Data, b"contents")).unwrap();
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(!server.decrypt(&sealed));
+
+        // the sequence number is authenticated too
+        let mut sealed = encrypt(&mut client, &packet(PacketType::Data, b"contents")).unwrap();
+        sealed[1] ^= 1;
+        assert!(!server.decrypt(&sealed));
+    }
+
+    #[test]
+    fn replayed_packets_are_dropped() {
+        let (mut client, mut server) = connected_pair();
+
+        let mut sealed_packets = Vec::new();
+        for _ in 0..3 {
+            let sealed = encrypt(&mut client, &packet(PacketType::Data, b"contents")).unwrap();
+            sealed_packets.push(sealed);
+        }
+
+        // out of order packets are accepted once
+        assert!(server.decrypt(&sealed_packets[2]));
+        assert!(server.decrypt(&sealed_packets[0]));
+        assert!(!server.decrypt(&sealed_packets[0]));
+        assert!(!server.decrypt(&sealed_packets[2]));
+        assert!(server.decrypt(&sealed_packets[1]));
+        assert!(!server.decrypt(&sealed_packets[1]));
+    }
+
+    #[test]
+    fn packets_older_than_replay_window_are_dropped() {
+        let (mut client, mut server) = connected_pair();
+
+        let old = encrypt(&mut client, &packet(PacketType::Data, b"")).unwrap();
+        for _ in 0..=super::REPLAY_WINDOW {
+            let sealed = encrypt(&mut client, &packet(PacketType::Data, b"")).unwrap();
+            assert!(server.decrypt(&sealed));
+        }
+        assert!(!server.decrypt(&old));
+    }
+
+    #[test]
+    fn connection_id_is_sent_and_authenticated() {
+        let (mut client, mut server) = connected_pair();
+        client.set_connection_id(0xC0FFEE);
+
+        let data = packet(PacketType::Data, b"contents");
+        let sealed = encrypt(&mut client, &data).unwrap();
+        assert_eq!(PacketEncryption::connection_id(&sealed), Some(0xC0FFEE));
+        assert!(server.decrypt(&sealed));
+        assert_eq!(server.payload(), data.as_slice());
+
+        let mut forged = encrypt(&mut client, &data).unwrap();
+        forged[1] ^= 1;
+        assert_eq!(PacketEncryption::connection_id(&forged), Some(0xC0FFEF));
+        assert!(!server.decrypt(&forged));
+
+        // packets without one don't carry it
+        let sealed = encrypt(&mut server, &packet(PacketType::Heartbeat, b"")).unwrap();
+        assert_eq!(PacketEncryption::connection_id(&sealed), None);
+    }
+
+    #[test]
+    fn handshake_payload_round_trips() {
+        let client_keys = KeyExchange::new();
+        let server_keys = KeyExchange::new();
+        let client = client_keys
+            .client_cipher(&server_keys.public_key())
+            .unwrap();
+        let server = server_keys
+            .server_cipher(&client_keys.public_key())
+            .unwrap();
+
+        let sealed = client.seal_handshake(b"auth");
+        assert_eq!(server.open_handshake(&sealed).unwrap(), b"auth");
+
+        // a third party agreeing a cipher with the server can't read it
+        let other_keys = KeyExchange::new();
+        let other = server_keys.server_cipher(&other_keys.public_key()).unwrap();
+        assert!(other.open_handshake(&sealed).is_none());
+    }
+}
@@ -2,11 +2,17 @@ pub mod ack_manager;
 pub mod bandwidth_monitor;
 pub mod base_connection;
 pub mod compression_config;
+pub mod congestion_control;
+pub mod connect_token;
 pub mod connection_config;
 pub mod decoder;
+pub mod disconnect_reason;
 pub mod encoder;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod packet_notifiable;
 pub mod packet_type;
 pub mod ping_store;
+pub mod reject_reason;
 pub mod sequence_buffer;
 pub mod standard_header;
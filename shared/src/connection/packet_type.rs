@@ -22,15 +22,20 @@ pub enum PacketType {
     // The final handshake message sent by the Server, indicating that the
     // connection has been established
     ServerConnectResponse,
-    // Indicates that the authentication payload was rejected, handshake must restart
+    // Indicates that the Client was rejected, with the RejectReason. The
+    // handshake must restart
     ServerRejectResponse,
+    // The Server's response to the Client's connect request while the Server
+    // is full, with the Client's position in the join queue
+    ServerQueueResponse,
     // A Ping message, used to calculate RTT. Must be responded to with a Pong
     // message
     Ping,
     // A Pong message, used to calculate RTT. Must be the response to all Ping
     // messages
     Pong,
-    // Used to request a graceful Client disconnect from the Server
+    // Used by either side to gracefully close the connection, with the
+    // DisconnectReason
     Disconnect,
 }
 
@@ -58,6 +63,7 @@ impl Serde for PacketType {
             PacketType::Ping => 8,
             PacketType::Pong => 9,
             PacketType::Disconnect => 10,
+            PacketType::ServerQueueResponse => 11,
         };
 
         UnsignedInteger::<4>::new(index).ser(writer);
@@ -81,7 +87,8 @@ impl Serde for PacketType {
             8 => Ok(PacketType::Ping),
             9 => Ok(PacketType::Pong),
             10 => Ok(PacketType::Disconnect),
-            _ => panic!("shouldn't happen, caught above"),
+            11 => Ok(PacketType::ServerQueueResponse),
+            _ => Err(SerdeErr),
         }
     }
 
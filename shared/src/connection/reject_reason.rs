@@ -0,0 +1,18 @@
+use naia_serde::SerdeInternal;
+
+/// Why a Client's attempt to connect was refused. Sent by the Server with
+/// `PacketType::ServerRejectResponse`, unless noted otherwise
+#[derive(Clone, Debug, PartialEq, Eq, SerdeInternal)]
+pub enum RejectReason {
+    /// The Server rejected the Client, e.g. because its auth was invalid
+    Rejected,
+    /// The Server already has as many Users as it allows
+    ServerFull,
+    /// The Client's Protocol does not match the Server's, e.g. because they
+    /// were built from different versions
+    ProtocolMismatch,
+    /// The Server presented a different public key than the Client was
+    /// configured to trust. The Client refuses the Server, so this is never
+    /// sent
+    ServerKeyMismatch,
+}
@@ -1,2 +1,37 @@
-pub const FRAGMENTATION_LIMIT_BYTES: usize = 400;
+use naia_serde::MTU_SIZE_BITS;
+
+// The most bits a Data packet spends on anything besides the single fragment
+// it carries, which is worst on packets from the Client:
+// - the finish bits reserved for each section of the packet
+const SECTION_FINISH_BITS: u32 = 4;
+// - the StandardHeader, a non-Data PacketType being the longest
+const STANDARD_HEADER_BITS: u32 = 5 + 16 + 16 + 32;
+// - the connection id following the header
+const CONNECTION_ID_BITS: u32 = 64;
+// - the Client's tick, and the empty tick buffer section before the Messages
+const CLIENT_TICK_BITS: u32 = 16 + 1;
+// - the channel's continue bit & NetId, the Message's continue bit & index
+const CHANNEL_HEADER_BITS: u32 = 1 + 16 + 1 + 16;
+// - room for a header the channel sends along with each Message, a keyed
+//   MessageOrdering being the longest at 1 + 64 + 16
+const CHANNEL_MESSAGE_HEADER_BITS_MAX: u32 = 96;
+// - the fragment's MessageKind, id, index, total & byte length
+const FRAGMENT_HEADER_BITS: u32 = 16 + 10 + 20 + 20 + 20;
+// - the channel's finish bit
+const CHANNEL_FINISH_BITS: u32 = 1;
+
+const PACKET_HEADER_BITS_MAX: u32 = SECTION_FINISH_BITS
+    + STANDARD_HEADER_BITS
+    + CONNECTION_ID_BITS
+    + CLIENT_TICK_BITS
+    + CHANNEL_HEADER_BITS
+    + CHANNEL_MESSAGE_HEADER_BITS_MAX
+    + FRAGMENT_HEADER_BITS
+    + CHANNEL_FINISH_BITS;
+
+pub const FRAGMENTATION_LIMIT_BYTES: usize =
+    ((MTU_SIZE_BITS - PACKET_HEADER_BITS_MAX) / 8) as usize;
 pub const FRAGMENTATION_LIMIT_BITS: u32 = (FRAGMENTATION_LIMIT_BYTES as u32) * 8;
+
+// a full fragment must always fit in a packet along with its headers
+const _: () = assert!(FRAGMENTATION_LIMIT_BITS + PACKET_HEADER_BITS_MAX <= MTU_SIZE_BITS);
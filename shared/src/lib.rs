@@ -23,12 +23,13 @@ pub use naia_derive::{
     Channel, Message, MessageBevy, MessageHecs, Replicate, ReplicateBevy, ReplicateHecs,
 };
 pub use naia_serde::{
-    BitReader, BitWrite, BitWriter, ConstBitLength, OutgoingPacket, OwnedBitReader, Serde,
-    SerdeBevy, SerdeErr, SerdeHecs, SerdeInternal, UnsignedInteger, UnsignedVariableInteger,
-    MTU_SIZE_BITS, MTU_SIZE_BYTES,
+    BitCounter, BitReader, BitWrite, BitWriter, ConstBitLength, NormalizedVec2, NormalizedVec3,
+    OutgoingPacket, OwnedBitReader, QuantizedF32, Serde, SerdeBevy, SerdeDelta, SerdeErr,
+    SerdeHecs, SerdeInternal, SignedInteger, SignedVariableInteger, SmallestThreeQuat,
+    UnsignedInteger, UnsignedVariableInteger, MTU_SIZE_BITS, MTU_SIZE_BYTES,
 };
 pub use naia_socket_shared::{
-    link_condition_logic, Instant, LinkConditionerConfig, Random, SocketConfig, TimeQueue,
+    link_condition_logic, Clock, Instant, LinkConditionerConfig, Random, SocketConfig, TimeQueue,
 };
 
 mod backends;
@@ -39,7 +40,11 @@ mod game_time;
 mod key_generator;
 mod messages;
 mod protocol;
+mod protocol_schema;
 mod sequence_list;
+mod stable_hasher;
+mod transport;
+mod type_layout;
 mod types;
 mod world;
 mod wrapping_number;
@@ -50,35 +55,50 @@ pub use connection::{
     bandwidth_monitor::BandwidthMonitor,
     base_connection::BaseConnection,
     compression_config::{CompressionConfig, CompressionMode},
+    congestion_control::{CongestionConfig, CongestionController},
+    connect_token::ConnectToken,
     connection_config::ConnectionConfig,
     decoder::Decoder,
+    disconnect_reason::{DisconnectReason, KICK_MESSAGE_MAX_BYTES},
     encoder::Encoder,
     packet_notifiable::PacketNotifiable,
     packet_type::PacketType,
     ping_store::{PingIndex, PingStore},
+    reject_reason::RejectReason,
     standard_header::StandardHeader,
 };
+#[cfg(feature = "encryption")]
+pub use connection::encryption::{EncryptionPublicKey, EncryptionSecretKey, KeyExchange, PacketCipher, PacketEncryption};
 pub use messages::{
     channels::{
         channel::{Channel, ChannelDirection, ChannelMode, ReliableSettings, TickBufferSettings},
         channel_kinds::{ChannelKind, ChannelKinds},
         default_channels,
+        message_ordering::{OrderingKey, MAX_ORDERING_KEYS},
         receivers::{
             channel_receiver::ChannelReceiver, ordered_reliable_receiver::OrderedReliableReceiver,
             unordered_reliable_receiver::UnorderedReliableReceiver,
         },
         senders::{channel_sender::ChannelSender, reliable_sender::ReliableSender},
+        system_channel::SystemChannel,
     },
     message::{Message, Message as MessageBevy, Message as MessageHecs, MessageBuilder},
     message_container::MessageContainer,
+    message_handle::MessageHandle,
     message_kinds::{MessageKind, MessageKinds},
     message_manager::MessageManager,
     named::Named,
+    request::{
+        OutgoingRequest, Request, RequestId, ResponseError, ResponseHandle, ResponseSendKey,
+    },
 };
 pub use world::{
     component::{
         component_kinds::{ComponentKind, ComponentKinds},
+        component_settings::ComponentSettings,
         component_update::{ComponentFieldUpdate, ComponentUpdate},
+        delta_baselines::DeltaBaselines,
+        delta_property::DeltaProperty,
         diff_mask::DiffMask,
         entity_property::EntityProperty,
         property::Property,
@@ -96,6 +116,7 @@ pub use world::{
         entity_action::EntityAction,
         entity_action_receiver::EntityActionReceiver,
         entity_action_type::EntityActionType,
+        entity_auth::{EntityAuthAction, EntityAuthMessage, EntityAuthStatus},
         entity_converters::{
             EntityAndGlobalEntityConverter, EntityConverter, EntityConverterMut,
             FakeEntityConverter, GlobalWorldManagerType, LocalEntityAndGlobalEntityConverter,
@@ -123,5 +144,8 @@ pub use bigmap::{BigMap, BigMapKey};
 pub use game_time::{GameDuration, GameInstant, GAME_TIME_LIMIT};
 pub use key_generator::KeyGenerator;
 pub use protocol::{Protocol, ProtocolPlugin};
+pub use protocol_schema::{ChannelSchema, ProtocolSchema, TypeSchema};
+pub use transport::local_transport_hub::LocalTransportHub;
+pub use type_layout::{FieldLayout, TypeLayout};
 pub use types::{HostType, MessageIndex, PacketIndex, ShortMessageIndex, Tick};
 pub use wrapping_number::{sequence_greater_than, sequence_less_than, wrapping_diff};
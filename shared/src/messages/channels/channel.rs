@@ -1,5 +1,16 @@
+use std::time::Duration;
+
 // Channel Trait
-pub trait Channel: 'static {}
+pub trait Channel: 'static {
+    /// Gets the name of the Channel type
+    fn name() -> &'static str
+    where
+        Self: Sized;
+    /// Gets the NetId given by the type's `#[net_id = N]` attribute, if any
+    fn explicit_net_id() -> Option<u16>
+    where
+        Self: Sized;
+}
 
 // ChannelSettings
 #[derive(Clone)]
@@ -13,6 +24,11 @@ impl ChannelSettings {
         if mode.tick_buffered() && direction != ChannelDirection::ClientToServer {
             panic!("TickBuffered Messages are only allowed to be sent from Client to Server");
         }
+        if let ChannelMode::OrderedReliable(settings) = &mode {
+            if settings.message_ttl.is_some() {
+                panic!("Messages on an OrderedReliable Channel can't expire, as every later Message would wait on the expired one forever");
+            }
+        }
 
         Self { mode, direction }
     }
@@ -21,6 +37,7 @@ impl ChannelSettings {
         match &self.mode {
             ChannelMode::UnorderedUnreliable => false,
             ChannelMode::SequencedUnreliable => false,
+            ChannelMode::UnreliableLatestByKey => false,
             ChannelMode::UnorderedReliable(_) => true,
             ChannelMode::SequencedReliable(_) => true,
             ChannelMode::OrderedReliable(_) => true,
@@ -32,6 +49,41 @@ impl ChannelSettings {
         self.mode.tick_buffered()
     }
 
+    /// Whether Messages sent through the Channel may be given a key, which
+    /// keeps them apart from Messages with other keys
+    pub fn keyed(&self) -> bool {
+        matches!(
+            self.mode,
+            ChannelMode::OrderedReliable(_) | ChannelMode::UnreliableLatestByKey
+        )
+    }
+
+    /// Whether Messages sent through the Channel may be given a time-to-live,
+    /// which must be reliable but not ordered
+    pub fn can_expire_messages(&self) -> bool {
+        matches!(
+            self.mode,
+            ChannelMode::UnorderedReliable(_) | ChannelMode::SequencedReliable(_)
+        )
+    }
+
+    /// How long Messages sent through the Channel are resent for, unless
+    /// given their own time-to-live
+    pub fn message_ttl(&self) -> Option<Duration> {
+        match &self.mode {
+            ChannelMode::UnorderedReliable(settings) | ChannelMode::SequencedReliable(settings) => {
+                settings.message_ttl
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether Requests may be sent through the Channel, which must be
+    /// reliable & bidirectional for their Responses to come back through it
+    pub fn can_send_requests(&self) -> bool {
+        self.reliable() && self.direction == ChannelDirection::Bidirectional
+    }
+
     pub fn can_send_to_server(&self) -> bool {
         match &self.direction {
             ChannelDirection::ClientToServer => true,
@@ -52,12 +104,16 @@ impl ChannelSettings {
 #[derive(Clone)]
 pub struct ReliableSettings {
     pub rtt_resend_factor: f32,
+    /// How long a Message may go undelivered before it is dropped rather than
+    /// resent. Messages are resent until delivered if this is `None`.
+    pub message_ttl: Option<Duration>,
 }
 
 impl ReliableSettings {
     pub const fn default() -> Self {
         Self {
             rtt_resend_factor: 1.5,
+            message_ttl: None,
         }
     }
 }
@@ -82,6 +138,9 @@ impl TickBufferSettings {
 pub enum ChannelMode {
     UnorderedUnreliable,
     SequencedUnreliable,
+    /// Like SequencedUnreliable, but for each key Messages are sent with, so
+    /// only the newest Message with each key is sent & received
+    UnreliableLatestByKey,
     UnorderedReliable(ReliableSettings),
     SequencedReliable(ReliableSettings),
     OrderedReliable(ReliableSettings),
@@ -2,7 +2,10 @@ use std::{any::TypeId, collections::HashMap};
 
 use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
 
-use crate::messages::channels::channel::{Channel, ChannelSettings};
+use crate::{
+    messages::channels::channel::{Channel, ChannelSettings},
+    protocol::net_id_from_name,
+};
 
 type NetId = u16;
 
@@ -33,7 +36,7 @@ impl ChannelKind {
 
     pub fn de(channel_kinds: &ChannelKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(channel_kinds.net_id_to_kind(&net_id))
+        channel_kinds.net_id_to_kind(&net_id)
     }
 }
 
@@ -46,26 +49,51 @@ impl ConstBitLength for ChannelKind {
 // ChannelKinds
 pub struct ChannelKinds {
     current_net_id: NetId,
+    name_based_net_ids: bool,
     kind_map: HashMap<ChannelKind, (NetId, ChannelSettings)>,
     net_id_map: HashMap<NetId, ChannelKind>,
+    name_map: HashMap<ChannelKind, &'static str>,
+    explicit_net_id_map: HashMap<ChannelKind, NetId>,
 }
 
 impl ChannelKinds {
     pub fn new() -> Self {
         Self {
             current_net_id: 0,
+            name_based_net_ids: false,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            name_map: HashMap::new(),
+            explicit_net_id_map: HashMap::new(),
         }
     }
 
     pub fn add_channel<C: Channel>(&mut self, settings: ChannelSettings) {
         let channel_kind = ChannelKind::of::<C>();
-        let net_id = self.current_net_id;
+        if let Some(net_id) = C::explicit_net_id() {
+            self.explicit_net_id_map.insert(channel_kind, net_id);
+        }
+
+        let net_id = self.next_net_id(&channel_kind, C::name());
         self.kind_map.insert(channel_kind, (net_id, settings));
         self.net_id_map.insert(net_id, channel_kind);
-        self.current_net_id += 1;
-        //TODO: check for current_id overflow?
+        self.name_map.insert(channel_kind, C::name());
+    }
+
+    /// Reassigns the NetId of every Channel from its name, or its
+    /// `#[net_id = N]` attribute, rather than its registration order. Channels
+    /// added afterwards are assigned NetIds the same way
+    pub fn use_name_based_net_ids(&mut self) {
+        self.name_based_net_ids = true;
+
+        let mut registered: Vec<(NetId, ChannelKind)> = self.net_id_map.drain().collect();
+        registered.sort_by_key(|(net_id, _)| *net_id);
+        for (_, channel_kind) in registered {
+            let name = *self.name_map.get(&channel_kind).unwrap();
+            let net_id = self.next_net_id(&channel_kind, name);
+            self.kind_map.get_mut(&channel_kind).unwrap().0 = net_id;
+            self.net_id_map.insert(net_id, channel_kind);
+        }
     }
 
     pub fn channels(&self) -> Vec<(ChannelKind, ChannelSettings)> {
@@ -83,10 +111,44 @@ impl ChannelKinds {
         settings.clone()
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> ChannelKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Channel with Protocol via `add_channel()` function!",
-        );
+    /// Gets the NetId, name & settings of every Channel, in NetId order
+    pub fn settings_list(&self) -> Vec<(NetId, &'static str, &ChannelSettings)> {
+        let mut list: Vec<(NetId, &'static str, &ChannelSettings)> = self
+            .kind_map
+            .iter()
+            .map(|(channel_kind, (net_id, settings))| {
+                (*net_id, *self.name_map.get(channel_kind).unwrap(), settings)
+            })
+            .collect();
+        list.sort_by_key(|(net_id, _, _)| *net_id);
+        list
+    }
+
+    fn next_net_id(&mut self, channel_kind: &ChannelKind, name: &str) -> NetId {
+        if !self.name_based_net_ids {
+            let net_id = self.current_net_id;
+            self.current_net_id += 1;
+            //TODO: check for current_id overflow?
+            return net_id;
+        }
+
+        let net_id = match self.explicit_net_id_map.get(channel_kind) {
+            Some(net_id) => *net_id,
+            None => net_id_from_name(name),
+        };
+        if let Some(other_kind) = self.net_id_map.get(&net_id) {
+            panic!(
+                "Channels `{}` & `{}` both have NetId {}, give one of them a different NetId with `#[net_id = N]`",
+                self.name_map.get(other_kind).unwrap(),
+                name,
+                net_id
+            );
+        }
+        net_id
+    }
+
+    fn net_id_to_kind(&self, net_id: &NetId) -> Result<ChannelKind, SerdeErr> {
+        self.net_id_map.get(net_id).copied().ok_or(SerdeErr)
     }
 
     fn kind_to_net_id(&self, channel_kind: &ChannelKind) -> NetId {
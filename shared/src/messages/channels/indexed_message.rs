@@ -0,0 +1,65 @@
+use naia_serde::{BitReader, BitWrite, Serde, SerdeErr};
+
+use crate::{
+    messages::{message_container::MessageContainer, message_kinds::MessageKinds},
+    LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
+};
+
+// What's written after each index in an indexed channel: a Message, which may
+// be preceded by whatever the receiving channel needs to arrange it
+pub trait IndexedMessage: Sized {
+    fn write(
+        &self,
+        message_kinds: &MessageKinds,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    );
+
+    fn read(
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        reader: &mut BitReader,
+    ) -> Result<Self, SerdeErr>;
+}
+
+impl IndexedMessage for MessageContainer {
+    fn write(
+        &self,
+        message_kinds: &MessageKinds,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        MessageContainer::write(self, message_kinds, writer, converter);
+    }
+
+    fn read(
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        reader: &mut BitReader,
+    ) -> Result<Self, SerdeErr> {
+        message_kinds.read(reader, converter)
+    }
+}
+
+impl<H: Serde> IndexedMessage for (H, MessageContainer) {
+    fn write(
+        &self,
+        message_kinds: &MessageKinds,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        let (header, message) = self;
+        header.ser(writer);
+        message.write(message_kinds, writer, converter);
+    }
+
+    fn read(
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        reader: &mut BitReader,
+    ) -> Result<Self, SerdeErr> {
+        let header = H::de(reader)?;
+        let message = message_kinds.read(reader, converter)?;
+        Ok((header, message))
+    }
+}
@@ -0,0 +1,51 @@
+use std::hash::{Hash, Hasher};
+
+use naia_serde::SerdeInternal;
+
+use crate::stable_hasher::StableHasher;
+
+/// Identifies one of the independent streams of Messages within an
+/// OrderedReliable or UnreliableLatestByKey Channel. Keys are hashed to 64
+/// bits with a fixed hash function, so the same key gives the same
+/// OrderingKey on the Server & on every Client, whatever their platform.
+/// Two unrelated keys sharing an OrderingKey would be treated as the same
+/// key, but this is vanishingly unlikely.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, SerdeInternal)]
+pub struct OrderingKey(u64);
+
+impl OrderingKey {
+    pub fn new<K: Hash + ?Sized>(key: &K) -> Self {
+        let mut hasher = StableHasher::new();
+        key.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// The most keys a keyed Channel keeps track of at once, including the one
+/// Messages sent without a key share. The keys Messages are sent with must
+/// come from a bounded set: an OrderedReliable Channel can't forget a key
+/// without breaking its ordering, so going over this limit is an error. An
+/// UnreliableLatestByKey Channel forgets the key least recently received.
+pub const MAX_ORDERING_KEYS: usize = 1024;
+
+// Where a Message sits within its stream, unkeyed Messages sharing a stream of
+// their own
+#[derive(Copy, Clone, PartialEq, SerdeInternal)]
+pub struct MessageOrdering {
+    key: Option<OrderingKey>,
+    sequence: u16,
+}
+
+impl MessageOrdering {
+    pub(crate) fn new(key: Option<OrderingKey>, sequence: u16) -> Self {
+        Self { key, sequence }
+    }
+
+    pub(crate) fn key(&self) -> Option<OrderingKey> {
+        self.key
+    }
+
+    pub(crate) fn sequence(&self) -> u16 {
+        self.sequence
+    }
+}
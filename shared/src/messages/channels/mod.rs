@@ -1,5 +1,8 @@
 pub mod channel;
 pub mod channel_kinds;
 pub mod default_channels;
+pub mod indexed_message;
+pub mod message_ordering;
 pub mod receivers;
 pub mod senders;
+pub mod system_channel;
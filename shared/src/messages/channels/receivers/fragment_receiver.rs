@@ -1,17 +1,16 @@
 use std::collections::HashMap;
 
-use log::info;
-
-use naia_serde::BitReader;
+use naia_serde::{BitReader, SerdeErr};
+use naia_socket_shared::Instant;
 
 use crate::{
-    messages::fragment::{FragmentId, FragmentedMessage},
+    messages::fragment::{FragmentId, FragmentedMessage, FRAGMENT_INDEX_LIMIT},
     LocalEntityAndGlobalEntityConverter, MessageContainer, MessageIndex, MessageKinds,
 };
 
 pub struct FragmentReceiver {
     current_index: MessageIndex,
-    map: HashMap<FragmentId, (u32, Vec<Box<[u8]>>)>,
+    map: HashMap<FragmentId, (Option<Instant>, usize, HashMap<usize, Box<[u8]>>)>,
 }
 
 impl FragmentReceiver {
@@ -22,54 +21,76 @@ impl FragmentReceiver {
         }
     }
 
+    // The sender stops resending a fragmented Message once it expires, so
+    // any fragments still missing by then are never coming
+    pub(crate) fn expire_fragments(&mut self) {
+        let now = Instant::now();
+        self.map.retain(|_, (expires_at, _, _)| match expires_at {
+            Some(expires_at) => now < *expires_at,
+            None => true,
+        });
+    }
+
     pub(crate) fn receive(
         &mut self,
         message_kinds: &MessageKinds,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         message: MessageContainer,
-    ) -> Option<(MessageIndex, MessageContainer)> {
+    ) -> Result<Option<(MessageIndex, MessageContainer)>, SerdeErr> {
         // returns a new index, 1 per full message
 
         // Pass right through if not a fragment
         if !message.is_fragment() {
             let output = Some((self.current_index, message));
             self.current_index = self.current_index.wrapping_add(1);
-            return output;
+            return Ok(output);
         }
 
         // Message is a fragment, need to process
-        let fragment = message
-            .to_boxed_any()
-            .downcast::<FragmentedMessage>()
-            .unwrap();
+        self.expire_fragments();
+        let Ok(fragment) = message.to_boxed_any().downcast::<FragmentedMessage>() else {
+            return Err(SerdeErr);
+        };
         let fragment_id = fragment.id();
-        let fragment_index = fragment.index();
+        let fragment_index = fragment.index().as_usize();
         let fragment_total = fragment.total().as_usize();
-        info!("fragment_total: {fragment_total}");
-        if !self.map.contains_key(&fragment_id) {
-            self.map
-                .insert(fragment_id, (0, vec![Box::new([]); fragment_total]));
+        if fragment_index >= fragment_total || fragment_total > FRAGMENT_INDEX_LIMIT as usize {
+            return Err(SerdeErr);
         }
-        let (fragments_received, fragment_list) = self.map.get_mut(&fragment_id).unwrap();
-        fragment_list[fragment_index.as_usize()] = fragment.to_payload();
-        *fragments_received += 1;
-        if *fragments_received != fragment_total as u32 {
-            return None;
+        // fragments are stored as they arrive, so a claimed total is never
+        // allocated up front
+        let expires_at = fragment.ttl_millis().map(|ttl_millis| {
+            // the first fragment received was sent no earlier than now, so
+            // this is never before the sender gives up
+            let mut expires_at = Instant::now();
+            expires_at.add_millis(ttl_millis);
+            expires_at
+        });
+        let (_, expected_total, fragments) = self
+            .map
+            .entry(fragment_id)
+            .or_insert_with(|| (expires_at, fragment_total, HashMap::new()));
+        if *expected_total != fragment_total {
+            return Err(SerdeErr);
+        }
+        fragments.insert(fragment_index, fragment.to_payload());
+        if fragments.len() != fragment_total {
+            return Ok(None);
         }
 
         // we have received all fragments! put it all together
-        let (_, fragment_list) = self.map.remove(&fragment_id).unwrap();
-        let concat_list = fragment_list.concat();
+        let Some((_, _, mut fragments)) = self.map.remove(&fragment_id) else {
+            return Err(SerdeErr);
+        };
+        let concat_list = (0..fragment_total)
+            .filter_map(|index| fragments.remove(&index))
+            .collect::<Vec<_>>()
+            .concat();
         let mut reader = BitReader::new(&concat_list);
-        let full_message_result = message_kinds.read(&mut reader, converter);
-        if full_message_result.is_err() {
-            // TODO: bubble up error instead of panicking here
-            panic!("Cannot read fragmented message!");
-        }
-        let full_message = full_message_result.unwrap();
+        let full_message = message_kinds.read(&mut reader, converter)?;
         let output = Some((self.current_index, full_message));
         self.current_index = self.current_index.wrapping_add(1);
-        output
+        Ok(output)
     }
 }
 
@@ -1,18 +1,19 @@
 use naia_serde::{BitReader, Serde, SerdeErr, UnsignedVariableInteger};
 
 use crate::{
-    messages::message_kinds::MessageKinds, types::MessageIndex,
-    LocalEntityAndGlobalEntityConverter, MessageContainer,
+    messages::{channels::indexed_message::IndexedMessage, message_kinds::MessageKinds},
+    types::MessageIndex,
+    LocalEntityAndGlobalEntityConverter,
 };
 
 pub struct IndexedMessageReader;
 
 impl IndexedMessageReader {
-    pub fn read_messages(
+    pub fn read_messages<P: IndexedMessage>(
         message_kinds: &MessageKinds,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         reader: &mut BitReader,
-    ) -> Result<Vec<(MessageIndex, MessageContainer)>, SerdeErr> {
+    ) -> Result<Vec<(MessageIndex, P)>, SerdeErr> {
         let mut last_read_id: Option<MessageIndex> = None;
         let mut output = Vec::new();
 
@@ -43,17 +44,17 @@ impl IndexedMessageReader {
         };
     }
 
-    fn read_message(
+    fn read_message<P: IndexedMessage>(
         message_kinds: &MessageKinds,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         reader: &mut BitReader,
         last_read_id: &Option<MessageIndex>,
-    ) -> Result<(MessageIndex, MessageContainer), SerdeErr> {
+    ) -> Result<(MessageIndex, P), SerdeErr> {
         // read index
         let message_index = Self::read_message_index(reader, last_read_id)?;
 
         // read payload
-        let new_message = message_kinds.read(reader, converter)?;
+        let new_message = P::read(message_kinds, converter, reader)?;
 
         Ok((message_index, new_message))
     }
@@ -6,6 +6,7 @@ pub mod sequenced_reliable_receiver;
 pub mod sequenced_unreliable_receiver;
 pub mod unordered_reliable_receiver;
 pub mod unordered_unreliable_receiver;
+pub mod unreliable_latest_by_key_receiver;
 
 mod reliable_message_receiver;
 pub mod reliable_receiver;
@@ -1,9 +1,13 @@
-use std::collections::VecDeque;
+use std::collections::HashMap;
+
+use naia_serde::SerdeErr;
 
 use crate::{
-    messages::channels::receivers::reliable_message_receiver::{
-        ReceiverArranger, ReliableMessageReceiver,
+    messages::channels::{
+        message_ordering::{MessageOrdering, OrderingKey, MAX_ORDERING_KEYS},
+        receivers::reliable_message_receiver::{ReceiverArranger, ReliableMessageReceiver},
     },
+    sequence_less_than,
     types::MessageIndex,
     MessageContainer,
 };
@@ -14,67 +18,61 @@ pub type OrderedReliableReceiver = ReliableMessageReceiver<OrderedArranger>;
 impl OrderedReliableReceiver {
     pub fn new() -> Self {
         Self::with_arranger(OrderedArranger {
-            oldest_received_message_index: 0,
-            buffer: VecDeque::new(),
+            streams: HashMap::new(),
         })
     }
 }
 
 // OrderedArranger
 pub struct OrderedArranger {
-    buffer: VecDeque<(MessageIndex, Option<MessageContainer>)>,
-    oldest_received_message_index: MessageIndex,
+    streams: HashMap<Option<OrderingKey>, OrderedStream>,
+}
+
+// Messages are only held back by missing Messages in the same stream
+#[derive(Default)]
+struct OrderedStream {
+    next_sequence: u16,
+    buffer: HashMap<u16, (MessageIndex, MessageContainer)>,
 }
 
 impl ReceiverArranger for OrderedArranger {
+    type Ordering = MessageOrdering;
+
+    // A stream is kept for as long as the Channel is, so the remote host may
+    // only open so many
+    fn admit(&mut self, ordering: &MessageOrdering) -> Result<(), SerdeErr> {
+        let key = ordering.key();
+        if !self.streams.contains_key(&key) {
+            if self.streams.len() >= MAX_ORDERING_KEYS {
+                return Err(SerdeErr);
+            }
+            self.streams.insert(key, OrderedStream::default());
+        }
+        Ok(())
+    }
+
     fn process(
         &mut self,
         incoming_messages: &mut Vec<(MessageIndex, MessageContainer)>,
         message_index: MessageIndex,
+        ordering: MessageOrdering,
         message: MessageContainer,
     ) {
-        let mut current_index = 0;
+        let stream = self.streams.entry(ordering.key()).or_default();
+        if sequence_less_than(ordering.sequence(), stream.next_sequence) {
+            // already received this message
+            return;
+        }
 
         // Put message where it needs to go in buffer
-        loop {
-            if current_index < self.buffer.len() {
-                if let Some((old_message_index, old_message)) = self.buffer.get_mut(current_index) {
-                    if *old_message_index == message_index {
-                        if old_message.is_none() {
-                            *old_message = Some(message);
-                            break;
-                        }
-                    }
-                }
-            } else {
-                let next_message_index = self
-                    .oldest_received_message_index
-                    .wrapping_add(current_index as u16);
-
-                if next_message_index == message_index {
-                    self.buffer.push_back((next_message_index, Some(message)));
-                    break;
-                } else {
-                    self.buffer.push_back((next_message_index, None));
-                    // keep filling up buffer
-                }
-            }
-
-            current_index += 1;
-        }
+        stream
+            .buffer
+            .insert(ordering.sequence(), (message_index, message));
 
         // Pop messages out in order
-        loop {
-            let Some((_, Some(_))) = self.buffer.front() else {
-                // no more messages, return
-                return;
-            };
-            let Some((index, Some(message))) = self.buffer.pop_front() else {
-                panic!("shouldn't be possible due to above check");
-            };
-
-            incoming_messages.push((index, message));
-            self.oldest_received_message_index = self.oldest_received_message_index.wrapping_add(1);
+        while let Some(next_message) = stream.buffer.remove(&stream.next_sequence) {
+            incoming_messages.push(next_message);
+            stream.next_sequence = stream.next_sequence.wrapping_add(1);
         }
     }
 }
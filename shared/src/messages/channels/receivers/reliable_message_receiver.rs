@@ -1,4 +1,6 @@
-use naia_serde::{BitReader, SerdeErr};
+use std::collections::HashSet;
+
+use naia_serde::{BitReader, Serde, SerdeErr};
 
 use crate::{
     messages::{
@@ -8,30 +10,42 @@ use crate::{
             indexed_message_reader::IndexedMessageReader,
             reliable_receiver::ReliableReceiver,
         },
-        message_kinds::MessageKinds,
+        message_kinds::{MessageKind, MessageKinds},
+        request::RpcMessage,
     },
     types::MessageIndex,
     world::remote::entity_waitlist::{EntityWaitlist, WaitlistStore},
-    LocalEntityAndGlobalEntityConverter, MessageContainer,
+    LocalEntity, LocalEntityAndGlobalEntityConverter, MessageContainer,
 };
 
 // Receiver Arranger Trait
 pub trait ReceiverArranger: Send + Sync {
+    /// Sent along with each Message (and each fragment of one) to tell the
+    /// arranger where it belongs
+    type Ordering: Serde + Send + Sync;
+
+    /// Checks that a Message with the given ordering can be kept track of,
+    /// before any of it is processed
+    fn admit(&mut self, _ordering: &Self::Ordering) -> Result<(), SerdeErr> {
+        Ok(())
+    }
+
     fn process(
         &mut self,
         incoming_messages: &mut Vec<(MessageIndex, MessageContainer)>,
         message_index: MessageIndex,
+        ordering: Self::Ordering,
         message: MessageContainer,
     );
 }
 
 // Reliable Receiver
 pub struct ReliableMessageReceiver<A: ReceiverArranger> {
-    reliable_receiver: ReliableReceiver<MessageContainer>,
+    reliable_receiver: ReliableReceiver<(A::Ordering, MessageContainer)>,
     incoming_messages: Vec<(MessageIndex, MessageContainer)>,
     arranger: A,
     fragment_receiver: FragmentReceiver,
-    waitlist_store: WaitlistStore<(MessageIndex, MessageContainer)>,
+    waitlist_store: WaitlistStore<(MessageIndex, A::Ordering, MessageContainer)>,
 }
 
 impl<A: ReceiverArranger> ReliableMessageReceiver<A> {
@@ -50,25 +64,57 @@ impl<A: ReceiverArranger> ReliableMessageReceiver<A> {
         message_kinds: &MessageKinds,
         entity_waitlist: &mut EntityWaitlist,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
+        ordering: A::Ordering,
         message: MessageContainer,
-    ) {
+    ) -> Result<(), SerdeErr> {
+        self.arranger.admit(&ordering)?;
+
+        // every fragment of a Message is sent with the same ordering
         let Some((first_index, full_message)) =
             self.fragment_receiver
-                .receive(message_kinds, converter, message) else {
-            return;
+                .receive(message_kinds, converter, message)? else {
+            return Ok(());
         };
 
-        if let Some(entity_set) = full_message.relations_waiting() {
+        let (full_message, relations_waiting) =
+            Self::relations_waiting(message_kinds, converter, full_message)?;
+        if let Some(entity_set) = relations_waiting {
             entity_waitlist.queue(
                 &entity_set,
                 &mut self.waitlist_store,
-                (first_index, full_message),
+                (first_index, ordering, full_message),
             );
-            return;
+            return Ok(());
         }
 
-        self.arranger
-            .process(&mut self.incoming_messages, first_index, full_message);
+        self.arranger.process(
+            &mut self.incoming_messages,
+            first_index,
+            ordering,
+            full_message,
+        );
+        Ok(())
+    }
+
+    // Gets the Entities a Message refers to which have not arrived yet. A
+    // Request or Response is only read once it is received, so the Message it
+    // wraps is read here just to find them
+    fn relations_waiting(
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        message: MessageContainer,
+    ) -> Result<(MessageContainer, Option<HashSet<LocalEntity>>), SerdeErr> {
+        if message.kind() != MessageKind::of::<RpcMessage>() {
+            let relations_waiting = message.relations_waiting();
+            return Ok((message, relations_waiting));
+        }
+        let Ok(rpc_message) = message.to_boxed_any().downcast::<RpcMessage>() else {
+            return Err(SerdeErr);
+        };
+        let relations_waiting = rpc_message
+            .read_message(message_kinds, converter)?
+            .relations_waiting();
+        Ok((MessageContainer::from_read(rpc_message), relations_waiting))
     }
 
     pub fn buffer_message(
@@ -77,14 +123,21 @@ impl<A: ReceiverArranger> ReliableMessageReceiver<A> {
         entity_waitlist: &mut EntityWaitlist,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         message_index: MessageIndex,
-        message: MessageContainer,
-    ) {
+        message: (A::Ordering, MessageContainer),
+    ) -> Result<(), SerdeErr> {
         self.reliable_receiver
             .buffer_message(message_index, message);
         let received_messages = self.reliable_receiver.receive_messages();
-        for (_, received_message) in received_messages {
-            self.push_message(message_kinds, entity_waitlist, converter, received_message)
+        for (_, (ordering, received_message)) in received_messages {
+            self.push_message(
+                message_kinds,
+                entity_waitlist,
+                converter,
+                ordering,
+                received_message,
+            )?;
         }
+        Ok(())
     }
 
     pub fn receive_messages(
@@ -92,11 +145,17 @@ impl<A: ReceiverArranger> ReliableMessageReceiver<A> {
         entity_waitlist: &mut EntityWaitlist,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
     ) -> Vec<(MessageIndex, MessageContainer)> {
+        self.fragment_receiver.expire_fragments();
+
         if let Some(list) = entity_waitlist.collect_ready_items(&mut self.waitlist_store) {
-            for (first_index, mut full_message) in list {
+            for (first_index, ordering, mut full_message) in list {
                 full_message.relations_complete(converter);
-                self.arranger
-                    .process(&mut self.incoming_messages, first_index, full_message);
+                self.arranger.process(
+                    &mut self.incoming_messages,
+                    first_index,
+                    ordering,
+                    full_message,
+                );
             }
         }
 
@@ -128,7 +187,7 @@ impl<A: ReceiverArranger> MessageChannelReceiver for ReliableMessageReceiver<A>
     ) -> Result<(), SerdeErr> {
         let id_w_msgs = IndexedMessageReader::read_messages(message_kinds, converter, reader)?;
         for (id, message) in id_w_msgs {
-            self.buffer_message(message_kinds, entity_waitlist, converter, id, message);
+            self.buffer_message(message_kinds, entity_waitlist, converter, id, message)?;
         }
         Ok(())
     }
@@ -2,6 +2,10 @@ use std::collections::VecDeque;
 
 use crate::{sequence_less_than, MessageIndex};
 
+// How far behind the newest Message the receiver will wait for a missing one,
+// which the sender may have given up on if it expired
+const MAX_RECORD_LEN: usize = (u16::MAX / 4) as usize;
+
 pub struct ReliableReceiver<M> {
     oldest_received_message_index: MessageIndex,
     record: VecDeque<(MessageIndex, bool)>,
@@ -71,13 +75,14 @@ impl<M> ReliableReceiver<M> {
     }
 
     fn clear_old_messages(&mut self) {
-        // clear all received messages from record
+        // clear all received messages from record, along with any missing
+        // messages which have fallen too far behind
         loop {
             let mut has_message = false;
             if let Some((_, true)) = self.record.front() {
                 has_message = true;
             }
-            if has_message {
+            if has_message || self.record.len() > MAX_RECORD_LEN {
                 self.record.pop_front();
                 self.oldest_received_message_index =
                     self.oldest_received_message_index.wrapping_add(1);
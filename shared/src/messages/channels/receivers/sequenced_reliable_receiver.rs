@@ -23,10 +23,13 @@ pub struct SequencedArranger {
 }
 
 impl ReceiverArranger for SequencedArranger {
+    type Ordering = ();
+
     fn process(
         &mut self,
         incoming_messages: &mut Vec<(MessageIndex, MessageContainer)>,
         message_index: MessageIndex,
+        _: (),
         message: MessageContainer,
     ) {
         if !sequence_less_than(message_index, self.newest_received_message_index) {
@@ -18,10 +18,13 @@ impl UnorderedReliableReceiver {
 pub struct UnorderedArranger;
 
 impl ReceiverArranger for UnorderedArranger {
+    type Ordering = ();
+
     fn process(
         &mut self,
         incoming_messages: &mut Vec<(MessageIndex, MessageContainer)>,
         message_index: MessageIndex,
+        _: (),
         message: MessageContainer,
     ) {
         incoming_messages.push((message_index, message));
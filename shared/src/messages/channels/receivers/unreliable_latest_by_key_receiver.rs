@@ -0,0 +1,127 @@
+use std::{collections::HashMap, mem};
+
+use naia_serde::{BitReader, SerdeErr};
+
+use crate::{
+    messages::{
+        channels::{
+            message_ordering::{MessageOrdering, OrderingKey, MAX_ORDERING_KEYS},
+            receivers::{
+                channel_receiver::{ChannelReceiver, MessageChannelReceiver},
+                indexed_message_reader::IndexedMessageReader,
+            },
+        },
+        message_kinds::MessageKinds,
+    },
+    sequence_greater_than,
+    world::remote::entity_waitlist::{EntityWaitlist, WaitlistStore},
+    LocalEntityAndGlobalEntityConverter, MessageContainer,
+};
+
+pub struct UnreliableLatestByKeyReceiver {
+    /// The newest sequence received for each key, along with when it was
+    /// received, counted in Messages
+    newest_received_sequences: HashMap<Option<OrderingKey>, (u16, u64)>,
+    received_count: u64,
+    incoming_messages: Vec<MessageContainer>,
+    waitlist_store: WaitlistStore<(MessageOrdering, MessageContainer)>,
+}
+
+impl UnreliableLatestByKeyReceiver {
+    pub fn new() -> Self {
+        Self {
+            newest_received_sequences: HashMap::new(),
+            received_count: 0,
+            incoming_messages: Vec::new(),
+            waitlist_store: WaitlistStore::new(),
+        }
+    }
+
+    pub fn buffer_message(
+        &mut self,
+        entity_waitlist: &mut EntityWaitlist,
+        ordering: MessageOrdering,
+        message: MessageContainer,
+    ) {
+        if let Some(entity_set) = message.relations_waiting() {
+            entity_waitlist.queue(&entity_set, &mut self.waitlist_store, (ordering, message));
+            return;
+        }
+
+        self.arrange_message(ordering, message);
+    }
+
+    pub fn arrange_message(&mut self, ordering: MessageOrdering, message: MessageContainer) {
+        let key = ordering.key();
+        match self.newest_received_sequences.get(&key) {
+            Some((most_recent_sequence, _)) => {
+                if !sequence_greater_than(ordering.sequence(), *most_recent_sequence) {
+                    return;
+                }
+            }
+            None => {
+                if self.newest_received_sequences.len() >= MAX_ORDERING_KEYS {
+                    self.evict_oldest_key();
+                }
+            }
+        }
+        self.incoming_messages.push(message);
+        self.newest_received_sequences
+            .insert(key, (ordering.sequence(), self.received_count));
+        self.received_count += 1;
+    }
+
+    // Forgets the key which has gone longest without a Message, so that the
+    // remote host can't grow this without bound. A late Message with that key
+    // would then be received as if it were new.
+    fn evict_oldest_key(&mut self) {
+        let Some(oldest_key) = self
+            .newest_received_sequences
+            .iter()
+            .min_by_key(|(_, (_, received_at))| *received_at)
+            .map(|(key, _)| *key)
+        else {
+            return;
+        };
+        self.newest_received_sequences.remove(&oldest_key);
+    }
+}
+
+impl ChannelReceiver<MessageContainer> for UnreliableLatestByKeyReceiver {
+    fn receive_messages(
+        &mut self,
+        entity_waitlist: &mut EntityWaitlist,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Vec<MessageContainer> {
+        if let Some(list) = entity_waitlist.collect_ready_items(&mut self.waitlist_store) {
+            for (ordering, mut message) in list {
+                message.relations_complete(converter);
+                self.arrange_message(ordering, message);
+            }
+        }
+
+        mem::take(&mut self.incoming_messages)
+    }
+}
+
+impl MessageChannelReceiver for UnreliableLatestByKeyReceiver {
+    /// Read messages and add them to the buffer, discard messages that are older
+    /// than the most recent received message with the same key
+    fn read_messages(
+        &mut self,
+        message_kinds: &MessageKinds,
+        entity_waitlist: &mut EntityWaitlist,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        reader: &mut BitReader,
+    ) -> Result<(), SerdeErr> {
+        let id_w_msgs = IndexedMessageReader::read_messages::<(MessageOrdering, MessageContainer)>(
+            message_kinds,
+            converter,
+            reader,
+        )?;
+        for (_, (ordering, message)) in id_w_msgs {
+            self.buffer_message(entity_waitlist, ordering, message);
+        }
+        Ok(())
+    }
+}
@@ -1,24 +1,49 @@
+use std::time::Duration;
+
 use naia_serde::BitWriter;
 use naia_socket_shared::Instant;
 
 use crate::{
-    messages::{message_container::MessageContainer, message_kinds::MessageKinds},
+    messages::{
+        channels::message_ordering::OrderingKey, message_container::MessageContainer,
+        message_kinds::MessageKinds,
+    },
     types::MessageIndex,
     LocalEntityAndGlobalEntityConverterMut,
 };
 
 pub trait ChannelSender<P>: Send + Sync {
-    /// Queues a Message to be transmitted to the remote host into an internal buffer
-    fn send_message(&mut self, message: P);
+    /// Queues a Message to be transmitted to the remote host into an internal buffer.
+    /// Returns the index of the Message if the channel tracks its delivery
+    fn send_message(&mut self, message: P) -> Option<MessageIndex>;
+    /// Queues a Message like `send_message()`, but for reliable channels, gives up
+    /// on resending it once it has gone undelivered for the given time
+    fn send_message_with_ttl(&mut self, message: P, ttl: Duration) -> Option<MessageIndex>;
     /// For reliable channels, will collect any Messages that need to be resent
     fn collect_messages(&mut self, now: &Instant, rtt_millis: &f32);
     /// Returns true if there are queued Messages ready to be written
     fn has_messages(&self) -> bool;
     /// Called when it receives acknowledgement that a Message has been received
     fn notify_message_delivered(&mut self, message_index: &MessageIndex);
+    /// Takes the indices of Messages which expired before being delivered
+    fn take_expired_messages(&mut self) -> Vec<MessageIndex>;
 }
 
 pub trait MessageChannelSender: ChannelSender<MessageContainer> {
+    /// Queues the fragments of a Message (or just the Message, if it wasn't
+    /// fragmented) to be transmitted together. Channels which keep Messages
+    /// apart by key send them with the given key, others ignore it.
+    /// Returns the indices of those the channel tracks the delivery of
+    fn send_keyed_message(
+        &mut self,
+        _: Option<OrderingKey>,
+        messages: Vec<MessageContainer>,
+    ) -> Vec<MessageIndex> {
+        messages
+            .into_iter()
+            .filter_map(|message| self.send_message(message))
+            .collect()
+    }
     /// Gets Messages from the internal buffer and writes it to the BitWriter
     fn write_messages(
         &mut self,
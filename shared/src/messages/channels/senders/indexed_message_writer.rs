@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use naia_serde::{BitWrite, BitWriter, Serde, UnsignedVariableInteger};
 
 use crate::{
-    messages::{message_container::MessageContainer, message_kinds::MessageKinds},
+    messages::{channels::indexed_message::IndexedMessage, message_kinds::MessageKinds},
     types::MessageIndex,
     world::entity::entity_converters::LocalEntityAndGlobalEntityConverterMut,
     wrapping_diff,
@@ -13,9 +13,9 @@ use crate::{
 pub struct IndexedMessageWriter;
 
 impl IndexedMessageWriter {
-    pub fn write_messages(
+    pub fn write_messages<P: IndexedMessage>(
         message_kinds: &MessageKinds,
-        outgoing_messages: &mut VecDeque<(MessageIndex, MessageContainer)>,
+        outgoing_messages: &mut VecDeque<(MessageIndex, P)>,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
@@ -90,13 +90,13 @@ impl IndexedMessageWriter {
         }
     }
 
-    fn write_message(
+    fn write_message<P: IndexedMessage>(
         message_kinds: &MessageKinds,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut dyn BitWrite,
         last_written_id: &Option<MessageIndex>,
         message_index: &MessageIndex,
-        message: &MessageContainer,
+        message: &P,
     ) {
         Self::write_message_index(writer, last_written_id, message_index);
 
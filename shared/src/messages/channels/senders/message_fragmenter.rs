@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use naia_serde::{BitWrite, BitWriter};
 
 use crate::{
@@ -18,13 +20,16 @@ impl MessageFragmenter {
         }
     }
 
+    /// Splits a Message into fragments, each carrying the Message's
+    /// time-to-live, if it has one
     pub fn fragment_message(
         &mut self,
         message_kinds: &MessageKinds,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         message: MessageContainer,
+        ttl: Option<Duration>,
     ) -> Vec<MessageContainer> {
-        let mut fragmenter = FragmentWriter::new(self.current_fragment_id);
+        let mut fragmenter = FragmentWriter::new(self.current_fragment_id, ttl);
         self.current_fragment_id.increment();
         message.write(message_kinds, &mut fragmenter, converter);
         fragmenter.to_messages(converter)
@@ -34,15 +39,17 @@ impl MessageFragmenter {
 // FragmentWriter
 pub struct FragmentWriter {
     fragment_id: FragmentId,
+    ttl: Option<Duration>,
     current_fragment_index: FragmentIndex,
     fragments: Vec<FragmentedMessage>,
     current_writer: BitWriter,
 }
 
 impl FragmentWriter {
-    fn new(id: FragmentId) -> Self {
+    fn new(id: FragmentId, ttl: Option<Duration>) -> Self {
         Self {
             fragment_id: id,
+            ttl,
             current_fragment_index: FragmentIndex::zero(),
             fragments: Vec::new(),
             current_writer: BitWriter::with_capacity(FRAGMENTATION_LIMIT_BITS),
@@ -55,8 +62,12 @@ impl FragmentWriter {
             BitWriter::with_capacity(FRAGMENTATION_LIMIT_BITS),
         );
         let bytes = current.to_bytes();
-        let fragmented_message =
-            FragmentedMessage::new(self.fragment_id, self.current_fragment_index, bytes);
+        let fragmented_message = FragmentedMessage::new(
+            self.fragment_id,
+            self.current_fragment_index,
+            self.ttl,
+            bytes,
+        );
         self.current_fragment_index.increment();
         self.fragments.push(fragmented_message);
     }
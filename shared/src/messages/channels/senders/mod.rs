@@ -1,6 +1,8 @@
 pub mod channel_sender;
 pub mod indexed_message_writer;
 pub mod message_fragmenter;
+pub mod ordered_reliable_sender;
 pub mod reliable_sender;
 pub mod sequenced_unreliable_sender;
 pub mod unordered_unreliable_sender;
+pub mod unreliable_latest_by_key_sender;
@@ -0,0 +1,113 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use naia_serde::BitWriter;
+use naia_socket_shared::Instant;
+
+use crate::{
+    messages::{
+        channels::{
+            message_ordering::{MessageOrdering, OrderingKey, MAX_ORDERING_KEYS},
+            senders::{
+                channel_sender::{ChannelSender, MessageChannelSender},
+                indexed_message_writer::IndexedMessageWriter,
+                reliable_sender::ReliableSender,
+            },
+        },
+        message_container::MessageContainer,
+        message_kinds::MessageKinds,
+    },
+    types::MessageIndex,
+    LocalEntityAndGlobalEntityConverterMut,
+};
+
+// Sender
+pub struct OrderedReliableSender {
+    reliable_sender: ReliableSender<(MessageOrdering, MessageContainer)>,
+    outgoing_messages: VecDeque<(MessageIndex, (MessageOrdering, MessageContainer))>,
+    next_sequences: HashMap<Option<OrderingKey>, u16>,
+}
+
+impl OrderedReliableSender {
+    pub fn new(rtt_resend_factor: f32) -> Self {
+        Self {
+            reliable_sender: ReliableSender::new(rtt_resend_factor, None),
+            outgoing_messages: VecDeque::new(),
+            next_sequences: HashMap::new(),
+        }
+    }
+
+    fn next_ordering(&mut self, key: Option<OrderingKey>) -> MessageOrdering {
+        if !self.next_sequences.contains_key(&key) && self.next_sequences.len() >= MAX_ORDERING_KEYS
+        {
+            panic!("Attempting to send Messages with more than {MAX_ORDERING_KEYS} keys over an OrderedReliable Channel. The keys Messages are sent with must come from a bounded set.");
+        }
+        let sequence = self.next_sequences.entry(key).or_insert(0);
+        let ordering = MessageOrdering::new(key, *sequence);
+        *sequence = sequence.wrapping_add(1);
+        ordering
+    }
+}
+
+impl ChannelSender<MessageContainer> for OrderedReliableSender {
+    fn send_message(&mut self, message: MessageContainer) -> Option<MessageIndex> {
+        let ordering = self.next_ordering(None);
+        self.reliable_sender.send_message((ordering, message))
+    }
+
+    fn send_message_with_ttl(&mut self, _: MessageContainer, _: Duration) -> Option<MessageIndex> {
+        panic!("Messages on an OrderedReliable Channel can't expire");
+    }
+
+    fn collect_messages(&mut self, now: &Instant, rtt_millis: &f32) {
+        self.reliable_sender.collect_messages(now, rtt_millis);
+        self.outgoing_messages
+            .extend(self.reliable_sender.take_next_messages());
+    }
+
+    fn has_messages(&self) -> bool {
+        !self.outgoing_messages.is_empty()
+    }
+
+    fn notify_message_delivered(&mut self, message_index: &MessageIndex) {
+        self.reliable_sender.deliver_message(message_index);
+    }
+
+    fn take_expired_messages(&mut self) -> Vec<MessageIndex> {
+        // Messages on an ordered channel never expire
+        Vec::new()
+    }
+}
+
+impl MessageChannelSender for OrderedReliableSender {
+    fn send_keyed_message(
+        &mut self,
+        key: Option<OrderingKey>,
+        messages: Vec<MessageContainer>,
+    ) -> Vec<MessageIndex> {
+        // the fragments of a Message share its place in the stream
+        let ordering = self.next_ordering(key);
+        messages
+            .into_iter()
+            .filter_map(|message| self.reliable_sender.send_message((ordering, message)))
+            .collect()
+    }
+
+    fn write_messages(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        writer: &mut BitWriter,
+        has_written: &mut bool,
+    ) -> Option<Vec<MessageIndex>> {
+        IndexedMessageWriter::write_messages(
+            message_kinds,
+            &mut self.outgoing_messages,
+            converter,
+            writer,
+            has_written,
+        )
+    }
+}
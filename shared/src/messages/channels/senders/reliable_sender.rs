@@ -16,21 +16,65 @@ use crate::{
     LocalEntityAndGlobalEntityConverterMut,
 };
 
+// A Message waiting to be delivered, with when it was last sent, and when it
+// expires, if ever
+type SendingMessage<P> = (MessageIndex, Option<Instant>, Option<Instant>, P);
+
 // Sender
 pub struct ReliableSender<P: Send + Sync> {
     rtt_resend_factor: f32,
-    sending_messages: VecDeque<Option<(MessageIndex, Option<Instant>, P)>>,
+    message_ttl: Option<Duration>,
+    sending_messages: VecDeque<Option<SendingMessage<P>>>,
     next_send_message_index: MessageIndex,
     outgoing_messages: VecDeque<(MessageIndex, P)>,
+    expired_messages: Vec<MessageIndex>,
 }
 
 impl<P: Send + Sync> ReliableSender<P> {
-    pub fn new(rtt_resend_factor: f32) -> Self {
+    pub fn new(rtt_resend_factor: f32, message_ttl: Option<Duration>) -> Self {
         Self {
             rtt_resend_factor,
+            message_ttl,
             next_send_message_index: 0,
             sending_messages: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
+            expired_messages: Vec::new(),
+        }
+    }
+
+    fn push_message(&mut self, message: P, ttl: Option<Duration>) -> MessageIndex {
+        let message_index = self.next_send_message_index;
+        let expires_at = ttl.map(|ttl| {
+            let mut expires_at = Instant::now();
+            expires_at.add_millis(ttl.as_millis().min(u32::MAX as u128) as u32);
+            expires_at
+        });
+        self.sending_messages
+            .push_back(Some((message_index, None, expires_at, message)));
+        self.next_send_message_index = self.next_send_message_index.wrapping_add(1);
+        message_index
+    }
+
+    // Drops any Messages which have gone undelivered past their expiry, so
+    // that they're never resent
+    fn expire_messages(&mut self, now: &Instant) {
+        let mut expired = false;
+        for message_opt in self.sending_messages.iter_mut() {
+            let Some((message_index, _, Some(expires_at), _)) = message_opt else {
+                continue;
+            };
+            if *expires_at <= *now {
+                self.expired_messages.push(*message_index);
+                *message_opt = None;
+                expired = true;
+            }
+        }
+
+        if expired {
+            let expired_messages = &self.expired_messages;
+            self.outgoing_messages
+                .retain(|(message_index, _)| !expired_messages.contains(message_index));
+            self.cleanup_sent_messages();
         }
     }
 
@@ -67,7 +111,7 @@ impl<P: Send + Sync> ReliableSender<P> {
                 return None;
             }
 
-            if let Some(Some((old_message_index, _, _))) = self.sending_messages.get(index) {
+            if let Some(Some((old_message_index, _, _, _))) = self.sending_messages.get(index) {
                 if *message_index == *old_message_index {
                     found = true;
                 }
@@ -81,7 +125,7 @@ impl<P: Send + Sync> ReliableSender<P> {
                 self.cleanup_sent_messages();
 
                 // stop loop
-                return output.map(|(_, _, message)| message);
+                return output.map(|(_, _, _, message)| message);
             }
 
             index += 1;
@@ -90,16 +134,21 @@ impl<P: Send + Sync> ReliableSender<P> {
 }
 
 impl<P: Send + Sync + Clone> ChannelSender<P> for ReliableSender<P> {
-    fn send_message(&mut self, message: P) {
-        self.sending_messages
-            .push_back(Some((self.next_send_message_index, None, message)));
-        self.next_send_message_index = self.next_send_message_index.wrapping_add(1);
+    fn send_message(&mut self, message: P) -> Option<MessageIndex> {
+        Some(self.push_message(message, self.message_ttl))
+    }
+
+    fn send_message_with_ttl(&mut self, message: P, ttl: Duration) -> Option<MessageIndex> {
+        Some(self.push_message(message, Some(ttl)))
     }
 
     fn collect_messages(&mut self, now: &Instant, rtt_millis: &f32) {
+        self.expire_messages(now);
+
         let resend_duration = Duration::from_millis((self.rtt_resend_factor * rtt_millis) as u64);
 
-        for (message_index, last_sent_opt, message) in self.sending_messages.iter_mut().flatten() {
+        for (message_index, last_sent_opt, _, message) in self.sending_messages.iter_mut().flatten()
+        {
             let mut should_send = false;
             if let Some(last_sent) = last_sent_opt {
                 if last_sent.elapsed() >= resend_duration {
@@ -123,6 +172,10 @@ impl<P: Send + Sync + Clone> ChannelSender<P> for ReliableSender<P> {
     fn notify_message_delivered(&mut self, message_index: &MessageIndex) {
         self.deliver_message(message_index);
     }
+
+    fn take_expired_messages(&mut self) -> Vec<MessageIndex> {
+        mem::take(&mut self.expired_messages)
+    }
 }
 
 impl MessageChannelSender for ReliableSender<MessageContainer> {
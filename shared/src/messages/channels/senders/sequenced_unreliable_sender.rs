@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, time::Duration};
 
 use naia_serde::BitWriter;
 use naia_socket_shared::Instant;
@@ -33,10 +33,21 @@ impl SequencedUnreliableSender {
 }
 
 impl ChannelSender<MessageContainer> for SequencedUnreliableSender {
-    fn send_message(&mut self, message: MessageContainer) {
+    fn send_message(&mut self, message: MessageContainer) -> Option<MessageIndex> {
         self.outgoing_messages
             .push_back((self.next_send_message_index, message));
         self.next_send_message_index = self.next_send_message_index.wrapping_add(1);
+        // delivery isn't tracked for an unreliable channel
+        None
+    }
+
+    fn send_message_with_ttl(
+        &mut self,
+        message: MessageContainer,
+        _: Duration,
+    ) -> Option<MessageIndex> {
+        // an unreliable channel never resends, so there's nothing to expire
+        self.send_message(message)
     }
 
     fn collect_messages(&mut self, _: &Instant, _: &f32) {
@@ -50,6 +61,11 @@ impl ChannelSender<MessageContainer> for SequencedUnreliableSender {
     fn notify_message_delivered(&mut self, _: &MessageIndex) {
         // not necessary for an unreliable channel
     }
+
+    fn take_expired_messages(&mut self) -> Vec<MessageIndex> {
+        // not necessary for an unreliable channel
+        Vec::new()
+    }
 }
 
 impl MessageChannelSender for SequencedUnreliableSender {
@@ -0,0 +1,120 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use naia_serde::BitWriter;
+use naia_socket_shared::Instant;
+
+use crate::{
+    messages::{
+        channels::{
+            message_ordering::{MessageOrdering, OrderingKey},
+            senders::{
+                channel_sender::{ChannelSender, MessageChannelSender},
+                indexed_message_writer::IndexedMessageWriter,
+            },
+        },
+        message_container::MessageContainer,
+        message_kinds::MessageKinds,
+    },
+    types::MessageIndex,
+    LocalEntityAndGlobalEntityConverterMut,
+};
+
+pub struct UnreliableLatestByKeySender {
+    /// Buffer of the next messages to send, holding at most one per key
+    outgoing_messages: VecDeque<(MessageIndex, (MessageOrdering, MessageContainer))>,
+    /// Next message id to use (not yet used in the buffer)
+    next_send_message_index: MessageIndex,
+    /// Next sequence to use for each key, so the receiver can tell which
+    /// Message is newest no matter how long a key has gone unused
+    next_sequences: HashMap<Option<OrderingKey>, u16>,
+}
+
+impl UnreliableLatestByKeySender {
+    pub fn new() -> Self {
+        Self {
+            outgoing_messages: VecDeque::new(),
+            next_send_message_index: 0,
+            next_sequences: HashMap::new(),
+        }
+    }
+}
+
+impl ChannelSender<MessageContainer> for UnreliableLatestByKeySender {
+    fn send_message(&mut self, message: MessageContainer) -> Option<MessageIndex> {
+        self.send_keyed_message(None, vec![message]);
+        // delivery isn't tracked for an unreliable channel
+        None
+    }
+
+    fn send_message_with_ttl(
+        &mut self,
+        message: MessageContainer,
+        _: Duration,
+    ) -> Option<MessageIndex> {
+        // an unreliable channel never resends, so there's nothing to expire
+        self.send_message(message)
+    }
+
+    fn collect_messages(&mut self, _: &Instant, _: &f32) {
+        // not necessary for an unreliable channel
+    }
+
+    fn has_messages(&self) -> bool {
+        !self.outgoing_messages.is_empty()
+    }
+
+    fn notify_message_delivered(&mut self, _: &MessageIndex) {
+        // not necessary for an unreliable channel
+    }
+
+    fn take_expired_messages(&mut self) -> Vec<MessageIndex> {
+        // not necessary for an unreliable channel
+        Vec::new()
+    }
+}
+
+impl MessageChannelSender for UnreliableLatestByKeySender {
+    /// Replaces any Message with the same key which is still waiting to be
+    /// sent, as only the newest one matters
+    fn send_keyed_message(
+        &mut self,
+        key: Option<OrderingKey>,
+        messages: Vec<MessageContainer>,
+    ) -> Vec<MessageIndex> {
+        self.outgoing_messages
+            .retain(|(_, (ordering, _))| ordering.key() != key);
+
+        let sequence = self.next_sequences.entry(key).or_insert(0);
+        for message in messages {
+            let ordering = MessageOrdering::new(key, *sequence);
+            *sequence = sequence.wrapping_add(1);
+            self.outgoing_messages
+                .push_back((self.next_send_message_index, (ordering, message)));
+            self.next_send_message_index = self.next_send_message_index.wrapping_add(1);
+        }
+
+        // delivery isn't tracked for an unreliable channel
+        Vec::new()
+    }
+
+    /// Write messages from the buffer into the channel
+    /// Include each message's key & sequence for discarding stale ones
+    fn write_messages(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        writer: &mut BitWriter,
+        has_written: &mut bool,
+    ) -> Option<Vec<MessageIndex>> {
+        IndexedMessageWriter::write_messages(
+            message_kinds,
+            &mut self.outgoing_messages,
+            converter,
+            writer,
+            has_written,
+        )
+    }
+}
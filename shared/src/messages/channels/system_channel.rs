@@ -0,0 +1,7 @@
+use crate::Channel;
+
+/// Channel used internally by naia for protocol messages which are not
+/// exposed to the user, such as Entity authority requests. It is registered
+/// in every Protocol, and should not be used to send user Messages.
+#[derive(Channel)]
+pub struct SystemChannel;
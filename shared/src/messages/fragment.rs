@@ -1,10 +1,12 @@
+use std::time::Duration;
+
 use naia_derive::MessageFragment;
 use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr, UnsignedInteger};
 
 const FRAGMENT_ID_BITS: u8 = 10;
-const FRAGMENT_ID_LIMIT: u16 = 2 ^ (FRAGMENT_ID_BITS as u16);
+const FRAGMENT_ID_LIMIT: u16 = 1 << FRAGMENT_ID_BITS;
 const FRAGMENT_INDEX_BITS: u8 = 20;
-const FRAGMENT_INDEX_LIMIT: u32 = 2 ^ (FRAGMENT_INDEX_BITS as u32);
+pub(crate) const FRAGMENT_INDEX_LIMIT: u32 = 1 << FRAGMENT_INDEX_BITS;
 
 // FragmentId
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
@@ -99,15 +101,24 @@ pub struct FragmentedMessage {
     id: FragmentId,
     index: FragmentIndex,
     total: FragmentIndex,
+    // milliseconds until the sender gives up on the Message, after which the
+    // receiver drops any fragments it has of it
+    ttl_millis: Option<u32>,
     bytes: Box<[u8]>,
 }
 
 impl FragmentedMessage {
-    pub fn new(id: FragmentId, index: FragmentIndex, bytes: Box<[u8]>) -> Self {
+    pub fn new(
+        id: FragmentId,
+        index: FragmentIndex,
+        ttl: Option<Duration>,
+        bytes: Box<[u8]>,
+    ) -> Self {
         Self {
             id,
             index,
             bytes,
+            ttl_millis: ttl.map(|ttl| ttl.as_millis().min(u32::MAX as u128) as u32),
             total: FragmentIndex::zero(),
         }
     }
@@ -128,6 +139,10 @@ impl FragmentedMessage {
         self.total
     }
 
+    pub(crate) fn ttl_millis(&self) -> Option<u32> {
+        self.ttl_millis
+    }
+
     pub(crate) fn to_payload(self) -> Box<[u8]> {
         self.bytes
     }
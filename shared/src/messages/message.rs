@@ -8,7 +8,7 @@ use crate::{
         named::Named,
     },
     world::entity::entity_converters::LocalEntityAndGlobalEntityConverterMut,
-    LocalEntity, LocalEntityAndGlobalEntityConverter, MessageContainer,
+    LocalEntity, LocalEntityAndGlobalEntityConverter, MessageContainer, TypeLayout,
 };
 
 // MessageBuilder
@@ -27,6 +27,15 @@ pub trait Message: Send + Sync + Named + MessageClone + Any {
     fn kind(&self) -> MessageKind;
     fn to_boxed_any(self: Box<Self>) -> Box<dyn Any>;
     fn create_builder() -> Box<dyn MessageBuilder>
+    where
+        Self: Sized;
+    /// Describes the type & the fields it writes, used to check that the
+    /// Client & Server Protocols match
+    fn type_layout() -> TypeLayout
+    where
+        Self: Sized;
+    /// Gets the NetId given by the type's `#[net_id = N]` attribute, if any
+    fn explicit_net_id() -> Option<u16>
     where
         Self: Sized;
     fn bit_length(&self, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) -> u32;
@@ -0,0 +1,13 @@
+/// Returned when sending a Message, and given back once the remote host has
+/// acknowledged receiving it. Only Messages sent through reliable Channels
+/// are acknowledged.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MessageHandle {
+    id: u64,
+}
+
+impl MessageHandle {
+    pub(crate) fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
@@ -2,7 +2,10 @@ use std::{any::TypeId, collections::HashMap};
 
 use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
 
-use crate::{LocalEntityAndGlobalEntityConverter, Message, MessageBuilder, MessageContainer};
+use crate::{
+    protocol::net_id_from_name, LocalEntityAndGlobalEntityConverter, Message, MessageBuilder,
+    MessageContainer, TypeLayout,
+};
 
 type NetId = u16;
 
@@ -33,7 +36,7 @@ impl MessageKind {
 
     pub fn de(message_kinds: &MessageKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(message_kinds.net_id_to_kind(&net_id))
+        message_kinds.net_id_to_kind(&net_id)
     }
 }
 
@@ -46,28 +49,53 @@ impl ConstBitLength for MessageKind {
 // MessageKinds
 pub struct MessageKinds {
     current_net_id: NetId,
+    name_based_net_ids: bool,
     kind_map: HashMap<MessageKind, (NetId, Box<dyn MessageBuilder>)>,
     net_id_map: HashMap<NetId, MessageKind>,
+    layout_map: HashMap<MessageKind, TypeLayout>,
+    explicit_net_id_map: HashMap<MessageKind, NetId>,
 }
 
 impl MessageKinds {
     pub fn new() -> Self {
         Self {
             current_net_id: 0,
+            name_based_net_ids: false,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            layout_map: HashMap::new(),
+            explicit_net_id_map: HashMap::new(),
         }
     }
 
     pub fn add_message<M: Message>(&mut self) {
         let message_kind = MessageKind::of::<M>();
+        let layout = M::type_layout();
+        if let Some(net_id) = M::explicit_net_id() {
+            self.explicit_net_id_map.insert(message_kind, net_id);
+        }
 
-        let net_id = self.current_net_id;
+        let net_id = self.next_net_id(&message_kind, &layout.name);
         self.kind_map
             .insert(message_kind, (net_id, M::create_builder()));
         self.net_id_map.insert(net_id, message_kind);
-        self.current_net_id += 1;
-        //TODO: check for current_id overflow?
+        self.layout_map.insert(message_kind, layout);
+    }
+
+    /// Reassigns the NetId of every Message from its name, or its
+    /// `#[net_id = N]` attribute, rather than its registration order. Messages
+    /// added afterwards are assigned NetIds the same way
+    pub fn use_name_based_net_ids(&mut self) {
+        self.name_based_net_ids = true;
+
+        let mut registered: Vec<(NetId, MessageKind)> = self.net_id_map.drain().collect();
+        registered.sort_by_key(|(net_id, _)| *net_id);
+        for (_, message_kind) in registered {
+            let name = self.layout_map.get(&message_kind).unwrap().name.clone();
+            let net_id = self.next_net_id(&message_kind, &name);
+            self.kind_map.get_mut(&message_kind).unwrap().0 = net_id;
+            self.net_id_map.insert(net_id, message_kind);
+        }
     }
 
     pub fn read(
@@ -79,10 +107,44 @@ impl MessageKinds {
         return self.kind_to_builder(&message_kind).read(reader, converter);
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> MessageKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Message with Protocol via `add_message()` function!",
-        );
+    /// Gets the NetId & layout of every Message type, in NetId order
+    pub fn layouts(&self) -> Vec<(NetId, &TypeLayout)> {
+        let mut layouts: Vec<(NetId, &TypeLayout)> = self
+            .kind_map
+            .iter()
+            .map(|(message_kind, (net_id, _))| {
+                (*net_id, self.layout_map.get(message_kind).unwrap())
+            })
+            .collect();
+        layouts.sort_by_key(|(net_id, _)| *net_id);
+        layouts
+    }
+
+    fn next_net_id(&mut self, message_kind: &MessageKind, name: &str) -> NetId {
+        if !self.name_based_net_ids {
+            let net_id = self.current_net_id;
+            self.current_net_id += 1;
+            //TODO: check for current_id overflow?
+            return net_id;
+        }
+
+        let net_id = match self.explicit_net_id_map.get(message_kind) {
+            Some(net_id) => *net_id,
+            None => net_id_from_name(name),
+        };
+        if let Some(other_kind) = self.net_id_map.get(&net_id) {
+            panic!(
+                "Messages `{}` & `{}` both have NetId {}, give one of them a different NetId with `#[net_id = N]`",
+                self.layout_map.get(other_kind).unwrap().name,
+                name,
+                net_id
+            );
+        }
+        net_id
+    }
+
+    fn net_id_to_kind(&self, net_id: &NetId) -> Result<MessageKind, SerdeErr> {
+        self.net_id_map.get(net_id).copied().ok_or(SerdeErr)
     }
 
     fn kind_to_net_id(&self, message_kind: &MessageKind) -> NetId {
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::time::Duration;
+
+use log::warn;
 
 use naia_serde::{BitReader, BitWrite, BitWriter, ConstBitLength, Serde, SerdeErr};
 use naia_socket_shared::Instant;
@@ -11,6 +14,7 @@ use crate::{
             channel::ChannelMode,
             channel::ChannelSettings,
             channel_kinds::{ChannelKind, ChannelKinds},
+            message_ordering::OrderingKey,
             receivers::{
                 channel_receiver::MessageChannelReceiver,
                 ordered_reliable_receiver::OrderedReliableReceiver,
@@ -18,15 +22,21 @@ use crate::{
                 sequenced_unreliable_receiver::SequencedUnreliableReceiver,
                 unordered_reliable_receiver::UnorderedReliableReceiver,
                 unordered_unreliable_receiver::UnorderedUnreliableReceiver,
+                unreliable_latest_by_key_receiver::UnreliableLatestByKeyReceiver,
             },
             senders::{
                 channel_sender::MessageChannelSender, message_fragmenter::MessageFragmenter,
-                reliable_sender::ReliableSender,
+                ordered_reliable_sender::OrderedReliableSender, reliable_sender::ReliableSender,
                 sequenced_unreliable_sender::SequencedUnreliableSender,
                 unordered_unreliable_sender::UnorderedUnreliableSender,
+                unreliable_latest_by_key_sender::UnreliableLatestByKeySender,
             },
+            system_channel::SystemChannel,
         },
         message_container::MessageContainer,
+        message_handle::MessageHandle,
+        message_kinds::MessageKind,
+        request::{OutgoingRequest, RequestId, ResponseError, RpcMessage, RpcWriter},
     },
     types::{HostType, MessageIndex, PacketIndex},
     world::{
@@ -45,6 +55,21 @@ pub struct MessageManager {
     channel_settings: HashMap<ChannelKind, ChannelSettings>,
     packet_to_message_map: HashMap<PacketIndex, Vec<(ChannelKind, Vec<MessageIndex>)>>,
     message_fragmenter: MessageFragmenter,
+    next_message_handle_id: u64,
+    // the Message each sent reliable Message (or fragment of one) belongs to
+    message_handles: HashMap<(ChannelKind, MessageIndex), MessageHandle>,
+    // how many fragments of each sent Message are still to be delivered
+    undelivered_messages: HashMap<MessageHandle, usize>,
+    delivered_messages: Vec<MessageHandle>,
+    expired_messages: Vec<MessageHandle>,
+    // Requests sent to the remote host which are waiting on a Response, with
+    // the kind of Response expected, when they were sent & how long to wait
+    outgoing_requests: HashMap<RequestId, (MessageKind, Instant, Duration)>,
+    // Responses (or why there won't be one) to Requests sent to the remote
+    // host, waiting to be received
+    request_outcomes: HashMap<RequestId, Result<MessageContainer, ResponseError>>,
+    // Requests received from the remote host, waiting to be responded to
+    incoming_requests: Vec<(ChannelKind, RequestId, MessageContainer)>,
 }
 
 impl MessageManager {
@@ -77,16 +102,26 @@ impl MessageManager {
                     channel_senders
                         .insert(channel_kind, Box::new(SequencedUnreliableSender::new()));
                 }
+                ChannelMode::UnreliableLatestByKey => {
+                    channel_senders
+                        .insert(channel_kind, Box::new(UnreliableLatestByKeySender::new()));
+                }
                 ChannelMode::UnorderedReliable(settings)
-                | ChannelMode::SequencedReliable(settings)
-                | ChannelMode::OrderedReliable(settings) => {
+                | ChannelMode::SequencedReliable(settings) => {
                     channel_senders.insert(
                         channel_kind,
                         Box::new(ReliableSender::<MessageContainer>::new(
                             settings.rtt_resend_factor,
+                            settings.message_ttl,
                         )),
                     );
                 }
+                ChannelMode::OrderedReliable(settings) => {
+                    channel_senders.insert(
+                        channel_kind,
+                        Box::new(OrderedReliableSender::new(settings.rtt_resend_factor)),
+                    );
+                }
                 ChannelMode::TickBuffered(_) => {
                     // Tick buffered channel uses another manager, skip
                 }
@@ -122,6 +157,12 @@ impl MessageManager {
                         Box::new(SequencedUnreliableReceiver::new()),
                     );
                 }
+                ChannelMode::UnreliableLatestByKey => {
+                    channel_receivers.insert(
+                        channel_kind.clone(),
+                        Box::new(UnreliableLatestByKeyReceiver::new()),
+                    );
+                }
                 ChannelMode::UnorderedReliable(_) => {
                     channel_receivers.insert(
                         channel_kind.clone(),
@@ -158,25 +199,105 @@ impl MessageManager {
             channel_settings: channel_settings_map,
             packet_to_message_map: HashMap::new(),
             message_fragmenter: MessageFragmenter::new(),
+            next_message_handle_id: 0,
+            message_handles: HashMap::new(),
+            undelivered_messages: HashMap::new(),
+            delivered_messages: Vec::new(),
+            expired_messages: Vec::new(),
+            outgoing_requests: HashMap::new(),
+            request_outcomes: HashMap::new(),
+            incoming_requests: Vec::new(),
         }
     }
 
     // Outgoing Messages
 
-    /// Queues an Message to be transmitted to the remote host
+    /// Queues an Message to be transmitted to the remote host. Returns a
+    /// handle which is given back by `take_delivered_messages()` once the
+    /// Message has been delivered, if sent through a reliable channel.
     pub fn send_message(
         &mut self,
         message_kinds: &MessageKinds,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         channel_kind: &ChannelKind,
         message: MessageContainer,
-    ) {
+    ) -> MessageHandle {
+        // delivery of internal Messages isn't reported
+        let report_delivery = *channel_kind != ChannelKind::of::<SystemChannel>();
+        self.send_message_inner(
+            message_kinds,
+            converter,
+            channel_kind,
+            message,
+            None,
+            None,
+            report_delivery,
+        )
+    }
+
+    /// Queues a Message to be transmitted to the remote host like
+    /// `send_message()`, through an ordered channel, in which it is only
+    /// ordered along with other Messages sent with the same key. At most
+    /// `MAX_ORDERING_KEYS` keys may be used.
+    pub fn send_message_keyed(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        key: OrderingKey,
+        message: MessageContainer,
+    ) -> MessageHandle {
+        self.send_message_inner(
+            message_kinds,
+            converter,
+            channel_kind,
+            message,
+            None,
+            Some(key),
+            true,
+        )
+    }
+
+    /// Queues a Message to be transmitted to the remote host like
+    /// `send_message()`, but which is given up on if it hasn't been delivered
+    /// within `ttl`, at which point its handle is given back by
+    /// `take_expired_messages()`.
+    pub fn send_message_with_ttl(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        message: MessageContainer,
+        ttl: Duration,
+    ) -> MessageHandle {
+        self.send_message_inner(
+            message_kinds,
+            converter,
+            channel_kind,
+            message,
+            Some(ttl),
+            None,
+            true,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn send_message_inner(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        message: MessageContainer,
+        ttl: Option<Duration>,
+        key: Option<OrderingKey>,
+        report_delivery: bool,
+    ) -> MessageHandle {
         let Some(channel) = self.channel_senders.get_mut(channel_kind) else {
             panic!("Channel not configured correctly! Cannot send message.");
         };
 
         let message_bit_length = message.bit_length();
-        if message_bit_length > FRAGMENTATION_LIMIT_BITS {
+        let messages = if message_bit_length > FRAGMENTATION_LIMIT_BITS {
             let Some(settings) = self.channel_settings.get(channel_kind) else {
                 panic!("Channel not configured correctly! Cannot send message.");
             };
@@ -184,22 +305,87 @@ impl MessageManager {
                 panic!("ERROR: Attempting to send Message above the fragmentation size limit over an unreliable Message channel! Slim down the size of your Message, or send this Message through a reliable message channel.");
             }
 
-            // Now fragment this message ...
-            let messages =
-                self.message_fragmenter
-                    .fragment_message(message_kinds, converter, message);
-            for message_fragment in messages {
-                channel.send_message(message_fragment);
-            }
+            // Now fragment this message, letting the receiver know when the
+            // sender will give up on it
+            self.message_fragmenter.fragment_message(
+                message_kinds,
+                converter,
+                message,
+                ttl.or(settings.message_ttl()),
+            )
         } else {
-            channel.send_message(message);
+            vec![message]
+        };
+
+        let message_indices = match ttl {
+            Some(ttl) => messages
+                .into_iter()
+                .filter_map(|message| channel.send_message_with_ttl(message, ttl))
+                .collect::<Vec<_>>(),
+            None => channel.send_keyed_message(key, messages),
+        };
+
+        let message_handle = MessageHandle::new(self.next_message_handle_id);
+        self.next_message_handle_id = self.next_message_handle_id.wrapping_add(1);
+
+        // unreliable channels give back no indices, as they never know if a
+        // Message was delivered
+        if report_delivery && !message_indices.is_empty() {
+            self.undelivered_messages
+                .insert(message_handle, message_indices.len());
+            for message_index in message_indices {
+                self.message_handles
+                    .insert((*channel_kind, message_index), message_handle);
+            }
         }
+
+        message_handle
+    }
+
+    #[cfg(test)]
+    pub(crate) fn undelivered_message_count(&self) -> usize {
+        self.undelivered_messages.len()
+    }
+
+    /// Takes the handles of sent Messages which have been delivered to the
+    /// remote host since this was last called
+    pub fn take_delivered_messages(&mut self) -> Vec<MessageHandle> {
+        std::mem::take(&mut self.delivered_messages)
+    }
+
+    /// Takes the handles of sent Messages which expired before they could be
+    /// delivered to the remote host since this was last called
+    pub fn take_expired_messages(&mut self) -> Vec<MessageHandle> {
+        std::mem::take(&mut self.expired_messages)
     }
 
     pub fn collect_outgoing_messages(&mut self, now: &Instant, rtt_millis: &f32) {
-        for channel in self.channel_senders.values_mut() {
+        for (channel_kind, channel) in &mut self.channel_senders {
             channel.collect_messages(now, rtt_millis);
+
+            // a fragmented Message expires as soon as any of its fragments do
+            for message_index in channel.take_expired_messages() {
+                let Some(message_handle) =
+                    self.message_handles.remove(&(*channel_kind, message_index))
+                else {
+                    continue;
+                };
+                if self.undelivered_messages.remove(&message_handle).is_some() {
+                    self.expired_messages.push(message_handle);
+                }
+            }
         }
+
+        // give up on Requests which have waited too long for a Response
+        let request_outcomes = &mut self.request_outcomes;
+        self.outgoing_requests
+            .retain(|request_id, (_, sent_instant, timeout)| {
+                if sent_instant.elapsed() < *timeout {
+                    return true;
+                }
+                request_outcomes.insert(*request_id, Err(ResponseError::TimedOut));
+                false
+            });
     }
 
     /// Returns whether the Manager has queued Messages that can be transmitted
@@ -261,6 +447,144 @@ impl MessageManager {
         }
     }
 
+    // Requests
+
+    /// Queues a Request to be transmitted to the remote host, which is
+    /// expected to answer it with a Message of the given kind within `timeout`
+    pub fn send_request(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        request: MessageContainer,
+        outgoing_request: OutgoingRequest,
+    ) {
+        let OutgoingRequest {
+            request_id,
+            response_kind,
+            timeout,
+        } = outgoing_request;
+        self.outgoing_requests
+            .insert(request_id, (response_kind, Instant::now(), timeout));
+        self.send_rpc_message(
+            message_kinds,
+            converter,
+            channel_kind,
+            request_id,
+            false,
+            request,
+        );
+    }
+
+    /// Queues the Response to a Request received from the remote host
+    pub fn send_response(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        request_id: RequestId,
+        response: MessageContainer,
+    ) {
+        self.send_rpc_message(
+            message_kinds,
+            converter,
+            channel_kind,
+            request_id,
+            true,
+            response,
+        );
+    }
+
+    fn send_rpc_message(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        channel_kind: &ChannelKind,
+        request_id: RequestId,
+        is_response: bool,
+        message: MessageContainer,
+    ) {
+        let mut writer = RpcWriter::new();
+        message.write(message_kinds, &mut writer, converter);
+        let rpc_message = RpcMessage::new(request_id, is_response, writer.into_bytes());
+
+        let rpc_message = MessageContainer::from_write(Box::new(rpc_message), converter);
+        self.send_message_inner(
+            message_kinds,
+            converter,
+            channel_kind,
+            rpc_message,
+            None,
+            None,
+            false,
+        );
+    }
+
+    /// Stops waiting for the Response to a Request, which is ignored if it
+    /// arrives later
+    pub fn cancel_request(&mut self, request_id: &RequestId) {
+        self.outgoing_requests.remove(request_id);
+        self.request_outcomes.remove(request_id);
+    }
+
+    /// Takes the Response to a Request sent over this connection, if it has
+    /// arrived, or why it never will. Requests which were not sent over this
+    /// connection were sent over one which has since closed.
+    pub fn receive_response(
+        &mut self,
+        request_id: &RequestId,
+    ) -> Option<Result<MessageContainer, ResponseError>> {
+        if let Some(outcome) = self.request_outcomes.remove(request_id) {
+            return Some(outcome);
+        }
+        if self.outgoing_requests.contains_key(request_id) {
+            return None;
+        }
+        Some(Err(ResponseError::Disconnected))
+    }
+
+    /// Takes the Requests received from the remote host, along with the
+    /// Channel each should be responded to on
+    pub fn take_incoming_requests(&mut self) -> Vec<(ChannelKind, RequestId, MessageContainer)> {
+        std::mem::take(&mut self.incoming_requests)
+    }
+
+    fn receive_rpc_message(
+        &mut self,
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        channel_kind: &ChannelKind,
+        rpc_message: RpcMessage,
+    ) {
+        let Ok(message) = rpc_message.read_message(message_kinds, converter) else {
+            warn!("Received malformed Request or Response");
+            return;
+        };
+        let request_id = rpc_message.request_id();
+
+        if !rpc_message.is_response() {
+            // the Response is sent back through the same Channel
+            if !self.channel_senders.contains_key(channel_kind) {
+                warn!("Received Request on a Channel which can't carry its Response");
+                return;
+            }
+            self.incoming_requests
+                .push((*channel_kind, request_id, message));
+            return;
+        }
+
+        // Responses to cancelled or timed out Requests are ignored
+        let Some((response_kind, _, _)) = self.outgoing_requests.get(&request_id) else {
+            return;
+        };
+        if message.kind() != *response_kind {
+            warn!("Received Response of the wrong type");
+            return;
+        }
+        self.outgoing_requests.remove(&request_id);
+        self.request_outcomes.insert(request_id, Ok(message));
+    }
+
     // Incoming Messages
 
     pub fn read_messages(
@@ -279,17 +603,22 @@ impl MessageManager {
             // read channel id
             let channel_kind = ChannelKind::de(&protocol.channel_kinds, reader)?;
 
-            // continue read inside channel
-            let channel = self.channel_receivers.get_mut(&channel_kind).unwrap();
+            // continue read inside channel, which must be one this side can
+            // receive on
+            let Some(channel) = self.channel_receivers.get_mut(&channel_kind) else {
+                return Err(SerdeErr);
+            };
             channel.read_messages(&protocol.message_kinds, entity_waitlist, converter, reader)?;
         }
 
         Ok(())
     }
 
-    /// Retrieve all messages from the channel buffers
+    /// Retrieve all messages from the channel buffers. Requests & Responses
+    /// are kept back, see `take_incoming_requests()` & `receive_response()`
     pub fn receive_messages<E: Eq + Copy + Hash>(
         &mut self,
+        message_kinds: &MessageKinds,
         global_entity_converter: &dyn EntityAndGlobalEntityConverter<E>,
         local_entity_converter: &dyn LocalEntityConverter<E>,
         entity_waitlist: &mut EntityWaitlist,
@@ -297,11 +626,29 @@ impl MessageManager {
         let entity_converter =
             EntityConverter::new(global_entity_converter, local_entity_converter);
         let mut output = Vec::new();
+        let mut rpc_messages = Vec::new();
         // TODO: shouldn't we have a priority mechanisms between channels?
         for (channel_kind, channel) in &mut self.channel_receivers {
-            let messages = channel.receive_messages(entity_waitlist, &entity_converter);
+            let (channel_rpc_messages, messages): (Vec<_>, Vec<_>) = channel
+                .receive_messages(entity_waitlist, &entity_converter)
+                .into_iter()
+                .partition(|message| message.kind() == MessageKind::of::<RpcMessage>());
+            for message in channel_rpc_messages {
+                rpc_messages.push((*channel_kind, message));
+            }
             output.push((channel_kind.clone(), messages));
         }
+        for (channel_kind, message) in rpc_messages {
+            let Ok(rpc_message) = message.to_boxed_any().downcast::<RpcMessage>() else {
+                continue;
+            };
+            self.receive_rpc_message(
+                message_kinds,
+                &entity_converter,
+                &channel_kind,
+                *rpc_message,
+            );
+        }
         output
     }
 }
@@ -317,6 +664,25 @@ impl MessageManager {
                         channel.notify_message_delivered(message_index);
                     }
                 }
+
+                // a Message is delivered once all of its fragments are
+                for message_index in message_indices {
+                    let Some(message_handle) = self
+                        .message_handles
+                        .remove(&(*channel_kind, *message_index))
+                    else {
+                        continue;
+                    };
+                    let Some(undelivered) = self.undelivered_messages.get_mut(&message_handle)
+                    else {
+                        continue;
+                    };
+                    *undelivered -= 1;
+                    if *undelivered == 0 {
+                        self.undelivered_messages.remove(&message_handle);
+                        self.delivered_messages.push(message_handle);
+                    }
+                }
             }
         }
     }
@@ -2,9 +2,11 @@ pub mod channels;
 pub mod fragment;
 pub mod message;
 pub mod message_container;
+pub mod message_handle;
 pub mod message_kinds;
 pub mod message_manager;
 pub mod named;
+pub mod request;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,216 @@
+use std::{marker::PhantomData, time::Duration};
+
+use naia_derive::MessageInternal;
+use naia_serde::{BitReader, BitWrite, Serde, SerdeErr, UnsignedVariableInteger};
+
+use crate::{
+    LocalEntityAndGlobalEntityConverter, Message, MessageContainer, MessageKind, MessageKinds,
+};
+
+/// A Message which the remote host answers with a Message of type
+/// `Self::Response`
+pub trait Request: Message {
+    type Response: Message;
+}
+
+/// Why a Request will never receive its Response
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ResponseError {
+    /// No Response arrived within the Request timeout
+    TimedOut,
+    /// The connection the Request was sent over has closed
+    Disconnected,
+}
+
+// RequestId
+/// Identifies a Request among all those sent by a host
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RequestId {
+    inner: u64,
+}
+
+impl RequestId {
+    pub fn zero() -> Self {
+        Self { inner: 0 }
+    }
+
+    pub fn increment(&mut self) {
+        self.inner = self.inner.wrapping_add(1);
+    }
+}
+
+impl Serde for RequestId {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        UnsignedVariableInteger::<7>::new(self.inner).ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let inner = UnsignedVariableInteger::<7>::de(reader)?.get() as u64;
+        Ok(Self { inner })
+    }
+
+    fn bit_length(&self) -> u32 {
+        UnsignedVariableInteger::<7>::new(self.inner).bit_length()
+    }
+}
+
+// OutgoingRequest
+/// A Request being sent: its id, the kind of Response it awaits, and how long
+/// to await it
+pub struct OutgoingRequest {
+    pub(crate) request_id: RequestId,
+    pub(crate) response_kind: MessageKind,
+    pub(crate) timeout: Duration,
+}
+
+impl OutgoingRequest {
+    pub fn of<Q: Request>(request_id: RequestId, timeout: Duration) -> Self {
+        Self {
+            request_id,
+            response_kind: MessageKind::of::<Q::Response>(),
+            timeout,
+        }
+    }
+}
+
+// ResponseHandle
+/// Returned when sending a Request, used to receive its Response of type `R`
+pub struct ResponseHandle<R: Message> {
+    request_id: RequestId,
+    phantom_r: PhantomData<R>,
+}
+
+impl<R: Message> ResponseHandle<R> {
+    pub fn new(request_id: RequestId) -> Self {
+        Self {
+            request_id,
+            phantom_r: PhantomData,
+        }
+    }
+
+    pub fn request_id(&self) -> RequestId {
+        self.request_id
+    }
+}
+
+impl<R: Message> Clone for ResponseHandle<R> {
+    fn clone(&self) -> Self {
+        Self::new(self.request_id)
+    }
+}
+
+// ResponseSendKey
+/// Received along with a Request, used to send back its Response of type `R`
+pub struct ResponseSendKey<R: Message> {
+    id: u64,
+    phantom_r: PhantomData<R>,
+}
+
+impl<R: Message> ResponseSendKey<R> {
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            phantom_r: PhantomData,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl<R: Message> Clone for ResponseSendKey<R> {
+    fn clone(&self) -> Self {
+        Self::new(self.id)
+    }
+}
+
+// RpcMessage
+/// Message which carries a Request, or the Response to one, through the
+/// Channel it was sent on. The wrapped Message is written into `bytes`.
+#[derive(MessageInternal)]
+pub struct RpcMessage {
+    request_id: RequestId,
+    is_response: bool,
+    bytes: Box<[u8]>,
+}
+
+impl RpcMessage {
+    pub(crate) fn new(request_id: RequestId, is_response: bool, bytes: Box<[u8]>) -> Self {
+        Self {
+            request_id,
+            is_response,
+            bytes,
+        }
+    }
+
+    pub(crate) fn request_id(&self) -> RequestId {
+        self.request_id
+    }
+
+    pub(crate) fn is_response(&self) -> bool {
+        self.is_response
+    }
+
+    /// Reads the wrapped Message
+    pub(crate) fn read_message(
+        &self,
+        message_kinds: &MessageKinds,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<MessageContainer, SerdeErr> {
+        let mut reader = BitReader::new(&self.bytes);
+        message_kinds.read(&mut reader, converter)
+    }
+}
+
+// RpcWriter
+/// Writes the Message wrapped by an RpcMessage. Unlike a BitWriter, this
+/// grows as needed, as the Message may be larger than a packet (in which
+/// case the RpcMessage is fragmented)
+pub(crate) struct RpcWriter {
+    bytes: Vec<u8>,
+    bit_index: u32,
+}
+
+impl RpcWriter {
+    pub(crate) fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            bit_index: 0,
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> Box<[u8]> {
+        self.bytes.into_boxed_slice()
+    }
+}
+
+impl BitWrite for RpcWriter {
+    fn write_bit(&mut self, bit: bool) {
+        // bits are read from the lowest of each byte first, as by BitReader
+        let bit_in_byte = self.bit_index % 8;
+        if bit_in_byte == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 1 << bit_in_byte;
+        }
+        self.bit_index += 1;
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        let mut temp = byte;
+        for _ in 0..8 {
+            self.write_bit(temp & 1 != 0);
+            temp >>= 1;
+        }
+    }
+
+    fn write_bits(&mut self, _bits: u32) {
+        panic!("This method should only be used by BitCounter");
+    }
+
+    fn is_counter(&self) -> bool {
+        false
+    }
+}
@@ -1,11 +1,20 @@
+use std::time::Duration;
+
 use naia_derive::MessageInternal;
 
 use crate::{
-    messages::channels::{
-        receivers::fragment_receiver::FragmentReceiver,
-        senders::message_fragmenter::MessageFragmenter,
+    messages::{
+        channels::{
+            receivers::{
+                fragment_receiver::FragmentReceiver,
+                unordered_reliable_receiver::UnorderedReliableReceiver,
+            },
+            senders::message_fragmenter::MessageFragmenter,
+        },
+        fragment::{FragmentId, FragmentIndex, FragmentedMessage},
     },
-    FakeEntityConverter, MessageContainer, MessageKinds, Protocol,
+    world::remote::entity_waitlist::EntityWaitlist,
+    Clock, FakeEntityConverter, MessageContainer, MessageKinds, Protocol,
 };
 
 #[derive(MessageInternal)]
@@ -45,23 +54,25 @@ fn setup() -> (
 
 #[test]
 fn convert_single_fragment() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("hello");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container =
+        MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container, None);
     let fragment_count = fragments.len();
 
     // Receive Fragments
     let mut incoming_message_container_opt = None;
     for fragment in fragments {
-        if let Some((_, reassembled_message)) =
-            receiver.receive(&message_kinds, &converter, fragment)
+        if let Some((_, reassembled_message)) = receiver
+            .receive(&message_kinds, &converter, fragment)
+            .unwrap()
         {
             incoming_message_container_opt = Some(reassembled_message);
             break;
@@ -81,7 +92,7 @@ fn convert_single_fragment() {
 
 #[test]
 fn convert_multiple_fragments() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Donec sed justo a mi ultricies ultrices. \
@@ -95,10 +106,11 @@ fn convert_multiple_fragments() {
             Donec ut purus venenatis, mollis est ut, sollicitudin egestas.");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container =
+        MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container, None);
     let fragment_count = fragments.len();
 
     // Receive Fragments
@@ -112,8 +124,9 @@ fn convert_multiple_fragments() {
         };
 
         let fragment = &fragments[j];
-        if let Some((_, reassembled_message)) =
-            receiver.receive(&message_kinds, &converter, fragment.clone())
+        if let Some((_, reassembled_message)) = receiver
+            .receive(&message_kinds, &converter, fragment.clone())
+            .unwrap()
         {
             incoming_message_container_opt = Some(reassembled_message);
             break;
@@ -130,3 +143,194 @@ fn convert_multiple_fragments() {
     assert_eq!(fragment_count, 3);
     assert_eq!(initial_message.inner, incoming_message.inner);
 }
+
+#[test]
+fn convert_many_fragments() {
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
+
+    // Message
+    let initial_message = StringMessage::new(&"Lorem ipsum dolor sit amet. ".repeat(1000));
+    let outgoing_message = initial_message.clone();
+
+    let container =
+        MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
+
+    // Fragment Message
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container, None);
+    let fragment_count = fragments.len();
+
+    // Receive Fragments, in reverse
+    let mut incoming_message_container_opt = None;
+    for fragment in fragments.into_iter().rev() {
+        if let Some((_, reassembled_message)) = receiver
+            .receive(&message_kinds, &converter, fragment)
+            .unwrap()
+        {
+            incoming_message_container_opt = Some(reassembled_message);
+            break;
+        }
+    }
+    let Some(incoming_message_container) = incoming_message_container_opt else {
+        panic!("Did not receive reassembled message!");
+    };
+    let Ok(incoming_message) = incoming_message_container.to_boxed_any().downcast::<StringMessage>() else {
+        panic!("cannot cast message container into proper message!");
+    };
+
+    // Compare
+    assert!(fragment_count > 22);
+    assert_eq!(initial_message.inner, incoming_message.inner);
+}
+
+#[test]
+fn fragment_ids_wrap_at_ten_bits() {
+    let mut fragment_id = FragmentId::zero();
+    for _ in 0..1023 {
+        fragment_id.increment();
+        assert!(fragment_id != FragmentId::zero());
+    }
+    fragment_id.increment();
+    assert!(fragment_id == FragmentId::zero());
+}
+
+fn fragment(index: u32, total: u32) -> MessageContainer {
+    fragment_with_ttl(index, total, None)
+}
+
+fn fragment_with_ttl(index: u32, total: u32, ttl: Option<Duration>) -> MessageContainer {
+    let mut fragment_index = FragmentIndex::zero();
+    for _ in 0..index {
+        fragment_index.increment();
+    }
+    let mut fragment_total = FragmentIndex::zero();
+    for _ in 0..total {
+        fragment_total.increment();
+    }
+
+    let mut fragment =
+        FragmentedMessage::new(FragmentId::zero(), fragment_index, ttl, Box::new([0, 1, 2]));
+    fragment.set_total(fragment_total);
+    MessageContainer::from_write(Box::new(fragment), &mut FakeEntityConverter)
+}
+
+#[test]
+fn reject_fragment_index_beyond_total() {
+    let (message_kinds, converter, _, mut receiver) = setup();
+
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(3, 2))
+        .is_err());
+}
+
+#[test]
+fn reject_fragments_with_mismatched_totals() {
+    let (message_kinds, converter, _, mut receiver) = setup();
+
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(0, 2))
+        .unwrap()
+        .is_none());
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(1, 3))
+        .is_err());
+}
+
+#[test]
+fn reject_reassembled_garbage() {
+    let (message_kinds, converter, _, mut receiver) = setup();
+
+    // duplicate fragments are not counted twice
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(0, 2))
+        .unwrap()
+        .is_none());
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(0, 2))
+        .unwrap()
+        .is_none());
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment(1, 2))
+        .is_err());
+}
+
+#[test]
+fn expire_incomplete_fragments_after_ttl() {
+    Clock::set_manual();
+    let (message_kinds, converter, _, _) = setup();
+    let mut receiver = FragmentReceiver::new();
+    let ttl = Some(Duration::from_millis(100));
+
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment_with_ttl(0, 2, ttl))
+        .unwrap()
+        .is_none());
+
+    // the sender has given up on the rest of the Message by now, so the
+    // remaining fragment starts a new set instead of completing the old one
+    Clock::advance(Duration::from_millis(100));
+    assert!(receiver
+        .receive(&message_kinds, &converter, fragment_with_ttl(1, 2, ttl))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn lost_fragment_of_expired_message_is_not_merged_after_ids_wrap() {
+    Clock::set_manual();
+    let (message_kinds, mut converter, mut fragmenter, _) = setup();
+    let mut entity_waitlist = EntityWaitlist::new();
+    let mut receiver = UnorderedReliableReceiver::new();
+    let mut message_index = 0;
+
+    // a Message sent with a TTL over a Channel without one loses a fragment
+    let lost_message = StringMessage::new(&"Lorem ipsum dolor sit amet. ".repeat(20));
+    let container = MessageContainer::from_write(Box::new(lost_message), &mut FakeEntityConverter);
+    let fragments = fragmenter.fragment_message(
+        &message_kinds,
+        &mut converter,
+        container,
+        Some(Duration::from_millis(100)),
+    );
+    assert!(fragments.len() > 1);
+    receiver
+        .buffer_message(
+            &message_kinds,
+            &mut entity_waitlist,
+            &converter,
+            message_index,
+            ((), fragments.into_iter().next().unwrap()),
+        )
+        .unwrap();
+    // the remaining fragments take up these indices, and are never received
+    message_index = message_index.wrapping_add(10);
+
+    // the sender gives up on the Message
+    Clock::advance(Duration::from_millis(100));
+
+    // larger Messages are sent until their fragment ids wrap around to the
+    // lost Message's
+    let message = StringMessage::new(&"Lorem ipsum dolor sit amet. ".repeat(40));
+    let mut received = 0;
+    for _ in 0..1024 {
+        let container =
+            MessageContainer::from_write(Box::new(message.clone()), &mut FakeEntityConverter);
+        for fragment in fragmenter.fragment_message(&message_kinds, &mut converter, container, None)
+        {
+            receiver
+                .buffer_message(
+                    &message_kinds,
+                    &mut entity_waitlist,
+                    &converter,
+                    message_index,
+                    ((), fragment),
+                )
+                .unwrap();
+            message_index = message_index.wrapping_add(1);
+        }
+        received += receiver
+            .receive_messages(&mut entity_waitlist, &converter)
+            .len();
+    }
+
+    assert_eq!(received, 1024);
+}
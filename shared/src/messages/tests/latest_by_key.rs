@@ -0,0 +1,77 @@
+use naia_derive::MessageInternal;
+
+use crate::{
+    messages::channels::{
+        message_ordering::{MessageOrdering, OrderingKey, MAX_ORDERING_KEYS},
+        receivers::{
+            channel_receiver::ChannelReceiver,
+            unreliable_latest_by_key_receiver::UnreliableLatestByKeyReceiver,
+        },
+    },
+    world::remote::entity_waitlist::EntityWaitlist,
+    FakeEntityConverter, MessageContainer,
+};
+
+#[derive(MessageInternal)]
+pub struct Position {
+    pub x: u8,
+}
+
+fn buffer_position(
+    entity_waitlist: &mut EntityWaitlist,
+    receiver: &mut UnreliableLatestByKeyReceiver,
+    key: usize,
+    sequence: u16,
+) {
+    let container =
+        MessageContainer::from_write(Box::new(Position { x: 0 }), &mut FakeEntityConverter);
+    receiver.buffer_message(
+        entity_waitlist,
+        MessageOrdering::new(Some(OrderingKey::new(&key)), sequence),
+        container,
+    );
+}
+
+fn receive_count(
+    entity_waitlist: &mut EntityWaitlist,
+    receiver: &mut UnreliableLatestByKeyReceiver,
+) -> usize {
+    receiver
+        .receive_messages(entity_waitlist, &FakeEntityConverter)
+        .len()
+}
+
+#[test]
+fn stale_message_is_dropped() {
+    let mut entity_waitlist = EntityWaitlist::new();
+    let mut receiver = UnreliableLatestByKeyReceiver::new();
+
+    buffer_position(&mut entity_waitlist, &mut receiver, 0, 5);
+    buffer_position(&mut entity_waitlist, &mut receiver, 0, 3);
+
+    assert_eq!(receive_count(&mut entity_waitlist, &mut receiver), 1);
+}
+
+#[test]
+fn least_recently_received_key_is_forgotten() {
+    let mut entity_waitlist = EntityWaitlist::new();
+    let mut receiver = UnreliableLatestByKeyReceiver::new();
+
+    buffer_position(&mut entity_waitlist, &mut receiver, 0, 5);
+    for key in 1..=MAX_ORDERING_KEYS {
+        buffer_position(&mut entity_waitlist, &mut receiver, key, 5);
+    }
+    assert_eq!(
+        receive_count(&mut entity_waitlist, &mut receiver),
+        MAX_ORDERING_KEYS + 1
+    );
+
+    // the other keys are still tracked
+    buffer_position(&mut entity_waitlist, &mut receiver, 1, 3);
+    buffer_position(&mut entity_waitlist, &mut receiver, MAX_ORDERING_KEYS, 3);
+    assert_eq!(receive_count(&mut entity_waitlist, &mut receiver), 0);
+
+    // while the first has been forgotten to make room for them
+    buffer_position(&mut entity_waitlist, &mut receiver, 0, 3);
+    assert_eq!(receive_count(&mut entity_waitlist, &mut receiver), 1);
+}
@@ -0,0 +1,63 @@
+use naia_derive::MessageInternal;
+
+use crate::{
+    messages::{
+        channels::default_channels::{UnorderedReliableChannel, UnorderedUnreliableChannel},
+        message_manager::MessageManager,
+    },
+    ChannelKind, FakeEntityConverter, HostType, MessageContainer, Protocol,
+};
+
+#[derive(MessageInternal)]
+pub struct StringMessage {
+    pub inner: String,
+}
+
+fn setup() -> (Protocol, MessageManager) {
+    let mut protocol = Protocol::builder();
+    protocol
+        .add_default_channels()
+        .add_message::<StringMessage>();
+    let protocol = protocol.build();
+    let message_manager = MessageManager::new(HostType::Server, &protocol.channel_kinds);
+    (protocol, message_manager)
+}
+
+fn send(protocol: &Protocol, message_manager: &mut MessageManager, channel_kind: &ChannelKind) {
+    let message = StringMessage {
+        inner: "hello".to_string(),
+    };
+    let message = MessageContainer::from_write(Box::new(message), &mut FakeEntityConverter);
+    message_manager.send_message(
+        &protocol.message_kinds,
+        &mut FakeEntityConverter,
+        channel_kind,
+        message,
+    );
+}
+
+#[test]
+fn unreliable_message_is_not_tracked_for_delivery() {
+    let (protocol, mut message_manager) = setup();
+
+    send(
+        &protocol,
+        &mut message_manager,
+        &ChannelKind::of::<UnorderedUnreliableChannel>(),
+    );
+
+    assert_eq!(message_manager.undelivered_message_count(), 0);
+}
+
+#[test]
+fn reliable_message_is_tracked_for_delivery() {
+    let (protocol, mut message_manager) = setup();
+
+    send(
+        &protocol,
+        &mut message_manager,
+        &ChannelKind::of::<UnorderedReliableChannel>(),
+    );
+
+    assert_eq!(message_manager.undelivered_message_count(), 1);
+}
@@ -0,0 +1,40 @@
+use naia_serde::{BitReader, BitWriter, Serde};
+
+use crate::messages::channels::message_ordering::OrderingKey;
+
+#[test]
+fn ordering_key_is_stable() {
+    // must be the same on every platform & Rust version, since the Server &
+    // Client each hash keys themselves
+    let mut writer = BitWriter::new();
+    OrderingKey::new("naia").ser(&mut writer);
+    let bytes = writer.to_bytes();
+    let mut reader = BitReader::new(&bytes);
+    assert_eq!(u64::de(&mut reader).unwrap(), 0x8c44_08ff_de19_14e3);
+}
+
+#[test]
+fn ordering_key_ignores_pointer_width() {
+    assert!(OrderingKey::new(&7usize) == OrderingKey::new(&7u64));
+    assert!(OrderingKey::new(&-7isize) == OrderingKey::new(&-7i64));
+}
+
+#[test]
+fn ordering_keys_do_not_collide() {
+    let mut keys = std::collections::HashSet::new();
+    for unit in 0..100_000u32 {
+        assert!(keys.insert(OrderingKey::new(&unit)));
+    }
+}
+
+#[test]
+fn ordering_key_round_trips() {
+    let key = OrderingKey::new(&(3u8, "unit"));
+
+    let mut writer = BitWriter::new();
+    key.ser(&mut writer);
+    let bytes = writer.to_bytes();
+
+    let mut reader = BitReader::new(&bytes);
+    assert!(OrderingKey::de(&mut reader).unwrap() == key);
+}
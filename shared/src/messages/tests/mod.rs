@@ -1 +1,5 @@
 mod fragment;
+mod latest_by_key;
+mod message_manager;
+mod message_ordering;
+mod ordered_reliable;
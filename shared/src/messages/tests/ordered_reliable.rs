@@ -0,0 +1,184 @@
+use naia_derive::MessageInternal;
+
+use crate::{
+    messages::channels::{
+        message_ordering::{MessageOrdering, OrderingKey, MAX_ORDERING_KEYS},
+        receivers::ordered_reliable_receiver::OrderedReliableReceiver,
+    },
+    world::remote::entity_waitlist::EntityWaitlist,
+    FakeEntityConverter, MessageContainer, MessageKinds, Protocol,
+};
+
+#[derive(MessageInternal)]
+pub struct Move {
+    pub unit: u8,
+    pub step: u8,
+}
+
+fn setup() -> (MessageKinds, EntityWaitlist, OrderedReliableReceiver) {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_message::<Move>();
+
+    (
+        protocol.message_kinds,
+        EntityWaitlist::new(),
+        OrderedReliableReceiver::new(),
+    )
+}
+
+fn buffer_move(
+    message_kinds: &MessageKinds,
+    entity_waitlist: &mut EntityWaitlist,
+    receiver: &mut OrderedReliableReceiver,
+    message_index: u16,
+    key: Option<OrderingKey>,
+    sequence: u16,
+    outgoing_move: Move,
+) {
+    let container = MessageContainer::from_write(Box::new(outgoing_move), &mut FakeEntityConverter);
+    receiver
+        .buffer_message(
+            message_kinds,
+            entity_waitlist,
+            &FakeEntityConverter,
+            message_index,
+            (MessageOrdering::new(key, sequence), container),
+        )
+        .unwrap();
+}
+
+fn receive_moves(
+    entity_waitlist: &mut EntityWaitlist,
+    receiver: &mut OrderedReliableReceiver,
+) -> Vec<(u8, u8)> {
+    receiver
+        .receive_messages(entity_waitlist, &FakeEntityConverter)
+        .into_iter()
+        .map(|(_, container)| {
+            let Ok(incoming_move) = container.to_boxed_any().downcast::<Move>() else {
+                panic!("cannot cast message container into proper message!");
+            };
+            (incoming_move.unit, incoming_move.step)
+        })
+        .collect()
+}
+
+#[test]
+fn missing_message_only_holds_back_its_own_key() {
+    let (message_kinds, mut entity_waitlist, mut receiver) = setup();
+    let unit_1 = Some(OrderingKey::new(&1u8));
+    let unit_2 = Some(OrderingKey::new(&2u8));
+
+    // the first Move for unit 1 has not arrived yet
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        1,
+        unit_1,
+        1,
+        Move { unit: 1, step: 1 },
+    );
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        2,
+        unit_2,
+        0,
+        Move { unit: 2, step: 0 },
+    );
+    assert_eq!(
+        receive_moves(&mut entity_waitlist, &mut receiver),
+        vec![(2, 0)]
+    );
+
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        0,
+        unit_1,
+        0,
+        Move { unit: 1, step: 0 },
+    );
+    assert_eq!(
+        receive_moves(&mut entity_waitlist, &mut receiver),
+        vec![(1, 0), (1, 1)]
+    );
+}
+
+#[test]
+fn missing_unkeyed_message_holds_back_later_unkeyed_messages() {
+    let (message_kinds, mut entity_waitlist, mut receiver) = setup();
+
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        1,
+        None,
+        1,
+        Move { unit: 1, step: 1 },
+    );
+    assert!(receive_moves(&mut entity_waitlist, &mut receiver).is_empty());
+
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        0,
+        None,
+        0,
+        Move { unit: 1, step: 0 },
+    );
+    assert_eq!(
+        receive_moves(&mut entity_waitlist, &mut receiver),
+        vec![(1, 0), (1, 1)]
+    );
+}
+
+#[test]
+fn too_many_keys_is_an_error() {
+    let (message_kinds, mut entity_waitlist, mut receiver) = setup();
+
+    for key in 0..MAX_ORDERING_KEYS {
+        buffer_move(
+            &message_kinds,
+            &mut entity_waitlist,
+            &mut receiver,
+            key as u16,
+            Some(OrderingKey::new(&key)),
+            0,
+            Move { unit: 1, step: 0 },
+        );
+    }
+    assert_eq!(
+        receive_moves(&mut entity_waitlist, &mut receiver).len(),
+        MAX_ORDERING_KEYS
+    );
+
+    // an existing key can still be used
+    buffer_move(
+        &message_kinds,
+        &mut entity_waitlist,
+        &mut receiver,
+        MAX_ORDERING_KEYS as u16,
+        Some(OrderingKey::new(&0usize)),
+        1,
+        Move { unit: 1, step: 1 },
+    );
+
+    let outgoing_move = Move { unit: 2, step: 0 };
+    let container = MessageContainer::from_write(Box::new(outgoing_move), &mut FakeEntityConverter);
+    assert!(receiver
+        .buffer_message(
+            &message_kinds,
+            &mut entity_waitlist,
+            &FakeEntityConverter,
+            MAX_ORDERING_KEYS as u16 + 1,
+            (MessageOrdering::new(None, 0), container),
+        )
+        .is_err());
+}
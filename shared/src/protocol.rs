@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{hash::Hasher, time::Duration};
 
 use naia_socket_shared::{LinkConditionerConfig, SocketConfig};
 
@@ -6,15 +6,25 @@ use crate::{
     connection::compression_config::CompressionConfig,
     messages::{
         channels::{
-            channel::{Channel, ChannelDirection, ChannelMode, ChannelSettings},
+            channel::{Channel, ChannelDirection, ChannelMode, ChannelSettings, ReliableSettings},
             channel_kinds::ChannelKinds,
             default_channels::DefaultChannelsPlugin,
+            system_channel::SystemChannel,
         },
         fragment::FragmentedMessage,
         message::Message,
         message_kinds::MessageKinds,
+        request::RpcMessage,
+    },
+    protocol_schema::{ChannelSchema, ProtocolSchema, TypeSchema},
+    stable_hasher::StableHasher,
+    world::{
+        component::{
+            component_kinds::ComponentKinds, component_settings::ComponentSettings,
+            replicate::Replicate,
+        },
+        entity::entity_auth::EntityAuthMessage,
     },
-    world::component::{component_kinds::ComponentKinds, replicate::Replicate},
 };
 
 // Protocol Plugin
@@ -35,21 +45,30 @@ pub struct Protocol {
     pub compression: Option<CompressionConfig>,
     /// Whether or not Client Authoritative Entities will be allowed
     pub client_authoritative_entities: bool,
+    name_based_net_ids: bool,
     locked: bool,
 }
 
 impl Default for Protocol {
     fn default() -> Self {
+        let mut channel_kinds = ChannelKinds::new();
+        channel_kinds.add_channel::<SystemChannel>(ChannelSettings::new(
+            ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ChannelDirection::Bidirectional,
+        ));
         let mut message_kinds = MessageKinds::new();
         message_kinds.add_message::<FragmentedMessage>();
+        message_kinds.add_message::<EntityAuthMessage>();
+        message_kinds.add_message::<RpcMessage>();
         Self {
-            channel_kinds: ChannelKinds::new(),
+            channel_kinds,
             message_kinds,
             component_kinds: ComponentKinds::new(),
             socket: SocketConfig::new(None, None),
             tick_interval: Duration::from_millis(50),
             compression: None,
             client_authoritative_entities: false,
+            name_based_net_ids: false,
             locked: false,
         }
     }
@@ -96,6 +115,28 @@ impl Protocol {
         self
     }
 
+    /// Assigns every Channel, Message & Component a NetId from its name, or
+    /// from its `#[net_id = N]` attribute, rather than from the order it was
+    /// registered in. Types can then be added & reordered without changing
+    /// the NetIds of other types. `#[net_id = N]` attributes are ignored
+    /// unless this is enabled.
+    ///
+    /// Messages & Components are then left out of the Protocol's
+    /// fingerprint, so a Client which registers more or fewer of them than
+    /// the Server can still connect. Reading a Message or Component with a
+    /// NetId that isn't registered is a `SerdeErr`, so a connection is only
+    /// closed (with `DisconnectReason::ProtocolError`) if the other side
+    /// actually sends a type it doesn't know. Types registered on both sides
+    /// must still have the same fields, as the handshake no longer checks them
+    pub fn enable_name_based_net_ids(&mut self) -> &mut Self {
+        self.check_lock();
+        self.name_based_net_ids = true;
+        self.channel_kinds.use_name_based_net_ids();
+        self.message_kinds.use_name_based_net_ids();
+        self.component_kinds.use_name_based_net_ids();
+        self
+    }
+
     pub fn add_default_channels(&mut self) -> &mut Self {
         self.check_lock();
         let plugin = DefaultChannelsPlugin;
@@ -126,6 +167,16 @@ impl Protocol {
         self
     }
 
+    pub fn add_component_with_settings<C: Replicate>(
+        &mut self,
+        settings: ComponentSettings,
+    ) -> &mut Self {
+        self.check_lock();
+        self.component_kinds
+            .add_component_with_settings::<C>(settings);
+        self
+    }
+
     pub fn lock(&mut self) {
         self.check_lock();
         self.locked = true;
@@ -140,4 +191,86 @@ impl Protocol {
     pub fn build(&mut self) -> Self {
         std::mem::take(self)
     }
+
+    /// Describes every registered Channel, Message & Component with its
+    /// NetId, in NetId order
+    pub fn schema(&self) -> ProtocolSchema {
+        let channels = self
+            .channel_kinds
+            .settings_list()
+            .into_iter()
+            .map(|(net_id, name, settings)| {
+                ChannelSchema::new(net_id, name, &settings.mode, &settings.direction)
+            })
+            .collect();
+        let [messages, components] = [self.message_kinds.layouts(), self.component_kinds.layouts()]
+            .map(|layouts| {
+                layouts
+                    .into_iter()
+                    .map(|(net_id, layout)| TypeSchema {
+                        net_id,
+                        name: layout.name.clone(),
+                        fields: layout.fields.clone(),
+                    })
+                    .collect()
+            });
+
+        ProtocolSchema {
+            tick_interval: self.tick_interval,
+            name_based_net_ids: self.name_based_net_ids,
+            channels,
+            messages,
+            components,
+        }
+    }
+
+    /// Computes a fingerprint of the registered Channels, Messages &
+    /// Components (with their NetIds & fields) and the tick interval. The
+    /// Server rejects Clients whose Protocol has a different fingerprint, as
+    /// they would misread its packets.
+    ///
+    /// With name based NetIds, Messages & Components are left out: their
+    /// NetIds don't depend on which other types are registered, so the
+    /// Client & Server may register different sets of them
+    pub fn fingerprint(&self) -> u64 {
+        let schema = self.schema();
+        let mut hasher = StableHasher::new();
+
+        hasher.write(&[u8::from(schema.name_based_net_ids)]);
+
+        hasher.write_usize(schema.channels.len());
+        for channel in &schema.channels {
+            hasher.write(&channel.net_id.to_le_bytes());
+            hasher.write_len_prefixed_str(&channel.name);
+            hasher.write_len_prefixed_str(&channel.mode);
+            hasher.write_len_prefixed_str(&channel.direction);
+        }
+
+        if !schema.name_based_net_ids {
+            for types in [&schema.messages, &schema.components] {
+                hasher.write_usize(types.len());
+                for type_schema in types {
+                    hasher.write(&type_schema.net_id.to_le_bytes());
+                    hasher.write_len_prefixed_str(&type_schema.name);
+                    hasher.write_usize(type_schema.fields.len());
+                    for field in &type_schema.fields {
+                        hasher.write_len_prefixed_str(&field.name);
+                        hasher.write_len_prefixed_str(&field.type_name);
+                    }
+                }
+            }
+        }
+
+        hasher.write(&schema.tick_interval.as_nanos().to_le_bytes());
+
+        hasher.finish()
+    }
+}
+
+/// Derives a NetId from a type's name, for Protocols with name based NetIds
+pub(crate) fn net_id_from_name(name: &str) -> u16 {
+    let mut hasher = StableHasher::new();
+    hasher.write(name.as_bytes());
+    let hash = hasher.finish();
+    (hash ^ (hash >> 16) ^ (hash >> 32) ^ (hash >> 48)) as u16
 }
@@ -0,0 +1,114 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    messages::channels::channel::{ChannelDirection, ChannelMode},
+    FieldLayout,
+};
+
+/// A description of every type registered with a Protocol & the NetId it is
+/// written to the network with, used to review changes between versions of a
+/// Protocol. Its `Display` output is a stable, line-based text format which
+/// diffs cleanly
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ProtocolSchema {
+    pub tick_interval: Duration,
+    pub name_based_net_ids: bool,
+    pub channels: Vec<ChannelSchema>,
+    pub messages: Vec<TypeSchema>,
+    pub components: Vec<TypeSchema>,
+}
+
+/// Describes a registered Channel
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ChannelSchema {
+    pub net_id: u16,
+    pub name: String,
+    pub mode: String,
+    pub direction: String,
+}
+
+/// Describes a registered Message or Component type
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct TypeSchema {
+    pub net_id: u16,
+    pub name: String,
+    pub fields: Vec<FieldLayout>,
+}
+
+impl ChannelSchema {
+    pub(crate) fn new(
+        net_id: u16,
+        name: &str,
+        mode: &ChannelMode,
+        direction: &ChannelDirection,
+    ) -> Self {
+        let mode = match mode {
+            ChannelMode::UnorderedUnreliable => "UnorderedUnreliable",
+            ChannelMode::SequencedUnreliable => "SequencedUnreliable",
+            ChannelMode::UnreliableLatestByKey => "UnreliableLatestByKey",
+            ChannelMode::UnorderedReliable(_) => "UnorderedReliable",
+            ChannelMode::SequencedReliable(_) => "SequencedReliable",
+            ChannelMode::OrderedReliable(_) => "OrderedReliable",
+            ChannelMode::TickBuffered(_) => "TickBuffered",
+        };
+        let direction = match direction {
+            ChannelDirection::ClientToServer => "ClientToServer",
+            ChannelDirection::ServerToClient => "ServerToClient",
+            ChannelDirection::Bidirectional => "Bidirectional",
+        };
+        Self {
+            net_id,
+            name: name.to_string(),
+            mode: mode.to_string(),
+            direction: direction.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "tick_interval: {:?}", self.tick_interval)?;
+        let net_ids = if self.name_based_net_ids {
+            "names"
+        } else {
+            "registration order"
+        };
+        writeln!(f, "net_ids: {}", net_ids)?;
+
+        writeln!(f, "channels:")?;
+        for channel in &self.channels {
+            writeln!(
+                f,
+                "  {} {}: {}, {}",
+                channel.net_id, channel.name, channel.mode, channel.direction
+            )?;
+        }
+
+        for (title, types) in [
+            ("messages", &self.messages),
+            ("components", &self.components),
+        ] {
+            writeln!(f, "{}:", title)?;
+            for type_schema in types {
+                writeln!(f, "  {} {}", type_schema.net_id, type_schema.name)?;
+                for field in &type_schema.fields {
+                    writeln!(f, "    {}: {}", field.name, field.type_name)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
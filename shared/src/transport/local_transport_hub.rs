@@ -0,0 +1,149 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+/// A set of in-memory packet queues connecting a single Server to any number
+/// of Clients living in the same process. Used by the `transport_local`
+/// Socket implementations in naia-server & naia-client.
+#[derive(Clone)]
+pub struct LocalTransportHub {
+    inner: Arc<Mutex<HubInner>>,
+}
+
+struct HubInner {
+    server_addr: SocketAddr,
+    server_inbox: VecDeque<(SocketAddr, Box<[u8]>)>,
+    client_inboxes: HashMap<SocketAddr, VecDeque<Box<[u8]>>>,
+    // The address the Server sees each Client at, like a NAT mapping. This
+    // is the Client's own address, unless it has been rebound.
+    public_addrs: HashMap<SocketAddr, SocketAddr>,
+    // The reverse of `public_addrs`
+    client_addrs: HashMap<SocketAddr, SocketAddr>,
+    next_client_port: u16,
+}
+
+impl HubInner {
+    fn allocate_addr(&mut self) -> SocketAddr {
+        loop {
+            let port = self.next_client_port;
+            self.next_client_port = self.next_client_port.wrapping_add(1).max(1);
+            let address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
+            if address == self.server_addr
+                || self.client_inboxes.contains_key(&address)
+                || self.client_addrs.contains_key(&address)
+            {
+                continue;
+            }
+            return address;
+        }
+    }
+}
+
+impl LocalTransportHub {
+    /// Creates a new LocalTransportHub, with the Server listening at the
+    /// given (virtual) address
+    pub fn new(server_addr: SocketAddr) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(HubInner {
+                server_addr,
+                server_inbox: VecDeque::new(),
+                client_inboxes: HashMap::new(),
+                public_addrs: HashMap::new(),
+                client_addrs: HashMap::new(),
+                next_client_port: 1,
+            })),
+        }
+    }
+
+    /// Gets the (virtual) address of the Server
+    pub fn server_addr(&self) -> SocketAddr {
+        self.inner.lock().unwrap().server_addr
+    }
+
+    /// Allocates a new, unique (virtual) address for a Client, and creates
+    /// an inbox for it
+    pub fn register_client(&self) -> SocketAddr {
+        let mut inner = self.inner.lock().unwrap();
+        let address = inner.allocate_addr();
+        inner.client_inboxes.insert(address, VecDeque::new());
+        inner.public_addrs.insert(address, address);
+        inner.client_addrs.insert(address, address);
+        address
+    }
+
+    /// Removes a Client's inbox, any packets sent to it afterwards are dropped
+    pub fn deregister_client(&self, client_addr: &SocketAddr) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.client_inboxes.remove(client_addr);
+        if let Some(public_addr) = inner.public_addrs.remove(client_addr) {
+            inner.client_addrs.remove(&public_addr);
+        }
+    }
+
+    /// Changes the address the Server sees a Client at, as when a NAT
+    /// mapping is rebound. Packets the Server sends to the old address are
+    /// dropped afterwards. Returns the new address.
+    pub fn rebind_client(&self, client_addr: &SocketAddr) -> SocketAddr {
+        let mut inner = self.inner.lock().unwrap();
+        let new_public_addr = inner.allocate_addr();
+        if let Some(old_public_addr) = inner.public_addrs.insert(*client_addr, new_public_addr) {
+            inner.client_addrs.remove(&old_public_addr);
+        }
+        inner.client_addrs.insert(new_public_addr, *client_addr);
+        new_public_addr
+    }
+
+    /// Changes the address the Server sees a Client at to the given one, as
+    /// when a NAT hands a mapping vacated by another host to this Client.
+    /// Panics if another Client is currently seen at that address.
+    pub fn rebind_client_to(&self, client_addr: &SocketAddr, public_addr: &SocketAddr) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.client_addrs.contains_key(public_addr) {
+            panic!("another Client is already seen at {}", public_addr);
+        }
+        if let Some(old_public_addr) = inner.public_addrs.insert(*client_addr, *public_addr) {
+            inner.client_addrs.remove(&old_public_addr);
+        }
+        inner.client_addrs.insert(*public_addr, *client_addr);
+    }
+
+    /// Queues a packet sent from a Client to the Server
+    pub fn send_to_server(&self, client_addr: &SocketAddr, payload: &[u8]) {
+        let mut inner = self.inner.lock().unwrap();
+        let public_addr = inner
+            .public_addrs
+            .get(client_addr)
+            .copied()
+            .unwrap_or(*client_addr);
+        inner.server_inbox.push_back((public_addr, payload.into()));
+    }
+
+    /// Pops the next packet received by the Server, if any
+    pub fn recv_on_server(&self) -> Option<(SocketAddr, Box<[u8]>)> {
+        self.inner.lock().unwrap().server_inbox.pop_front()
+    }
+
+    /// Queues a packet sent from the Server to a Client. Like UDP, packets
+    /// sent to an unknown address are silently dropped
+    pub fn send_to_client(&self, client_addr: &SocketAddr, payload: &[u8]) {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(client_addr) = inner.client_addrs.get(client_addr).copied() else {
+            return;
+        };
+        if let Some(inbox) = inner.client_inboxes.get_mut(&client_addr) {
+            inbox.push_back(payload.into());
+        }
+    }
+
+    /// Pops the next packet received by the given Client, if any
+    pub fn recv_on_client(&self, client_addr: &SocketAddr) -> Option<Box<[u8]>> {
+        self.inner
+            .lock()
+            .unwrap()
+            .client_inboxes
+            .get_mut(client_addr)
+            .and_then(|inbox| inbox.pop_front())
+    }
+}
@@ -0,0 +1,41 @@
+/// Describes a Message or Component type & the fields it writes to the
+/// network, so that Protocols can be compared
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct TypeLayout {
+    pub name: String,
+    pub fields: Vec<FieldLayout>,
+}
+
+impl TypeLayout {
+    pub fn new(name: &str, fields: Vec<FieldLayout>) -> Self {
+        Self {
+            name: name.to_string(),
+            fields,
+        }
+    }
+}
+
+/// Describes a single field written to the network, with its type's
+/// `Serde::layout()`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct FieldLayout {
+    pub name: String,
+    pub type_name: String,
+}
+
+impl FieldLayout {
+    pub fn new(name: &str, type_name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        }
+    }
+}
@@ -3,8 +3,8 @@ use std::{any::TypeId, collections::HashMap};
 use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
 
 use crate::{
-    ComponentFieldUpdate, ComponentUpdate, LocalEntity, LocalEntityAndGlobalEntityConverter,
-    Replicate, ReplicateBuilder,
+    protocol::net_id_from_name, ComponentFieldUpdate, ComponentSettings, ComponentUpdate,
+    LocalEntity, LocalEntityAndGlobalEntityConverter, Replicate, ReplicateBuilder, TypeLayout,
 };
 
 type NetId = u16;
@@ -39,7 +39,7 @@ impl ComponentKind {
 
     pub fn de(component_kinds: &ComponentKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(component_kinds.net_id_to_kind(&net_id))
+        component_kinds.net_id_to_kind(&net_id)
     }
 }
 
@@ -52,28 +52,60 @@ impl ConstBitLength for ComponentKind {
 /// A map to hold all component types
 pub struct ComponentKinds {
     current_net_id: NetId,
+    name_based_net_ids: bool,
     kind_map: HashMap<ComponentKind, (NetId, Box<dyn ReplicateBuilder>)>,
     net_id_map: HashMap<NetId, ComponentKind>,
+    settings_map: HashMap<ComponentKind, ComponentSettings>,
+    layout_map: HashMap<ComponentKind, TypeLayout>,
+    explicit_net_id_map: HashMap<ComponentKind, NetId>,
 }
 
 impl ComponentKinds {
     pub fn new() -> Self {
         Self {
             current_net_id: 0,
+            name_based_net_ids: false,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            settings_map: HashMap::new(),
+            layout_map: HashMap::new(),
+            explicit_net_id_map: HashMap::new(),
         }
     }
 
     pub fn add_component<C: Replicate>(&mut self) {
+        self.add_component_with_settings::<C>(ComponentSettings::default());
+    }
+
+    pub fn add_component_with_settings<C: Replicate>(&mut self, settings: ComponentSettings) {
         let component_kind = ComponentKind::of::<C>();
+        let layout = C::type_layout();
+        if let Some(net_id) = C::explicit_net_id() {
+            self.explicit_net_id_map.insert(component_kind, net_id);
+        }
 
-        let net_id = self.current_net_id;
+        let net_id = self.next_net_id(&component_kind, &layout.name);
         self.kind_map
             .insert(component_kind, (net_id, C::create_builder()));
         self.net_id_map.insert(net_id, component_kind);
-        self.current_net_id += 1;
-        //TODO: check for current_id overflow?
+        self.settings_map.insert(component_kind, settings);
+        self.layout_map.insert(component_kind, layout);
+    }
+
+    /// Reassigns the NetId of every Component from its name, or its
+    /// `#[net_id = N]` attribute, rather than its registration order.
+    /// Components added afterwards are assigned NetIds the same way
+    pub fn use_name_based_net_ids(&mut self) {
+        self.name_based_net_ids = true;
+
+        let mut registered: Vec<(NetId, ComponentKind)> = self.net_id_map.drain().collect();
+        registered.sort_by_key(|(net_id, _)| *net_id);
+        for (_, component_kind) in registered {
+            let name = self.layout_map.get(&component_kind).unwrap().name.clone();
+            let net_id = self.next_net_id(&component_kind, &name);
+            self.kind_map.get_mut(&component_kind).unwrap().0 = net_id;
+            self.net_id_map.insert(net_id, component_kind);
+        }
     }
 
     pub fn read(
@@ -115,12 +147,52 @@ impl ComponentKinds {
         return self.kind_to_builder(component_kind).name();
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> ComponentKind {
-        return *self.net_id_map.get(net_id).expect(
+    pub fn kind_to_settings(&self, component_kind: &ComponentKind) -> &ComponentSettings {
+        return self.settings_map.get(component_kind).expect(
             "Must properly initialize Component with Protocol via `add_component()` function!",
         );
     }
 
+    /// Gets the NetId & layout of every Component type, in NetId order
+    pub fn layouts(&self) -> Vec<(NetId, &TypeLayout)> {
+        let mut layouts: Vec<(NetId, &TypeLayout)> = self
+            .kind_map
+            .iter()
+            .map(|(component_kind, (net_id, _))| {
+                (*net_id, self.layout_map.get(component_kind).unwrap())
+            })
+            .collect();
+        layouts.sort_by_key(|(net_id, _)| *net_id);
+        layouts
+    }
+
+    fn next_net_id(&mut self, component_kind: &ComponentKind, name: &str) -> NetId {
+        if !self.name_based_net_ids {
+            let net_id = self.current_net_id;
+            self.current_net_id += 1;
+            //TODO: check for current_id overflow?
+            return net_id;
+        }
+
+        let net_id = match self.explicit_net_id_map.get(component_kind) {
+            Some(net_id) => *net_id,
+            None => net_id_from_name(name),
+        };
+        if let Some(other_kind) = self.net_id_map.get(&net_id) {
+            panic!(
+                "Components `{}` & `{}` both have NetId {}, give one of them a different NetId with `#[net_id = N]`",
+                self.layout_map.get(other_kind).unwrap().name,
+                name,
+                net_id
+            );
+        }
+        net_id
+    }
+
+    fn net_id_to_kind(&self, net_id: &NetId) -> Result<ComponentKind, SerdeErr> {
+        self.net_id_map.get(net_id).copied().ok_or(SerdeErr)
+    }
+
     fn kind_to_net_id(&self, component_kind: &ComponentKind) -> NetId {
         return self
             .kind_map
@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+/// Settings which control how Components of a given kind are replicated
+#[derive(Clone, Default)]
+pub struct ComponentSettings {
+    /// The minimum duration between two updates of a Component of this kind
+    /// being sent. Changes made in-between are accumulated & sent together.
+    /// A zero duration sends changes as soon as possible.
+    pub update_interval: Duration,
+}
+
+impl ComponentSettings {
+    pub fn new(update_interval: Duration) -> Self {
+        Self { update_interval }
+    }
+}
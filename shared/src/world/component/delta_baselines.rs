@@ -0,0 +1,152 @@
+use std::{any::Any, collections::HashMap};
+
+use naia_serde::SerdeDelta;
+
+use crate::{sequence_greater_than, PacketIndex};
+
+/// The number of sequence numbers an update may reach back to find its
+/// baseline. The receiving end of a DeltaProperty keeps this many of the
+/// most recent values it has received.
+pub const DELTA_HISTORY: u16 = 32;
+
+/// Tracks the values of a Component's DeltaProperties which have been sent
+/// over a single connection, and which of those the remote host has
+/// acknowledged. Acknowledged values are used as the baselines that later
+/// updates are encoded against.
+#[derive(Default)]
+pub struct DeltaBaselines {
+    properties: HashMap<u8, Box<dyn PropertyBaselinesType>>,
+}
+
+impl DeltaBaselines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the baselines of the DeltaProperty with the given index
+    pub(crate) fn property<T: SerdeDelta + Send + Sync + 'static>(
+        &mut self,
+        index: u8,
+    ) -> &mut PropertyBaselines<T> {
+        self.properties
+            .entry(index)
+            .or_insert_with(|| Box::new(PropertyBaselines::<T>::new()))
+            .to_any_mut()
+            .downcast_mut::<PropertyBaselines<T>>()
+            .expect("DeltaProperty at index has changed type")
+    }
+
+    /// Forgets the values recorded while writing an update which was then
+    /// not sent. Call before writing each update.
+    pub fn clear_unsent(&mut self) {
+        for property in self.properties.values_mut() {
+            property.clear_unsent();
+        }
+    }
+
+    /// Records that the last update written was sent in the given packet
+    pub fn record_sent(&mut self, packet_index: PacketIndex) {
+        for property in self.properties.values_mut() {
+            property.record_sent(packet_index);
+        }
+    }
+
+    /// Promotes the values sent in the given packet to baselines
+    pub fn packet_delivered(&mut self, packet_index: PacketIndex) {
+        for property in self.properties.values_mut() {
+            property.packet_delivered(packet_index);
+        }
+    }
+
+    /// Forgets the values sent in the given packet
+    pub fn packet_dropped(&mut self, packet_index: PacketIndex) {
+        for property in self.properties.values_mut() {
+            property.packet_dropped(packet_index);
+        }
+    }
+}
+
+trait PropertyBaselinesType: Send + Sync {
+    fn clear_unsent(&mut self);
+    fn record_sent(&mut self, packet_index: PacketIndex);
+    fn packet_delivered(&mut self, packet_index: PacketIndex);
+    fn packet_dropped(&mut self, packet_index: PacketIndex);
+    fn to_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Baselines of a single DeltaProperty. Each value written is given the next
+/// sequence number, which the receiver uses to find the baseline of later
+/// updates.
+pub(crate) struct PropertyBaselines<T: SerdeDelta> {
+    next_sequence: u16,
+    written: Option<T>,
+    sent: HashMap<PacketIndex, (u16, T)>,
+    acked: Option<(u16, T)>,
+}
+
+impl<T: SerdeDelta> PropertyBaselines<T> {
+    fn new() -> Self {
+        Self {
+            next_sequence: 0,
+            written: None,
+            sent: HashMap::new(),
+            acked: None,
+        }
+    }
+
+    /// Gets the sequence number the next written value will be sent with
+    pub(crate) fn next_sequence(&self) -> u16 {
+        self.next_sequence
+    }
+
+    /// Gets the most recently acknowledged value, and how many sequence
+    /// numbers behind the next value it is, if it is recent enough to be
+    /// used as a baseline
+    pub(crate) fn baseline(&self) -> Option<(u16, &T)> {
+        let (sequence, value) = self.acked.as_ref()?;
+        let offset = self.next_sequence.wrapping_sub(*sequence);
+        if offset < DELTA_HISTORY {
+            Some((offset, value))
+        } else {
+            None
+        }
+    }
+
+    /// Records the value which has been written with the next sequence number
+    pub(crate) fn record_written(&mut self, value: &T) {
+        self.written = Some(value.clone());
+    }
+}
+
+impl<T: SerdeDelta + Send + Sync + 'static> PropertyBaselinesType for PropertyBaselines<T> {
+    fn clear_unsent(&mut self) {
+        self.written = None;
+    }
+
+    fn record_sent(&mut self, packet_index: PacketIndex) {
+        if let Some(value) = self.written.take() {
+            self.sent.insert(packet_index, (self.next_sequence, value));
+            self.next_sequence = self.next_sequence.wrapping_add(1);
+        }
+    }
+
+    fn packet_delivered(&mut self, packet_index: PacketIndex) {
+        let Some((sequence, value)) = self.sent.remove(&packet_index) else {
+            return;
+        };
+        if let Some((acked_sequence, _)) = &self.acked {
+            if !sequence_greater_than(sequence, *acked_sequence) {
+                return;
+            }
+        }
+        self.acked = Some((sequence, value));
+    }
+
+    fn packet_dropped(&mut self, packet_index: PacketIndex) {
+        self.sent.remove(&packet_index);
+    }
+
+    fn to_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
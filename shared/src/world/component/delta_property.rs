@@ -0,0 +1,485 @@
+use std::{
+    collections::VecDeque,
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+use naia_serde::{BitReader, BitWrite, BitWriter, Serde, SerdeDelta, SerdeErr, UnsignedInteger};
+
+use crate::world::component::{
+    delta_baselines::{DeltaBaselines, DELTA_HISTORY},
+    property_mutate::PropertyMutator,
+};
+
+type BaselineOffset = UnsignedInteger<5>;
+
+#[derive(Clone)]
+enum DeltaPropertyImpl<T: SerdeDelta> {
+    HostOwned(HostOwnedDeltaProperty<T>),
+    RemoteOwned(RemoteOwnedDeltaProperty<T>),
+}
+
+/// A Property of a Component, that contains data which must be tracked for
+/// updates. Unlike a [`Property`](crate::Property), updates are encoded
+/// relative to the last value the remote host acknowledged, so that only
+/// the changed parts of a large value are sent.
+#[derive(Clone)]
+pub struct DeltaProperty<T: SerdeDelta> {
+    inner: DeltaPropertyImpl<T>,
+}
+
+// should be shared
+impl<T: SerdeDelta> DeltaProperty<T> {
+    /// Create a new host-owned DeltaProperty
+    pub fn host_owned(value: T, mutator_index: u8) -> Self {
+        Self {
+            inner: DeltaPropertyImpl::HostOwned(HostOwnedDeltaProperty::new(value, mutator_index)),
+        }
+    }
+
+    /// Set an PropertyMutator to track changes to the DeltaProperty
+    pub fn set_mutator(&mut self, mutator: &PropertyMutator) {
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                inner.set_mutator(mutator);
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never have a mutator.");
+            }
+        }
+    }
+
+    /// Lets this host change a Remote DeltaProperty, tracking the changes
+    /// with the given PropertyMutator, for as long as it holds authority over
+    /// the DeltaProperty's Entity
+    pub fn remote_publish(&mut self, mutator_index: u8, mutator: &PropertyMutator) {
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(_) => {
+                panic!("Host Property should never be published.");
+            }
+            DeltaPropertyImpl::RemoteOwned(inner) => {
+                let mut new_impl = HostOwnedDeltaProperty::new(inner.inner.clone(), mutator_index);
+                new_impl.set_mutator(mutator);
+                new_impl.remote_history = mem::take(&mut inner.history);
+                self.inner = DeltaPropertyImpl::HostOwned(new_impl);
+            }
+        }
+    }
+
+    /// Returns a published DeltaProperty to being synced from the remote host
+    pub fn remote_unpublish(&mut self) {
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                let mut new_impl = RemoteOwnedDeltaProperty::new(inner.inner.clone());
+                new_impl.history = mem::take(&mut inner.remote_history);
+                self.inner = DeltaPropertyImpl::RemoteOwned(new_impl);
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be unpublished.");
+            }
+        }
+    }
+
+    // Serialization / deserialization
+
+    /// Writes the whole contained value into outgoing byte stream
+    pub fn write(&self, writer: &mut dyn BitWrite) {
+        match &self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                inner.inner.ser(writer);
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be written.");
+            }
+        }
+    }
+
+    /// Writes the contained value into outgoing byte stream, relative to the
+    /// last value acknowledged by the remote host if there is one
+    pub fn write_update(&self, delta_baselines: &mut DeltaBaselines, writer: &mut dyn BitWrite)
+    where
+        T: Send + Sync + 'static,
+    {
+        match &self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                inner.write_update(delta_baselines, writer);
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be written.");
+            }
+        }
+    }
+
+    /// Given a cursor into incoming packet data, initializes the
+    /// DeltaProperty with the synced value
+    pub fn new_read(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let inner_value = T::de(reader)?;
+
+        Ok(Self {
+            inner: DeltaPropertyImpl::RemoteOwned(RemoteOwnedDeltaProperty::new(inner_value)),
+        })
+    }
+
+    /// Reads an update from a stream and immediately writes it to a stream
+    /// Used to buffer updates for later
+    pub fn read_write(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr> {
+        u16::de(reader)?.ser(writer);
+        let has_baseline = bool::de(reader)?;
+        has_baseline.ser(writer);
+        if has_baseline {
+            BaselineOffset::de(reader)?.ser(writer);
+            T::Delta::de(reader)?.ser(writer);
+        } else {
+            T::de(reader)?.ser(writer);
+        }
+        Ok(())
+    }
+
+    /// Given a cursor into incoming packet data, updates the DeltaProperty
+    /// with the synced value
+    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                // the change of a Client holding authority over the Entity
+                inner.read(reader)?;
+            }
+            DeltaPropertyImpl::RemoteOwned(inner) => {
+                inner.read(reader)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Comparison
+
+    fn inner(&self) -> &T {
+        match &self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => &inner.inner,
+            DeltaPropertyImpl::RemoteOwned(inner) => &inner.inner,
+        }
+    }
+
+    /// Compare to another DeltaProperty
+    pub fn equals(&self, other: &Self) -> bool {
+        self.inner() == other.inner()
+    }
+
+    /// Set value to the value of another DeltaProperty, queues for update if
+    /// value changes
+    pub fn mirror(&mut self, other: &Self) {
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                let other_inner = other.inner();
+                inner.mirror(other_inner);
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be set manually.");
+            }
+        }
+    }
+}
+
+impl<T: SerdeDelta> Deref for DeltaProperty<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner()
+    }
+}
+
+impl<T: SerdeDelta> DerefMut for DeltaProperty<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Just assume inner value will be changed, queue for update
+        match &mut self.inner {
+            DeltaPropertyImpl::HostOwned(inner) => {
+                inner.mutate();
+                &mut inner.inner
+            }
+            DeltaPropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be set manually.");
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HostOwnedDeltaProperty<T: SerdeDelta> {
+    inner: T,
+    mutator: Option<PropertyMutator>,
+    mutator_index: u8,
+    // the values received while this was a Remote DeltaProperty, which the
+    // remote host may still use as baselines once it is unpublished
+    remote_history: VecDeque<(u16, T)>,
+    // the values received from the remote host holding authority over the
+    // DeltaProperty's Entity, which it uses as baselines
+    holder_history: VecDeque<(u16, T)>,
+}
+
+impl<T: SerdeDelta> HostOwnedDeltaProperty<T> {
+    /// Create a new HostOwnedDeltaProperty
+    pub fn new(value: T, mutator_index: u8) -> Self {
+        Self {
+            inner: value,
+            mutator: None,
+            mutator_index,
+            remote_history: VecDeque::new(),
+            holder_history: VecDeque::new(),
+        }
+    }
+
+    pub fn set_mutator(&mut self, mutator: &PropertyMutator) {
+        self.mutator = Some(mutator.clone_new());
+    }
+
+    /// Reads a change from the Client holding authority, which is then sent
+    /// on to other Users
+    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.inner = read_update(&mut self.holder_history, reader)?;
+        self.mutate();
+        Ok(())
+    }
+
+    pub fn write_update(&self, delta_baselines: &mut DeltaBaselines, writer: &mut dyn BitWrite)
+    where
+        T: Send + Sync + 'static,
+    {
+        let baselines = delta_baselines.property::<T>(self.mutator_index);
+
+        baselines.next_sequence().ser(writer);
+        if let Some((offset, baseline)) = baselines.baseline() {
+            true.ser(writer);
+            BaselineOffset::new(offset).ser(writer);
+            self.inner.delta(baseline).ser(writer);
+        } else {
+            false.ser(writer);
+            self.inner.ser(writer);
+        }
+
+        baselines.record_written(&self.inner);
+    }
+
+    pub fn mirror(&mut self, other: &T) {
+        self.mutate();
+        self.inner = other.clone();
+    }
+
+    pub fn mutate(&mut self) {
+        if let Some(mutator) = &mut self.mutator {
+            mutator.mutate(self.mutator_index);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoteOwnedDeltaProperty<T: SerdeDelta> {
+    inner: T,
+    history: VecDeque<(u16, T)>,
+}
+
+impl<T: SerdeDelta> RemoteOwnedDeltaProperty<T> {
+    /// Create a new RemoteOwnedDeltaProperty
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: value,
+            history: VecDeque::new(),
+        }
+    }
+
+    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.inner = read_update(&mut self.history, reader)?;
+        Ok(())
+    }
+}
+
+/// Reads an update written relative to one of the values previously received
+/// from the same host, and records the value it holds
+fn read_update<T: SerdeDelta>(
+    history: &mut VecDeque<(u16, T)>,
+    reader: &mut BitReader,
+) -> Result<T, SerdeErr> {
+    let sequence = u16::de(reader)?;
+    let value = if bool::de(reader)? {
+        let offset = BaselineOffset::de(reader)?.get() as u16;
+        let baseline_sequence = sequence.wrapping_sub(offset);
+        let delta = T::Delta::de(reader)?;
+        let Some((_, baseline)) = history
+            .iter()
+            .find(|(history_sequence, _)| *history_sequence == baseline_sequence)
+        else {
+            return Err(SerdeErr);
+        };
+        T::apply_delta(baseline, delta)?
+    } else {
+        T::de(reader)?
+    };
+
+    // a host's sequences restart each time it is given authority, so a value
+    // left over from an earlier grant must never be taken as a baseline
+    history.retain(|(history_sequence, _)| *history_sequence != sequence);
+
+    // keep enough history to find the baseline of any later update
+    if history.len() >= DELTA_HISTORY as usize {
+        history.pop_front();
+    }
+    history.push_back((sequence, value.clone()));
+
+    Ok(value)
+}
+
+// Tests
+#[cfg(test)]
+mod delta_property_tests {
+    use naia_serde::{BitReader, BitWriter};
+
+    use super::DeltaProperty;
+    use crate::world::component::delta_baselines::DeltaBaselines;
+
+    fn send(
+        host: &DeltaProperty<Vec<u32>>,
+        baselines: &mut DeltaBaselines,
+        packet_index: u16,
+    ) -> (u32, Box<[u8]>) {
+        let mut writer = BitWriter::new();
+
+        // count the update before writing it, as the HostWorldWriter does
+        let mut counter = writer.counter();
+        baselines.clear_unsent();
+        host.write_update(baselines, &mut counter);
+
+        baselines.clear_unsent();
+        host.write_update(baselines, &mut writer);
+        baselines.record_sent(packet_index);
+
+        (counter.bits_needed(), writer.to_bytes())
+    }
+
+    fn receive(remote: &mut DeltaProperty<Vec<u32>>, bytes: &[u8]) {
+        let mut reader = BitReader::new(bytes);
+        remote.read(&mut reader).unwrap();
+    }
+
+    fn remote(host: &DeltaProperty<Vec<u32>>) -> DeltaProperty<Vec<u32>> {
+        let mut writer = BitWriter::new();
+        host.write(&mut writer);
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+        DeltaProperty::new_read(&mut reader).unwrap()
+    }
+
+    #[test]
+    fn acknowledged_value_is_used_as_baseline() {
+        let mut host = DeltaProperty::host_owned(vec![0; 64], 0);
+        let mut remote = remote(&host);
+        let mut baselines = DeltaBaselines::new();
+
+        host[3] = 7;
+        let (full_bits, bytes) = send(&host, &mut baselines, 0);
+        receive(&mut remote, &bytes);
+        baselines.packet_delivered(0);
+
+        host[5] = 9;
+        let (delta_bits, bytes) = send(&host, &mut baselines, 1);
+        receive(&mut remote, &bytes);
+
+        assert!(delta_bits < full_bits);
+        assert_eq!(*remote, *host);
+    }
+
+    #[test]
+    fn unacknowledged_value_is_not_used_as_baseline() {
+        let mut host = DeltaProperty::host_owned(vec![0; 8], 0);
+        let mut remote = remote(&host);
+        let mut baselines = DeltaBaselines::new();
+
+        host[0] = 1;
+        let (_, bytes) = send(&host, &mut baselines, 0);
+        receive(&mut remote, &bytes);
+        baselines.packet_delivered(0);
+
+        // this update is lost
+        host[1] = 2;
+        send(&host, &mut baselines, 1);
+        baselines.packet_dropped(1);
+
+        host[2] = 3;
+        let (_, bytes) = send(&host, &mut baselines, 2);
+        receive(&mut remote, &bytes);
+
+        assert_eq!(*remote, vec![1, 2, 3, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn missing_baseline_is_an_error() {
+        let mut host = DeltaProperty::host_owned(vec![0; 8], 0);
+        let mut remote = remote(&host);
+        let mut baselines = DeltaBaselines::new();
+
+        host[0] = 1;
+        send(&host, &mut baselines, 0);
+        baselines.packet_delivered(0);
+
+        let mut writer = BitWriter::new();
+        baselines.clear_unsent();
+        host.write_update(&mut baselines, &mut writer);
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        assert!(remote.read(&mut reader).is_err());
+    }
+
+    #[test]
+    fn holder_update_is_read_against_its_baselines() {
+        let mut server = DeltaProperty::host_owned(vec![0; 64], 0);
+
+        // the copy of the Client holding authority over the Entity
+        let mut holder = DeltaProperty::host_owned(vec![0; 64], 0);
+        let mut holder_baselines = DeltaBaselines::new();
+
+        holder[3] = 7;
+        let (_, bytes) = send(&holder, &mut holder_baselines, 0);
+        receive(&mut server, &bytes);
+        holder_baselines.packet_delivered(0);
+
+        holder[5] = 9;
+        let (delta_bits, bytes) = send(&holder, &mut holder_baselines, 1);
+        receive(&mut server, &bytes);
+
+        assert!(delta_bits < 64 * 32);
+        assert_eq!(*server, *holder);
+
+        // authority is granted again, and the holder's sequences restart
+        let mut holder_baselines = DeltaBaselines::new();
+        holder[7] = 11;
+        let (_, bytes) = send(&holder, &mut holder_baselines, 2);
+        receive(&mut server, &bytes);
+        holder_baselines.packet_delivered(2);
+
+        holder[9] = 13;
+        let (_, bytes) = send(&holder, &mut holder_baselines, 3);
+        receive(&mut server, &bytes);
+
+        assert_eq!(*server, *holder);
+    }
+
+    #[test]
+    fn buffered_update_reads_the_same() {
+        let mut host = DeltaProperty::host_owned(vec![0; 8], 0);
+        let mut remote = remote(&host);
+        let mut baselines = DeltaBaselines::new();
+
+        host[0] = 1;
+        let (_, bytes) = send(&host, &mut baselines, 0);
+        receive(&mut remote, &bytes);
+        baselines.packet_delivered(0);
+
+        host[4] = 5;
+        let (_, bytes) = send(&host, &mut baselines, 1);
+        let mut reader = BitReader::new(&bytes);
+        let mut buffer_writer = BitWriter::new();
+        DeltaProperty::<Vec<u32>>::read_write(&mut reader, &mut buffer_writer).unwrap();
+        let buffer_reader = buffer_writer.to_owned_reader();
+        remote.read(&mut buffer_reader.borrow()).unwrap();
+
+        assert_eq!(*remote, *host);
+    }
+}
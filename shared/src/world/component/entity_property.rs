@@ -142,7 +142,9 @@ impl EntityProperty {
         converter: &dyn LocalEntityAndGlobalEntityConverter,
     ) -> Result<(), SerdeErr> {
         if self.inner.is_host_owned() {
-            panic!("HostOwned EntityProperty should never read.");
+            // this host owns the EntityProperty, so the remote host never
+            // writes updates to it, even while holding authority
+            return Err(SerdeErr);
         }
         let exists = bool::de(reader)?;
         let new_inner = {
@@ -226,20 +228,21 @@ impl EntityProperty {
 
     pub fn waiting_complete(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter) {
         match &mut self.inner {
-            EntityRelation::HostOwned(_) | EntityRelation::RemoteOwned(_) => {
-                panic!("Can't complete a RemoteOwned or HostOwned Relation!");
+            EntityRelation::HostOwned(_) => {
+                panic!("Can't complete a HostOwned Relation!");
+            }
+            EntityRelation::RemoteOwned(_) => {
+                // this Relation wasn't one being waited on
             }
             EntityRelation::RemoteWaiting(inner) => {
-                if let Ok(global_entity) =
-                    converter.local_entity_to_global_entity(&inner.local_entity)
-                {
-                    let mut new_impl = RemoteOwnedRelation::new();
-                    new_impl.global_entity = Some(global_entity);
+                // the Entity may have been despawned again since it was
+                // spawned, in which case the Relation is left empty
+                let mut new_impl = RemoteOwnedRelation::new();
+                new_impl.global_entity = converter
+                    .local_entity_to_global_entity(&inner.local_entity)
+                    .ok();
 
-                    self.inner = EntityRelation::RemoteOwned(new_impl);
-                } else {
-                    panic!("Could not find Global Entity from Local Entity! Should only call `waiting_complete` when it is known the converter will not fail!");
-                }
+                self.inner = EntityRelation::RemoteOwned(new_impl);
             }
         }
     }
@@ -1,5 +1,8 @@
 pub mod component_kinds;
+pub mod component_settings;
 pub mod component_update;
+pub mod delta_baselines;
+pub mod delta_property;
 pub mod diff_mask;
 pub mod entity_property;
 pub mod property;
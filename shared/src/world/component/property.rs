@@ -38,6 +38,35 @@ impl<T: Serde> Property<T> {
         }
     }
 
+    /// Lets this host change a Remote Property, tracking the changes with
+    /// the given PropertyMutator, for as long as it holds authority over
+    /// the Property's Entity
+    pub fn remote_publish(&mut self, mutator_index: u8, mutator: &PropertyMutator) {
+        match &mut self.inner {
+            PropertyImpl::HostOwned(_) => {
+                panic!("Host Property should never be published.");
+            }
+            PropertyImpl::RemoteOwned(inner) => {
+                let mut new_impl = HostOwnedProperty::new(inner.inner.clone(), mutator_index);
+                new_impl.set_mutator(mutator);
+                self.inner = PropertyImpl::HostOwned(new_impl);
+            }
+        }
+    }
+
+    /// Returns a published Property to being synced from the remote host
+    pub fn remote_unpublish(&mut self) {
+        match &mut self.inner {
+            PropertyImpl::HostOwned(inner) => {
+                let new_impl = RemoteOwnedProperty::new(inner.inner.clone());
+                self.inner = PropertyImpl::RemoteOwned(new_impl);
+            }
+            PropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never be unpublished.");
+            }
+        }
+    }
+
     // Serialization / deserialization
 
     /// Writes contained value into outgoing byte stream
@@ -73,8 +102,9 @@ impl<T: Serde> Property<T> {
     /// synced value
     pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
         match &mut self.inner {
-            PropertyImpl::HostOwned(_) => {
-                panic!("Host Property should never read.");
+            PropertyImpl::HostOwned(inner) => {
+                // the change of a Client holding authority over the Entity
+                inner.read(reader)?;
             }
             PropertyImpl::RemoteOwned(inner) => {
                 inner.read(reader)?;
@@ -166,6 +196,12 @@ impl<T: Serde> HostOwnedProperty<T> {
         self.inner.ser(writer);
     }
 
+    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.inner = Property::read_inner(reader)?;
+        self.mutate();
+        Ok(())
+    }
+
     pub fn mirror(&mut self, other: &T) {
         self.mutate();
         self.inner = other.clone();
@@ -8,13 +8,14 @@ use crate::{
         component::{
             component_kinds::{ComponentKind, ComponentKinds},
             component_update::ComponentUpdate,
+            delta_baselines::DeltaBaselines,
             diff_mask::DiffMask,
             property_mutate::PropertyMutator,
             replica_ref::{ReplicaDynMut, ReplicaDynRef},
         },
         entity::entity_converters::LocalEntityAndGlobalEntityConverter,
     },
-    ComponentFieldUpdate, LocalEntity, LocalEntityAndGlobalEntityConverterMut,
+    ComponentFieldUpdate, LocalEntity, LocalEntityAndGlobalEntityConverterMut, TypeLayout,
 };
 
 pub trait ReplicateBuilder: Send + Sync + Named {
@@ -51,6 +52,15 @@ pub trait Replicate: ReplicateInner + Named + Any {
     fn to_boxed_any(self: Box<Self>) -> Box<dyn Any>;
     fn copy_to_box(&self) -> Box<dyn Replicate>;
     fn create_builder() -> Box<dyn ReplicateBuilder>
+    where
+        Self: Sized;
+    /// Describes the type & the Properties it replicates, used to check that
+    /// the Client & Server Protocols match
+    fn type_layout() -> TypeLayout
+    where
+        Self: Sized;
+    /// Gets the NetId given by the type's `#[net_id = N]` attribute, if any
+    fn explicit_net_id() -> Option<u16>
     where
         Self: Sized;
     /// Gets the number of bytes of the Component's DiffMask
@@ -66,6 +76,14 @@ pub trait Replicate: ReplicateInner + Named + Any {
     /// of which Properties have been mutated, necessary to sync only the
     /// Properties that have changed with the client
     fn set_mutator(&mut self, mutator: &PropertyMutator);
+    /// Lets this host change the Properties of a Component it received, once
+    /// it holds authority over the Component's Entity. Changes are tracked
+    /// with the given PropertyMutator. EntityProperties are left unchanged,
+    /// and can still only be set by the remote host.
+    fn publish(&mut self, mutator: &PropertyMutator);
+    /// Returns the Properties of a published Component to being synced from
+    /// the remote host
+    fn unpublish(&mut self);
     /// Writes data into an outgoing byte stream, sufficient to completely
     /// recreate the Component on the client
     fn write(
@@ -75,12 +93,14 @@ pub trait Replicate: ReplicateInner + Named + Any {
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
     );
     /// Write data into an outgoing byte stream, sufficient only to update the
-    /// mutated Properties of the Component on the client
+    /// mutated Properties of the Component on the client. DeltaProperties
+    /// are written relative to the given baselines.
     fn write_update(
         &self,
         diff_mask: &DiffMask,
         writer: &mut dyn BitWrite,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+        delta_baselines: &mut DeltaBaselines,
     );
     /// Reads data from an incoming packet, sufficient to sync the in-memory
     /// Component with it's replica on the Server
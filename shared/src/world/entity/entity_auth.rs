@@ -0,0 +1,59 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+use naia_serde::SerdeInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// The status of a Client's authority over a Server Entity
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EntityAuthStatus {
+    /// No Client has authority over the Entity, it may be requested
+    Available,
+    /// Authority over the Entity has been requested, and the Server has not
+    /// yet responded
+    Requested,
+    /// A Client has been granted authority over the Entity
+    Granted,
+    /// A request for authority over the Entity was denied by the Server
+    Denied,
+}
+
+// Enum used as a shared network protocol, representing the steps of
+// transferring authority over an Entity between the Server & a Client
+#[derive(Copy, Clone, PartialEq, Eq, SerdeInternal)]
+pub enum EntityAuthAction {
+    // Client requests authority over an Entity
+    Request,
+    // Client gives up authority over an Entity
+    Release,
+    // Server grants authority over an Entity to the Client
+    Grant,
+    // Server denies the Client's request for authority over an Entity
+    Deny,
+    // Server takes back authority over an Entity from the Client
+    Revoke,
+}
+
+/// Message sent through the [`SystemChannel`](crate::SystemChannel) to
+/// transfer authority over an Entity
+#[derive(MessageInternal)]
+pub struct EntityAuthMessage {
+    pub entity: EntityProperty,
+    pub action: EntityAuthAction,
+}
+
+impl EntityAuthMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        action: EntityAuthAction,
+    ) -> Self {
+        let mut message = Self {
+            entity: EntityProperty::new(),
+            action,
+        };
+        message.entity.set(converter, entity);
+        message
+    }
+}
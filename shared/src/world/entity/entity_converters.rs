@@ -1,6 +1,7 @@
 use std::{
     hash::Hash,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use crate::{
@@ -18,6 +19,9 @@ pub trait GlobalWorldManagerType<E: Copy + Eq + Hash>: EntityAndGlobalEntityConv
     fn component_kinds(&self, entity: &E) -> Option<Vec<ComponentKind>>;
     fn to_global_entity_converter(&self) -> &dyn EntityAndGlobalEntityConverter<E>;
     fn entity_can_relate_to_user(&self, entity: &E, user_key: &u64) -> bool;
+    fn entity_is_delegated_to_user(&self, entity: &E, user_key: &u64) -> bool;
+    fn entity_priority(&self, entity: &E) -> f32;
+    fn entity_update_interval(&self, entity: &E) -> Option<Duration>;
     fn new_mut_channel(&self, diff_mask_length: u8) -> Arc<RwLock<dyn MutChannelType>>;
     fn diff_handler(&self) -> Arc<RwLock<GlobalDiffHandler<E>>>;
     fn remote_spawn_entity(&mut self, entity: &E, user_key: &u64);
@@ -191,7 +195,8 @@ impl<'a, 'b, E: Copy + Eq + Hash> LocalEntityAndGlobalEntityConverterMut
     ) -> Result<LocalEntity, EntityDoesNotExistError> {
         let Ok(entity) = self
             .global_world_manager
-            .global_entity_to_entity(global_entity) else {
+            .global_entity_to_entity(global_entity)
+        else {
             return Err(EntityDoesNotExistError);
         };
         if !self
@@ -1,11 +1,12 @@
 use std::hash::Hash;
 
-use crate::{ReplicaRefWrapper, Replicate, WorldRefType};
+use crate::{EntityAuthStatus, ReplicaRefWrapper, Replicate, WorldRefType};
 
 // EntityRef
 pub struct EntityRef<E: Copy + Eq + Hash, W: WorldRefType<E>> {
     world: W,
     entity: E,
+    auth_status: Option<EntityAuthStatus>,
 }
 
 impl<E: Copy + Eq + Hash, W: WorldRefType<E>> EntityRef<E, W> {
@@ -13,6 +14,15 @@ impl<E: Copy + Eq + Hash, W: WorldRefType<E>> EntityRef<E, W> {
         EntityRef {
             world,
             entity: *entity,
+            auth_status: None,
+        }
+    }
+
+    pub fn with_auth_status(world: W, entity: &E, auth_status: Option<EntityAuthStatus>) -> Self {
+        EntityRef {
+            world,
+            entity: *entity,
+            auth_status,
         }
     }
 
@@ -27,4 +37,10 @@ impl<E: Copy + Eq + Hash, W: WorldRefType<E>> EntityRef<E, W> {
     pub fn component<R: Replicate>(&self) -> Option<ReplicaRefWrapper<R>> {
         self.world.component::<R>(&self.entity)
     }
+
+    /// Gets the status of authority over the Entity. Returns None if the
+    /// Entity is not a Server Entity whose authority can be transferred.
+    pub fn auth_status(&self) -> Option<EntityAuthStatus> {
+        self.auth_status
+    }
 }
@@ -1,6 +1,7 @@
 pub mod entity_action;
 pub mod entity_action_receiver;
 pub mod entity_action_type;
+pub mod entity_auth;
 pub mod entity_converters;
 pub mod entity_ref;
 pub mod error;
@@ -0,0 +1,77 @@
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+use crate::GlobalWorldManagerType;
+
+/// Tracks the update priority of each Entity for a single connection.
+///
+/// Every tick, each Entity with pending updates accumulates its priority
+/// gain (the global gain multiplied by the gain for this connection). Entities
+/// are written in order of accumulated priority, and an Entity's
+/// accumulated priority is reset once all of its updates have been written,
+/// so Entities which are skipped grow in priority until they are sent.
+pub struct EntityPriorities<E: Copy + Eq + Hash> {
+    gains: HashMap<E, f32>,
+    accumulated: HashMap<E, f32>,
+}
+
+impl<E: Copy + Eq + Hash> EntityPriorities<E> {
+    pub fn new() -> Self {
+        Self {
+            gains: HashMap::new(),
+            accumulated: HashMap::new(),
+        }
+    }
+
+    /// Gets the priority gain of the Entity for this connection
+    pub fn gain(&self, entity: &E) -> f32 {
+        self.gains.get(entity).copied().unwrap_or(1.0)
+    }
+
+    /// Sets the priority gain of the Entity for this connection, which
+    /// multiplies the Entity's global priority gain
+    pub fn set_gain(&mut self, entity: &E, gain: f32) {
+        self.gains.insert(*entity, gain);
+    }
+
+    /// Gets the priority the Entity has accumulated so far
+    pub fn accumulated(&self, entity: &E) -> f32 {
+        self.accumulated.get(entity).copied().unwrap_or(0.0)
+    }
+
+    /// Accumulates priority for each of the given Entities with pending
+    /// updates, and forgets any Entity without pending updates
+    pub fn accumulate<'a>(
+        &mut self,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        pending_entities: impl Iterator<Item = &'a E>,
+    ) where
+        E: 'a,
+    {
+        let mut next_accumulated = HashMap::new();
+        for entity in pending_entities {
+            let gain = global_world_manager.entity_priority(entity) * self.gain(entity);
+            next_accumulated.insert(*entity, self.accumulated(entity) + gain);
+        }
+        self.accumulated = next_accumulated;
+    }
+
+    /// Sorts the given Entities from highest to lowest accumulated priority
+    pub fn sort(&self, entities: &mut [E]) {
+        entities.sort_by(|a, b| {
+            self.accumulated(b)
+                .partial_cmp(&self.accumulated(a))
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Resets the accumulated priority of the Entity, after it has been sent
+    pub fn reset(&mut self, entity: &E) {
+        self.accumulated.remove(entity);
+    }
+
+    /// Forgets all priority information about the Entity
+    pub fn remove(&mut self, entity: &E) {
+        self.gains.remove(entity);
+        self.accumulated.remove(entity);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, hash::Hash, net::SocketAddr};
+use std::{collections::HashMap, hash::Hash};
 
 use crate::{ComponentKind, GlobalWorldManagerType};
 
@@ -44,13 +44,20 @@ impl<E: Copy + Eq + Hash> GlobalDiffHandler<E> {
 
     pub fn receiver(
         &self,
-        address: &Option<SocketAddr>,
+        user_key: &Option<u64>,
         entity: &E,
         component_kind: &ComponentKind,
     ) -> Option<MutReceiver> {
         if let Some(builder) = self.mut_receiver_builders.get(&(*entity, *component_kind)) {
-            return builder.build(address);
+            return builder.build(user_key);
         }
         None
     }
+
+    /// Removes the receivers of a User which has been deleted
+    pub fn remove_user(&mut self, user_key: &u64) {
+        for builder in self.mut_receiver_builders.values() {
+            builder.remove(user_key);
+        }
+    }
 }
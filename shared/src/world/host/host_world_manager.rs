@@ -2,7 +2,6 @@ use std::{
     clone::Clone,
     collections::{HashMap, HashSet, VecDeque},
     hash::Hash,
-    net::SocketAddr,
     time::Duration,
 };
 
@@ -11,10 +10,14 @@ use crate::{
     world::{
         entity::entity_converters::GlobalWorldManagerType, local_world_manager::LocalWorldManager,
     },
-    ComponentKind, DiffMask, EntityAction, Instant, MessageIndex, PacketIndex,
+    ComponentKind, ComponentKinds, DeltaBaselines, DiffMask, EntityAction, Instant, MessageIndex,
+    PacketIndex,
 };
 
-use super::{entity_action_event::EntityActionEvent, world_channel::WorldChannel};
+use super::{
+    entity_action_event::EntityActionEvent, entity_priority::EntityPriorities,
+    world_channel::WorldChannel,
+};
 
 const DROP_UPDATE_RTT_FACTOR: f32 = 1.5;
 const ACTION_RECORD_TTL: Duration = Duration::from_secs(60);
@@ -35,33 +38,55 @@ pub struct HostWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     pub sent_updates: HashMap<PacketIndex, (Instant, HashMap<(E, ComponentKind), DiffMask>)>,
     /// Last [`PacketIndex`] where a component update was written by the server
     pub last_update_packet_index: PacketIndex,
+    /// Priority of each Entity's updates
+    pub entity_priorities: EntityPriorities<E>,
+    /// Values of each Component's DeltaProperties sent to & acknowledged by
+    /// the remote host, which later updates are encoded against
+    pub delta_baselines: HashMap<(E, ComponentKind), DeltaBaselines>,
+    /// Maximum number of bits of updates to write each tick
+    update_bits_per_tick: Option<u32>,
 }
 
 pub struct HostWorldEvents<E: Copy + Eq + Hash + Send + Sync> {
     pub next_send_actions: VecDeque<(ActionId, EntityActionEvent<E>)>,
     pub next_send_updates: HashMap<E, HashSet<ComponentKind>>,
+    /// Remaining bits of updates which can be written this tick,
+    /// None if unlimited
+    pub update_bits_remaining: Option<u32>,
+    /// Whether any update has been written this tick
+    pub has_written_updates: bool,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> HostWorldEvents<E> {
     pub fn has_events(&self) -> bool {
-        !self.next_send_actions.is_empty() || !self.next_send_updates.is_empty()
+        !self.next_send_actions.is_empty() || self.has_sendable_updates()
+    }
+
+    /// Whether there are updates left which fit in this tick's budget
+    pub fn has_sendable_updates(&self) -> bool {
+        !self.next_send_updates.is_empty() && self.update_bits_remaining != Some(0)
     }
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
-    /// Create a new HostWorldManager, given the client's address
+    /// Create a new HostWorldManager, given the key of the User it sends to,
+    /// if any
     pub fn new(
-        address: &Option<SocketAddr>,
+        user_key: &Option<u64>,
+        update_bytes_per_tick: Option<u32>,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
         HostWorldManager {
             // World
-            world_channel: WorldChannel::new(address, global_world_manager),
+            world_channel: WorldChannel::new(user_key, global_world_manager),
             sent_action_packets: SequenceList::new(),
 
             // Update
             sent_updates: HashMap::new(),
             last_update_packet_index: 0,
+            entity_priorities: EntityPriorities::new(),
+            delta_baselines: HashMap::new(),
+            update_bits_per_tick: update_bytes_per_tick.map(|bytes| bytes * 8),
         }
     }
 
@@ -88,6 +113,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
 
     pub fn despawn_entity(&mut self, entity: &E) {
         self.world_channel.host_despawn_entity(entity);
+        self.entity_priorities.remove(entity);
+        self.delta_baselines
+            .retain(|(baseline_entity, _), _| baseline_entity != entity);
     }
 
     pub fn insert_component(&mut self, entity: &E, component_kind: &ComponentKind) {
@@ -98,6 +126,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
     pub fn remove_component(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.world_channel
             .host_remove_component(entity, component_kind);
+        self.delta_baselines.remove(&(*entity, *component_kind));
+    }
+
+    /// Starts sending the changes to a Component of a remote Entity this host
+    /// has been given authority over
+    pub fn delegate_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.world_channel
+            .host_delegate_component(entity, component_kind);
+    }
+
+    pub fn undelegate_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.world_channel
+            .host_undelegate_component(entity, component_kind);
+        self.delta_baselines.remove(&(*entity, *component_kind));
+    }
+
+    /// Stops sending the changes to a remote Entity once this host no longer
+    /// holds authority over it
+    pub fn undelegate_entity(&mut self, entity: &E) {
+        self.world_channel.host_undelegate_entity(entity);
+        self.entity_priorities.remove(entity);
+        self.delta_baselines
+            .retain(|(baseline_entity, _), _| baseline_entity != entity);
+    }
+
+    pub fn delegated_entities(&self) -> Vec<E> {
+        self.world_channel.delegated_entities()
     }
 
     pub fn host_has_entity(&self, entity: &E) -> bool {
@@ -156,6 +211,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
     fn dropped_update_cleanup(&mut self, dropped_packet_index: PacketIndex) {
         if let Some((_, diff_mask_map)) = self.sent_updates.remove(&dropped_packet_index) {
             for (component_index, diff_mask) in &diff_mask_map {
+                if let Some(delta_baselines) = self.delta_baselines.get_mut(component_index) {
+                    delta_baselines.packet_dropped(dropped_packet_index);
+                }
+
                 let (entity, component) = component_index;
                 if !self
                     .world_channel
@@ -189,10 +248,29 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
         }
     }
 
-    pub fn take_outgoing_events(&mut self, now: &Instant, rtt_millis: &f32) -> HostWorldEvents<E> {
+    pub fn take_outgoing_events(
+        &mut self,
+        now: &Instant,
+        rtt_millis: &f32,
+        component_kinds: &ComponentKinds,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        user_key: &u64,
+    ) -> HostWorldEvents<E> {
+        let next_send_updates = self.world_channel.collect_next_updates(
+            component_kinds,
+            global_world_manager,
+            user_key,
+        );
+
+        // entities still waiting to send updates gain priority
+        self.entity_priorities
+            .accumulate(global_world_manager, next_send_updates.keys());
+
         HostWorldEvents {
             next_send_actions: self.world_channel.take_next_actions(now, rtt_millis),
-            next_send_updates: self.world_channel.collect_next_updates(),
+            next_send_updates,
+            update_bits_remaining: self.update_bits_per_tick,
+            has_written_updates: false,
         }
     }
 }
@@ -204,7 +282,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
         local_world_manager: &mut LocalWorldManager<E>,
     ) {
         // Updates
-        self.sent_updates.remove(&packet_index);
+        if let Some((_, diff_mask_map)) = self.sent_updates.remove(&packet_index) {
+            for component_index in diff_mask_map.keys() {
+                if let Some(delta_baselines) = self.delta_baselines.get_mut(component_index) {
+                    delta_baselines.packet_delivered(packet_index);
+                }
+            }
+        }
 
         // Actions
         if let Some((_, action_list)) = self
@@ -55,7 +55,7 @@ impl HostWorldWriter {
                 local_world_manager,
                 has_written,
                 host_manager,
-                &mut world_events.next_send_updates,
+                world_events,
             );
 
             // finish updates
@@ -405,33 +405,59 @@ impl HostWorldWriter {
         local_world_manager: &mut LocalWorldManager<E>,
         has_written: &mut bool,
         host_manager: &mut HostWorldManager<E>,
-        next_send_updates: &mut HashMap<E, HashSet<ComponentKind>>,
+        world_events: &mut HostWorldEvents<E>,
     ) {
-        let all_update_entities: Vec<E> = next_send_updates.keys().copied().collect();
+        // write updates for the highest priority entities first
+        let mut all_update_entities: Vec<E> =
+            world_events.next_send_updates.keys().copied().collect();
+        host_manager
+            .entity_priorities
+            .sort(&mut all_update_entities);
 
         for entity in all_update_entities {
-            // check that we can at least write a LocalEntity and a ComponentContinue bit
-            let mut counter = writer.counter();
-
             // get LocalEntity
             let local_entity = local_world_manager.entity_to_local_entity(&entity).unwrap();
 
+            // check that the entity's updates fit in what's left of this tick's budget,
+            // always allowing the first so that large updates can't be starved
+            if let Some(bits_remaining) = world_events.update_bits_remaining {
+                let bits_needed = Self::update_bit_length(
+                    world,
+                    global_world_manager,
+                    local_world_manager,
+                    writer,
+                    &entity,
+                    host_manager,
+                    &world_events.next_send_updates,
+                );
+                if bits_needed > bits_remaining && world_events.has_written_updates {
+                    world_events.update_bits_remaining = Some(0);
+                    break;
+                }
+            }
+
+            // check that we can at least write a LocalEntity and a ComponentContinue bit
+            let mut counter = writer.counter();
+
             // write LocalEntity
-            local_entity.host_ser(&mut counter);
+            local_entity.owned_ser(&mut counter);
             counter.write_bit(false);
 
             if counter.overflowed() {
                 break;
             }
 
+            let bits_free_before = writer.bits_free();
+
             // write UpdateContinue bit
             true.ser(writer);
 
             // reserve ComponentContinue bit
             writer.reserve_bits(1);
 
-            // write LocalEntity
-            local_entity.host_ser(writer);
+            // write LocalEntity, which is remote if this host holds authority
+            // over it
+            local_entity.owned_ser(writer);
 
             // write Components
             Self::write_update(
@@ -445,13 +471,68 @@ impl HostWorldWriter {
                 &entity,
                 has_written,
                 host_manager,
-                next_send_updates,
+                &mut world_events.next_send_updates,
             );
 
             // write ComponentContinue finish bit, release
             false.ser(writer);
             writer.release_bits(1);
+
+            // charge the written bits against this tick's budget
+            world_events.has_written_updates = true;
+            if let Some(bits_remaining) = &mut world_events.update_bits_remaining {
+                let bits_written = bits_free_before - writer.bits_free();
+                *bits_remaining = bits_remaining.saturating_sub(bits_written);
+            }
+        }
+    }
+
+    /// Counts the number of bits needed to write all of an entity's pending updates
+    fn update_bit_length<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>>(
+        world: &W,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        writer: &BitWriter,
+        entity: &E,
+        host_manager: &mut HostWorldManager<E>,
+        next_send_updates: &HashMap<E, HashSet<ComponentKind>>,
+    ) -> u32 {
+        let mut counter = writer.counter();
+
+        // UpdateContinue bit, LocalEntity, and ComponentContinue finish bit
+        counter.write_bit(true);
+        local_world_manager
+            .entity_to_local_entity(entity)
+            .unwrap()
+            .owned_ser(&mut counter);
+        counter.write_bit(false);
+
+        for component_kind in next_send_updates.get(entity).unwrap() {
+            let diff_mask = host_manager
+                .world_channel
+                .diff_handler
+                .diff_mask(entity, component_kind)
+                .expect("DiffHandler does not have registered Component!")
+                .clone();
+
+            let mut converter = EntityConverterMut::new(global_world_manager, local_world_manager);
+
+            let delta_baselines = host_manager
+                .delta_baselines
+                .entry((*entity, *component_kind))
+                .or_default();
+            delta_baselines.clear_unsent();
+
+            // ComponentContinue bit, ComponentKind, and update
+            counter.write_bit(true);
+            counter.write_bits(<ComponentKind as ConstBitLength>::const_bit_length());
+            world
+                .component_of_kind(entity, component_kind)
+                .expect("Component does not exist in World")
+                .write_update(&diff_mask, &mut counter, &mut converter, delta_baselines);
         }
+
+        counter.bits_needed()
     }
 
     /// For a given entity, write component value updates into a packet
@@ -482,13 +563,19 @@ impl HostWorldWriter {
 
             let mut converter = EntityConverterMut::new(global_world_manager, local_world_manager);
 
+            let delta_baselines = host_manager
+                .delta_baselines
+                .entry((*entity, *component_kind))
+                .or_default();
+            delta_baselines.clear_unsent();
+
             // check that we can write the next component update
             let mut counter = writer.counter();
             counter.write_bits(<ComponentKind as ConstBitLength>::const_bit_length());
             world
                 .component_of_kind(entity, component_kind)
                 .expect("Component does not exist in World")
-                .write_update(&diff_mask, &mut counter, &mut converter);
+                .write_update(&diff_mask, &mut counter, &mut converter, delta_baselines);
 
             if counter.overflowed() {
                 // if nothing useful has been written in this packet yet,
@@ -514,10 +601,12 @@ impl HostWorldWriter {
             component_kind.ser(component_kinds, writer);
 
             // write data
+            delta_baselines.clear_unsent();
             world
                 .component_of_kind(entity, component_kind)
                 .expect("Component does not exist in World")
-                .write_update(&diff_mask, writer, &mut converter);
+                .write_update(&diff_mask, writer, &mut converter, delta_baselines);
+            delta_baselines.record_sent(*packet_index);
 
             written_component_kinds.push(*component_kind);
 
@@ -537,6 +626,10 @@ impl HostWorldWriter {
                 .world_channel
                 .diff_handler
                 .clear_diff_mask(entity, component_kind);
+            host_manager
+                .world_channel
+                .diff_handler
+                .record_update_sent(entity, component_kind, now);
         }
 
         let update_kinds = next_send_updates.get_mut(entity).unwrap();
@@ -545,6 +638,7 @@ impl HostWorldWriter {
         }
         if update_kinds.is_empty() {
             next_send_updates.remove(entity);
+            host_manager.entity_priorities.reset(entity);
         }
     }
 
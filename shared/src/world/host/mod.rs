@@ -1,3 +1,4 @@
+pub mod entity_priority;
 pub mod global_diff_handler;
 pub mod host_world_manager;
 pub mod host_world_writer;
@@ -1,13 +1,13 @@
 use std::{
     hash::Hash,
-    net::SocketAddr,
     sync::{Arc, RwLock, RwLockReadGuard},
 };
 
 use crate::{DiffMask, GlobalWorldManagerType, PropertyMutate};
 
 pub trait MutChannelType: Send + Sync {
-    fn new_receiver(&mut self, address: &Option<SocketAddr>) -> Option<MutReceiver>;
+    fn new_receiver(&mut self, user_key: &Option<u64>) -> Option<MutReceiver>;
+    fn remove_receiver(&mut self, user_key: &u64);
     fn send(&self, diff: u8);
 }
 
@@ -37,13 +37,19 @@ impl MutChannel {
         MutSender::new(self)
     }
 
-    pub fn new_receiver(&self, address: &Option<SocketAddr>) -> Option<MutReceiver> {
+    pub fn new_receiver(&self, user_key: &Option<u64>) -> Option<MutReceiver> {
         if let Ok(mut data) = self.data.as_ref().write() {
-            return data.new_receiver(address);
+            return data.new_receiver(user_key);
         }
         None
     }
 
+    pub fn remove_receiver(&self, user_key: &u64) {
+        if let Ok(mut data) = self.data.as_ref().write() {
+            data.remove_receiver(user_key);
+        }
+    }
+
     pub fn send(&self, diff: u8) -> bool {
         if let Ok(data) = self.data.as_ref().read() {
             data.send(diff);
@@ -128,7 +134,11 @@ impl MutReceiverBuilder {
         }
     }
 
-    pub fn build(&self, address: &Option<SocketAddr>) -> Option<MutReceiver> {
-        self.channel.new_receiver(address)
+    pub fn build(&self, user_key: &Option<u64>) -> Option<MutReceiver> {
+        self.channel.new_receiver(user_key)
+    }
+
+    pub fn remove(&self, user_key: &u64) {
+        self.channel.remove_receiver(user_key);
     }
 }
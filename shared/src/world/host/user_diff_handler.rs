@@ -1,17 +1,18 @@
 use std::{
     collections::HashMap,
     hash::Hash,
-    net::SocketAddr,
     sync::{Arc, RwLock, RwLockReadGuard},
+    time::Duration,
 };
 
-use crate::{ComponentKind, DiffMask, GlobalWorldManagerType};
+use crate::{ComponentKind, DiffMask, GlobalWorldManagerType, Instant};
 
 use super::{global_diff_handler::GlobalDiffHandler, mut_channel::MutReceiver};
 
 #[derive(Clone)]
 pub struct UserDiffHandler<E: Copy + Eq + Hash> {
     receivers: HashMap<(E, ComponentKind), MutReceiver>,
+    last_updates_sent: HashMap<(E, ComponentKind), Instant>,
     global_diff_handler: Arc<RwLock<GlobalDiffHandler<E>>>,
 }
 
@@ -19,6 +20,7 @@ impl<E: Copy + Eq + Hash> UserDiffHandler<E> {
     pub fn new(global_world_manager: &dyn GlobalWorldManagerType<E>) -> Self {
         UserDiffHandler {
             receivers: HashMap::new(),
+            last_updates_sent: HashMap::new(),
             global_diff_handler: global_world_manager.diff_handler(),
         }
     }
@@ -26,13 +28,13 @@ impl<E: Copy + Eq + Hash> UserDiffHandler<E> {
     // Component Registration
     pub fn register_component(
         &mut self,
-        address: &Option<SocketAddr>,
+        user_key: &Option<u64>,
         entity: &E,
         component_kind: &ComponentKind,
     ) {
         if let Ok(global_handler) = self.global_diff_handler.as_ref().read() {
             let receiver = global_handler
-                .receiver(address, entity, component_kind)
+                .receiver(user_key, entity, component_kind)
                 .expect("GlobalDiffHandler has not yet registered this Component");
             self.receivers.insert((*entity, *component_kind), receiver);
         }
@@ -40,6 +42,7 @@ impl<E: Copy + Eq + Hash> UserDiffHandler<E> {
 
     pub fn deregister_component(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.receivers.remove(&(*entity, *component_kind));
+        self.last_updates_sent.remove(&(*entity, *component_kind));
     }
 
     pub fn has_component(&self, entity: &E, component: &ComponentKind) -> bool {
@@ -83,4 +86,32 @@ impl<E: Copy + Eq + Hash> UserDiffHandler<E> {
         let receiver = self.receivers.get_mut(&(*entity, *component_kind)).unwrap();
         receiver.clear_mask();
     }
+
+    // Update intervals
+    pub fn record_update_sent(
+        &mut self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        now: &Instant,
+    ) {
+        self.last_updates_sent
+            .insert((*entity, *component_kind), now.clone());
+    }
+
+    /// Returns whether enough time has passed since the Component's last
+    /// update was sent for another to be sent
+    pub fn update_is_due(
+        &self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        update_interval: &Duration,
+    ) -> bool {
+        if update_interval.is_zero() {
+            return true;
+        }
+        match self.last_updates_sent.get(&(*entity, *component_kind)) {
+            Some(last_sent) => last_sent.elapsed() >= *update_interval,
+            None => true,
+        }
+    }
 }
@@ -1,7 +1,6 @@
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     hash::Hash,
-    net::SocketAddr,
 };
 
 use log::warn;
@@ -11,8 +10,8 @@ use super::{
     user_diff_handler::UserDiffHandler,
 };
 use crate::{
-    world::local_world_manager::LocalWorldManager, ChannelSender, ComponentKind, EntityAction,
-    EntityActionReceiver, GlobalWorldManagerType, Instant, ReliableSender,
+    world::local_world_manager::LocalWorldManager, ChannelSender, ComponentKind, ComponentKinds,
+    EntityAction, EntityActionReceiver, GlobalWorldManagerType, Instant, ReliableSender,
 };
 
 const RESEND_ACTION_RTT_FACTOR: f32 = 1.5;
@@ -46,26 +45,30 @@ pub struct WorldChannel<E: Copy + Eq + Hash + Send + Sync> {
     /// EntityActions (Entity spawned, component inserted) that were actually received on the client
     remote_world: CheckedMap<E, CheckedSet<ComponentKind>>,
     entity_channels: CheckedMap<E, EntityChannel>,
+    /// Remote Entities this host holds authority over, with the Components
+    /// whose changes it sends as updates
+    delegated_entities: HashMap<E, HashSet<ComponentKind>>,
     outgoing_actions: ReliableSender<EntityActionEvent<E>>,
     delivered_actions: EntityActionReceiver<E>,
 
-    address: Option<SocketAddr>,
+    user_key: Option<u64>,
     pub diff_handler: UserDiffHandler<E>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
     pub fn new(
-        address: &Option<SocketAddr>,
+        user_key: &Option<u64>,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
         Self {
             host_world: CheckedMap::new(),
             remote_world: CheckedMap::new(),
             entity_channels: CheckedMap::new(),
-            outgoing_actions: ReliableSender::new(RESEND_ACTION_RTT_FACTOR),
+            delegated_entities: HashMap::new(),
+            outgoing_actions: ReliableSender::new(RESEND_ACTION_RTT_FACTOR, None),
             delivered_actions: EntityActionReceiver::new(),
 
-            address: *address,
+            user_key: *user_key,
             diff_handler: UserDiffHandler::new(global_world_manager),
         }
     }
@@ -193,6 +196,47 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         }
     }
 
+    // Delegated Entities
+
+    /// Starts sending the changes to a Component of a remote Entity this host
+    /// has been given authority over. The Component already exists on the
+    /// remote host, so no action is sent.
+    pub fn host_delegate_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        let components = self.delegated_entities.entry(*entity).or_default();
+        if !components.insert(*component_kind) {
+            return;
+        }
+        self.diff_handler
+            .register_component(&self.user_key, entity, component_kind);
+    }
+
+    /// Stops sending the changes to a Component of a delegated Entity
+    pub fn host_undelegate_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        let Some(components) = self.delegated_entities.get_mut(entity) else {
+            return;
+        };
+        if components.remove(component_kind) {
+            self.diff_handler
+                .deregister_component(entity, component_kind);
+        }
+    }
+
+    /// Stops sending the changes to an Entity this host no longer holds
+    /// authority over
+    pub fn host_undelegate_entity(&mut self, entity: &E) {
+        let Some(components) = self.delegated_entities.remove(entity) else {
+            return;
+        };
+        for component_kind in components {
+            self.diff_handler
+                .deregister_component(entity, &component_kind);
+        }
+    }
+
+    pub fn delegated_entities(&self) -> Vec<E> {
+        self.delegated_entities.keys().copied().collect()
+    }
+
     // Remote Actions
 
     pub fn remote_spawn_entity(
@@ -382,7 +426,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
 
     fn on_component_channel_opened(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.diff_handler
-            .register_component(&self.address, entity, component_kind);
+            .register_component(&self.user_key, entity, component_kind);
     }
 
     fn on_component_channel_closing(&mut self, entity: &E, component_kind: &ComponentKind) {
@@ -440,33 +484,80 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         self.outgoing_actions.take_next_messages()
     }
 
-    pub fn collect_next_updates(&self) -> HashMap<E, HashSet<ComponentKind>> {
+    pub fn collect_next_updates(
+        &self,
+        component_kinds: &ComponentKinds,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        user_key: &u64,
+    ) -> HashMap<E, HashSet<ComponentKind>> {
         let mut output = HashMap::new();
 
         for (entity, entity_channel) in self.entity_channels.iter() {
             if let EntityChannel::Spawned(component_channels) = entity_channel {
+                if global_world_manager.entity_is_delegated_to_user(entity, user_key) {
+                    // the User holding authority makes the changes itself,
+                    // which keep accumulating until authority returns
+                    continue;
+                }
                 for (component, component_channel) in component_channels.iter() {
                     if let ComponentChannel::Inserted = component_channel {
-                        match self.diff_handler.diff_mask_is_clear(entity, component) {
-                            None | Some(true) => {
-                                // no updates detected, do nothing
-                                continue;
-                            }
-                            _ => {}
-                        }
-
-                        if !output.contains_key(entity) {
-                            output.insert(*entity, HashSet::new());
-                        }
-                        let send_component_set = output.get_mut(entity).unwrap();
-                        send_component_set.insert(*component);
+                        self.collect_next_update(
+                            component_kinds,
+                            global_world_manager,
+                            entity,
+                            component,
+                            &mut output,
+                        );
                     }
                 }
             }
         }
 
+        for (entity, components) in &self.delegated_entities {
+            for component in components {
+                self.collect_next_update(
+                    component_kinds,
+                    global_world_manager,
+                    entity,
+                    component,
+                    &mut output,
+                );
+            }
+        }
+
         output
     }
+
+    fn collect_next_update(
+        &self,
+        component_kinds: &ComponentKinds,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        entity: &E,
+        component: &ComponentKind,
+        output: &mut HashMap<E, HashSet<ComponentKind>>,
+    ) {
+        match self.diff_handler.diff_mask_is_clear(entity, component) {
+            None | Some(true) => {
+                // no updates detected, do nothing
+                return;
+            }
+            _ => {}
+        }
+
+        let update_interval = global_world_manager
+            .entity_update_interval(entity)
+            .unwrap_or_else(|| component_kinds.kind_to_settings(component).update_interval);
+        if !self
+            .diff_handler
+            .update_is_due(entity, component, &update_interval)
+        {
+            // changes keep accumulating in the diff mask until
+            // the update interval has elapsed
+            return;
+        }
+
+        output.entry(*entity).or_default().insert(*component);
+    }
 }
 
 // CheckedMap
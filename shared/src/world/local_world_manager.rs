@@ -98,15 +98,12 @@ impl<E: Copy + Eq + Hash> LocalWorldManager<E> {
 
     // Remote entities
 
-    pub(crate) fn get_world_entity(&self, local_entity: &LocalEntity) -> E {
+    /// Gets the World Entity of a remote Entity, if it has been spawned
+    pub(crate) fn remote_world_entity(&self, local_entity: &LocalEntity) -> Option<E> {
         if !local_entity.is_remote() {
-            panic!("can only call this method with remote entities");
-        }
-
-        if let Some(world_entity) = self.local_to_world_entity.get(&local_entity) {
-            return *world_entity;
+            return None;
         }
-        panic!("Attempting to access remote entity which does not exist!")
+        self.local_to_world_entity.get(local_entity).copied()
     }
 
     pub(crate) fn remote_entities(&self) -> Vec<E> {
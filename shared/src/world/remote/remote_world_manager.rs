@@ -109,6 +109,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         for action in incoming_actions {
             match action {
                 EntityAction::SpawnEntity(local_entity, components) => {
+                    if local_world_manager
+                        .remote_world_entity(&local_entity)
+                        .is_some()
+                    {
+                        warn!("Remote World Manager: cannot spawn an Entity which already exists");
+                        continue;
+                    }
+
                     // set up entity
                     let world_entity = world.spawn_entity();
                     local_world_manager.remote_spawn_entity(&world_entity, &local_entity);
@@ -121,14 +129,25 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
 
                     // read component list
                     for component_kind in components {
-                        let component = incoming_components
-                            .remove(&(local_entity, component_kind))
-                            .unwrap();
+                        let Some(component) =
+                            incoming_components.remove(&(local_entity, component_kind))
+                        else {
+                            continue;
+                        };
 
                         self.process_insert(world, world_entity, component, &component_kind);
                     }
                 }
                 EntityAction::DespawnEntity(local_entity) => {
+                    if local_world_manager
+                        .remote_world_entity(&local_entity)
+                        .is_none()
+                    {
+                        warn!(
+                            "Remote World Manager: cannot despawn an Entity which does not exist"
+                        );
+                        continue;
+                    }
                     let world_entity = local_world_manager.remote_despawn_entity(&local_entity);
                     global_world_manager.remote_despawn_entity(&world_entity);
 
@@ -144,16 +163,25 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                         .push(EntityEvent::<E>::DespawnEntity(world_entity));
                 }
                 EntityAction::InsertComponent(local_entity, component_kind) => {
-                    let component = incoming_components
-                        .remove(&(local_entity, component_kind))
-                        .unwrap();
-
-                    let world_entity = local_world_manager.get_world_entity(&local_entity);
+                    let Some(component) =
+                        incoming_components.remove(&(local_entity, component_kind))
+                    else {
+                        continue;
+                    };
+                    let Some(world_entity) = local_world_manager.remote_world_entity(&local_entity)
+                    else {
+                        warn!("Remote World Manager: cannot insert a Component into an Entity which does not exist");
+                        continue;
+                    };
 
                     self.process_insert(world, world_entity, component, &component_kind);
                 }
                 EntityAction::RemoveComponent(local_entity, component_kind) => {
-                    let world_entity = local_world_manager.get_world_entity(&local_entity);
+                    let Some(world_entity) = local_world_manager.remote_world_entity(&local_entity)
+                    else {
+                        warn!("Remote World Manager: cannot remove a Component from an Entity which does not exist");
+                        continue;
+                    };
                     self.process_remove(world, world_entity, component_kind);
                 }
                 EntityAction::Noop => {
@@ -307,7 +335,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                 // warn!("Incoming Update split into ONLY ready part");
             }
             if waiting_updates_opt.is_none() && ready_update_opt.is_none() {
-                panic!("Incoming Update split into NEITHER waiting nor ready parts. This should not happen.");
+                warn!("Incoming Update split into NEITHER waiting nor ready parts");
+                continue;
             }
 
             // if it exists, queue the waiting part of the component update
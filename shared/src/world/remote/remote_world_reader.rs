@@ -4,8 +4,8 @@ use crate::{
     messages::channels::receivers::indexed_message_reader::IndexedMessageReader,
     world::local_world_manager::LocalWorldManager, BitReader, ComponentKind, ComponentKinds,
     ComponentUpdate, EntityAction, EntityActionReceiver, EntityActionType, EntityConverter,
-    GlobalWorldManagerType, LocalEntity, LocalEntityAndGlobalEntityConverter, MessageIndex,
-    Protocol, Replicate, Serde, SerdeErr, Tick, UnsignedVariableInteger,
+    GlobalWorldManagerType, LocalEntity, LocalEntityAndGlobalEntityConverter, LocalEntityConverter,
+    MessageIndex, Protocol, Replicate, Serde, SerdeErr, Tick, UnsignedVariableInteger,
 };
 
 pub struct RemoteWorldReader<E: Copy + Eq + Hash + Send + Sync> {
@@ -56,17 +56,25 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         protocol: &Protocol,
+        allow_actions: bool,
         tick: Tick,
         reader: &mut BitReader,
     ) -> Result<(), SerdeErr> {
         // read entity updates
-        self.read_updates(local_world_manager, &protocol.component_kinds, tick, reader)?;
+        self.read_updates(
+            global_world_manager,
+            local_world_manager,
+            &protocol.component_kinds,
+            tick,
+            reader,
+        )?;
 
         // read entity actions
         self.read_actions(
             global_world_manager,
             local_world_manager,
             &protocol.component_kinds,
+            allow_actions,
             reader,
         )?;
 
@@ -79,6 +87,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
+        allow_actions: bool,
         reader: &mut BitReader,
     ) -> Result<(), SerdeErr> {
         let mut last_read_id: Option<MessageIndex> = None;
@@ -95,6 +104,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 if !action_continue {
                     break;
                 }
+                if !allow_actions {
+                    // a peer which may only update the Entities delegated to
+                    // it can't spawn, despawn, insert or remove
+                    return Err(SerdeErr);
+                }
 
                 self.read_action(&converter, component_kinds, reader, &mut last_read_id)?;
             }
@@ -185,6 +199,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
     /// Read component updates from raw bits
     fn read_updates(
         &mut self,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
         local_world_manager: &LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         tick: Tick,
@@ -197,9 +212,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 break;
             }
 
-            let local_entity = LocalEntity::remote_de(reader)?;
+            // the sender writes an Entity it holds authority over as one of
+            // its remote Entities, which is one of our host Entities
+            let local_entity = LocalEntity::owned_de(reader)?.to_reversed();
 
             self.read_update(
+                global_world_manager,
                 local_world_manager,
                 component_kinds,
                 tick,
@@ -214,12 +232,38 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
     /// Read component updates from raw bits for a given entity
     fn read_update(
         &mut self,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
         local_world_manager: &LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         tick: Tick,
         reader: &mut BitReader,
         local_entity: &LocalEntity,
     ) -> Result<(), SerdeErr> {
+        let world_entity = match local_entity {
+            LocalEntity::Remote(_) => {
+                let Some(world_entity) = local_world_manager.remote_world_entity(local_entity)
+                else {
+                    // updates are only sent for Entities which have been spawned
+                    return Err(SerdeErr);
+                };
+                Some(world_entity)
+            }
+            LocalEntity::Host(_) => {
+                // the sender holds authority over one of our Entities, or held
+                // it when these updates were written, in which case they're
+                // read and dropped
+                local_world_manager
+                    .local_entity_to_entity(local_entity)
+                    .ok()
+                    .filter(|world_entity| {
+                        global_world_manager.entity_is_delegated_to_user(
+                            world_entity,
+                            local_world_manager.get_user_key(),
+                        )
+                    })
+            }
+        };
+
         loop {
             // read update continue bit
             let component_continue = bool::de(reader)?;
@@ -229,10 +273,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
 
             let component_update = component_kinds.read_create_update(reader)?;
 
-            let world_entity = local_world_manager.get_world_entity(local_entity);
-
-            self.received_updates
-                .push((tick, world_entity, component_update));
+            if let Some(world_entity) = world_entity {
+                self.received_updates
+                    .push((tick, world_entity, component_update));
+            }
         }
 
         Ok(())
@@ -95,3 +95,11 @@ fn read_write_enum_2() {
     assert_eq!(in_2, out_2);
     assert_eq!(in_3, out_3);
 }
+
+#[test]
+fn enum_layout_describes_variants() {
+    assert_eq!(
+        SomeEnum::layout(),
+        "SomeEnum { Variant1, Variant2(bool), Variant3(u16, String), Variant4 { some_bool: bool, some_number: i8, some_string: String }, Variant5 }"
+    );
+}
@@ -0,0 +1,230 @@
+mod some_types {
+    use naia_shared::Serde;
+
+    fn default_health() -> u16 {
+        100
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeStruct {
+        #[serde(bits = 7)]
+        pub some_small_int: u8,
+        #[serde(bits = 4)]
+        pub some_signed_int: i32,
+        #[serde(varint)]
+        pub some_large_int: u64,
+        #[serde(bounded(-10, 1000))]
+        pub some_bounded_int: i16,
+        #[serde(skip)]
+        pub some_cache: Vec<u32>,
+        #[serde(skip, default = "default_health")]
+        pub some_health: u16,
+        pub some_bool: bool,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeTupleStruct(#[serde(bits = 3)] pub u8, #[serde(varint = 3)] pub i64);
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub enum SomeEnum {
+        Position {
+            #[serde(bounded(0, 255))]
+            x: u32,
+            #[serde(bounded(0, 255))]
+            y: u32,
+        },
+        Id(#[serde(bits = 10)] u16, #[serde(skip)] bool),
+        Empty,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeBoundedStruct {
+        #[serde(bounded(0, 100))]
+        pub some_int: u8,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeUnboundedStruct {
+        #[serde(bits = 7)]
+        pub some_int: u8,
+    }
+
+    // keys other derives give to the `serde` attribute are ignored
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeSharedAttributeStruct {
+        #[serde(rename = "id", bits = 7)]
+        pub some_small_int: u8,
+        #[serde(with = "some::module", skip_serializing_if(Option::is_none))]
+        pub some_int: u16,
+    }
+}
+
+use naia_shared::{BitReader, BitWriter, Serde, MTU_SIZE_BITS};
+
+use some_types::{
+    SomeBoundedStruct, SomeEnum, SomeSharedAttributeStruct, SomeStruct, SomeTupleStruct,
+    SomeUnboundedStruct,
+};
+
+fn bits_written(writer: &BitWriter) -> u32 {
+    MTU_SIZE_BITS - writer.bits_free()
+}
+
+#[test]
+fn read_write_struct_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeStruct {
+        some_small_int: 127,
+        some_signed_int: -12,
+        some_large_int: 1_000_000_000_000,
+        some_bounded_int: -10,
+        some_cache: vec![1, 2, 3],
+        some_health: 5,
+        some_bool: true,
+    };
+
+    in_1.ser(&mut writer);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1: SomeStruct = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(out_1.some_small_int, 127);
+    assert_eq!(out_1.some_signed_int, -12);
+    assert_eq!(out_1.some_large_int, 1_000_000_000_000);
+    assert_eq!(out_1.some_bounded_int, -10);
+    assert!(out_1.some_cache.is_empty());
+    assert_eq!(out_1.some_health, 100);
+    assert!(out_1.some_bool);
+}
+
+#[test]
+fn struct_with_attributes_bit_length() {
+    let in_1 = SomeStruct {
+        some_small_int: 3,
+        some_signed_int: 3,
+        some_large_int: 3,
+        some_bounded_int: 3,
+        some_cache: vec![1, 2, 3],
+        some_health: 5,
+        some_bool: false,
+    };
+
+    let mut writer = BitWriter::new();
+    in_1.ser(&mut writer);
+
+    // 7 bits, a sign bit + 4 bits, one 7 bit group + a continuation bit,
+    // 10 bits for the range [-10, 1000], nothing for the skipped fields,
+    // and 1 bit for the bool
+    assert_eq!(in_1.bit_length(), 7 + 5 + 8 + 10 + 1);
+    assert_eq!(in_1.bit_length(), bits_written(&writer));
+}
+
+#[test]
+fn read_write_tuple_struct_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeTupleStruct(5, -300);
+    let in_2 = SomeTupleStruct(0, 2);
+
+    in_1.ser(&mut writer);
+    in_2.ser(&mut writer);
+
+    assert_eq!(in_1.bit_length() + in_2.bit_length(), bits_written(&writer));
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = Serde::de(&mut reader).unwrap();
+    let out_2 = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(in_1, out_1);
+    assert_eq!(in_2, out_2);
+}
+
+#[test]
+fn read_write_enum_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeEnum::Position { x: 255, y: 17 };
+    let in_2 = SomeEnum::Id(1000, true);
+    let in_3 = SomeEnum::Empty;
+
+    in_1.ser(&mut writer);
+    in_2.ser(&mut writer);
+    in_3.ser(&mut writer);
+
+    assert_eq!(
+        in_1.bit_length() + in_2.bit_length() + in_3.bit_length(),
+        bits_written(&writer)
+    );
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = Serde::de(&mut reader).unwrap();
+    let out_2 = Serde::de(&mut reader).unwrap();
+    let out_3 = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(in_1, out_1);
+    assert_eq!(SomeEnum::Id(1000, false), out_2);
+    assert_eq!(in_3, out_3);
+}
+
+#[test]
+fn read_out_of_bounds_errors() {
+    // Written with 7 bits, but read as bounded within [0, 100]
+    let mut writer = BitWriter::new();
+
+    SomeUnboundedStruct { some_int: 120 }.ser(&mut writer);
+
+    let bytes = writer.to_bytes();
+
+    let mut reader = BitReader::new(&bytes);
+
+    let result: Result<SomeBoundedStruct, _> = Serde::de(&mut reader);
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic]
+fn write_out_of_bounds_panics() {
+    let mut writer = BitWriter::new();
+
+    SomeBoundedStruct { some_int: 101 }.ser(&mut writer);
+}
+
+#[test]
+fn unknown_attributes_are_ignored() {
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeSharedAttributeStruct {
+        some_small_int: 100,
+        some_int: 5000,
+    };
+
+    in_1.ser(&mut writer);
+
+    assert_eq!(bits_written(&writer), 7 + 16);
+    assert_eq!(in_1.bit_length(), 7 + 16);
+
+    let bytes = writer.to_bytes();
+
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(in_1, out_1);
+}
@@ -44,3 +44,11 @@ fn read_write_struct() {
     assert_eq!(in_1, out_1);
     assert_eq!(in_2, out_2);
 }
+
+#[test]
+fn struct_layout_describes_fields() {
+    assert_eq!(
+        SomeStruct::layout(),
+        "SomeStruct { some_string: String, some_int: i16, some_bool: bool }"
+    );
+}
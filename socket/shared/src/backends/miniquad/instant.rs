@@ -1,9 +1,7 @@
-extern "C" {
-    pub fn naia_now() -> f64;
-}
-
 use std::{cmp::Ordering, time::Duration};
 
+use super::clock::Clock;
+
 /// Represents a specific moment in time
 #[derive(Clone, PartialEq, PartialOrd)]
 pub struct Instant {
@@ -13,27 +11,25 @@ pub struct Instant {
 impl Instant {
     /// Creates an Instant from the moment the method is called
     pub fn now() -> Self {
-        unsafe { Instant { inner: naia_now() } }
+        Instant {
+            inner: Clock::now(),
+        }
     }
 
     /// Returns time elapsed since the Instant
     pub fn elapsed(&self) -> Duration {
-        unsafe {
-            let inner_duration = naia_now() - self.inner;
-            let seconds: u64 = (inner_duration as u64) / 1000;
-            let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
-            Duration::new(seconds, nanos)
-        }
+        let inner_duration = Clock::now() - self.inner;
+        let seconds: u64 = (inner_duration as u64) / 1000;
+        let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
+        Duration::new(seconds, nanos)
     }
 
     /// Returns time until the Instant occurs
     pub fn until(&self) -> Duration {
-        unsafe {
-            let inner_duration = self.inner - naia_now();
-            let seconds: u64 = (inner_duration as u64) / 1000;
-            let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
-            Duration::new(seconds, nanos)
-        }
+        let inner_duration = self.inner - Clock::now();
+        let seconds: u64 = (inner_duration as u64) / 1000;
+        let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
+        Duration::new(seconds, nanos)
     }
 
     /// Adds a given number of milliseconds to the Instant
@@ -3,15 +3,18 @@ cfg_if! {
         mod wasm_bindgen;
         pub use self::wasm_bindgen::random::Random;
         pub use self::wasm_bindgen::instant::Instant;
+        pub use self::wasm_bindgen::clock::Clock;
     }
     else if #[cfg(all(target_arch = "wasm32", feature = "mquad"))] {
         mod miniquad;
         pub use self::miniquad::random::Random;
         pub use self::miniquad::instant::Instant;
+        pub use self::miniquad::clock::Clock;
     }
     else {
         mod native;
         pub use native::random::Random;
         pub use native::instant::Instant;
+        pub use native::clock::Clock;
     }
 }
@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use super::clock::Clock;
+
 /// Represents a specific moment in time
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Instant {
@@ -10,18 +12,18 @@ impl Instant {
     /// Creates an Instant from the moment the method is called
     pub fn now() -> Self {
         Instant {
-            inner: std::time::Instant::now(),
+            inner: Clock::now(),
         }
     }
 
     /// Returns time elapsed since the Instant
     pub fn elapsed(&self) -> Duration {
-        self.inner.elapsed()
+        Clock::now().saturating_duration_since(self.inner)
     }
 
     /// Returns time until the Instant occurs
     pub fn until(&self) -> Duration {
-        self.inner.duration_since(std::time::Instant::now())
+        self.inner.saturating_duration_since(Clock::now())
     }
 
     /// Adds a given number of milliseconds to the Instant
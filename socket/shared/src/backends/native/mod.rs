@@ -1,2 +1,3 @@
+pub mod clock;
 pub mod instant;
 pub mod random;
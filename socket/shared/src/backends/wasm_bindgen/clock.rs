@@ -0,0 +1,52 @@
+use js_sys::Date;
+
+use std::{cell::Cell, time::Duration};
+
+thread_local! {
+    static MANUAL_NOW: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+/// The source of the current time for every Instant & Timer created on the
+/// current thread. By default this is `Date::now()`, but it can be switched to a
+/// manual clock which only moves forward when advanced, which allows
+/// time-dependent logic to be tested without real sleeps.
+pub struct Clock;
+
+impl Clock {
+    /// Switches the current thread to a manual clock, starting at the
+    /// current system time. Has no effect if a manual clock is already set.
+    pub fn set_manual() {
+        MANUAL_NOW.with(|now| {
+            if now.get().is_none() {
+                now.set(Some(Date::now()));
+            }
+        });
+    }
+
+    /// Switches the current thread back to the system clock
+    pub fn set_system() {
+        MANUAL_NOW.with(|now| now.set(None));
+    }
+
+    /// Returns whether the current thread is using a manual clock
+    pub fn is_manual() -> bool {
+        MANUAL_NOW.with(|now| now.get().is_some())
+    }
+
+    /// Moves the manual clock of the current thread forward by the given
+    /// Duration. Panics if the current thread is using the system clock.
+    pub fn advance(duration: Duration) {
+        MANUAL_NOW.with(|now| match now.get() {
+            Some(millis) => now.set(Some(millis + duration.as_secs_f64() * 1000.0)),
+            None => panic!(
+                "Clock::advance() called while using the system clock, call Clock::set_manual() first"
+            ),
+        });
+    }
+
+    /// Returns the current time in milliseconds according to the clock of the
+    /// current thread
+    pub fn now() -> f64 {
+        MANUAL_NOW.with(|now| now.get()).unwrap_or_else(Date::now)
+    }
+}
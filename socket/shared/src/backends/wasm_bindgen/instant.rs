@@ -1,6 +1,7 @@
-use js_sys::Date;
 use std::{cmp::Ordering, time::Duration};
 
+use super::clock::Clock;
+
 /// Represents a specific moment in time
 #[derive(Clone, PartialEq, PartialOrd)]
 pub struct Instant {
@@ -10,12 +11,14 @@ pub struct Instant {
 impl Instant {
     /// Creates an Instant from the moment the method is called
     pub fn now() -> Self {
-        Instant { inner: Date::now() }
+        Instant {
+            inner: Clock::now(),
+        }
     }
 
     /// Returns time elapsed since the Instant
     pub fn elapsed(&self) -> Duration {
-        let inner_duration = Date::now() - self.inner;
+        let inner_duration = Clock::now() - self.inner;
         let seconds: u64 = (inner_duration as u64) / 1000;
         let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
         Duration::new(seconds, nanos)
@@ -23,7 +26,7 @@ impl Instant {
 
     /// Returns time until the Instant occurs
     pub fn until(&self) -> Duration {
-        let inner_duration = self.inner - Date::now();
+        let inner_duration = self.inner - Clock::now();
         let seconds: u64 = (inner_duration as u64) / 1000;
         let nanos: u32 = ((inner_duration as u32) % 1000) * 1000000;
         Duration::new(seconds, nanos)
@@ -0,0 +1,100 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use naia_client::transport::{
+    local::Socket as LocalClientSocket, PacketReceiver as ClientPacketReceiver,
+    PacketSender as ClientPacketSender, SendError as ClientSendError, ServerAddr,
+    Socket as ClientSocket,
+};
+use naia_server::transport::{
+    local::Socket as LocalServerSocket, PacketReceiver as ServerPacketReceiver,
+    PacketSender as ServerPacketSender, SendError as ServerSendError, Socket as ServerSocket,
+};
+
+/// Every packet sent through a Socket, in the order they were sent
+pub(crate) type Wire = Arc<Mutex<Vec<Box<[u8]>>>>;
+
+// Local Sockets which require naia to encrypt packets, as UDP does, and
+// record every packet they send
+
+pub(crate) struct EncryptedClientSocket {
+    pub(crate) inner: LocalClientSocket,
+    pub(crate) wire: Wire,
+}
+
+impl ClientSocket for EncryptedClientSocket {
+    fn connect(self: Box<Self>) -> (Box<dyn ClientPacketSender>, Box<dyn ClientPacketReceiver>) {
+        let (sender, receiver) = Box::new(self.inner).connect();
+        let sender = Box::new(RecordingClientSender {
+            inner: sender,
+            wire: self.wire,
+        });
+        (sender, receiver)
+    }
+
+    fn requires_encryption(&self) -> bool {
+        true
+    }
+}
+
+impl From<EncryptedClientSocket> for Box<dyn ClientSocket> {
+    fn from(socket: EncryptedClientSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+struct RecordingClientSender {
+    inner: Box<dyn ClientPacketSender>,
+    wire: Wire,
+}
+
+impl ClientPacketSender for RecordingClientSender {
+    fn send(&self, payload: &[u8]) -> Result<(), ClientSendError> {
+        self.wire.lock().unwrap().push(payload.into());
+        self.inner.send(payload)
+    }
+
+    fn server_addr(&self) -> ServerAddr {
+        self.inner.server_addr()
+    }
+}
+
+pub(crate) struct EncryptedServerSocket {
+    pub(crate) inner: LocalServerSocket,
+    pub(crate) wire: Wire,
+}
+
+impl ServerSocket for EncryptedServerSocket {
+    fn listen(self: Box<Self>) -> (Box<dyn ServerPacketSender>, Box<dyn ServerPacketReceiver>) {
+        let (sender, receiver) = Box::new(self.inner).listen();
+        let sender = Box::new(RecordingServerSender {
+            inner: sender,
+            wire: self.wire,
+        });
+        (sender, receiver)
+    }
+
+    fn requires_encryption(&self) -> bool {
+        true
+    }
+}
+
+impl From<EncryptedServerSocket> for Box<dyn ServerSocket> {
+    fn from(socket: EncryptedServerSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+struct RecordingServerSender {
+    inner: Box<dyn ServerPacketSender>,
+    wire: Wire,
+}
+
+impl ServerPacketSender for RecordingServerSender {
+    fn send(&self, address: &SocketAddr, payload: &[u8]) -> Result<(), ServerSendError> {
+        self.wire.lock().unwrap().push(payload.into());
+        self.inner.send(address, payload)
+    }
+}
@@ -0,0 +1,12 @@
+use naia_shared::{DeltaProperty, Replicate};
+
+#[derive(Replicate)]
+pub struct Inventory {
+    pub slots: DeltaProperty<Vec<u32>>,
+}
+
+impl Inventory {
+    pub fn new(slot_count: usize) -> Self {
+        Self::new_complete(vec![0; slot_count])
+    }
+}
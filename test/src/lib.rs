@@ -1,3 +1,10 @@
 mod auth;
+mod encrypted_socket;
+mod inventory;
+mod position;
+mod scenario;
 
 pub use auth::Auth;
+pub use inventory::Inventory;
+pub use position::Position;
+pub use scenario::Scenario;
@@ -0,0 +1,500 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::atomic::{AtomicU16, Ordering},
+    time::Duration,
+};
+
+use naia_client::{
+    transport::local::Socket as ClientSocket, Client, ClientConfig, Events as ClientEvents,
+};
+use naia_demo_world::{Entity, World, WorldRefType};
+use naia_server::{
+    transport::local::Socket as ServerSocket, ConnectTokenConfig, Events as ServerEvents, Server,
+    ServerConfig, UserKey,
+};
+use naia_shared::{
+    sequence_greater_than, Clock, ConnectionConfig, LinkConditionerConfig, LocalTransportHub,
+    Protocol, Replicate, Tick,
+};
+
+use crate::encrypted_socket::{EncryptedClientSocket, EncryptedServerSocket, Wire};
+
+// The port of the next Scenario's Server, so that Scenarios running in
+// parallel never share an address
+static NEXT_SERVER_PORT: AtomicU16 = AtomicU16::new(14191);
+
+/// A Server and any number of Clients, connected through an in-memory
+/// transport and stepped together one tick at a time. Used to script
+/// end-to-end replication scenarios.
+///
+/// A Scenario switches the current thread to a manual [`Clock`], which is
+/// advanced by one tick interval on every step. Call [`Clock::advance`] to
+/// move time forward further without stepping.
+pub struct Scenario {
+    hub: LocalTransportHub,
+    protocol: Box<dyn Fn() -> Protocol>,
+    client_config: ClientConfig,
+    encrypted: bool,
+    // every packet the Server has sent, if encrypted
+    server_wire: Wire,
+    tick_interval: Duration,
+    server: Server<Entity>,
+    server_world: World,
+    server_events: Option<ServerEvents<Entity>>,
+    clients: Vec<ScenarioClient>,
+}
+
+struct ScenarioClient {
+    client: Client<Entity>,
+    world: World,
+    // the address of the Client's Socket
+    socket_address: SocketAddr,
+    // the address the Server sees the Client at
+    address: SocketAddr,
+    events: Option<ClientEvents<Entity>>,
+    // every packet the Client has sent, if encrypted
+    wire: Wire,
+}
+
+impl Scenario {
+    /// Creates a new Scenario with a listening Server. The given function is
+    /// called once for the Server and once for each added Client.
+    pub fn new<P: Fn() -> Protocol + 'static>(protocol: P) -> Self {
+        let (server_config, client_config) = Self::default_configs();
+        Self::with_configs(server_config, client_config, protocol)
+    }
+
+    /// Gets the Server & Client configuration used by [`Scenario::new`], as a
+    /// starting point for [`Scenario::with_configs`]. Connections are
+    /// accepted without auth, and handshakes complete in a few ticks.
+    pub fn default_configs() -> (ServerConfig, ClientConfig) {
+        // heartbeats carry acks, so send them often to let idle connections
+        // acknowledge replicated state promptly
+        let connection = ConnectionConfig {
+            heartbeat_interval: Duration::from_millis(0),
+            ..Default::default()
+        };
+
+        let server_config = ServerConfig {
+            require_auth: false,
+            connection: connection.clone(),
+            ..Default::default()
+        };
+
+        let client_config = ClientConfig {
+            connection,
+            send_handshake_interval: Duration::from_millis(0),
+            ping_interval: Duration::from_millis(0),
+            handshake_pings: 2,
+            server_public_key: None,
+        };
+
+        (server_config, client_config)
+    }
+
+    /// Creates a new Scenario with the given Server & Client configuration
+    pub fn with_configs<P: Fn() -> Protocol + 'static>(
+        server_config: ServerConfig,
+        client_config: ClientConfig,
+        protocol: P,
+    ) -> Self {
+        Self::new_inner(
+            Self::next_server_address(),
+            server_config,
+            client_config,
+            protocol,
+            false,
+        )
+    }
+
+    /// Creates a new Scenario, like [`Scenario::with_configs`], whose
+    /// transport requires naia to encrypt packets, as UDP does. Every packet
+    /// sent is recorded, to be checked with [`Scenario::server_sent_packets`]
+    /// & [`Scenario::client_sent_packets`].
+    pub fn with_encryption<P: Fn() -> Protocol + 'static>(
+        server_config: ServerConfig,
+        client_config: ClientConfig,
+        protocol: P,
+    ) -> Self {
+        Self::new_inner(
+            Self::next_server_address(),
+            server_config,
+            client_config,
+            protocol,
+            true,
+        )
+    }
+
+    /// Creates a new Scenario, like [`Scenario::with_configs`], whose Server
+    /// requires Clients to connect with a ConnectToken signed with the given
+    /// key. Tokens must list [`Scenario::server_address`] to be accepted.
+    pub fn with_connect_tokens<P: Fn() -> Protocol + 'static>(
+        server_config: ServerConfig,
+        client_config: ClientConfig,
+        key: &[u8],
+        protocol: P,
+    ) -> Self {
+        let server_address = Self::next_server_address();
+        let server_config = ServerConfig {
+            connect_token: Some(ConnectTokenConfig {
+                key: key.to_vec(),
+                server_address,
+            }),
+            ..server_config
+        };
+        Self::new_inner(
+            server_address,
+            server_config,
+            client_config,
+            protocol,
+            false,
+        )
+    }
+
+    // Gets an address for a new Scenario's Server, which no other Scenario
+    // uses
+    fn next_server_address() -> SocketAddr {
+        let server_port = NEXT_SERVER_PORT.fetch_add(1, Ordering::Relaxed);
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port)
+    }
+
+    fn new_inner<P: Fn() -> Protocol + 'static>(
+        server_addr: SocketAddr,
+        server_config: ServerConfig,
+        client_config: ClientConfig,
+        protocol: P,
+        encrypted: bool,
+    ) -> Self {
+        Clock::set_manual();
+
+        let hub = LocalTransportHub::new(server_addr);
+
+        let server_protocol = protocol();
+        let tick_interval = server_protocol.tick_interval;
+        let mut server = Server::new(server_config, server_protocol);
+        let server_wire = Wire::default();
+        let socket = ServerSocket::new(&hub, None);
+        if encrypted {
+            server.listen(EncryptedServerSocket {
+                inner: socket,
+                wire: server_wire.clone(),
+            });
+        } else {
+            server.listen(socket);
+        }
+
+        Self {
+            hub,
+            protocol: Box::new(protocol),
+            client_config,
+            encrypted,
+            server_wire,
+            tick_interval,
+            server,
+            server_world: World::default(),
+            server_events: None,
+            clients: Vec::new(),
+        }
+    }
+
+    // Clients
+
+    /// Adds a new Client which immediately begins connecting to the Server.
+    /// Returns the index used to refer to the Client in the Scenario.
+    pub fn add_client(&mut self) -> usize {
+        self.add_client_inner((self.protocol)(), None, |_| {})
+    }
+
+    /// Adds a new Client, like [`Scenario::add_client`], passing it to the
+    /// given function before it begins connecting, e.g. to set its auth
+    pub fn add_client_with<F: FnOnce(&mut Client<Entity>)>(&mut self, setup: F) -> usize {
+        self.add_client_inner((self.protocol)(), None, setup)
+    }
+
+    /// Adds a new Client, like [`Scenario::add_client`], which uses the given
+    /// Protocol rather than the Scenario's
+    pub fn add_client_with_protocol(&mut self, protocol: Protocol) -> usize {
+        self.add_client_inner(protocol, None, |_| {})
+    }
+
+    /// Adds a new Client, like [`Scenario::add_client`], whose incoming
+    /// packets pass through a link conditioner with the given configuration
+    pub fn add_client_with_link_conditioner(&mut self, config: LinkConditionerConfig) -> usize {
+        self.add_client_inner((self.protocol)(), Some(config), |_| {})
+    }
+
+    fn add_client_inner<F: FnOnce(&mut Client<Entity>)>(
+        &mut self,
+        protocol: Protocol,
+        link_conditioner: Option<LinkConditionerConfig>,
+        setup: F,
+    ) -> usize {
+        let mut client = Client::new(self.client_config.clone(), protocol);
+        setup(&mut client);
+
+        let wire = Wire::default();
+        let socket = ClientSocket::new(&self.hub, link_conditioner);
+        let address = socket.client_addr();
+        if self.encrypted {
+            client.connect(EncryptedClientSocket {
+                inner: socket,
+                wire: wire.clone(),
+            });
+        } else {
+            client.connect(socket);
+        }
+
+        self.clients.push(ScenarioClient {
+            client,
+            world: World::default(),
+            socket_address: address,
+            address,
+            events: None,
+            wire,
+        });
+
+        self.clients.len() - 1
+    }
+
+    /// Steps the Scenario until every Client is connected.
+    /// Panics if that takes more than `max_ticks` steps.
+    pub fn connect_clients(&mut self, max_ticks: u16) {
+        let connected = self.step_until(max_ticks, |scenario| {
+            (0..scenario.clients.len()).all(|index| {
+                scenario.clients[index].client.is_connected() && scenario.user_key(index).is_some()
+            })
+        });
+        if !connected {
+            panic!("Clients did not connect within {} ticks", max_ticks);
+        }
+    }
+
+    /// Changes the address the Server sees the given Client at, as when a
+    /// NAT mapping is rebound. Returns the new address.
+    pub fn rebind_client(&mut self, index: usize) -> SocketAddr {
+        let scenario_client = &mut self.clients[index];
+        scenario_client.address = self.hub.rebind_client(&scenario_client.socket_address);
+        scenario_client.address
+    }
+
+    /// Changes the address the Server sees the given Client at to one which
+    /// another Client has vacated, as when a NAT reuses a mapping
+    pub fn rebind_client_to(&mut self, index: usize, address: &SocketAddr) {
+        let scenario_client = &mut self.clients[index];
+        self.hub
+            .rebind_client_to(&scenario_client.socket_address, address);
+        scenario_client.address = *address;
+    }
+
+    /// Sends the given bytes to the Server as if from the given Client,
+    /// bypassing the Client itself. Used to check how the Server handles
+    /// malformed or malicious packets.
+    pub fn send_raw_to_server(&self, index: usize, payload: &[u8]) {
+        self.hub
+            .send_to_server(&self.clients[index].socket_address, payload);
+    }
+
+    /// Takes every packet the Clients have sent which the Server has not
+    /// received yet, along with the address they were sent from
+    pub fn take_packets_to_server(&mut self) -> Vec<(SocketAddr, Box<[u8]>)> {
+        let mut packets = Vec::new();
+        while let Some(packet) = self.hub.recv_on_server() {
+            packets.push(packet);
+        }
+        packets
+    }
+
+    /// Returns every packet the Server has sent, if the Scenario was created
+    /// with [`Scenario::with_encryption`]
+    pub fn server_sent_packets(&self) -> Vec<Box<[u8]>> {
+        self.server_wire.lock().unwrap().clone()
+    }
+
+    /// Returns every packet the given Client has sent, if the Scenario was
+    /// created with [`Scenario::with_encryption`]
+    pub fn client_sent_packets(&self, index: usize) -> Vec<Box<[u8]>> {
+        self.clients[index].wire.lock().unwrap().clone()
+    }
+
+    /// Returns the number of Clients added to the Scenario
+    pub fn clients_count(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn client(&self, index: usize) -> &Client<Entity> {
+        &self.clients[index].client
+    }
+
+    pub fn client_mut(&mut self, index: usize) -> &mut Client<Entity> {
+        &mut self.clients[index].client
+    }
+
+    pub fn client_world(&self, index: usize) -> &World {
+        &self.clients[index].world
+    }
+
+    /// Returns the Client and its World, for operations which require both
+    pub fn client_and_world_mut(&mut self, index: usize) -> (&mut Client<Entity>, &mut World) {
+        let scenario_client = &mut self.clients[index];
+        (&mut scenario_client.client, &mut scenario_client.world)
+    }
+
+    /// Returns the Events received by the Client during the latest step
+    pub fn client_events(&mut self, index: usize) -> Option<&mut ClientEvents<Entity>> {
+        self.clients[index].events.as_mut()
+    }
+
+    /// Returns the Server's UserKey for the given Client, once connected
+    pub fn user_key(&self, index: usize) -> Option<UserKey> {
+        let address = self.clients[index].address;
+        self.server
+            .user_keys()
+            .into_iter()
+            .find(|user_key| self.server.user(user_key).address() == address)
+    }
+
+    // Server
+
+    /// Gets the address Clients reach the Server at
+    pub fn server_address(&self) -> SocketAddr {
+        self.hub.server_addr()
+    }
+
+    pub fn server(&self) -> &Server<Entity> {
+        &self.server
+    }
+
+    pub fn server_mut(&mut self) -> &mut Server<Entity> {
+        &mut self.server
+    }
+
+    pub fn server_world(&self) -> &World {
+        &self.server_world
+    }
+
+    /// Returns the Server and its World, for operations which require both
+    pub fn server_and_world_mut(&mut self) -> (&mut Server<Entity>, &mut World) {
+        (&mut self.server, &mut self.server_world)
+    }
+
+    /// Returns the Events received by the Server during the latest step
+    pub fn server_events(&mut self) -> Option<&mut ServerEvents<Entity>> {
+        self.server_events.as_mut()
+    }
+
+    /// Includes every Entity in scope for every User it shares a Room with
+    pub fn include_all_in_scope(&mut self) {
+        for (_, user_key, entity) in self.server.scope_checks() {
+            self.server.user_scope(&user_key).include(&entity);
+        }
+    }
+
+    /// Gets the current Tick of the Server
+    pub fn current_tick(&self) -> Tick {
+        self.server.current_tick()
+    }
+
+    // Stepping
+
+    /// Advances the Scenario by a single tick: the Server receives & sends,
+    /// then each Client receives (and sends, if it's their tick)
+    pub fn step(&mut self) {
+        self.server_events = Some(self.server.receive(self.server_world.proxy_mut()));
+        self.server.send_all_updates(self.server_world.proxy());
+
+        for scenario_client in &mut self.clients {
+            scenario_client.events = Some(
+                scenario_client
+                    .client
+                    .receive(scenario_client.world.proxy_mut()),
+            );
+        }
+
+        Clock::advance(self.tick_interval);
+    }
+
+    /// Advances the Scenario by the given number of ticks
+    pub fn step_ticks(&mut self, ticks: u16) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    /// Steps the Scenario until the given condition is met, checking before
+    /// each step. Returns false if the condition was not met within
+    /// `max_ticks` steps.
+    pub fn step_until<F: FnMut(&mut Self) -> bool>(
+        &mut self,
+        max_ticks: u16,
+        mut condition: F,
+    ) -> bool {
+        for _ in 0..max_ticks {
+            if condition(self) {
+                return true;
+            }
+            self.step();
+        }
+        condition(self)
+    }
+
+    // Entities
+
+    /// Gets the Entity in a Client's World which replicates the given Server
+    /// Entity, if it has been spawned there
+    pub fn client_entity(&self, index: usize, server_entity: &Entity) -> Option<Entity> {
+        let user_key = self.user_key(index)?;
+        let local_entity = self.server.user_local_entity(&user_key, server_entity)?;
+        self.clients[index]
+            .client
+            .local_entity_to_entity(&local_entity.to_reversed())
+    }
+
+    /// Steps the Scenario until the given Server Entity, with a Component of
+    /// type `R`, is visible in the World of the given Client.
+    /// Panics if this has not happened by the Server Tick `by_tick`.
+    pub fn expect_visible<R: Replicate>(
+        &mut self,
+        index: usize,
+        server_entity: &Entity,
+        by_tick: Tick,
+    ) -> Entity {
+        loop {
+            if let Some(client_entity) = self.client_entity(index, server_entity) {
+                if self.clients[index]
+                    .world
+                    .proxy()
+                    .has_component::<R>(&client_entity)
+                {
+                    return client_entity;
+                }
+            }
+            if sequence_greater_than(self.current_tick(), by_tick) {
+                panic!(
+                    "Entity with Component `{}` was not visible on Client {} by Tick {} (current Tick: {})",
+                    std::any::type_name::<R>(),
+                    index,
+                    by_tick,
+                    self.current_tick()
+                );
+            }
+            self.step();
+        }
+    }
+
+    /// Steps the Scenario until the given Entity has been despawned from the
+    /// World of the given Client.
+    /// Panics if this has not happened by the Server Tick `by_tick`.
+    pub fn expect_despawned(&mut self, index: usize, client_entity: &Entity, by_tick: Tick) {
+        while self.clients[index].world.proxy().has_entity(client_entity) {
+            if sequence_greater_than(self.current_tick(), by_tick) {
+                panic!(
+                    "Entity was not despawned on Client {} by Tick {} (current Tick: {})",
+                    index,
+                    by_tick,
+                    self.current_tick()
+                );
+            }
+            self.step();
+        }
+    }
+}
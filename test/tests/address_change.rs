@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use naia_demo_world::{Entity, WorldRefType};
+use naia_server::{AddressChangeEvent, DisconnectEvent};
+use naia_shared::Protocol;
+use naia_test::{Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_component::<Position>()
+        .build()
+}
+
+fn client_x(scenario: &Scenario, client: usize, entity: &Entity) -> i16 {
+    *scenario
+        .client_world(client)
+        .proxy()
+        .component::<Position>(entity)
+        .unwrap()
+        .x
+}
+
+#[test]
+fn connection_follows_client_to_new_address() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+    let old_address = scenario.server().user(&user_key).address();
+
+    let server_entity = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_key);
+        server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(0, 0))
+            .enter_room(&room_key)
+            .id()
+    };
+    scenario.include_all_in_scope();
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let client_entity = scenario.expect_visible::<Position>(client, &server_entity, by_tick);
+
+    let new_address = scenario.rebind_client(client);
+
+    let mut address_changes = Vec::new();
+    let changed = scenario.step_until(50, |scenario| {
+        let events = scenario.server_events().unwrap();
+        assert!(!events.has::<DisconnectEvent>());
+        address_changes.extend(events.read::<AddressChangeEvent>());
+        !address_changes.is_empty()
+    });
+    assert!(changed, "server never noticed the client's new address");
+    assert_eq!(address_changes.len(), 1);
+    let (changed_user_key, changed_old_address, changed_new_address) = address_changes[0];
+    assert!(changed_user_key == user_key);
+    assert_eq!(changed_old_address, old_address);
+    assert_eq!(changed_new_address, new_address);
+    assert_eq!(scenario.server().user(&user_key).address(), new_address);
+
+    // the Client keeps its scoped Entities, which are still kept up to date
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        let mut entity_mut = server.entity_mut(world.proxy_mut(), &server_entity);
+        *entity_mut.component::<Position>().unwrap().x = 5;
+    }
+    let updated = scenario.step_until(50, |scenario| {
+        client_x(scenario, client, &client_entity) == 5
+    });
+    assert!(updated, "client stopped receiving updates");
+    assert!(scenario.client(client).is_connected());
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn new_client_at_vacated_address_tracks_its_own_changes() {
+    let mut scenario = Scenario::new(protocol);
+    let client_a = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_a = scenario.user_key(client_a).unwrap();
+    let old_address = scenario.server().user(&user_a).address();
+
+    let (room_key, server_entity) = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_a);
+        let entity = server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(0, 0))
+            .enter_room(&room_key)
+            .id();
+        (room_key, entity)
+    };
+    scenario.include_all_in_scope();
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let entity_a = scenario.expect_visible::<Position>(client_a, &server_entity, by_tick);
+
+    // Client A moves away, and another Client connects from its old address
+    scenario.rebind_client(client_a);
+    let changed = scenario.step_until(50, |scenario| {
+        let events = scenario.server_events().unwrap();
+        events.read::<AddressChangeEvent>().next().is_some()
+    });
+    assert!(changed, "server never noticed the client's new address");
+
+    let client_b = scenario.add_client();
+    scenario.rebind_client_to(client_b, &old_address);
+    scenario.connect_clients(200);
+    let user_b = scenario.user_key(client_b).unwrap();
+    assert_eq!(scenario.server().user(&user_b).address(), old_address);
+    scenario.server_mut().room_mut(&room_key).add_user(&user_b);
+    scenario.include_all_in_scope();
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let entity_b = scenario.expect_visible::<Position>(client_b, &server_entity, by_tick);
+
+    // each change reaches both Clients, rather than whichever sends first,
+    // once the insert has been acknowledged
+    scenario.step_ticks(5);
+    for x in 1..4 {
+        {
+            let (server, world) = scenario.server_and_world_mut();
+            let mut entity_mut = server.entity_mut(world.proxy_mut(), &server_entity);
+            *entity_mut.component::<Position>().unwrap().x = x;
+        }
+        let updated = scenario.step_until(50, |scenario| {
+            client_x(scenario, client_a, &entity_a) == x
+                && client_x(scenario, client_b, &entity_b) == x
+        });
+        assert!(updated, "a client missed the update to {x}");
+    }
+}
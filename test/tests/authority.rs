@@ -0,0 +1,454 @@
+use std::time::Duration;
+
+use naia_client::{EntityAuthDeniedEvent, EntityAuthGrantedEvent, EntityAuthRevokedEvent};
+use naia_demo_world::Entity;
+use naia_server::{EntityAuthReleaseEvent, EntityAuthRequestEvent, UserKey};
+use naia_shared::{EntityAuthStatus, Protocol, WorldMutType, WorldRefType};
+use naia_test::{Inventory, Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build()
+}
+
+/// Sets up a Scenario with the given number of connected Clients, and a
+/// Server Entity visible to all of them. Returns the Server Entity, and the
+/// Entity replicating it on each Client.
+fn setup(client_count: usize) -> (Scenario, Entity, Vec<Entity>) {
+    let mut scenario = Scenario::new(protocol);
+    for _ in 0..client_count {
+        scenario.add_client();
+    }
+    scenario.connect_clients(200);
+
+    let server_entity = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        let entity = server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(0, 0))
+            .insert_component(Inventory::new(64))
+            .enter_room(&room_key)
+            .id();
+        for client in 0..client_count {
+            let user_key = scenario.user_key(client).unwrap();
+            scenario
+                .server_mut()
+                .room_mut(&room_key)
+                .add_user(&user_key);
+        }
+        entity
+    };
+    scenario.include_all_in_scope();
+
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let client_entities = (0..client_count)
+        .map(|client| scenario.expect_visible::<Position>(client, &server_entity, by_tick))
+        .collect();
+
+    (scenario, server_entity, client_entities)
+}
+
+fn auth_status(scenario: &Scenario, client: usize, entity: &Entity) -> Option<EntityAuthStatus> {
+    scenario
+        .client(client)
+        .entity(scenario.client_world(client).proxy(), entity)
+        .auth_status()
+}
+
+fn server_auth_status(scenario: &Scenario, entity: &Entity) -> Option<EntityAuthStatus> {
+    scenario
+        .server()
+        .entity(scenario.server_world().proxy(), entity)
+        .auth_status()
+}
+
+/// Steps until the Server receives a request for authority, and returns it
+fn expect_request(scenario: &mut Scenario) -> (UserKey, Entity) {
+    let mut request = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            request = events.read::<EntityAuthRequestEvent>().next();
+        }
+        request.is_some()
+    });
+    request.expect("Server never received request for authority")
+}
+
+/// Steps until the Server is notified that a User released authority
+fn expect_release(scenario: &mut Scenario) -> (UserKey, Entity) {
+    let mut release = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            release = events.read::<EntityAuthReleaseEvent>().next();
+        }
+        release.is_some()
+    });
+    release.expect("Server never received release of authority")
+}
+
+/// Steps until the Client's status of authority over the Entity is `status`
+fn expect_status(
+    scenario: &mut Scenario,
+    client: usize,
+    entity: &Entity,
+    status: EntityAuthStatus,
+) {
+    let reached = scenario.step_until(50, |scenario| {
+        auth_status(scenario, client, entity) == Some(status)
+    });
+    assert!(reached, "Client {client} never reached status {status:?}");
+}
+
+#[test]
+fn requested_authority_is_granted() {
+    let (mut scenario, server_entity, client_entities) = setup(1);
+    let client_entity = client_entities[0];
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Available)
+    );
+
+    scenario.client_mut(0).request_authority(&client_entity);
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Requested)
+    );
+
+    let (user_key, entity) = expect_request(&mut scenario);
+    assert!(user_key == scenario.user_key(0).unwrap());
+    assert!(entity == server_entity);
+    assert_eq!(
+        server_auth_status(&scenario, &server_entity),
+        Some(EntityAuthStatus::Requested)
+    );
+
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    assert!(scenario.server().entity_auth_holder(&server_entity) == Some(user_key));
+    assert_eq!(
+        server_auth_status(&scenario, &server_entity),
+        Some(EntityAuthStatus::Granted)
+    );
+
+    let mut granted = Vec::new();
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            granted.extend(events.read::<EntityAuthGrantedEvent>());
+        }
+        !granted.is_empty()
+    });
+    assert!(granted == vec![client_entity]);
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Granted)
+    );
+}
+
+#[test]
+fn requested_authority_is_denied() {
+    let (mut scenario, server_entity, client_entities) = setup(1);
+    let client_entity = client_entities[0];
+
+    scenario.client_mut(0).request_authority(&client_entity);
+    let (user_key, _) = expect_request(&mut scenario);
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .deny_authority(&user_key);
+    }
+
+    let mut denied = Vec::new();
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            denied.extend(events.read::<EntityAuthDeniedEvent>());
+        }
+        !denied.is_empty()
+    });
+    assert!(denied == vec![client_entity]);
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Denied)
+    );
+    assert!(scenario
+        .server()
+        .entity_auth_holder(&server_entity)
+        .is_none());
+}
+
+#[test]
+fn held_authority_denies_other_requests() {
+    let (mut scenario, server_entity, client_entities) = setup(2);
+
+    scenario
+        .client_mut(0)
+        .request_authority(&client_entities[0]);
+    let (user_a, _) = expect_request(&mut scenario);
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_a);
+    }
+    expect_status(
+        &mut scenario,
+        0,
+        &client_entities[0],
+        EntityAuthStatus::Granted,
+    );
+
+    // a request while another User holds authority is denied by the Server
+    scenario
+        .client_mut(1)
+        .request_authority(&client_entities[1]);
+    expect_status(
+        &mut scenario,
+        1,
+        &client_entities[1],
+        EntityAuthStatus::Denied,
+    );
+    assert!(scenario.server().entity_auth_holder(&server_entity) == Some(user_a));
+}
+
+#[test]
+fn granted_authority_is_revoked() {
+    let (mut scenario, server_entity, client_entities) = setup(1);
+    let client_entity = client_entities[0];
+    let user_key = scenario.user_key(0).unwrap();
+
+    // authority may be granted without being requested
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    expect_status(&mut scenario, 0, &client_entity, EntityAuthStatus::Granted);
+
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .revoke_authority();
+    }
+    assert!(scenario
+        .server()
+        .entity_auth_holder(&server_entity)
+        .is_none());
+
+    let mut revoked = Vec::new();
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            revoked.extend(events.read::<EntityAuthRevokedEvent>());
+        }
+        !revoked.is_empty()
+    });
+    assert!(revoked == vec![client_entity]);
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Available)
+    );
+}
+
+#[test]
+fn granted_authority_replicates_changes() {
+    let (mut scenario, server_entity, client_entities) = setup(2);
+    let user_key = scenario.user_key(0).unwrap();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    expect_status(
+        &mut scenario,
+        0,
+        &client_entities[0],
+        EntityAuthStatus::Granted,
+    );
+
+    // the Client holding authority changes the Component itself
+    {
+        let (_, world) = scenario.client_and_world_mut(0);
+        let mut proxy = world.proxy_mut();
+        let mut position = proxy
+            .component_mut::<Position>(&client_entities[0])
+            .unwrap();
+        *position.x = 7;
+    }
+
+    let replicated = scenario.step_until(50, |scenario| {
+        let server_x = scenario
+            .server_world()
+            .proxy()
+            .component::<Position>(&server_entity)
+            .map(|position| *position.x);
+        let other_client_x = scenario
+            .client_world(1)
+            .proxy()
+            .component::<Position>(&client_entities[1])
+            .map(|position| *position.x);
+        server_x == Some(7) && other_client_x == Some(7)
+    });
+    assert!(
+        replicated,
+        "change by the Client holding authority never replicated"
+    );
+
+    // the change isn't sent back to the Client which made it, so it keeps it
+    let holder_x = scenario
+        .client_world(0)
+        .proxy()
+        .component::<Position>(&client_entities[0])
+        .map(|position| *position.x);
+    assert_eq!(holder_x, Some(7));
+
+    // once authority is revoked, the Server's changes reach the Client again
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        let mut entity_mut = server.entity_mut(world.proxy_mut(), &server_entity);
+        entity_mut.revoke_authority();
+        let mut position = entity_mut.component::<Position>().unwrap();
+        *position.x = 9;
+    }
+    let updated = scenario.step_until(50, |scenario| {
+        scenario
+            .client_world(0)
+            .proxy()
+            .component::<Position>(&client_entities[0])
+            .map(|position| *position.x == 9)
+            .unwrap_or(false)
+    });
+    assert!(
+        updated,
+        "Client never received the Server's change after revocation"
+    );
+}
+
+#[test]
+fn granted_authority_replicates_delta_changes() {
+    let (mut scenario, server_entity, client_entities) = setup(2);
+    let user_key = scenario.user_key(0).unwrap();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    expect_status(
+        &mut scenario,
+        0,
+        &client_entities[0],
+        EntityAuthStatus::Granted,
+    );
+
+    // the second change is written relative to the first, once the Server
+    // has acknowledged it
+    for (slot, value) in [(3, 7), (5, 9)] {
+        {
+            let (_, world) = scenario.client_and_world_mut(0);
+            let mut proxy = world.proxy_mut();
+            let mut inventory = proxy
+                .component_mut::<Inventory>(&client_entities[0])
+                .unwrap();
+            inventory.slots[slot] = value;
+        }
+
+        let replicated = scenario.step_until(50, |scenario| {
+            let server_value = scenario
+                .server_world()
+                .proxy()
+                .component::<Inventory>(&server_entity)
+                .map(|inventory| inventory.slots[slot]);
+            let other_client_value = scenario
+                .client_world(1)
+                .proxy()
+                .component::<Inventory>(&client_entities[1])
+                .map(|inventory| inventory.slots[slot]);
+            server_value == Some(value) && other_client_value == Some(value)
+        });
+        assert!(
+            replicated,
+            "change by the Client holding authority never replicated"
+        );
+        scenario.step_ticks(5);
+    }
+
+    let server_slots = scenario
+        .server_world()
+        .proxy()
+        .component::<Inventory>(&server_entity)
+        .map(|inventory| (*inventory.slots).clone());
+    let mut expected_slots = vec![0; 64];
+    expected_slots[3] = 7;
+    expected_slots[5] = 9;
+    assert_eq!(server_slots, Some(expected_slots));
+}
+
+#[test]
+fn released_authority_returns_to_server() {
+    let (mut scenario, server_entity, client_entities) = setup(1);
+    let client_entity = client_entities[0];
+    let user_key = scenario.user_key(0).unwrap();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    expect_status(&mut scenario, 0, &client_entity, EntityAuthStatus::Granted);
+
+    scenario.client_mut(0).release_authority(&client_entity);
+    assert_eq!(
+        auth_status(&scenario, 0, &client_entity),
+        Some(EntityAuthStatus::Available)
+    );
+
+    let (released_by, entity) = expect_release(&mut scenario);
+    assert!(released_by == user_key);
+    assert!(entity == server_entity);
+    assert!(scenario
+        .server()
+        .entity_auth_holder(&server_entity)
+        .is_none());
+}
+
+#[test]
+fn leaving_scope_releases_authority() {
+    let (mut scenario, server_entity, client_entities) = setup(1);
+    let user_key = scenario.user_key(0).unwrap();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .grant_authority(&user_key);
+    }
+    expect_status(
+        &mut scenario,
+        0,
+        &client_entities[0],
+        EntityAuthStatus::Granted,
+    );
+
+    scenario
+        .server_mut()
+        .user_scope(&user_key)
+        .exclude(&server_entity);
+
+    let (released_by, entity) = expect_release(&mut scenario);
+    assert!(released_by == user_key);
+    assert!(entity == server_entity);
+    assert!(scenario
+        .server()
+        .entity_auth_holder(&server_entity)
+        .is_none());
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use naia_shared::{CongestionConfig, LinkConditionerConfig, Protocol};
+use naia_test::Scenario;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .build()
+}
+
+fn scenario_with_congestion_control() -> Scenario {
+    let (mut server_config, client_config) = Scenario::default_configs();
+    server_config.connection.congestion = Some(CongestionConfig::default());
+    Scenario::with_configs(server_config, client_config, protocol)
+}
+
+#[test]
+fn send_rate_is_none_without_congestion_control() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+
+    assert_eq!(scenario.server().send_rate(&user_key), None);
+}
+
+#[test]
+fn send_rate_holds_on_clean_link() {
+    let mut scenario = scenario_with_congestion_control();
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+
+    scenario.step_ticks(200);
+
+    let initial = CongestionConfig::default().initial_bytes_per_second as f32;
+    assert_eq!(scenario.server().send_rate(&user_key), Some(initial));
+}
+
+#[test]
+fn send_rate_backs_off_on_lossy_link() {
+    let mut scenario = scenario_with_congestion_control();
+    let client = scenario.add_client_with_link_conditioner(LinkConditionerConfig::new(0, 0, 0.5));
+    scenario.connect_clients(1000);
+    let user_key = scenario.user_key(client).unwrap();
+
+    scenario.step_ticks(200);
+
+    let initial = CongestionConfig::default().initial_bytes_per_second as f32;
+    let send_rate = scenario.server().send_rate(&user_key).unwrap();
+    assert!(
+        send_rate < initial,
+        "send rate of {} was not reduced by packet loss",
+        send_rate
+    );
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use naia_server::{AuthEvent, ConnectTokenEvent, ServerConfig};
+use naia_shared::{ConnectToken, Protocol, Timestamp};
+use naia_test::{Auth, Scenario};
+
+const KEY: &[u8] = b"shared with the matchmaker";
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+fn token_scenario() -> Scenario {
+    let (server_config, client_config) = Scenario::default_configs();
+    Scenario::with_connect_tokens(server_config, client_config, KEY, protocol)
+}
+
+fn valid_token(scenario: &Scenario, user_id: u64) -> ConnectToken {
+    ConnectToken::new(
+        user_id,
+        Timestamp::now() + 60,
+        vec![scenario.server_address()],
+        b"team blue".to_vec(),
+    )
+}
+
+fn add_client(scenario: &mut Scenario, signed_token: Option<Vec<u8>>) -> usize {
+    scenario.add_client_with(|client| {
+        if let Some(signed_token) = signed_token {
+            client.connect_token(signed_token);
+        }
+    })
+}
+
+// Steps the Scenario for a while, returning the ConnectTokens the Server has
+// received
+fn run(scenario: &mut Scenario) -> Vec<ConnectToken> {
+    let mut connect_tokens = Vec::new();
+    for _ in 0..200 {
+        scenario.step();
+        let events = scenario.server_events().unwrap();
+        connect_tokens.extend(
+            events
+                .read::<ConnectTokenEvent>()
+                .map(|(_, connect_token)| connect_token),
+        );
+    }
+    connect_tokens
+}
+
+#[test]
+fn valid_token_connects_without_server_accepting() {
+    let mut scenario = token_scenario();
+    let signed_token = valid_token(&scenario, 7).sign(KEY);
+    let client = add_client(&mut scenario, Some(signed_token));
+
+    let connect_tokens = run(&mut scenario);
+
+    assert!(scenario.client(client).is_connected());
+    assert_eq!(scenario.server().users_count(), 1);
+    assert_eq!(connect_tokens.len(), 1);
+    assert_eq!(connect_tokens[0].user_id, 7);
+    assert_eq!(connect_tokens[0].user_data, b"team blue".to_vec());
+}
+
+#[test]
+fn token_with_auth_awaits_server_accepting() {
+    let (server_config, client_config) = Scenario::default_configs();
+    let server_config = ServerConfig {
+        require_auth: true,
+        ..server_config
+    };
+    let mut scenario = Scenario::with_connect_tokens(server_config, client_config, KEY, protocol);
+    let signed_token = valid_token(&scenario, 7).sign(KEY);
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "12345"));
+        client.connect_token(signed_token);
+    });
+
+    let mut auths = Vec::new();
+    let mut connect_tokens = Vec::new();
+    let authed = scenario.step_until(200, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            auths.extend(events.read::<AuthEvent<Auth>>());
+            connect_tokens.extend(events.read::<ConnectTokenEvent>());
+        }
+        !auths.is_empty()
+    });
+    assert!(authed, "server never received the client's auth");
+    assert_eq!(connect_tokens.len(), 1);
+    let (auth_user_key, _) = &auths[0];
+    let (token_user_key, connect_token) = &connect_tokens[0];
+    assert!(auth_user_key == token_user_key);
+    assert_eq!(connect_token.user_id, 7);
+
+    scenario.step_ticks(20);
+    assert!(!scenario.client(client).is_connected());
+
+    scenario.server_mut().accept_connection(auth_user_key);
+    scenario.connect_clients(200);
+}
+
+#[test]
+fn invalid_tokens_do_not_connect() {
+    let mut scenario = token_scenario();
+
+    let mut expired_token = valid_token(&scenario, 2);
+    expired_token.expire_timestamp = Timestamp::now() - 1;
+
+    let mut other_server_token = valid_token(&scenario, 3);
+    other_server_token.server_addresses = vec!["10.0.0.1:14191".parse().unwrap()];
+
+    let other_key_token = valid_token(&scenario, 1).sign(b"some other key");
+    add_client(&mut scenario, None);
+    add_client(&mut scenario, Some(other_key_token));
+    add_client(&mut scenario, Some(expired_token.sign(KEY)));
+    add_client(&mut scenario, Some(other_server_token.sign(KEY)));
+
+    let connect_tokens = run(&mut scenario);
+
+    assert!((0..scenario.clients_count()).all(|client| !scenario.client(client).is_connected()));
+    assert_eq!(scenario.server().users_count(), 0);
+    assert!(connect_tokens.is_empty());
+}
+
+#[test]
+fn token_user_can_only_connect_once() {
+    let mut scenario = token_scenario();
+    let signed_token = valid_token(&scenario, 5).sign(KEY);
+    add_client(&mut scenario, Some(signed_token.clone()));
+    add_client(&mut scenario, Some(signed_token));
+
+    let connect_tokens = run(&mut scenario);
+
+    assert_eq!(scenario.server().users_count(), 1);
+    assert_eq!(connect_tokens.len(), 1);
+    assert_eq!(
+        (0..scenario.clients_count())
+            .filter(|client| scenario.client(*client).is_connected())
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use naia_demo_world::{Entity, WorldRefType};
+use naia_shared::{LinkConditionerConfig, Protocol};
+use naia_test::{Inventory, Scenario};
+
+const SLOTS: usize = 64;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .add_component::<Inventory>()
+        .build()
+}
+
+/// Spawns an Entity with an Inventory visible to the given Client, and
+/// returns the Server & Client Entities
+fn spawn_inventory(scenario: &mut Scenario, client: usize) -> (Entity, Entity) {
+    let user_key = scenario.user_key(client).unwrap();
+
+    let server_entity = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_key);
+        server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Inventory::new(SLOTS))
+            .enter_room(&room_key)
+            .id()
+    };
+    scenario.include_all_in_scope();
+
+    let by_tick = scenario.current_tick().wrapping_add(200);
+    let client_entity = scenario.expect_visible::<Inventory>(client, &server_entity, by_tick);
+
+    (server_entity, client_entity)
+}
+
+fn set_slot(scenario: &mut Scenario, entity: &Entity, slot: usize, item: u32) {
+    let (server, world) = scenario.server_and_world_mut();
+    let mut entity_mut = server.entity_mut(world.proxy_mut(), entity);
+    entity_mut.component::<Inventory>().unwrap().slots[slot] = item;
+}
+
+fn server_slots(scenario: &Scenario, entity: &Entity) -> Vec<u32> {
+    (*scenario
+        .server_world()
+        .proxy()
+        .component::<Inventory>(entity)
+        .unwrap()
+        .slots)
+        .clone()
+}
+
+fn client_slots(scenario: &Scenario, client: usize, entity: &Entity) -> Vec<u32> {
+    (*scenario
+        .client_world(client)
+        .proxy()
+        .component::<Inventory>(entity)
+        .unwrap()
+        .slots)
+        .clone()
+}
+
+/// Changes one slot of the Inventory every tick, then steps until the Client
+/// has caught up with the Server. Returns whether it did.
+fn change_slots_and_catch_up(
+    scenario: &mut Scenario,
+    client: usize,
+    server_entity: &Entity,
+    client_entity: &Entity,
+) -> bool {
+    for tick in 0..100 {
+        let slot = (tick * 7) % SLOTS;
+        set_slot(scenario, server_entity, slot, tick as u32 + 1);
+        scenario.step();
+    }
+
+    let expected = server_slots(scenario, server_entity);
+    scenario.step_until(500, |scenario| {
+        client_slots(scenario, client, client_entity) == expected
+    })
+}
+
+#[test]
+fn delta_property_replicates_changed_elements() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let (server_entity, client_entity) = spawn_inventory(&mut scenario, client);
+
+    assert!(change_slots_and_catch_up(
+        &mut scenario,
+        client,
+        &server_entity,
+        &client_entity
+    ));
+}
+
+#[test]
+fn delta_property_recovers_from_packet_loss() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client_with_link_conditioner(LinkConditionerConfig::new(0, 0, 0.3));
+    scenario.connect_clients(1000);
+    let (server_entity, client_entity) = spawn_inventory(&mut scenario, client);
+
+    assert!(change_slots_and_catch_up(
+        &mut scenario,
+        client,
+        &server_entity,
+        &client_entity
+    ));
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use naia_client::DisconnectEvent as ClientDisconnectEvent;
+use naia_server::DisconnectEvent as ServerDisconnectEvent;
+use naia_shared::{DisconnectReason, Protocol, KICK_MESSAGE_MAX_BYTES};
+use naia_test::Scenario;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .build()
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario until the Client disconnects, returning why
+fn client_disconnect_reason(scenario: &mut Scenario) -> Option<DisconnectReason> {
+    let mut reason = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            if let Some((_, disconnect_reason)) = events.read::<ClientDisconnectEvent>().next() {
+                reason = Some(disconnect_reason);
+            }
+        }
+        reason.is_some()
+    });
+    reason
+}
+
+// Steps the Scenario until the Server disconnects the User, returning why
+fn server_disconnect_reason(scenario: &mut Scenario) -> Option<DisconnectReason> {
+    let mut reason = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            if let Some((_, _, disconnect_reason)) = events.read::<ServerDisconnectEvent>().next() {
+                reason = Some(disconnect_reason);
+            }
+        }
+        reason.is_some()
+    });
+    reason
+}
+
+#[test]
+fn kicked_client_receives_message() {
+    let mut scenario = connected_scenario();
+    let user_key = scenario.user_key(0).unwrap();
+
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .user_mut(&user_key)
+            .kick(world.proxy_mut(), Some("cheating".to_string()));
+    }
+    let server_reason = server_disconnect_reason(&mut scenario);
+    assert_eq!(
+        server_reason,
+        Some(DisconnectReason::Kicked(Some("cheating".to_string())))
+    );
+    assert_eq!(scenario.server().users_count(), 0);
+
+    let client_reason = client_disconnect_reason(&mut scenario);
+    assert_eq!(
+        client_reason,
+        Some(DisconnectReason::Kicked(Some("cheating".to_string())))
+    );
+    assert!(!scenario.client(0).is_connected());
+}
+
+#[test]
+fn oversized_kick_message_is_truncated() {
+    let mut scenario = connected_scenario();
+    let user_key = scenario.user_key(0).unwrap();
+
+    // multi-byte characters, so the limit falls inside one of them
+    let message = "€".repeat(KICK_MESSAGE_MAX_BYTES);
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .user_mut(&user_key)
+            .kick(world.proxy_mut(), Some(message));
+    }
+    let expected = Some(DisconnectReason::Kicked(Some(
+        "€".repeat(KICK_MESSAGE_MAX_BYTES / 3),
+    )));
+    assert_eq!(server_disconnect_reason(&mut scenario), expected);
+    assert_eq!(client_disconnect_reason(&mut scenario), expected);
+}
+
+#[test]
+fn client_quit_is_seen_by_server() {
+    let mut scenario = connected_scenario();
+
+    scenario.client_mut(0).disconnect();
+
+    let client_reason = client_disconnect_reason(&mut scenario);
+    assert_eq!(client_reason, Some(DisconnectReason::ClientQuit));
+
+    let server_reason = server_disconnect_reason(&mut scenario);
+    assert_eq!(server_reason, Some(DisconnectReason::ClientQuit));
+    assert_eq!(scenario.server().users_count(), 0);
+}
+
+#[test]
+fn server_shutdown_disconnects_clients() {
+    let mut scenario = connected_scenario();
+
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server.shutdown(world.proxy_mut());
+    }
+    let server_reason = server_disconnect_reason(&mut scenario);
+    assert_eq!(server_reason, Some(DisconnectReason::ServerShutdown));
+
+    let client_reason = client_disconnect_reason(&mut scenario);
+    assert_eq!(client_reason, Some(DisconnectReason::ServerShutdown));
+}
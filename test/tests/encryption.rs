@@ -0,0 +1,248 @@
+use std::time::Duration;
+
+use naia_client::{ClientConfig, MessageEvent as ClientMessageEvent, RejectEvent};
+use naia_server::{
+    AddressChangeEvent, AuthEvent, ConnectEvent as ServerConnectEvent,
+    MessageEvent as ServerMessageEvent, ServerConfig, UserKey,
+};
+use naia_shared::{
+    default_channels::UnorderedReliableChannel, EncryptionPublicKey, EncryptionSecretKey,
+    KeyExchange, Protocol, RejectReason,
+};
+use naia_test::{Auth, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+// A Scenario whose transport requires encryption, and whose Server requires
+// auth, so that there is a secret to hide during the handshake
+fn encrypted_scenario() -> Scenario {
+    pinned_scenario(None, None)
+}
+
+// An encrypted Scenario, whose Server has the given secret key, and whose
+// Clients only trust a Server with the given public key
+fn pinned_scenario(
+    server_secret_key: Option<EncryptionSecretKey>,
+    trusted_public_key: Option<EncryptionPublicKey>,
+) -> Scenario {
+    let (server_config, client_config) = Scenario::default_configs();
+    let server_config = ServerConfig {
+        require_auth: true,
+        encryption_secret_key: server_secret_key,
+        ..server_config
+    };
+    let client_config = ClientConfig {
+        server_public_key: trusted_public_key,
+        ..client_config
+    };
+    Scenario::with_encryption(server_config, client_config, protocol)
+}
+
+// Steps the Scenario until the given Client has connected, accepting its auth
+fn connect(scenario: &mut Scenario, client: usize) -> UserKey {
+    let connected = scenario.step_until(200, |scenario| {
+        let user_keys: Vec<UserKey> = scenario
+            .server_events()
+            .map(|events| {
+                events
+                    .read::<AuthEvent<Auth>>()
+                    .map(|(user_key, _)| user_key)
+                    .collect()
+            })
+            .unwrap_or_default();
+        for user_key in user_keys {
+            scenario.server_mut().accept_connection(&user_key);
+        }
+        scenario.client(client).is_connected() && scenario.user_key(client).is_some()
+    });
+    assert!(connected, "client never connected to server");
+    scenario.user_key(client).unwrap()
+}
+
+fn wire_contains(packets: &[Box<[u8]>], secret: &str) -> bool {
+    packets.iter().any(|packet| {
+        packet
+            .windows(secret.len())
+            .any(|window| window == secret.as_bytes())
+    })
+}
+
+#[test]
+fn encrypted_transport_hides_auth_and_messages() {
+    let mut scenario = encrypted_scenario();
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "auth-password"));
+    });
+    let user_key = connect(&mut scenario, client);
+
+    scenario
+        .client_mut(client)
+        .send_message::<UnorderedReliableChannel, _>(&Auth::new("client", "client-secret"));
+    let server_received = scenario.step_until(50, |scenario| {
+        let Some(events) = scenario.server_events() else {
+            return false;
+        };
+        let passwords: Vec<String> = events
+            .read::<ServerMessageEvent<UnorderedReliableChannel, Auth>>()
+            .map(|(_, message)| message.password)
+            .collect();
+        passwords.iter().any(|password| password == "client-secret")
+    });
+    assert!(
+        server_received,
+        "server never received the client's message"
+    );
+
+    scenario
+        .server_mut()
+        .send_message::<UnorderedReliableChannel, _>(
+            &user_key,
+            &Auth::new("server", "server-secret"),
+        );
+    let client_received = scenario.step_until(50, |scenario| {
+        let Some(events) = scenario.client_events(client) else {
+            return false;
+        };
+        let passwords: Vec<String> = events
+            .read::<ClientMessageEvent<UnorderedReliableChannel, Auth>>()
+            .map(|message| message.password)
+            .collect();
+        passwords.iter().any(|password| password == "server-secret")
+    });
+    assert!(
+        client_received,
+        "client never received the server's message"
+    );
+
+    for packets in [
+        scenario.server_sent_packets(),
+        scenario.client_sent_packets(client),
+    ] {
+        assert!(!packets.is_empty());
+        assert!(!wire_contains(&packets, "auth-password"));
+        assert!(!wire_contains(&packets, "client-secret"));
+        assert!(!wire_contains(&packets, "server-secret"));
+    }
+}
+
+#[test]
+fn encrypted_transport_drops_forged_packets() {
+    let mut scenario = encrypted_scenario();
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "12345"));
+    });
+    connect(&mut scenario, client);
+
+    // Replay everything the Client has sent, then send it again with a
+    // flipped bit: none of it may reach the Server's connection
+    for packet in scenario.client_sent_packets(client) {
+        scenario.send_raw_to_server(client, &packet);
+        let mut tampered = packet.to_vec();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        scenario.send_raw_to_server(client, &tampered);
+    }
+    scenario.step();
+
+    let events = scenario.server_events().unwrap();
+    assert!(!events.has::<AuthEvent<Auth>>());
+    assert!(!events.has::<ServerConnectEvent>());
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn encrypted_connection_follows_client_to_new_address() {
+    let mut scenario = encrypted_scenario();
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "12345"));
+    });
+    let user_key = connect(&mut scenario, client);
+
+    let new_address = scenario.rebind_client(client);
+
+    let mut address_changes = Vec::new();
+    let address_changed = scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            address_changes.extend(events.read::<AddressChangeEvent>());
+        }
+        !address_changes.is_empty()
+    });
+    assert!(
+        address_changed,
+        "server never noticed the client's new address"
+    );
+    let (changed_user_key, _, changed_address) = address_changes[0];
+    assert!(changed_user_key == user_key);
+    assert_eq!(changed_address, new_address);
+
+    scenario
+        .server_mut()
+        .send_message::<UnorderedReliableChannel, _>(
+            &user_key,
+            &Auth::new("server", "after-rebind"),
+        );
+    let client_received = scenario.step_until(50, |scenario| {
+        let Some(events) = scenario.client_events(client) else {
+            return false;
+        };
+        let passwords: Vec<String> = events
+            .read::<ClientMessageEvent<UnorderedReliableChannel, Auth>>()
+            .map(|message| message.password)
+            .collect();
+        passwords.iter().any(|password| password == "after-rebind")
+    });
+    assert!(
+        client_received,
+        "client never received the server's message"
+    );
+    assert_eq!(scenario.server().user(&user_key).address(), new_address);
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn client_connects_to_server_with_pinned_key() {
+    let server_key = KeyExchange::new();
+    let mut scenario =
+        pinned_scenario(Some(server_key.secret_key()), Some(server_key.public_key()));
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "12345"));
+    });
+    connect(&mut scenario, client);
+}
+
+#[test]
+fn client_rejects_substituted_server_key() {
+    // the Client trusts the real Server's key, but reaches a Server with
+    // another key, as it would through a man-in-the-middle
+    let real_server_key = KeyExchange::new();
+    let impostor_key = KeyExchange::new();
+    let mut scenario = pinned_scenario(
+        Some(impostor_key.secret_key()),
+        Some(real_server_key.public_key()),
+    );
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new("charlie", "auth-password"));
+    });
+
+    let mut reasons = Vec::new();
+    let rejected = scenario.step_until(200, |scenario| {
+        if let Some(events) = scenario.client_events(client) {
+            reasons.extend(events.read::<RejectEvent>().map(|(_, reason)| reason));
+        }
+        !reasons.is_empty()
+    });
+    assert!(rejected, "client never rejected the server");
+    assert_eq!(reasons, vec![RejectReason::ServerKeyMismatch]);
+    assert!(!scenario.client(client).is_connected());
+    assert_eq!(scenario.server().users_count(), 0);
+    assert!(!wire_contains(
+        &scenario.client_sent_packets(client),
+        "auth-password"
+    ));
+}
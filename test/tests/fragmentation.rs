@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use naia_client::MessageEvent as ClientMessageEvent;
+use naia_server::MessageEvent as ServerMessageEvent;
+use naia_shared::{default_channels::UnorderedReliableChannel, Message, Protocol};
+use naia_test::Scenario;
+
+#[derive(Message)]
+pub struct Blob {
+    pub bytes: Vec<u8>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Blob>()
+        .build()
+}
+
+// large enough to be split into several fragments
+fn blob() -> Blob {
+    Blob {
+        bytes: (0..2000).map(|i| i as u8).collect(),
+    }
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+#[test]
+fn large_message_from_client_is_reassembled() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&blob());
+
+    let mut blobs = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            blobs.extend(events.read::<ServerMessageEvent<UnorderedReliableChannel, Blob>>());
+        }
+        !blobs.is_empty()
+    });
+    assert_eq!(blobs.len(), 1);
+    assert_eq!(blobs[0].1.bytes, blob().bytes);
+}
+
+#[test]
+fn large_message_from_server_is_reassembled() {
+    let mut scenario = connected_scenario();
+    let user_key = scenario.user_key(0).unwrap();
+
+    scenario
+        .server_mut()
+        .send_message::<UnorderedReliableChannel, _>(&user_key, &blob());
+
+    let mut blobs = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            blobs.extend(events.read::<ClientMessageEvent<UnorderedReliableChannel, Blob>>());
+        }
+        !blobs.is_empty()
+    });
+    assert_eq!(blobs.len(), 1);
+    assert_eq!(blobs[0].bytes, blob().bytes);
+}
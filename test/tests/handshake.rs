@@ -10,22 +10,28 @@ use naia_test::Auth;
 
 #[test]
 fn end_to_end_handshake_w_auth() {
-    let mut client = ClientHandshakeManager::new(Duration::new(0, 0), Duration::new(0, 0), 1);
-    let mut server = ServerHandshakeManager::new(true);
-    let mut bytes: Box<[u8]>;
-    let mut writer: BitWriter;
-    let mut reader: BitReader;
-
     // Set up Protocol
     let protocol = Protocol::builder().add_message::<Auth>().build();
+    let protocol_fingerprint = protocol.fingerprint();
     let message_kinds = protocol.message_kinds;
 
+    let mut client = ClientHandshakeManager::new(
+        Duration::new(0, 0),
+        Duration::new(0, 0),
+        1,
+        protocol_fingerprint,
+    );
+    let mut server = ServerHandshakeManager::new(true, protocol_fingerprint);
+    let mut bytes: Box<[u8]>;
+    let mut writer: BitWriter;
+    let mut reader: BitReader;
+
     // 0. set Client auth object
     let username = "charlie";
     let password = "1234567";
     client.set_auth_message(MessageContainer::from_write(
         Box::new(Auth::new(username, password)),
-        &FakeEntityConverter,
+        &mut FakeEntityConverter,
     ));
 
     // 1. Client send challenge request
@@ -38,7 +44,9 @@ fn end_to_end_handshake_w_auth() {
     {
         reader = BitReader::new(&bytes);
         StandardHeader::de(&mut reader).expect("unable to read standard header from stream");
-        writer = server.recv_challenge_request(&mut reader).unwrap();
+        writer = server
+            .recv_challenge_request(&message_kinds, &mut reader)
+            .unwrap();
     }
 
     // 3. Server send challenge response
@@ -50,7 +58,7 @@ fn end_to_end_handshake_w_auth() {
     {
         reader = BitReader::new(&bytes);
         StandardHeader::de(&mut reader).expect("unable to read standard header from stream");
-        client.recv_challenge_response(&mut reader);
+        client.recv_challenge_response(&message_kinds, &mut reader);
         assert!(client
             .connection_state
             .eq(&HandshakeState::AwaitingValidateResponse));
@@ -68,7 +76,7 @@ fn end_to_end_handshake_w_auth() {
         StandardHeader::de(&mut reader).expect("unable to read standard header from stream");
         let address = "127.0.0.1:4000".parse().unwrap();
         let result = server.recv_validate_request(&message_kinds, &address, &mut reader);
-        if let HandshakeResult::Success(Some(auth_message)) = result {
+        if let HandshakeResult::Success(Some(auth_message), None) = result {
             let boxed_any = auth_message.to_boxed_any();
             let auth_replica = boxed_any
                 .downcast_ref::<Auth>()
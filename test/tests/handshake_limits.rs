@@ -0,0 +1,161 @@
+use std::{net::IpAddr, time::Duration};
+
+use naia_client::RejectEvent;
+use naia_server::{AuthEvent, HandshakeConfig, ServerConfig};
+use naia_shared::Protocol;
+use naia_test::{Auth, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+fn limits_scenario(require_auth: bool, handshake: HandshakeConfig) -> Scenario {
+    let (server_config, client_config) = Scenario::default_configs();
+    let server_config = ServerConfig {
+        require_auth,
+        handshake,
+        ..server_config
+    };
+    Scenario::with_configs(server_config, client_config, protocol)
+}
+
+fn add_client(scenario: &mut Scenario, auth: bool) -> usize {
+    scenario.add_client_with(|client| {
+        if auth {
+            client.auth(Auth::new("charlie", "12345"));
+        }
+    })
+}
+
+fn localhost() -> IpAddr {
+    "127.0.0.1".parse().unwrap()
+}
+
+// Steps the Scenario for the given number of ticks, never deciding on auth.
+// Returns the number of AuthEvents & RejectEvents
+fn run(scenario: &mut Scenario, ticks: u16) -> (usize, usize) {
+    let mut auths = 0;
+    let mut rejections = 0;
+
+    for _ in 0..ticks {
+        scenario.step();
+        auths += scenario
+            .server_events()
+            .unwrap()
+            .read::<AuthEvent<Auth>>()
+            .count();
+        for client in 0..scenario.clients_count() {
+            rejections += scenario
+                .client_events(client)
+                .unwrap()
+                .read::<RejectEvent>()
+                .count();
+        }
+    }
+
+    (auths, rejections)
+}
+
+#[test]
+fn undecided_auth_times_out() {
+    let mut scenario = limits_scenario(
+        true,
+        HandshakeConfig {
+            auth_timeout: Some(Duration::from_millis(20)),
+            ..Default::default()
+        },
+    );
+    let client = add_client(&mut scenario, true);
+
+    let (auths, rejections) = run(&mut scenario, 100);
+
+    assert!(auths >= 1);
+    assert!(rejections >= 1);
+    assert!(!scenario.client(client).is_connected());
+    assert_eq!(scenario.server().users_count(), 0);
+}
+
+#[test]
+fn repeated_validate_requests_add_one_user() {
+    let mut scenario = limits_scenario(true, HandshakeConfig::default());
+    add_client(&mut scenario, true);
+
+    let (auths, _) = run(&mut scenario, 100);
+
+    assert_eq!(auths, 1);
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn pending_users_are_capped() {
+    let mut scenario = limits_scenario(
+        true,
+        HandshakeConfig {
+            max_pending_users: Some(1),
+            ..Default::default()
+        },
+    );
+    add_client(&mut scenario, true);
+    add_client(&mut scenario, true);
+
+    let (auths, _) = run(&mut scenario, 100);
+
+    assert_eq!(auths, 1);
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn banned_ip_cannot_connect_until_unbanned() {
+    let mut scenario = limits_scenario(false, HandshakeConfig::default());
+    scenario.server_mut().ban_ip(localhost(), None);
+    let client = add_client(&mut scenario, false);
+
+    run(&mut scenario, 100);
+    assert!(scenario.server().is_ip_banned(&localhost()));
+    assert!(!scenario.client(client).is_connected());
+
+    scenario.server_mut().unban_ip(&localhost());
+    run(&mut scenario, 200);
+    assert!(scenario.client(client).is_connected());
+}
+
+#[test]
+fn timed_ban_expires() {
+    let mut scenario = limits_scenario(false, HandshakeConfig::default());
+    scenario
+        .server_mut()
+        .ban_ip(localhost(), Some(Duration::from_millis(300)));
+    assert!(scenario.server().is_ip_banned(&localhost()));
+    let client = add_client(&mut scenario, false);
+
+    run(&mut scenario, 20);
+    assert!(scenario.server().is_ip_banned(&localhost()));
+    assert!(!scenario.client(client).is_connected());
+
+    run(&mut scenario, 200);
+    assert!(!scenario.server().is_ip_banned(&localhost()));
+    assert!(scenario.client(client).is_connected());
+}
+
+#[test]
+fn rate_limited_ip_connects_once_allowed() {
+    let mut scenario = limits_scenario(
+        false,
+        HandshakeConfig {
+            max_packets_per_ip_per_second: Some(3),
+            ..Default::default()
+        },
+    );
+    let client = add_client(&mut scenario, false);
+
+    run(&mut scenario, 100);
+    assert!(!scenario.client(client).is_connected());
+
+    scenario.server_mut().allow_ip(localhost());
+    run(&mut scenario, 200);
+    assert!(scenario.client(client).is_connected());
+}
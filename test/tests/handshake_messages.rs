@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use naia_client::{
+    AcceptMessageEvent, ConnectEvent as ClientConnectEvent, RejectEvent, RejectMessageEvent,
+};
+use naia_demo_world::Entity;
+use naia_server::{AuthEvent, Server, ServerConfig, UserKey};
+use naia_shared::{Message, Protocol, MTU_SIZE_BYTES};
+use naia_test::{Auth, Scenario};
+
+#[derive(Message)]
+pub struct Welcome {
+    pub player_id: u16,
+}
+
+#[derive(Message)]
+pub struct RejectReason {
+    pub reason: String,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_message::<Welcome>()
+        .add_message::<RejectReason>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+// Connects a Client with the given credentials, letting the Server accept or
+// reject it, until the Client connects or is rejected. The Client's events of
+// that step are left in the Scenario.
+fn run_handshake<F: FnMut(&mut Server<Entity>, Auth, &UserKey)>(
+    username: &str,
+    mut handle_auth: F,
+) -> (Scenario, usize) {
+    let (server_config, client_config) = Scenario::default_configs();
+    let server_config = ServerConfig {
+        require_auth: true,
+        ..server_config
+    };
+    let mut scenario = Scenario::with_configs(server_config, client_config, protocol);
+    let client = scenario.add_client_with(|client| {
+        client.auth(Auth::new(username, "12345"));
+    });
+
+    let finished = scenario.step_until(200, |scenario| {
+        let auths: Vec<(UserKey, Auth)> = scenario
+            .server_events()
+            .map(|events| events.read::<AuthEvent<Auth>>().collect())
+            .unwrap_or_default();
+        for (user_key, auth) in auths {
+            handle_auth(scenario.server_mut(), auth, &user_key);
+        }
+        scenario
+            .client_events(client)
+            .is_some_and(|events| events.has::<ClientConnectEvent>() || events.has::<RejectEvent>())
+    });
+    assert!(finished, "client was neither connected nor rejected");
+
+    (scenario, client)
+}
+
+#[test]
+fn accepted_client_receives_accept_message() {
+    let (mut scenario, client) = run_handshake("charlie", |server, _, user_key| {
+        server
+            .accept_connection_with(user_key, Welcome { player_id: 12 })
+            .unwrap();
+    });
+
+    assert!(scenario.client(client).is_connected());
+    let events = scenario.client_events(client).unwrap();
+    assert!(events.has::<AcceptMessageEvent<Welcome>>());
+    assert!(!events.has::<AcceptMessageEvent<RejectReason>>());
+    let welcomes: Vec<Welcome> = events.read::<AcceptMessageEvent<Welcome>>().collect();
+    assert_eq!(welcomes.len(), 1);
+    assert_eq!(welcomes[0].player_id, 12);
+}
+
+#[test]
+fn rejected_client_receives_reject_message() {
+    let (mut scenario, client) = run_handshake("mallory", |server, auth, user_key| {
+        assert_eq!(auth.username, "mallory");
+        server
+            .reject_connection_with(
+                user_key,
+                RejectReason {
+                    reason: "banned".to_string(),
+                },
+            )
+            .unwrap();
+    });
+
+    assert!(!scenario.client(client).is_connected());
+    let events = scenario.client_events(client).unwrap();
+    assert!(!events.has::<ClientConnectEvent>());
+    let reasons: Vec<RejectReason> = events.read::<RejectMessageEvent<RejectReason>>().collect();
+    assert_eq!(reasons.len(), 1);
+    assert_eq!(reasons[0].reason, "banned");
+}
+
+#[test]
+fn accept_without_message_has_no_accept_message() {
+    let (mut scenario, client) = run_handshake("charlie", |server, _, user_key| {
+        server.accept_connection(user_key);
+    });
+
+    assert!(scenario.client(client).is_connected());
+    let events = scenario.client_events(client).unwrap();
+    assert_eq!(events.read::<AcceptMessageEvent<Welcome>>().count(), 0);
+}
+
+#[test]
+fn oversized_accept_message_is_refused() {
+    let (mut scenario, client) = run_handshake("charlie", |server, _, user_key| {
+        let result = server.accept_connection_with(
+            user_key,
+            RejectReason {
+                reason: "x".repeat(MTU_SIZE_BYTES),
+            },
+        );
+        assert!(result.is_err());
+        server.accept_connection(user_key);
+    });
+
+    assert!(scenario.client(client).is_connected());
+    let events = scenario.client_events(client).unwrap();
+    assert_eq!(events.read::<AcceptMessageEvent<RejectReason>>().count(), 0);
+}
+
+#[test]
+fn oversized_reject_message_is_refused() {
+    let (mut scenario, client) = run_handshake("mallory", |server, _, user_key| {
+        let result = server.reject_connection_with(
+            user_key,
+            RejectReason {
+                reason: "x".repeat(MTU_SIZE_BYTES),
+            },
+        );
+        assert!(result.is_err());
+        server.reject_connection(user_key);
+    });
+
+    assert!(!scenario.client(client).is_connected());
+    let events = scenario.client_events(client).unwrap();
+    assert!(events.has::<RejectEvent>());
+    assert_eq!(events.read::<RejectMessageEvent<RejectReason>>().count(), 0);
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use naia_server::MessageEvent as ServerMessageEvent;
+use naia_shared::{
+    default_channels::SequencedUnreliableChannel, Channel, ChannelDirection, ChannelMode, Message,
+    Protocol,
+};
+use naia_test::Scenario;
+
+#[derive(Message)]
+pub struct Cursor {
+    pub player: u8,
+    pub x: u8,
+}
+
+#[derive(Channel)]
+pub struct CursorChannel;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_channel::<CursorChannel>(
+            ChannelDirection::Bidirectional,
+            ChannelMode::UnreliableLatestByKey,
+        )
+        .add_message::<Cursor>()
+        .build()
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario for the given number of ticks, collecting the Cursors the
+// Server has received
+fn server_cursors(scenario: &mut Scenario, ticks: u16) -> Vec<(u8, u8)> {
+    let mut cursors = Vec::new();
+    for _ in 0..ticks {
+        scenario.step();
+        let events = scenario.server_events().unwrap();
+        cursors.extend(
+            events
+                .read::<ServerMessageEvent<CursorChannel, Cursor>>()
+                .map(|(_, cursor)| (cursor.player, cursor.x)),
+        );
+    }
+    cursors
+}
+
+fn send_cursor(scenario: &mut Scenario, player: u8, x: u8) {
+    scenario
+        .client_mut(0)
+        .send_message_keyed::<CursorChannel, _>(&player, &Cursor { player, x });
+}
+
+#[test]
+fn only_newest_message_per_key_is_sent() {
+    let mut scenario = connected_scenario();
+
+    for x in 0..3 {
+        send_cursor(&mut scenario, 1, x);
+        send_cursor(&mut scenario, 2, x);
+    }
+    send_cursor(&mut scenario, 1, 3);
+
+    let mut cursors = server_cursors(&mut scenario, 20);
+    cursors.sort();
+    assert_eq!(cursors, vec![(1, 3), (2, 2)]);
+}
+
+#[test]
+fn unkeyed_messages_only_send_the_newest() {
+    let mut scenario = connected_scenario();
+
+    for x in 0..3 {
+        scenario
+            .client_mut(0)
+            .send_message::<CursorChannel, _>(&Cursor { player: 1, x });
+    }
+
+    assert_eq!(server_cursors(&mut scenario, 20), vec![(1, 2)]);
+}
+
+#[test]
+fn stale_message_is_dropped_per_key() {
+    let mut scenario = connected_scenario();
+
+    // hold back the packets carrying the first Cursor of each player
+    send_cursor(&mut scenario, 1, 0);
+    send_cursor(&mut scenario, 2, 0);
+    scenario.step();
+    let delayed_packets = scenario.take_packets_to_server();
+
+    send_cursor(&mut scenario, 1, 1);
+    assert_eq!(server_cursors(&mut scenario, 20), vec![(1, 1)]);
+
+    // once they arrive, only the Cursor for player 2 is still newest
+    for (_, payload) in delayed_packets {
+        scenario.send_raw_to_server(0, &payload);
+    }
+    assert_eq!(server_cursors(&mut scenario, 20), vec![(2, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn keyed_message_on_sequenced_unreliable_channel_panics() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message_keyed::<SequencedUnreliableChannel, _>(&1u8, &Cursor { player: 1, x: 0 });
+}
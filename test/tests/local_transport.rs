@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use naia_client::{
+    transport::local::Socket as ClientSocket, Client, ClientConfig,
+    ConnectEvent as ClientConnectEvent,
+};
+use naia_demo_world::{Entity, World};
+use naia_server::{
+    transport::local::Socket as ServerSocket, AuthEvent, ConnectEvent as ServerConnectEvent,
+    Server, ServerConfig,
+};
+use naia_shared::{Clock, LocalTransportHub, Protocol};
+use naia_test::Auth;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+fn client_config() -> ClientConfig {
+    ClientConfig {
+        send_handshake_interval: Duration::from_millis(5),
+        ping_interval: Duration::from_millis(5),
+        handshake_pings: 2,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn local_transport_connects_client() {
+    // drive time manually, so the outcome doesn't depend on how fast this runs
+    Clock::set_manual();
+
+    let hub = LocalTransportHub::new("127.0.0.1:14191".parse().unwrap());
+
+    let mut server_world = World::default();
+    let mut server = Server::<Entity>::new(ServerConfig::default(), protocol());
+    server.listen(ServerSocket::new(&hub, None));
+
+    let mut client_world = World::default();
+    let mut client = Client::<Entity>::new(client_config(), protocol());
+    client.auth(Auth::new("charlie", "12345"));
+    let client_socket = ClientSocket::new(&hub, None);
+    let client_addr = client_socket.client_addr();
+    client.connect(client_socket);
+
+    let mut server_connected = false;
+    let mut client_connected = false;
+
+    for _ in 0..1000 {
+        let mut events = server.receive(server_world.proxy_mut());
+        for (user_key, auth) in events.read::<AuthEvent<Auth>>() {
+            assert_eq!(auth.username, "charlie");
+            server.accept_connection(&user_key);
+        }
+        for user_key in events.read::<ServerConnectEvent>() {
+            assert_eq!(server.user(&user_key).address(), client_addr);
+            server_connected = true;
+        }
+        server.send_all_updates(server_world.proxy());
+
+        let mut events = client.receive(client_world.proxy_mut());
+        for server_addr in events.read::<ClientConnectEvent>() {
+            assert_eq!(server_addr, hub.server_addr());
+            client_connected = true;
+        }
+
+        if server_connected && client_connected {
+            break;
+        }
+        Clock::advance(Duration::from_millis(1));
+    }
+
+    assert!(server_connected, "server never received a connection");
+    assert!(client_connected, "client never connected to server");
+    assert!(client.is_connected());
+    assert_eq!(server.users_count(), 1);
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use naia_server::DisconnectEvent;
+use naia_shared::{
+    default_channels::{OrderedReliableChannel, UnorderedReliableChannel},
+    BitReader, BitWrite, BitWriter, DisconnectReason, PacketType, Protocol, Serde, StandardHeader,
+};
+use naia_test::{Auth, Inventory, Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_client_authoritative_entities()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build()
+}
+
+// Connects a Client which has sent a Message & spawned an Entity, returning
+// the packets it sent the Server on the last step, before they're received
+fn connected_scenario() -> (Scenario, Vec<Box<[u8]>>) {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+
+    {
+        let (client, world) = scenario.client_and_world_mut(0);
+        client
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(1, 2))
+            .insert_component(Inventory::new(3));
+        client.send_message::<UnorderedReliableChannel, _>(&Auth::new("charlie", "1234567"));
+        client.send_message::<OrderedReliableChannel, _>(&Auth::new("delta", "7654321"));
+    }
+    scenario.step();
+
+    let packets = scenario
+        .take_packets_to_server()
+        .into_iter()
+        .map(|(_, packet)| packet)
+        .collect();
+    (scenario, packets)
+}
+
+// Writes a Data packet which belongs to the Client's connection, followed
+// by the given bytes
+fn data_packet(sent_packet: &[u8], bytes: &[u8]) -> Box<[u8]> {
+    let mut reader = BitReader::new(sent_packet);
+    let header = StandardHeader::de(&mut reader).unwrap();
+    let connection_id = u64::de(&mut reader).unwrap();
+
+    let mut writer = BitWriter::new();
+    StandardHeader::new(
+        PacketType::Data,
+        header.sender_packet_index.wrapping_add(1),
+        header.sender_ack_index,
+        header.sender_ack_bitfield,
+    )
+    .ser(&mut writer);
+    connection_id.ser(&mut writer);
+    for byte in bytes {
+        writer.write_byte(*byte);
+    }
+    writer.to_bytes()
+}
+
+// Steps the Scenario until the Server disconnects the User, returning why
+fn server_disconnect_reason(scenario: &mut Scenario) -> Option<DisconnectReason> {
+    let mut reason = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            if let Some((_, _, disconnect_reason)) = events.read::<DisconnectEvent>().next() {
+                reason = Some(disconnect_reason);
+            }
+        }
+        reason.is_some()
+    });
+    reason
+}
+
+#[test]
+fn garbage_before_connecting_is_dropped() {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+
+    scenario.send_raw_to_server(0, &[]);
+    scenario.send_raw_to_server(0, &[0xFF; 64]);
+    for packet_type in 0..16 {
+        let mut packet = vec![packet_type << 4];
+        packet.extend_from_slice(&[0xAB; 32]);
+        scenario.send_raw_to_server(0, &packet);
+    }
+    scenario.step();
+
+    scenario.connect_clients(200);
+}
+
+#[test]
+fn malformed_data_packet_kicks_user() {
+    let (mut scenario, packets) = connected_scenario();
+
+    scenario.send_raw_to_server(0, &data_packet(&packets[0], &[0xFF; 64]));
+
+    assert_eq!(
+        server_disconnect_reason(&mut scenario),
+        Some(DisconnectReason::ProtocolError)
+    );
+    assert_eq!(scenario.server().users_count(), 0);
+}
+
+#[test]
+fn mutated_packets_do_not_crash_server() {
+    // xorshift, so the mutations are the same on every run
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    for _ in 0..500 {
+        let (mut scenario, packets) = connected_scenario();
+        for packet in packets {
+            let mut packet = packet.to_vec();
+            for _ in 0..1 + random() % 8 {
+                let index = random() as usize % packet.len();
+                packet[index] ^= 1 << (random() % 8);
+            }
+            packet.truncate(packet.len() - random() as usize % (packet.len() / 2 + 1));
+
+            scenario.send_raw_to_server(0, &packet);
+            scenario.step_ticks(3);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use naia_client::MessageDeliveredEvent as ClientMessageDeliveredEvent;
+use naia_server::MessageDeliveredEvent as ServerMessageDeliveredEvent;
+use naia_shared::{
+    default_channels::{UnorderedReliableChannel, UnorderedUnreliableChannel},
+    Message, MessageHandle, Protocol,
+};
+use naia_test::Scenario;
+
+#[derive(Message)]
+pub struct Trade {
+    pub items: Vec<u8>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Trade>()
+        .build()
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+fn trade(size: usize) -> Trade {
+    Trade {
+        items: vec![7; size],
+    }
+}
+
+// Steps the Scenario for the given number of ticks, collecting the handles of
+// the Messages the Client has been told were delivered
+fn client_deliveries(scenario: &mut Scenario, ticks: u16) -> Vec<MessageHandle> {
+    let mut deliveries = Vec::new();
+    for _ in 0..ticks {
+        scenario.step();
+        let events = scenario.client_events(0).unwrap();
+        deliveries.extend(events.read::<ClientMessageDeliveredEvent>());
+    }
+    deliveries
+}
+
+#[test]
+fn client_is_told_when_reliable_message_is_delivered() {
+    let mut scenario = connected_scenario();
+
+    let handle = scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&trade(4))
+        .unwrap();
+
+    assert_eq!(client_deliveries(&mut scenario, 50), vec![handle]);
+}
+
+#[test]
+fn server_is_told_when_reliable_message_is_delivered() {
+    let mut scenario = connected_scenario();
+    let user_key = scenario.user_key(0).unwrap();
+
+    let handle = scenario
+        .server_mut()
+        .send_message::<UnorderedReliableChannel, _>(&user_key, &trade(4))
+        .unwrap();
+
+    let mut deliveries = Vec::new();
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            deliveries.extend(events.read::<ServerMessageDeliveredEvent>());
+        }
+        !deliveries.is_empty()
+    });
+    assert_eq!(deliveries.len(), 1);
+    let (delivered_user_key, delivered_handle) = deliveries.pop().unwrap();
+    assert!(delivered_user_key == user_key);
+    assert_eq!(delivered_handle, handle);
+}
+
+#[test]
+fn fragmented_message_is_delivered_once() {
+    let mut scenario = connected_scenario();
+
+    // large enough to be split into several fragments
+    let handle = scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&trade(2000))
+        .unwrap();
+
+    assert_eq!(client_deliveries(&mut scenario, 100), vec![handle]);
+}
+
+#[test]
+fn unreliable_message_delivery_is_not_reported() {
+    let mut scenario = connected_scenario();
+
+    assert!(scenario
+        .client_mut(0)
+        .send_message::<UnorderedUnreliableChannel, _>(&trade(4))
+        .is_some());
+
+    assert!(client_deliveries(&mut scenario, 50).is_empty());
+}
+
+#[test]
+fn message_without_connection_has_no_handle() {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+
+    assert!(scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&trade(4))
+        .is_none());
+}
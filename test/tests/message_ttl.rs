@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use naia_client::MessageExpiredEvent as ClientMessageExpiredEvent;
+use naia_server::MessageEvent as ServerMessageEvent;
+use naia_shared::{
+    default_channels::{OrderedReliableChannel, UnorderedReliableChannel},
+    Channel, ChannelDirection, ChannelMode, Message, MessageHandle, Protocol, ReliableSettings,
+};
+use naia_test::Scenario;
+
+#[derive(Message)]
+pub struct Ability {
+    pub id: u8,
+}
+
+#[derive(Channel)]
+pub struct AbilityChannel;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_channel::<AbilityChannel>(
+            ChannelDirection::Bidirectional,
+            ChannelMode::SequencedReliable(ReliableSettings {
+                message_ttl: Some(Duration::from_millis(100)),
+                ..ReliableSettings::default()
+            }),
+        )
+        .add_message::<Ability>()
+        .build()
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario for the given number of ticks while every packet from the
+// Client is lost, collecting the handles of the Messages the Client has been
+// told expired
+fn lose_client_packets(scenario: &mut Scenario, ticks: u16) -> Vec<MessageHandle> {
+    let mut expiries = Vec::new();
+    for _ in 0..ticks {
+        scenario.step();
+        scenario.take_packets_to_server();
+        let events = scenario.client_events(0).unwrap();
+        expiries.extend(events.read::<ClientMessageExpiredEvent>());
+    }
+    expiries
+}
+
+// Steps the Scenario for the given number of ticks, collecting the ids of the
+// Abilities the Server has received on the given Channel
+fn server_abilities<C: Channel>(scenario: &mut Scenario, ticks: u16) -> Vec<u8> {
+    let mut abilities = Vec::new();
+    for _ in 0..ticks {
+        scenario.step();
+        let events = scenario.server_events().unwrap();
+        abilities.extend(
+            events
+                .read::<ServerMessageEvent<C, Ability>>()
+                .map(|(_, ability)| ability.id),
+        );
+    }
+    abilities
+}
+
+#[test]
+fn undelivered_message_expires_after_its_ttl() {
+    let mut scenario = connected_scenario();
+
+    let handle = scenario
+        .client_mut(0)
+        .send_message_with_ttl::<UnorderedReliableChannel, _>(
+            &Ability { id: 1 },
+            Duration::from_millis(100),
+        )
+        .unwrap();
+
+    assert_eq!(lose_client_packets(&mut scenario, 30), vec![handle]);
+
+    // the expired Message is never resent, but later ones still get through
+    scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&Ability { id: 2 });
+    assert_eq!(
+        server_abilities::<UnorderedReliableChannel>(&mut scenario, 50),
+        vec![2]
+    );
+}
+
+#[test]
+fn delivered_message_does_not_expire() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message_with_ttl::<UnorderedReliableChannel, _>(
+            &Ability { id: 1 },
+            Duration::from_millis(100),
+        );
+
+    let mut expiries = Vec::new();
+    let mut abilities = Vec::new();
+    for _ in 0..50 {
+        scenario.step();
+        let events = scenario.client_events(0).unwrap();
+        expiries.extend(events.read::<ClientMessageExpiredEvent>());
+        let events = scenario.server_events().unwrap();
+        abilities.extend(
+            events
+                .read::<ServerMessageEvent<UnorderedReliableChannel, Ability>>()
+                .map(|(_, ability)| ability.id),
+        );
+    }
+    assert!(expiries.is_empty());
+    assert_eq!(abilities, vec![1]);
+}
+
+#[test]
+fn channel_ttl_applies_to_every_message() {
+    let mut scenario = connected_scenario();
+
+    let handle = scenario
+        .client_mut(0)
+        .send_message::<AbilityChannel, _>(&Ability { id: 1 })
+        .unwrap();
+
+    assert_eq!(lose_client_packets(&mut scenario, 30), vec![handle]);
+
+    scenario
+        .client_mut(0)
+        .send_message::<AbilityChannel, _>(&Ability { id: 2 });
+    assert_eq!(
+        server_abilities::<AbilityChannel>(&mut scenario, 50),
+        vec![2]
+    );
+}
+
+#[test]
+#[should_panic]
+fn ttl_on_ordered_channel_panics() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message_with_ttl::<OrderedReliableChannel, _>(
+            &Ability { id: 1 },
+            Duration::from_millis(100),
+        );
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use naia_server::MessageEvent as ServerMessageEvent;
+use naia_shared::{
+    default_channels::{OrderedReliableChannel, UnorderedReliableChannel},
+    Message, Protocol,
+};
+use naia_test::Scenario;
+
+#[derive(Message)]
+pub struct Move {
+    pub unit: u8,
+    pub step: u8,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Move>()
+        .build()
+}
+
+fn connected_scenario() -> Scenario {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario for the given number of ticks, collecting the Moves the
+// Server has received on the OrderedReliable Channel
+fn server_moves(scenario: &mut Scenario, ticks: u16) -> Vec<(u8, u8)> {
+    let mut moves = Vec::new();
+    for _ in 0..ticks {
+        scenario.step();
+        let events = scenario.server_events().unwrap();
+        moves.extend(
+            events
+                .read::<ServerMessageEvent<OrderedReliableChannel, Move>>()
+                .map(|(_, message)| (message.unit, message.step)),
+        );
+    }
+    moves
+}
+
+fn send_move(scenario: &mut Scenario, unit: u8, step: u8) {
+    scenario
+        .client_mut(0)
+        .send_message_keyed::<OrderedReliableChannel, _>(&unit, &Move { unit, step });
+}
+
+#[test]
+fn keyed_messages_arrive_in_order() {
+    let mut scenario = connected_scenario();
+
+    for step in 0..5 {
+        send_move(&mut scenario, 1, step);
+        send_move(&mut scenario, 2, step);
+    }
+
+    let moves = server_moves(&mut scenario, 50);
+    let unit_steps = |unit: u8| -> Vec<u8> {
+        moves
+            .iter()
+            .filter(|(moved_unit, _)| *moved_unit == unit)
+            .map(|(_, step)| *step)
+            .collect()
+    };
+    assert_eq!(unit_steps(1), vec![0, 1, 2, 3, 4]);
+    assert_eq!(unit_steps(2), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn unkeyed_messages_arrive_in_order() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message::<OrderedReliableChannel, _>(&Move { unit: 1, step: 0 });
+    scenario.step();
+    scenario.take_packets_to_server();
+
+    for step in 1..4 {
+        scenario
+            .client_mut(0)
+            .send_message::<OrderedReliableChannel, _>(&Move { unit: 1, step });
+    }
+
+    assert_eq!(
+        server_moves(&mut scenario, 50),
+        vec![(1, 0), (1, 1), (1, 2), (1, 3)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn keyed_message_on_unordered_channel_panics() {
+    let mut scenario = connected_scenario();
+
+    scenario
+        .client_mut(0)
+        .send_message_keyed::<UnorderedReliableChannel, _>(&1u8, &Move { unit: 1, step: 0 });
+}
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use naia_demo_world::{Entity, WorldRefType};
+use naia_shared::Protocol;
+use naia_test::{Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .add_component::<Position>()
+        .build()
+}
+
+/// Sets up a Scenario with a single connected Client, whose connection only
+/// has room for a single Entity update each tick, and the given number of
+/// Entities visible to that Client. Returns the Server & Client Entities.
+fn setup(entity_count: usize) -> (Scenario, Vec<Entity>, Vec<Entity>) {
+    let (mut server_config, client_config) = Scenario::default_configs();
+    server_config.connection.update_bytes_per_tick = Some(1);
+
+    let mut scenario = Scenario::with_configs(server_config, client_config, protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+
+    let mut server_entities = Vec::new();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_key);
+        for _ in 0..entity_count {
+            let entity = server
+                .spawn_entity(world.proxy_mut())
+                .insert_component(Position::new(0, 0))
+                .enter_room(&room_key)
+                .id();
+            server_entities.push(entity);
+        }
+    }
+    scenario.include_all_in_scope();
+
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let client_entities = server_entities
+        .iter()
+        .map(|entity| scenario.expect_visible::<Position>(client, entity, by_tick))
+        .collect();
+
+    // let the inserts be acknowledged, so that updates can be sent
+    scenario.step_ticks(5);
+
+    (scenario, server_entities, client_entities)
+}
+
+fn set_x(scenario: &mut Scenario, entity: &Entity, x: i16) {
+    let (server, world) = scenario.server_and_world_mut();
+    let mut entity_mut = server.entity_mut(world.proxy_mut(), entity);
+    *entity_mut.component::<Position>().unwrap().x = x;
+}
+
+fn client_x(scenario: &Scenario, entity: &Entity) -> i16 {
+    *scenario
+        .client_world(0)
+        .proxy()
+        .component::<Position>(entity)
+        .unwrap()
+        .x
+}
+
+/// Steps until the first of the given Client Entities is updated
+fn step_until_first_update(scenario: &mut Scenario, client_entities: &[Entity]) {
+    let updated = scenario.step_until(20, |scenario| {
+        client_entities
+            .iter()
+            .any(|entity| client_x(scenario, entity) != 0)
+    });
+    assert!(updated, "No update was ever sent");
+}
+
+#[test]
+fn higher_priority_entity_updates_first() {
+    let (mut scenario, server_entities, client_entities) = setup(3);
+
+    let (server, world) = scenario.server_and_world_mut();
+    server
+        .entity_mut(world.proxy_mut(), &server_entities[2])
+        .set_priority(10.0);
+    for entity in &server_entities {
+        set_x(&mut scenario, entity, 1);
+    }
+
+    // only the highest priority update fits in the first tick
+    step_until_first_update(&mut scenario, &client_entities);
+    assert_eq!(client_x(&scenario, &client_entities[2]), 1);
+    assert_eq!(client_x(&scenario, &client_entities[0]), 0);
+    assert_eq!(client_x(&scenario, &client_entities[1]), 0);
+
+    // the rest follow in later ticks
+    let updated = scenario.step_until(10, |scenario| {
+        client_entities
+            .iter()
+            .all(|entity| client_x(scenario, entity) == 1)
+    });
+    assert!(updated, "Lower priority updates were never sent");
+}
+
+#[test]
+fn user_priority_multiplies_entity_priority() {
+    let (mut scenario, server_entities, client_entities) = setup(2);
+
+    // globally favour the first entity, but favour the second more for this user
+    let user_key = scenario.user_key(0).unwrap();
+    let (server, world) = scenario.server_and_world_mut();
+    server
+        .entity_mut(world.proxy_mut(), &server_entities[0])
+        .set_priority(2.0);
+    server
+        .user_scope(&user_key)
+        .set_priority(&server_entities[1], 4.0);
+    for entity in &server_entities {
+        set_x(&mut scenario, entity, 1);
+    }
+
+    step_until_first_update(&mut scenario, &client_entities);
+    assert_eq!(client_x(&scenario, &client_entities[1]), 1);
+    assert_eq!(client_x(&scenario, &client_entities[0]), 0);
+}
+
+#[test]
+fn skipped_entity_accumulates_priority() {
+    let (mut scenario, server_entities, client_entities) = setup(2);
+
+    // the busy entity changes every tick, with twice the priority
+    let busy_entity = server_entities[0];
+    let quiet_entity = server_entities[1];
+    let (server, world) = scenario.server_and_world_mut();
+    server
+        .entity_mut(world.proxy_mut(), &busy_entity)
+        .set_priority(2.0);
+    set_x(&mut scenario, &quiet_entity, 1);
+
+    let mut x = 0;
+    let updated = scenario.step_until(20, |scenario| {
+        x += 1;
+        set_x(scenario, &busy_entity, x);
+        client_x(scenario, &client_entities[1]) == 1
+    });
+    // the quiet entity should have been sent on the third tick
+    assert!(x < 10, "Quiet Entity was only sent after {} ticks", x);
+    assert!(updated, "Quiet Entity was starved by busy Entity");
+}
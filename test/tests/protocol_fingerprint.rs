@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use naia_client::{DisconnectEvent, RejectEvent};
+use naia_shared::{DisconnectReason, Protocol, RejectReason};
+use naia_test::{Auth, Inventory, Position, Scenario};
+
+mod v1 {
+    use naia_shared::Message;
+
+    #[derive(Message)]
+    pub struct Chat {
+        pub text: String,
+    }
+}
+
+mod v2 {
+    use naia_shared::Message;
+
+    #[derive(Message)]
+    pub struct Chat {
+        pub text: String,
+        pub team_only: bool,
+    }
+}
+
+mod v1_qualified {
+    use naia_shared::Message;
+
+    #[derive(Message)]
+    pub struct Chat {
+        pub text: std::string::String,
+    }
+}
+
+mod nested_v1 {
+    use naia_shared::{Message, Serde};
+
+    #[derive(Clone, PartialEq, Serde)]
+    pub struct Sender {
+        pub name: String,
+    }
+
+    #[derive(Message)]
+    pub struct Chat {
+        pub sender: Sender,
+    }
+}
+
+mod nested_v2 {
+    use naia_shared::{Message, Serde};
+
+    #[derive(Clone, PartialEq, Serde)]
+    pub struct Sender {
+        pub name: String,
+        pub team: u8,
+    }
+
+    #[derive(Message)]
+    pub struct Chat {
+        pub sender: Sender,
+    }
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .tick_interval(Duration::from_millis(10))
+        .build()
+}
+
+#[test]
+fn equal_protocols_have_equal_fingerprints() {
+    assert_eq!(protocol().fingerprint(), protocol().fingerprint());
+}
+
+#[test]
+fn fingerprint_depends_on_registration_order() {
+    let reordered = Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Inventory>()
+        .add_component::<Position>()
+        .tick_interval(Duration::from_millis(10))
+        .build();
+
+    assert_ne!(protocol().fingerprint(), reordered.fingerprint());
+}
+
+#[test]
+fn fingerprint_depends_on_fields() {
+    let old = Protocol::builder().add_message::<v1::Chat>().build();
+    let new = Protocol::builder().add_message::<v2::Chat>().build();
+
+    assert_ne!(old.fingerprint(), new.fingerprint());
+}
+
+#[test]
+fn fingerprint_ignores_type_paths() {
+    let unqualified = Protocol::builder().add_message::<v1::Chat>().build();
+    let qualified = Protocol::builder()
+        .add_message::<v1_qualified::Chat>()
+        .build();
+
+    assert_eq!(unqualified.fingerprint(), qualified.fingerprint());
+}
+
+#[test]
+fn fingerprint_depends_on_nested_fields() {
+    let old = Protocol::builder().add_message::<nested_v1::Chat>().build();
+    let new = Protocol::builder().add_message::<nested_v2::Chat>().build();
+
+    assert_ne!(old.fingerprint(), new.fingerprint());
+}
+
+#[test]
+fn fingerprint_depends_on_tick_interval() {
+    let slower = Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .tick_interval(Duration::from_millis(20))
+        .build();
+
+    assert_ne!(protocol().fingerprint(), slower.fingerprint());
+}
+
+#[test]
+fn mismatched_client_is_rejected() {
+    let client_protocol = Protocol::builder()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .tick_interval(Duration::from_millis(10))
+        .build();
+
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client_with_protocol(client_protocol);
+
+    let mut reason = None;
+    scenario.step_until(100, |scenario| {
+        let events = scenario.client_events(client);
+        reason = events.and_then(|events| events.read::<RejectEvent>().next());
+        reason.is_some()
+    });
+    assert_eq!(
+        reason.map(|(_, reason)| reason),
+        Some(RejectReason::ProtocolMismatch)
+    );
+    assert!(!scenario.client(client).is_connected());
+    assert_eq!(scenario.server().users_count(), 0);
+}
+
+fn name_based_protocol(with_inventory: bool) -> Protocol {
+    let mut builder = Protocol::builder();
+    builder
+        .enable_name_based_net_ids()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .tick_interval(Duration::from_millis(10));
+    if with_inventory {
+        builder.add_component::<Inventory>();
+    }
+    builder.build()
+}
+
+#[test]
+fn name_based_fingerprint_ignores_messages_and_components() {
+    assert_eq!(
+        name_based_protocol(true).fingerprint(),
+        name_based_protocol(false).fingerprint()
+    );
+}
+
+#[test]
+fn name_based_client_missing_a_type_connects() {
+    let mut scenario = Scenario::new(|| name_based_protocol(true));
+    scenario.add_client_with_protocol(name_based_protocol(false));
+    scenario.connect_clients(200);
+}
+
+#[test]
+fn name_based_client_disconnects_on_unknown_component() {
+    let mut scenario = Scenario::new(|| name_based_protocol(true));
+    scenario.add_client_with_protocol(name_based_protocol(false));
+    scenario.connect_clients(200);
+
+    let user_key = scenario.user_key(0).unwrap();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_key);
+        server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Inventory::new(4))
+            .enter_room(&room_key);
+    }
+    scenario.include_all_in_scope();
+
+    let mut reason = None;
+    scenario.step_until(200, |scenario| {
+        let events = scenario.client_events(0);
+        reason = events.and_then(|events| events.read::<DisconnectEvent>().next());
+        reason.is_some()
+    });
+    assert_eq!(
+        reason.map(|(_, reason)| reason),
+        Some(DisconnectReason::ProtocolError)
+    );
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use naia_server::MessageEvent;
+use naia_shared::{
+    default_channels::UnorderedReliableChannel, FieldLayout, Message, Protocol, Replicate,
+};
+use naia_test::{Auth, Inventory, Position, Scenario};
+
+#[derive(Message)]
+#[net_id = 500]
+pub struct Ping {
+    pub sequence: u16,
+}
+
+#[derive(Message)]
+#[net_id = 500]
+pub struct Pong {
+    pub sequence: u16,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .enable_name_based_net_ids()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build()
+}
+
+#[test]
+fn name_based_net_ids_ignore_registration_order() {
+    let reordered = Protocol::builder()
+        .add_component::<Inventory>()
+        .add_component::<Position>()
+        .add_message::<Auth>()
+        .add_default_channels()
+        .tick_interval(Duration::from_millis(10))
+        .enable_name_based_net_ids()
+        .build();
+
+    assert_eq!(protocol().schema(), reordered.schema());
+    assert_eq!(protocol().fingerprint(), reordered.fingerprint());
+}
+
+#[test]
+fn explicit_net_id_is_used() {
+    let protocol = Protocol::builder()
+        .enable_name_based_net_ids()
+        .add_message::<Ping>()
+        .build();
+
+    let schema = protocol.schema();
+    let ping = schema
+        .messages
+        .iter()
+        .find(|message| message.name == "Ping")
+        .unwrap();
+    assert_eq!(ping.net_id, 500);
+}
+
+#[test]
+fn explicit_net_id_is_ignored_in_registration_order() {
+    let protocol = Protocol::builder().add_message::<Ping>().build();
+
+    let schema = protocol.schema();
+    assert_eq!(schema.messages.last().unwrap().name, "Ping");
+    assert_eq!(
+        usize::from(schema.messages.last().unwrap().net_id),
+        schema.messages.len() - 1
+    );
+}
+
+#[test]
+#[should_panic]
+fn colliding_net_ids_panic() {
+    Protocol::builder()
+        .enable_name_based_net_ids()
+        .add_message::<Ping>()
+        .add_message::<Pong>();
+}
+
+#[test]
+fn schema_describes_registered_types() {
+    let schema = protocol().schema();
+
+    assert!(schema.name_based_net_ids);
+    assert_eq!(schema.tick_interval, Duration::from_millis(10));
+    assert!(schema
+        .channels
+        .iter()
+        .any(|channel| channel.name == "UnorderedReliableChannel"
+            && channel.mode == "UnorderedReliable"
+            && channel.direction == "Bidirectional"));
+
+    let position = schema
+        .components
+        .iter()
+        .find(|component| component.name == "Position")
+        .unwrap();
+    assert_eq!(position.fields, Position::type_layout().fields);
+    assert_eq!(
+        position.fields,
+        vec![
+            FieldLayout::new("x", "Property<i16>"),
+            FieldLayout::new("y", "Property<i16>"),
+        ]
+    );
+
+    let text = schema.to_string();
+    assert!(text.contains(&format!(
+        "  {} Position\n    x: Property<i16>\n",
+        position.net_id
+    )));
+
+    // NetId order
+    let net_ids: Vec<u16> = schema
+        .messages
+        .iter()
+        .map(|message| message.net_id)
+        .collect();
+    let mut sorted = net_ids.clone();
+    sorted.sort();
+    assert_eq!(net_ids, sorted);
+}
+
+#[test]
+fn added_types_keep_net_ids_and_fingerprint() {
+    let extended = Protocol::builder()
+        .enable_name_based_net_ids()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_message::<Ping>()
+        .add_component::<Position>()
+        .add_component::<Inventory>()
+        .build();
+
+    assert_eq!(protocol().fingerprint(), extended.fingerprint());
+
+    let old = protocol().schema();
+    let new = extended.schema();
+    for component in &old.components {
+        assert!(new.components.contains(component));
+    }
+    for message in &old.messages {
+        assert!(new.messages.contains(message));
+    }
+}
+
+#[test]
+fn name_based_net_ids_connect_and_send() {
+    let mut scenario = Scenario::new(protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+
+    scenario
+        .client_mut(0)
+        .send_message::<UnorderedReliableChannel, _>(&Auth::new("charlie", "1234567"));
+
+    let mut received = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            for (_, auth) in events.read::<MessageEvent<UnorderedReliableChannel, Auth>>() {
+                received = Some(auth.username);
+            }
+        }
+        received.is_some()
+    });
+
+    assert_eq!(received.as_deref(), Some("charlie"));
+}
@@ -0,0 +1,282 @@
+use std::time::Duration;
+
+use naia_client::RequestEvent as ClientRequestEvent;
+use naia_server::RequestEvent as ServerRequestEvent;
+use naia_shared::{
+    default_channels::UnorderedReliableChannel, EntityProperty, Message, Protocol, Request,
+    ResponseError, ResponseHandle,
+};
+use naia_test::{Position, Scenario};
+
+#[derive(Message)]
+pub struct Ask {
+    pub question: String,
+}
+
+#[derive(Message)]
+pub struct Answer {
+    pub answer: u32,
+}
+
+impl Request for Ask {
+    type Response = Answer;
+}
+
+#[derive(Message)]
+pub struct Inspect {
+    pub entity: EntityProperty,
+}
+
+impl Request for Inspect {
+    type Response = Answer;
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Ask>()
+        .add_message::<Answer>()
+        .add_message::<Inspect>()
+        .add_component::<Position>()
+        .build()
+}
+
+fn ask() -> Ask {
+    Ask {
+        question: "life, the universe & everything".to_string(),
+    }
+}
+
+fn connected_scenario(request_timeout: Duration) -> Scenario {
+    let (mut server_config, mut client_config) = Scenario::default_configs();
+    server_config.connection.request_timeout = request_timeout;
+    client_config.connection.request_timeout = request_timeout;
+
+    let mut scenario = Scenario::with_configs(server_config, client_config, protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario until the Client has received the Response to a Request
+fn client_response(
+    scenario: &mut Scenario,
+    handle: &ResponseHandle<Answer>,
+) -> Result<Answer, ResponseError> {
+    let mut outcome = None;
+    scenario.step_until(100, |scenario| {
+        outcome = scenario.client_mut(0).receive_response(handle);
+        outcome.is_some()
+    });
+    outcome.expect("no response received")
+}
+
+#[test]
+fn client_request_is_answered_by_server() {
+    let mut scenario = connected_scenario(Duration::from_secs(10));
+    let user_key = scenario.user_key(0).unwrap();
+
+    let handle = scenario
+        .client_mut(0)
+        .send_request::<UnorderedReliableChannel, Ask>(&ask());
+    assert!(scenario.client_mut(0).receive_response(&handle).is_none());
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            requests.extend(events.read::<ServerRequestEvent<UnorderedReliableChannel, Ask>>());
+        }
+        !requests.is_empty()
+    });
+    assert_eq!(requests.len(), 1);
+    let (request_user_key, response_key, request) = requests.pop().unwrap();
+    assert!(request_user_key == user_key);
+    assert_eq!(request.question, ask().question);
+
+    assert!(scenario
+        .server_mut()
+        .send_response(&response_key, &Answer { answer: 42 }));
+
+    let answer = client_response(&mut scenario, &handle).unwrap();
+    assert_eq!(answer.answer, 42);
+}
+
+#[test]
+fn server_request_is_answered_by_client() {
+    let mut scenario = connected_scenario(Duration::from_secs(10));
+    let user_key = scenario.user_key(0).unwrap();
+
+    let handle = scenario
+        .server_mut()
+        .send_request::<UnorderedReliableChannel, Ask>(&user_key, &ask());
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            requests.extend(events.read::<ClientRequestEvent<UnorderedReliableChannel, Ask>>());
+        }
+        !requests.is_empty()
+    });
+    assert_eq!(requests.len(), 1);
+    let (response_key, _) = requests.pop().unwrap();
+    assert!(scenario
+        .client_mut(0)
+        .send_response(&response_key, &Answer { answer: 7 }));
+
+    let mut outcome = None;
+    scenario.step_until(100, |scenario| {
+        outcome = scenario.server_mut().receive_response(&handle);
+        outcome.is_some()
+    });
+    assert_eq!(outcome.unwrap().unwrap().answer, 7);
+}
+
+#[test]
+fn unanswered_request_times_out() {
+    let mut scenario = connected_scenario(Duration::from_millis(200));
+
+    let handle = scenario
+        .client_mut(0)
+        .send_request::<UnorderedReliableChannel, Ask>(&ask());
+
+    let start_tick = scenario.current_tick();
+    assert_eq!(
+        client_response(&mut scenario, &handle).err(),
+        Some(ResponseError::TimedOut)
+    );
+    // 200ms at 10ms per tick
+    assert!(scenario.current_tick().wrapping_sub(start_tick) >= 20);
+}
+
+#[test]
+fn request_can_only_be_responded_to_once() {
+    let mut scenario = connected_scenario(Duration::from_secs(10));
+
+    scenario
+        .client_mut(0)
+        .send_request::<UnorderedReliableChannel, Ask>(&ask());
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            requests.extend(events.read::<ServerRequestEvent<UnorderedReliableChannel, Ask>>());
+        }
+        !requests.is_empty()
+    });
+    let (_, response_key, _) = requests.pop().unwrap();
+
+    let server = scenario.server_mut();
+    assert!(server.send_response(&response_key, &Answer { answer: 1 }));
+    assert!(!server.send_response(&response_key, &Answer { answer: 2 }));
+}
+
+#[test]
+fn request_to_disconnected_client_fails() {
+    let mut scenario = connected_scenario(Duration::from_secs(10));
+    let user_key = scenario.user_key(0).unwrap();
+
+    let handle = scenario
+        .server_mut()
+        .send_request::<UnorderedReliableChannel, Ask>(&user_key, &ask());
+    scenario.client_mut(0).disconnect();
+    assert!(scenario.step_until(100, |scenario| scenario.server().users_count() == 0));
+
+    assert_eq!(
+        scenario
+            .server_mut()
+            .receive_response(&handle)
+            .map(|outcome| outcome.err()),
+        Some(Some(ResponseError::Disconnected))
+    );
+}
+
+#[test]
+fn request_waits_for_the_entities_it_refers_to() {
+    let mut scenario = connected_scenario(Duration::from_secs(10));
+    let user_key = scenario.user_key(0).unwrap();
+
+    // the Request is sent in the same packet as the Entity's spawn, and
+    // Messages are read before Entities
+    let server_entity = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        let entity = server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(0, 0))
+            .enter_room(&room_key)
+            .id();
+        server.room_mut(&room_key).add_user(&user_key);
+        entity
+    };
+    scenario.include_all_in_scope();
+    let mut inspect = Inspect {
+        entity: EntityProperty::new(),
+    };
+    inspect.entity.set(scenario.server(), &server_entity);
+    scenario
+        .server_mut()
+        .send_request::<UnorderedReliableChannel, Inspect>(&user_key, &inspect);
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.client_events(0) {
+            requests.extend(events.read::<ClientRequestEvent<UnorderedReliableChannel, Inspect>>());
+        }
+        !requests.is_empty()
+    });
+    assert_eq!(requests.len(), 1);
+    let (_, request) = requests.pop().unwrap();
+
+    let client_entity = scenario.client_entity(0, &server_entity);
+    assert!(client_entity.is_some());
+    assert!(request.entity.get(scenario.client(0)) == client_entity);
+}
+
+#[test]
+fn requests_beyond_the_limit_are_dropped() {
+    let (mut server_config, client_config) = Scenario::default_configs();
+    server_config.connection.max_incoming_requests = 2;
+    let mut scenario = Scenario::with_configs(server_config, client_config, protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+
+    for _ in 0..3 {
+        scenario
+            .client_mut(0)
+            .send_request::<UnorderedReliableChannel, Ask>(&ask());
+    }
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            requests.extend(events.read::<ServerRequestEvent<UnorderedReliableChannel, Ask>>());
+        }
+        requests.len() > 2
+    });
+    assert_eq!(requests.len(), 2);
+}
+
+#[test]
+fn unanswered_incoming_request_expires() {
+    let mut scenario = connected_scenario(Duration::from_millis(200));
+
+    scenario
+        .client_mut(0)
+        .send_request::<UnorderedReliableChannel, Ask>(&ask());
+
+    let mut requests = Vec::new();
+    scenario.step_until(100, |scenario| {
+        if let Some(events) = scenario.server_events() {
+            requests.extend(events.read::<ServerRequestEvent<UnorderedReliableChannel, Ask>>());
+        }
+        !requests.is_empty()
+    });
+    let (_, response_key, _) = requests.pop().unwrap();
+
+    // 200ms at 10ms per tick
+    scenario.step_ticks(30);
+    assert!(!scenario
+        .server_mut()
+        .send_response(&response_key, &Answer { answer: 1 }));
+}
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use naia_demo_world::WorldRefType;
+use naia_server::DisconnectEvent;
+use naia_shared::{Clock, DisconnectReason, Protocol};
+use naia_test::{Position, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .add_component::<Position>()
+        .build()
+}
+
+#[test]
+fn entity_replicates_to_clients_in_room() {
+    let mut scenario = Scenario::new(protocol);
+    let client_a = scenario.add_client();
+    let client_b = scenario.add_client();
+    scenario.connect_clients(200);
+
+    let (server, world) = scenario.server_and_world_mut();
+    let entity = server
+        .spawn_entity(world.proxy_mut())
+        .insert_component(Position::new(1, 2))
+        .id();
+    let room_key = server.make_room().key();
+    server.room_mut(&room_key).add_entity(&entity);
+
+    let user_a = scenario.user_key(client_a).unwrap();
+    let user_b = scenario.user_key(client_b).unwrap();
+    scenario.server_mut().room_mut(&room_key).add_user(&user_a);
+    scenario.server_mut().room_mut(&room_key).add_user(&user_b);
+    scenario.include_all_in_scope();
+
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let entity_a = scenario.expect_visible::<Position>(client_a, &entity, by_tick);
+    scenario.expect_visible::<Position>(client_b, &entity, by_tick);
+
+    // update propagates, once the insert has been acknowledged
+    scenario.step_ticks(5);
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        let mut entity_mut = server.entity_mut(world.proxy_mut(), &entity);
+        let mut position = entity_mut.component::<Position>().unwrap();
+        *position.x = 7;
+    }
+    let updated = scenario.step_until(50, |scenario| {
+        scenario
+            .client_world(client_a)
+            .proxy()
+            .component::<Position>(&entity_a)
+            .map(|position| *position.x == 7)
+            .unwrap_or(false)
+    });
+    assert!(updated, "Position update never arrived at Client");
+
+    // leaving scope despawns
+    scenario.server_mut().user_scope(&user_a).exclude(&entity);
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    scenario.expect_despawned(client_a, &entity_a, by_tick);
+}
+
+#[test]
+fn rtt_is_measured_under_the_manual_clock() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+
+    // every handshake pong has the same rtt & offset under the manual clock
+    assert!(scenario.client(client).rtt().is_finite());
+    assert!(scenario.client(client).jitter().is_finite());
+}
+
+#[test]
+fn idle_connection_times_out() {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+
+    // default disconnection timeout is 30 seconds
+    Clock::advance(Duration::from_secs(31));
+    scenario.step();
+
+    let disconnected: Vec<_> = scenario
+        .server_events()
+        .unwrap()
+        .read::<DisconnectEvent>()
+        .map(|(user_key, _, reason)| (user_key, reason))
+        .collect();
+    assert!(disconnected == vec![(user_key, DisconnectReason::Timeout)]);
+    assert!(!scenario.client(client).is_connected());
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use naia_client::{QueuePositionEvent, RejectEvent};
+use naia_shared::{Protocol, RejectReason};
+use naia_test::Scenario;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .build()
+}
+
+// A Scenario with one connected Client, on a Server which allows only one User
+fn full_scenario(join_queue: bool) -> Scenario {
+    let (mut server_config, client_config) = Scenario::default_configs();
+    server_config.max_users = Some(1);
+    server_config.join_queue = join_queue;
+
+    let mut scenario = Scenario::with_configs(server_config, client_config, protocol);
+    scenario.add_client();
+    scenario.connect_clients(200);
+    scenario
+}
+
+// Steps the Scenario until the given Client receives its position in the join
+// queue, returning it
+fn queue_position(scenario: &mut Scenario, index: usize) -> Option<u32> {
+    let mut position = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(index) {
+            if let Some(new_position) = events.read::<QueuePositionEvent>().last() {
+                position = Some(new_position);
+            }
+        }
+        position.is_some()
+    });
+    position
+}
+
+#[test]
+fn full_server_rejects_client() {
+    let mut scenario = full_scenario(false);
+    scenario.add_client();
+
+    let mut reason = None;
+    scenario.step_until(50, |scenario| {
+        if let Some(events) = scenario.client_events(1) {
+            if let Some((_, reject_reason)) = events.read::<RejectEvent>().next() {
+                reason = Some(reject_reason);
+            }
+        }
+        reason.is_some()
+    });
+
+    assert_eq!(reason, Some(RejectReason::ServerFull));
+    assert!(!scenario.client(1).is_connected());
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn queued_client_connects_when_slot_frees() {
+    let mut scenario = full_scenario(true);
+    scenario.add_client();
+
+    assert_eq!(queue_position(&mut scenario, 1), Some(1));
+    assert_eq!(scenario.server().queued_users_count(), 1);
+    scenario.step_ticks(10);
+    assert!(!scenario.client(1).is_connected());
+
+    scenario.client_mut(0).disconnect();
+
+    assert!(scenario.step_until(50, |scenario| scenario.client(1).is_connected()));
+    assert_eq!(scenario.server().queued_users_count(), 0);
+    assert_eq!(scenario.server().users_count(), 1);
+}
+
+#[test]
+fn join_queue_is_first_in_first_out() {
+    let mut scenario = full_scenario(true);
+    scenario.add_client();
+    assert_eq!(queue_position(&mut scenario, 1), Some(1));
+    scenario.add_client();
+    assert_eq!(queue_position(&mut scenario, 2), Some(2));
+
+    scenario.client_mut(0).disconnect();
+
+    assert_eq!(queue_position(&mut scenario, 2), Some(1));
+    assert!(scenario.client(1).is_connected());
+    assert!(!scenario.client(2).is_connected());
+    assert_eq!(scenario.server().queued_users_count(), 1);
+}
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use naia_demo_world::{Entity, WorldRefType};
+use naia_shared::{ComponentSettings, Protocol};
+use naia_test::{Position, Scenario};
+
+const TICKS: i16 = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(5))
+        .add_default_channels()
+        .add_component_with_settings::<Position>(ComponentSettings::new(Duration::from_millis(50)))
+        .build()
+}
+
+/// Sets up a Scenario with a single connected Client, and an Entity visible
+/// to that Client. Returns the Server & Client Entities.
+fn setup() -> (Scenario, Entity, Entity) {
+    let mut scenario = Scenario::new(protocol);
+    let client = scenario.add_client();
+    scenario.connect_clients(200);
+    let user_key = scenario.user_key(client).unwrap();
+
+    let server_entity = {
+        let (server, world) = scenario.server_and_world_mut();
+        let room_key = server.make_room().key();
+        server.room_mut(&room_key).add_user(&user_key);
+        server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new(0, 0))
+            .enter_room(&room_key)
+            .id()
+    };
+    scenario.include_all_in_scope();
+
+    let by_tick = scenario.current_tick().wrapping_add(50);
+    let client_entity = scenario.expect_visible::<Position>(client, &server_entity, by_tick);
+
+    // let the insert be acknowledged, so that updates can be sent
+    scenario.step_ticks(5);
+
+    (scenario, server_entity, client_entity)
+}
+
+fn set_x(scenario: &mut Scenario, entity: &Entity, x: i16) {
+    let (server, world) = scenario.server_and_world_mut();
+    let mut entity_mut = server.entity_mut(world.proxy_mut(), entity);
+    *entity_mut.component::<Position>().unwrap().x = x;
+}
+
+fn client_x(scenario: &Scenario, entity: &Entity) -> i16 {
+    *scenario
+        .client_world(0)
+        .proxy()
+        .component::<Position>(entity)
+        .unwrap()
+        .x
+}
+
+/// Changes the Entity's Position every tick, and returns how many distinct
+/// values the Client saw, once it has caught up with the final value
+fn count_client_updates(
+    scenario: &mut Scenario,
+    server_entity: &Entity,
+    client_entity: &Entity,
+) -> usize {
+    let mut updates = 0;
+    let mut last_x = client_x(scenario, client_entity);
+    for x in 1..=TICKS {
+        set_x(scenario, server_entity, x);
+        scenario.step();
+
+        let next_x = client_x(scenario, client_entity);
+        if next_x != last_x {
+            updates += 1;
+            last_x = next_x;
+        }
+    }
+
+    let caught_up = scenario.step_until(50, |scenario| client_x(scenario, client_entity) == TICKS);
+    assert!(caught_up, "Final update was never received");
+
+    updates
+}
+
+#[test]
+fn component_update_interval_limits_updates() {
+    let (mut scenario, server_entity, client_entity) = setup();
+
+    let updates = count_client_updates(&mut scenario, &server_entity, &client_entity);
+
+    // 100 ticks of 5ms, with at most one update every 50ms
+    assert!(
+        updates <= (TICKS as usize) / 10 + 1,
+        "Received {updates} updates"
+    );
+    assert!(updates > 1, "Received {updates} updates");
+}
+
+#[test]
+fn entity_update_interval_overrides_component() {
+    let (mut scenario, server_entity, client_entity) = setup();
+    {
+        let (server, world) = scenario.server_and_world_mut();
+        server
+            .entity_mut(world.proxy_mut(), &server_entity)
+            .set_update_interval(Duration::ZERO);
+    }
+
+    let updates = count_client_updates(&mut scenario, &server_entity, &client_entity);
+
+    assert!(updates > (TICKS as usize) / 2, "Received {updates} updates");
+}